@@ -0,0 +1,112 @@
+// `name:version` followed by `name:<major>.<minor>`, `name:<major>` and `name:latest`, so
+// consumers can track a release line instead of an exact version. Versions that do not start with
+// a numeric `<major>.<minor>` (date snapshots, commit hashes) only get `name:latest`. The exact
+// alias stays first, which is the one `graph` and `sbom` read the version from.
+pub fn versioned(name: &str, version: &str) -> Vec<String> {
+    let mut aliases = vec![format!("{name}:{version}")];
+
+    if let Some((major, minor)) = release_line(version) {
+        let line = format!("{major}.{minor}");
+
+        if line != version {
+            aliases.push(format!("{name}:{line}"));
+        }
+
+        aliases.push(format!("{name}:{major}"));
+    }
+
+    aliases.push(format!("{name}:latest"));
+
+    aliases
+}
+
+// Aliases for a version pinned next to the default one: the exact version and its
+// `<major>.<minor>` line only, so several pinned versions (`terraform:1.5`, `terraform:1.13`)
+// coexist in one config without competing for `name:<major>` or `name:latest`.
+pub fn pinned(name: &str, version: &str) -> Vec<String> {
+    let mut aliases = vec![format!("{name}:{version}")];
+
+    if let Some((major, minor)) = release_line(version) {
+        let line = format!("{major}.{minor}");
+
+        if line != version {
+            aliases.push(format!("{name}:{line}"));
+        }
+    }
+
+    aliases
+}
+
+// `versioned` for the artifact's default version, `pinned` for any other.
+pub fn for_version(name: &str, version: &str, default: &str) -> Vec<String> {
+    match version == default {
+        true => versioned(name, version),
+        false => pinned(name, version),
+    }
+}
+
+// Leading numeric component, e.g. `1` for `1.5.7`; `None` for date snapshots and commit hashes.
+pub fn major(version: &str) -> Option<&str> {
+    release_line(version).map(|(major, _)| major)
+}
+
+fn release_line(version: &str) -> Option<(&str, &str)> {
+    let (major, rest) = version.split_once('.')?;
+
+    let end = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+
+    let minor = &rest[..end];
+
+    let is_numeric = |value: &str| !value.is_empty() && value.chars().all(|c| c.is_ascii_digit());
+
+    (is_numeric(major) && is_numeric(minor)).then_some((major, minor))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versioned_adds_release_lines() {
+        assert_eq!(
+            versioned("zlib", "1.3.1"),
+            ["zlib:1.3.1", "zlib:1.3", "zlib:1", "zlib:latest"]
+        );
+        assert_eq!(
+            versioned("icu", "77.1"),
+            ["icu:77.1", "icu:77", "icu:latest"]
+        );
+        assert_eq!(
+            versioned("ncurses", "6.5-20250809"),
+            [
+                "ncurses:6.5-20250809",
+                "ncurses:6.5",
+                "ncurses:6",
+                "ncurses:latest"
+            ]
+        );
+    }
+
+    #[test]
+    fn for_version_pins_other_versions() {
+        assert_eq!(
+            for_version("terraform", "1.5.7", "1.13.1"),
+            ["terraform:1.5.7", "terraform:1.5"]
+        );
+        assert_eq!(
+            for_version("terraform", "1.13.1", "1.13.1"),
+            versioned("terraform", "1.13.1")
+        );
+    }
+
+    #[test]
+    fn versioned_skips_non_numeric_versions() {
+        assert_eq!(
+            versioned("x264", "20191217-2245"),
+            ["x264:20191217-2245", "x264:latest"]
+        );
+        assert_eq!(versioned("tool", "v2"), ["tool:v2", "tool:latest"]);
+    }
+}
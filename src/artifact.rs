@@ -1,83 +1,337 @@
+use crate::{
+    metadata::{self, Metadata},
+    upstream::{self, Upstream},
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{api::artifact::ArtifactSystem, context::ConfigContext};
+
+#[cfg(feature = "library")]
+pub mod abseil_cpp;
+#[cfg(feature = "terminal")]
 pub mod abtop;
+#[cfg(feature = "cloud")]
+pub mod ansible;
+#[cfg(feature = "kubernetes")]
 pub mod argocd;
+#[cfg(feature = "database")]
+pub mod atlas;
+#[cfg(feature = "cloud")]
 pub mod awscli2;
+#[cfg(feature = "terminal")]
+pub mod b3sum;
+#[cfg(feature = "language-server")]
 pub mod bash_language_server;
+#[cfg(feature = "terminal")]
 pub mod bat;
+#[cfg(feature = "development")]
 pub mod beads;
+#[cfg(feature = "terminal")]
 pub mod bottom;
+#[cfg(feature = "container")]
+pub mod bubblewrap;
+#[cfg(feature = "library")]
+pub mod c_ares;
+#[cfg(feature = "library")]
+pub mod capnproto;
+#[cfg(feature = "build-tools")]
+pub mod ccache;
+#[cfg(feature = "network")]
+pub mod cfssl;
+#[cfg(feature = "development")]
+pub mod chezmoi;
+#[cfg(feature = "build-tools")]
 pub mod cmake;
+#[cfg(feature = "container")]
 pub mod crane;
+#[cfg(feature = "development")]
 pub mod cue;
+#[cfg(feature = "database")]
+pub mod dbmate;
+#[cfg(feature = "terminal")]
 pub mod delta;
+#[cfg(feature = "terminal")]
 pub mod direnv;
+#[cfg(feature = "language-server")]
 pub mod dockerfile_language_server;
+#[cfg(feature = "network")]
+pub mod doggo;
+#[cfg(feature = "cloud")]
 pub mod doppler;
+#[cfg(feature = "container")]
+pub mod dumb_init;
+#[cfg(feature = "kubernetes")]
 pub mod eksctl;
+#[cfg(feature = "terminal")]
+pub mod fastfetch;
+#[cfg(feature = "terminal")]
 pub mod fd;
+#[cfg(feature = "media")]
 pub mod ffmpeg;
 pub mod file;
+#[cfg(feature = "build-tools")]
+pub mod flatbuffers;
+#[cfg(feature = "build-tools")]
+pub mod flex;
+#[cfg(feature = "kubernetes")]
 pub mod fluxcd;
+#[cfg(feature = "database")]
+pub mod flyway;
+#[cfg(feature = "library")]
+pub mod font_stack;
+#[cfg(feature = "library")]
+pub mod fontconfig;
+#[cfg(feature = "library")]
+pub mod freetype;
+#[cfg(feature = "terminal")]
+pub mod fx;
+#[cfg(feature = "terminal")]
 pub mod fzf;
+#[cfg(feature = "development")]
+pub mod gh_actions_tool_cache;
+#[cfg(feature = "development")]
+pub mod ghz;
+#[cfg(feature = "library")]
+pub mod giflib;
+#[cfg(feature = "development")]
+pub mod git_absorb;
+#[cfg(feature = "terminal")]
 pub mod glow;
+#[cfg(feature = "development")]
 pub mod golangci_lint;
+#[cfg(feature = "gnupg")]
 pub mod gpg;
+#[cfg(feature = "terminal")]
+pub mod gron;
+#[cfg(feature = "container")]
+pub mod grpc_health_probe;
+#[cfg(feature = "terminal")]
 pub mod gum;
+#[cfg(feature = "network")]
+pub mod haproxy;
+#[cfg(feature = "library")]
+pub mod harfbuzz;
+#[cfg(feature = "kubernetes")]
 pub mod helm;
+#[cfg(feature = "development")]
 pub mod herdr;
+#[cfg(feature = "terminal")]
+pub mod hexyl;
+#[cfg(feature = "development")]
 pub mod hunk;
+#[cfg(feature = "library")]
+pub mod icu;
+#[cfg(feature = "library")]
+pub mod image_libs;
+#[cfg(feature = "development")]
+pub mod jbang;
+#[cfg(feature = "development")]
 pub mod jj;
+#[cfg(feature = "terminal")]
+pub mod jless;
+#[cfg(feature = "terminal")]
 pub mod jq;
+#[cfg(feature = "library")]
 pub mod json_c;
+#[cfg(feature = "build-tools")]
 pub mod just;
+#[cfg(feature = "kubernetes")]
 pub mod k9s;
+#[cfg(feature = "kubernetes")]
 pub mod kind;
+#[cfg(feature = "kubernetes")]
 pub mod kn;
+#[cfg(feature = "kubernetes")]
 pub mod kubectl;
+#[cfg(feature = "kubernetes")]
 pub mod kubeseal;
+#[cfg(feature = "development")]
 pub mod lazygit;
+#[cfg(feature = "gnupg")]
 pub mod libassuan;
+#[cfg(feature = "library")]
+pub mod libcap;
+#[cfg(feature = "library")]
 pub mod libevent;
+#[cfg(feature = "library")]
+pub mod libffi;
+#[cfg(feature = "gnupg")]
 pub mod libgcrypt;
+#[cfg(feature = "gnupg")]
 pub mod libgpg_error;
+#[cfg(feature = "library")]
+pub mod libjpeg_turbo;
+#[cfg(feature = "gnupg")]
 pub mod libksba;
+#[cfg(feature = "library")]
+pub mod libpcap;
+#[cfg(feature = "library")]
+pub mod libpng;
+#[cfg(feature = "library")]
+pub mod libssh2;
+#[cfg(feature = "library")]
 pub mod libuv;
+#[cfg(feature = "library")]
+pub mod libwebp;
+#[cfg(feature = "library")]
 pub mod libwebsockets;
+#[cfg(feature = "library")]
+pub mod libxml2;
+#[cfg(feature = "library")]
+pub mod libxslt;
+#[cfg(feature = "library")]
+pub mod libyaml;
+#[cfg(feature = "development")]
 pub mod lima;
+#[cfg(feature = "language-server")]
 pub mod lua_language_server;
+#[cfg(feature = "library")]
 pub mod mbedtls;
+#[cfg(feature = "cloud")]
+pub mod mc;
+#[cfg(feature = "terminal")]
+pub mod minisign;
+#[cfg(feature = "database")]
+pub mod mongosh;
+#[cfg(feature = "network")]
+pub mod mtr;
+pub mod musl;
+#[cfg(feature = "terminal")]
+pub mod ncdu;
+#[cfg(feature = "library")]
 pub mod ncurses;
+#[cfg(feature = "development")]
 pub mod neovim;
+#[cfg(feature = "library")]
+pub mod nghttp2;
+#[cfg(feature = "network")]
 pub mod nginx;
+#[cfg(feature = "network")]
+pub mod nmap;
+#[cfg(feature = "terminal")]
 pub mod nnn;
+#[cfg(feature = "development")]
+pub mod node_matrix;
+#[cfg(feature = "gnupg")]
 pub mod npth;
+#[cfg(feature = "library")]
+pub mod oniguruma;
+#[cfg(feature = "cloud")]
 pub mod op;
+#[cfg(feature = "development")]
 pub mod openapi_generator_cli;
+#[cfg(feature = "development")]
 pub mod opencode;
+#[cfg(feature = "development")]
 pub mod openjdk;
+#[cfg(feature = "library")]
+pub mod openssl;
+#[cfg(feature = "build-tools")]
+pub mod patchelf;
+#[cfg(feature = "library")]
+pub mod pcre2;
+#[cfg(feature = "development")]
 pub mod pi;
+#[cfg(feature = "build-tools")]
 pub mod pkg_config;
+#[cfg(feature = "development")]
+pub mod pre_commit;
+#[cfg(feature = "library")]
+pub mod protobuf;
+#[cfg(feature = "development")]
+pub mod protoc_gen_grpc_web;
+#[cfg(feature = "development")]
+pub mod protoc_plugins;
+#[cfg(feature = "terminal")]
+pub mod qsv;
+#[cfg(feature = "library")]
 pub mod readline;
+#[cfg(feature = "terminal")]
 pub mod ripgrep;
+#[cfg(feature = "cloud")]
+pub mod s5cmd;
+#[cfg(feature = "build-tools")]
 pub mod sccache;
+#[cfg(feature = "terminal")]
 pub mod sesh;
+#[cfg(feature = "container")]
 pub mod skopeo;
+#[cfg(feature = "database")]
 pub mod sqlite3;
+#[cfg(feature = "terminal")]
 pub mod starship;
+#[cfg(feature = "cloud")]
+pub mod steampipe;
+#[cfg(feature = "development")]
+pub mod stow;
+#[cfg(feature = "kubernetes")]
 pub mod talosctl;
+#[cfg(feature = "network")]
+pub mod tcpdump;
+#[cfg(feature = "cloud")]
 pub mod terraform;
+#[cfg(feature = "language-server")]
 pub mod terraform_ls;
+#[cfg(feature = "container")]
+pub mod tini;
+#[cfg(feature = "terminal")]
 pub mod tmux;
+#[cfg(feature = "development")]
 pub mod tree_sitter;
+#[cfg(feature = "terminal")]
 pub mod ttyd;
+#[cfg(feature = "development")]
 pub mod typescript;
+#[cfg(feature = "language-server")]
 pub mod typescript_language_server;
+#[cfg(feature = "container")]
 pub mod umoci;
+#[cfg(feature = "development")]
+pub mod universal_ctags;
+#[cfg(feature = "build-tools")]
+pub mod upx;
+#[cfg(feature = "development")]
 pub mod uv;
+#[cfg(feature = "terminal")]
 pub mod vhs;
+#[cfg(feature = "kubernetes")]
 pub mod virtctl;
+#[cfg(feature = "language-server")]
 pub mod vscode_langservers_extracted;
+#[cfg(feature = "network")]
+pub mod wireguard_go;
+#[cfg(feature = "network")]
+pub mod wireguard_tools;
+#[cfg(feature = "media")]
 pub mod x264;
+#[cfg(feature = "language-server")]
 pub mod yaml_language_server;
+#[cfg(feature = "terminal")]
 pub mod yq;
+pub mod zig;
+pub mod zig_cc;
+#[cfg(feature = "library")]
+pub mod zlib;
+#[cfg(feature = "terminal")]
 pub mod zoxide;
+#[cfg(feature = "terminal")]
 pub mod zsh;
+
+#[async_trait]
+pub trait ArtifactPackage: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn version(&self) -> &str;
+
+    fn systems(&self) -> Vec<ArtifactSystem>;
+
+    fn metadata(&self) -> Option<&'static Metadata> {
+        metadata::get(self.name())
+    }
+
+    fn upstream(&self) -> Option<Upstream> {
+        upstream::get(self.name())
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String>;
+}
@@ -0,0 +1,89 @@
+use crate::{
+    artifact::{cmake, ArtifactPackage},
+    builder::cmake::CmakePackage,
+    context_cache, overrides, source, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct AbseilCpp<'a> {
+    cmake: Option<&'a str>,
+    version: Option<&'a str>,
+}
+
+impl<'a> AbseilCpp<'a> {
+    pub fn new() -> Self {
+        Self {
+            cmake: None,
+            version: None,
+        }
+    }
+
+    pub fn with_cmake(mut self, cmake: &'a str) -> Self {
+        self.cmake = Some(cmake);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for AbseilCpp<'a> {
+    fn name(&self) -> &str {
+        "abseil-cpp"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::ABSEIL_CPP))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let cmake = match self.cmake {
+            Some(val) => val,
+            None => &context_cache::build(context, &cmake::Cmake::new()).await?,
+        };
+
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!(
+            "https://github.com/abseil/abseil-cpp/releases/download/{version}/abseil-cpp-{version}.tar.gz"
+        );
+
+        let source = source::new(name, &path);
+
+        let source_directory = format!("{name}-{version}");
+
+        // Abseil's ABI depends on the C++ standard it was compiled with (its `absl::` types alias
+        // the `std::` ones from C++17 on), so the standard is pinned and exported to consumers.
+        // Position-independent code lets the static archives link into shared libraries.
+        CmakePackage::new(name, version, source, self.systems())
+            .with_cmake(cmake)
+            .with_definition("ABSL_BUILD_TESTING=OFF")
+            .with_definition("ABSL_PROPAGATE_CXX_STD=ON")
+            .with_definition("BUILD_SHARED_LIBS=OFF")
+            .with_definition("BUILD_TESTING=OFF")
+            .with_definition("CMAKE_CXX_STANDARD=17")
+            .with_definition("CMAKE_INSTALL_LIBDIR=lib")
+            .with_definition("CMAKE_POSITION_INDEPENDENT_CODE=ON")
+            .with_source_directory(&source_directory)
+            .build(context)
+            .await
+    }
+}
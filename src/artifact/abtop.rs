@@ -1,52 +1,17 @@
-use anyhow::Result;
-use indoc::formatdoc;
-use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
-    context::ConfigContext,
-};
-
-#[derive(Default)]
-pub struct Abtop;
-
-impl Abtop {
-    pub fn new() -> Self {
-        Self
-    }
-
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "abtop";
-        let source_version = "0.5.1";
-
-        let source_system = match context.get_system() {
+use crate::{binary_artifact, versions};
+
+binary_artifact! {
+    Abtop {
+        name: "abtop",
+        version: versions::ABTOP,
+        repository: "graykode/abtop",
+        asset: "abtop-{target}.tar.xz",
+        binaries: ["abtop-{target}/abtop" => "abtop"],
+        targets: {
             Aarch64Darwin => "aarch64-apple-darwin",
             Aarch64Linux => "aarch64-unknown-linux-gnu",
             X8664Darwin => "x86_64-apple-darwin",
             X8664Linux => "x86_64-unknown-linux-gnu",
-            _ => return Err(anyhow::anyhow!("Unsupported system for abtop artifact")),
-        };
-
-        let source_path = format!(
-            "https://github.com/graykode/abtop/releases/download/v{source_version}/abtop-{source_system}.tar.xz"
-        );
-
-        let source = ArtifactSource::new(name, &source_path).build();
-
-        let step_script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT/bin\"
-            pushd ./source/{name}/abtop-{source_system}
-            cp abtop \"$VORPAL_OUTPUT/bin/abtop\"
-            chmod +x \"$VORPAL_OUTPUT/bin/abtop\"",
-        };
-
-        let steps = vec![step::shell(context, vec![], vec![], step_script, vec![]).await?];
-
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
-
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{source_version}")])
-            .with_sources(vec![source])
-            .build(context)
-            .await
+        },
     }
 }
@@ -0,0 +1,232 @@
+use crate::{
+    artifact::{uv::Uv, ArtifactPackage},
+    builder::wrapper,
+    context_cache, overrides, source, versions,
+};
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::{cpython::Cpython, get_env_key, step, Artifact},
+    context::ConfigContext,
+};
+
+// Entry points of ansible-core and the `ansible.cli` module each one runs.
+const TOOLS: &[(&str, &str)] = &[
+    ("ansible", "adhoc"),
+    ("ansible-config", "config"),
+    ("ansible-console", "console"),
+    ("ansible-doc", "doc"),
+    ("ansible-galaxy", "galaxy"),
+    ("ansible-inventory", "inventory"),
+    ("ansible-playbook", "playbook"),
+    ("ansible-pull", "pull"),
+    ("ansible-vault", "vault"),
+];
+
+#[derive(Default)]
+pub struct Ansible<'a> {
+    collections: Vec<&'a str>,
+    python: Option<&'a str>,
+    uv: Option<&'a str>,
+    version: Option<&'a str>,
+}
+
+impl<'a> Ansible<'a> {
+    pub fn new() -> Self {
+        Self {
+            collections: vec![],
+            python: None,
+            uv: None,
+            version: None,
+        }
+    }
+
+    // Galaxy collections vendored into the artifact, each as `namespace.name:version`, e.g.
+    // `community.general:11.4.0`.
+    pub fn with_collections(mut self, collections: Vec<&'a str>) -> Self {
+        self.collections.extend(collections);
+        self
+    }
+
+    pub fn with_python(mut self, python: &'a str) -> Self {
+        self.python = Some(python);
+        self
+    }
+
+    pub fn with_uv(mut self, uv: &'a str) -> Self {
+        self.uv = Some(uv);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Ansible<'a> {
+    fn name(&self) -> &str {
+        "ansible"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::ANSIBLE))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let python = match self.python {
+            Some(val) => val,
+            None => &Cpython::new().build(context).await?,
+        };
+
+        let uv = match self.uv {
+            Some(val) => val,
+            None => &context_cache::build(context, &Uv::new()).await?,
+        };
+
+        let name = self.name();
+        let version = self.version();
+
+        let source_path = format!(
+            "https://files.pythonhosted.org/packages/source/a/ansible-core/ansible_core-{version}.tar.gz"
+        );
+
+        let mut sources = vec![source::new(name, &source_path)];
+
+        let mut collections_script = vec![];
+
+        // Galaxy artifacts unpack to the installed layout, so vendoring a collection is a copy
+        // into `ansible_collections/<namespace>/<name>` and needs no galaxy server at build time.
+        for collection in self.collections.iter() {
+            let Some((namespace, collection_name, collection_version)) =
+                collection.split_once(':').and_then(|(id, version)| {
+                    let (namespace, name) = id.split_once('.')?;
+
+                    Some((namespace, name, version))
+                })
+            else {
+                bail!("collection '{collection}' is not 'namespace.name:version'");
+            };
+
+            let source_name = format!("{namespace}-{collection_name}");
+
+            let path = format!(
+                "https://galaxy.ansible.com/api/v3/plugin/ansible/content/published/collections/artifacts/{source_name}-{collection_version}.tar.gz"
+            );
+
+            sources.push(source::new(&source_name, &path));
+
+            collections_script.push(formatdoc! {"
+                mkdir -pv \"$VORPAL_OUTPUT/share/ansible/collections/ansible_collections/{namespace}\"
+                cp -pR ./source/{source_name} \"$VORPAL_OUTPUT/share/ansible/collections/ansible_collections/{namespace}/{collection_name}\"",
+            });
+        }
+
+        let collections_script = collections_script.join("\n\n");
+
+        let env_python = get_env_key(&python.to_string());
+        let env_uv = get_env_key(&uv.to_string());
+
+        // Console scripts in the venv would point at the build's output path, so the wrappers run
+        // the CLI modules instead. Vendored collections come before the user's, so the pins win.
+        let wrapper_script = TOOLS
+            .iter()
+            .map(|(tool, module)| {
+                wrapper::wrap_binary(
+                    &format!("$VORPAL_OUTPUT/bin/{tool}"),
+                    &format!("\"$VORPAL_OUTPUT/lib/{name}/bin/python\" -m ansible.cli.{module}"),
+                    &[],
+                    &[(
+                        "ANSIBLE_COLLECTIONS_PATH",
+                        "$VORPAL_OUTPUT/share/ansible/collections:\\${ANSIBLE_COLLECTIONS_PATH:-\\$HOME/.ansible/collections:/usr/share/ansible/collections}".to_string(),
+                    )],
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        let step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT/bin\" \"$VORPAL_OUTPUT/share/ansible/collections\"
+
+            {env_uv}/bin/uv venv --python \"{env_python}/bin/python3\" \"$VORPAL_OUTPUT/lib/{name}\"
+
+            {env_uv}/bin/uv pip install \\
+                --python \"$VORPAL_OUTPUT/lib/{name}/bin/python\" \\
+                ./source/{name}/ansible_core-{version}
+
+            {collections_script}
+
+            {wrapper_script}"
+        };
+
+        let mut step_environments = vec!["UV_CACHE_DIR=$VORPAL_WORKSPACE/uv".to_string()];
+
+        if let Some(index) = source::mirrored(source::PYPI_INDEX) {
+            step_environments.push(format!("UV_DEFAULT_INDEX={index}"));
+        }
+
+        let steps = vec![
+            step::shell(
+                context,
+                vec![python.to_string(), uv.to_string()],
+                step_environments,
+                step_script,
+                vec![],
+            )
+            .await?,
+        ];
+
+        let systems = self.systems();
+
+        Artifact::new(name, steps, systems)
+            .with_aliases(vec![format!("{name}:{version}")])
+            .with_sources(sources)
+            .build(context)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, MockContext};
+
+    #[tokio::test]
+    async fn build_vendors_collections() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Linux).build().await?;
+
+        let ansible = Ansible::new()
+            .with_collections(vec!["community.general:11.4.0"])
+            .build(context)
+            .await?;
+
+        assert_eq!(
+            testing::source_paths(context, &ansible)?,
+            [
+                format!(
+                    "https://files.pythonhosted.org/packages/source/a/ansible-core/ansible_core-{}.tar.gz",
+                    versions::ANSIBLE
+                ),
+                "https://galaxy.ansible.com/api/v3/plugin/ansible/content/published/collections/artifacts/community-general-11.4.0.tar.gz".to_string(),
+            ]
+        );
+        assert!(Ansible::new()
+            .with_collections(vec!["community.general"])
+            .build(context)
+            .await
+            .is_err());
+
+        Ok(())
+    }
+}
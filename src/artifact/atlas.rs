@@ -0,0 +1,122 @@
+use crate::{
+    artifact::ArtifactPackage, builder::check, error::ArtifactError, overrides, source, target,
+    versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct Atlas<'a> {
+    check: Option<bool>,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
+
+impl<'a> Atlas<'a> {
+    pub fn new() -> Self {
+        Self {
+            check: None,
+            target: None,
+            version: None,
+        }
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Atlas<'a> {
+    fn name(&self) -> &str {
+        "atlas"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::ATLAS))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let name = self.name();
+        let version = self.version();
+
+        let source_system = match system {
+            Aarch64Darwin => "darwin-arm64",
+            Aarch64Linux => "linux-arm64",
+            X8664Darwin => "darwin-amd64",
+            X8664Linux => "linux-amd64",
+            system => {
+                return Err(ArtifactError::UnsupportedSystem {
+                    artifact: name.to_string(),
+                    system,
+                }
+                .into())
+            }
+        };
+
+        // GitHub releases carry no binaries. The community edition is the Apache-2.0 build of the
+        // open source tree; the default download is the proprietary one.
+        let source_file = format!("atlas-community-{source_system}-v{version}");
+        let path = format!("https://release.ariga.io/atlas/{source_file}");
+
+        let source = source::for_system(name, &path, system)?;
+
+        let step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT/bin\"
+            cp ./source/{name}/{source_file} \"$VORPAL_OUTPUT/bin/atlas\"
+            chmod +x \"$VORPAL_OUTPUT/bin/atlas\"",
+        };
+
+        let mut steps = vec![target::shell(context, system, vec![], vec![], step_script).await?];
+
+        if check::enabled(context, system, self.check)? {
+            steps.push(check::step(context, system, vec![], "bin/atlas version", version).await?);
+        }
+
+        let systems = self.systems();
+
+        target::build(
+            context,
+            system,
+            name,
+            vec![format!("{name}:{version}")],
+            vec![source],
+            steps,
+            systems,
+        )
+        .await
+    }
+}
@@ -1,24 +1,90 @@
+use crate::{
+    artifact::ArtifactPackage,
+    builder::{check, darwin_sign},
+    error::ArtifactError,
+    overrides, source, target, versions,
+};
 use anyhow::Result;
+use async_trait::async_trait;
 use indoc::formatdoc;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
     context::ConfigContext,
 };
 
 #[derive(Default)]
-pub struct Awscli2;
+pub struct Awscli2<'a> {
+    check: Option<bool>,
+    darwin_sign: bool,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
 
-impl Awscli2 {
+impl<'a> Awscli2<'a> {
     pub fn new() -> Self {
-        Self
+        Self {
+            check: None,
+            darwin_sign: false,
+            target: None,
+            version: None,
+        }
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    // Clears the quarantine attribute and ad hoc signs the darwin binaries, see
+    // `darwin_sign::step`.
+    pub fn with_darwin_sign(mut self) -> Self {
+        self.darwin_sign = true;
+        self
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
     }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Awscli2<'a> {
+    fn name(&self) -> &str {
+        "awscli2"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::AWSCLI2))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "awscli2";
-        let source_version = "2.33.1";
+        let name = self.name();
+        let source_version = self.version();
 
-        let (source_path, step_script) = match context.get_system() {
+        let (source_path, step_script) = match system {
             Aarch64Linux => {
                 let path = format!(
                     "https://awscli.amazonaws.com/awscli-exe-linux-aarch64-{source_version}.zip"
@@ -62,19 +128,50 @@ impl Awscli2 {
                 };
                 (path, script)
             }
-            _ => return Err(anyhow::anyhow!("Unsupported system for awscli2 artifact")),
+            system => {
+                return Err(ArtifactError::UnsupportedSystem {
+                    artifact: name.to_string(),
+                    system,
+                }
+                .into())
+            }
         };
 
-        let source = ArtifactSource::new(name, &source_path).build();
+        let source = source::for_system(name, &source_path, system)?;
+
+        // The installer relocates its bundled runtime, so make sure the result still starts.
+        let check_expected = format!("aws-cli/{source_version}");
+
+        let mut steps = vec![target::shell(context, system, vec![], vec![], step_script).await?];
+
+        if self.darwin_sign {
+            steps.extend(darwin_sign::step(context, system).await?);
+        }
 
-        let steps = vec![step::shell(context, vec![], vec![], step_script, vec![]).await?];
+        if check::enabled(context, system, self.check)? {
+            steps.push(
+                check::step(
+                    context,
+                    system,
+                    vec![],
+                    "bin/aws --version",
+                    &check_expected,
+                )
+                .await?,
+            );
+        }
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let systems = self.systems();
 
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{source_version}")])
-            .with_sources(vec![source])
-            .build(context)
-            .await
+        target::build(
+            context,
+            system,
+            name,
+            vec![format!("{name}:{source_version}")],
+            vec![source],
+            steps,
+            systems,
+        )
+        .await
     }
 }
@@ -0,0 +1,72 @@
+use crate::{artifact::ArtifactPackage, builder::cargo::CargoPackage, overrides, source, versions};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct B3sum<'a> {
+    rust_toolchain: Option<&'a str>,
+    version: Option<&'a str>,
+}
+
+impl<'a> B3sum<'a> {
+    pub fn new() -> Self {
+        Self {
+            rust_toolchain: None,
+            version: None,
+        }
+    }
+
+    pub fn with_rust_toolchain(mut self, rust_toolchain: &'a str) -> Self {
+        self.rust_toolchain = Some(rust_toolchain);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for B3sum<'a> {
+    fn name(&self) -> &str {
+        "b3sum"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::B3SUM))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name();
+        let version = self.version();
+
+        // Upstream only publishes x86_64 b3sum binaries, so every system builds the b3sum crate
+        // from the BLAKE3 release tag instead.
+        let path =
+            format!("https://github.com/BLAKE3-team/BLAKE3/archive/refs/tags/{version}.tar.gz");
+
+        let source_directory = format!("BLAKE3-{version}/b3sum");
+
+        let mut package = CargoPackage::new(name, version, self.systems())
+            .with_source(source::new(name, &path), &source_directory);
+
+        if let Some(rust_toolchain) = self.rust_toolchain {
+            package = package.with_rust_toolchain(rust_toolchain);
+        }
+
+        package.build(context).await
+    }
+}
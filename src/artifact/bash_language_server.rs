@@ -1,8 +1,13 @@
+use crate::{artifact::ArtifactPackage, overrides, source, versions};
 use anyhow::Result;
+use async_trait::async_trait;
 use indoc::formatdoc;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{get_env_key, nodejs::NodeJS, step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::{get_env_key, nodejs::NodeJS, step, Artifact},
     context::ConfigContext,
 };
 
@@ -70,11 +75,15 @@ server.stdin.write(frame(initialize));
 #[derive(Default)]
 pub struct BashLanguageServer<'a> {
     node: Option<&'a str>,
+    version: Option<&'a str>,
 }
 
 impl<'a> BashLanguageServer<'a> {
     pub fn new() -> Self {
-        Self { node: None }
+        Self {
+            node: None,
+            version: None,
+        }
     }
 
     pub fn with_node(mut self, node: &'a str) -> Self {
@@ -82,14 +91,37 @@ impl<'a> BashLanguageServer<'a> {
         self
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for BashLanguageServer<'a> {
+    fn name(&self) -> &str {
+        "bash-language-server"
+    }
+
+    fn version(&self) -> &str {
+        self.version.unwrap_or(overrides::version(
+            self.name(),
+            versions::BASH_LANGUAGE_SERVER,
+        ))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
         let node = match self.node {
             Some(val) => val,
             None => &NodeJS::new().build(context).await?,
         };
 
-        let name = "bash-language-server";
-        let source_version = "5.6.0";
+        let name = self.name();
+        let source_version = self.version();
 
         let source_path =
             format!("https://registry.npmjs.org/{name}/-/{name}-{source_version}.tgz");
@@ -102,52 +134,52 @@ impl<'a> BashLanguageServer<'a> {
         // are "bls-"-prefixed because vorpal locks sources by key globally, and bare npm names collide
         // with other artifacts' sources.
         let sources = vec![
-            ArtifactSource::new(name, &source_path).build(),
+            source::new(name, &source_path),
             // Direct dependencies
-            ArtifactSource::new("bls-editorconfig", "https://registry.npmjs.org/editorconfig/-/editorconfig-2.0.1.tgz").build(),
-            ArtifactSource::new("bls-fast-glob", "https://registry.npmjs.org/fast-glob/-/fast-glob-3.3.3.tgz").build(),
-            ArtifactSource::new("bls-fuzzy-search", "https://registry.npmjs.org/fuzzy-search/-/fuzzy-search-3.2.1.tgz").build(),
-            ArtifactSource::new("bls-node-fetch", "https://registry.npmjs.org/node-fetch/-/node-fetch-2.7.0.tgz").build(),
-            ArtifactSource::new("bls-turndown", "https://registry.npmjs.org/turndown/-/turndown-7.2.0.tgz").build(),
-            ArtifactSource::new("bls-vscode-languageserver", "https://registry.npmjs.org/vscode-languageserver/-/vscode-languageserver-8.0.2.tgz").build(),
-            ArtifactSource::new("bls-vscode-languageserver-textdocument", "https://registry.npmjs.org/vscode-languageserver-textdocument/-/vscode-languageserver-textdocument-1.0.12.tgz").build(),
-            ArtifactSource::new("bls-web-tree-sitter", "https://registry.npmjs.org/web-tree-sitter/-/web-tree-sitter-0.24.5.tgz").build(),
-            ArtifactSource::new("bls-zod", "https://registry.npmjs.org/zod/-/zod-3.24.2.tgz").build(),
+            source::new("bls-editorconfig", "https://registry.npmjs.org/editorconfig/-/editorconfig-2.0.1.tgz"),
+            source::new("bls-fast-glob", "https://registry.npmjs.org/fast-glob/-/fast-glob-3.3.3.tgz"),
+            source::new("bls-fuzzy-search", "https://registry.npmjs.org/fuzzy-search/-/fuzzy-search-3.2.1.tgz"),
+            source::new("bls-node-fetch", "https://registry.npmjs.org/node-fetch/-/node-fetch-2.7.0.tgz"),
+            source::new("bls-turndown", "https://registry.npmjs.org/turndown/-/turndown-7.2.0.tgz"),
+            source::new("bls-vscode-languageserver", "https://registry.npmjs.org/vscode-languageserver/-/vscode-languageserver-8.0.2.tgz"),
+            source::new("bls-vscode-languageserver-textdocument", "https://registry.npmjs.org/vscode-languageserver-textdocument/-/vscode-languageserver-textdocument-1.0.12.tgz"),
+            source::new("bls-web-tree-sitter", "https://registry.npmjs.org/web-tree-sitter/-/web-tree-sitter-0.24.5.tgz"),
+            source::new("bls-zod", "https://registry.npmjs.org/zod/-/zod-3.24.2.tgz"),
             // editorconfig transitive deps
-            ArtifactSource::new("bls-semver", "https://registry.npmjs.org/semver/-/semver-7.8.5.tgz").build(),
-            ArtifactSource::new("bls-commander", "https://registry.npmjs.org/commander/-/commander-15.0.0.tgz").build(),
-            ArtifactSource::new("bls-minimatch", "https://registry.npmjs.org/minimatch/-/minimatch-10.0.1.tgz").build(),
-            ArtifactSource::new("bls-one-ini-wasm", "https://registry.npmjs.org/@one-ini/wasm/-/wasm-0.2.0.tgz").build(),
-            ArtifactSource::new("bls-brace-expansion", "https://registry.npmjs.org/brace-expansion/-/brace-expansion-2.0.1.tgz").build(),
-            ArtifactSource::new("bls-balanced-match", "https://registry.npmjs.org/balanced-match/-/balanced-match-1.0.2.tgz").build(),
+            source::new("bls-semver", "https://registry.npmjs.org/semver/-/semver-7.8.5.tgz"),
+            source::new("bls-commander", "https://registry.npmjs.org/commander/-/commander-15.0.0.tgz"),
+            source::new("bls-minimatch", "https://registry.npmjs.org/minimatch/-/minimatch-10.0.1.tgz"),
+            source::new("bls-one-ini-wasm", "https://registry.npmjs.org/@one-ini/wasm/-/wasm-0.2.0.tgz"),
+            source::new("bls-brace-expansion", "https://registry.npmjs.org/brace-expansion/-/brace-expansion-2.0.1.tgz"),
+            source::new("bls-balanced-match", "https://registry.npmjs.org/balanced-match/-/balanced-match-1.0.2.tgz"),
             // fast-glob transitive deps
-            ArtifactSource::new("bls-nodelib-fs-stat", "https://registry.npmjs.org/@nodelib/fs.stat/-/fs.stat-2.0.5.tgz").build(),
-            ArtifactSource::new("bls-nodelib-fs-walk", "https://registry.npmjs.org/@nodelib/fs.walk/-/fs.walk-1.2.8.tgz").build(),
-            ArtifactSource::new("bls-nodelib-fs-scandir", "https://registry.npmjs.org/@nodelib/fs.scandir/-/fs.scandir-2.1.5.tgz").build(),
-            ArtifactSource::new("bls-fastq", "https://registry.npmjs.org/fastq/-/fastq-1.20.1.tgz").build(),
-            ArtifactSource::new("bls-reusify", "https://registry.npmjs.org/reusify/-/reusify-1.1.0.tgz").build(),
-            ArtifactSource::new("bls-run-parallel", "https://registry.npmjs.org/run-parallel/-/run-parallel-1.2.0.tgz").build(),
-            ArtifactSource::new("bls-queue-microtask", "https://registry.npmjs.org/queue-microtask/-/queue-microtask-1.2.3.tgz").build(),
-            ArtifactSource::new("bls-glob-parent", "https://registry.npmjs.org/glob-parent/-/glob-parent-5.1.2.tgz").build(),
-            ArtifactSource::new("bls-is-glob", "https://registry.npmjs.org/is-glob/-/is-glob-4.0.3.tgz").build(),
-            ArtifactSource::new("bls-is-extglob", "https://registry.npmjs.org/is-extglob/-/is-extglob-2.1.1.tgz").build(),
-            ArtifactSource::new("bls-merge2", "https://registry.npmjs.org/merge2/-/merge2-1.4.1.tgz").build(),
-            ArtifactSource::new("bls-micromatch", "https://registry.npmjs.org/micromatch/-/micromatch-4.0.8.tgz").build(),
-            ArtifactSource::new("bls-braces", "https://registry.npmjs.org/braces/-/braces-3.0.3.tgz").build(),
-            ArtifactSource::new("bls-fill-range", "https://registry.npmjs.org/fill-range/-/fill-range-7.1.1.tgz").build(),
-            ArtifactSource::new("bls-to-regex-range", "https://registry.npmjs.org/to-regex-range/-/to-regex-range-5.0.1.tgz").build(),
-            ArtifactSource::new("bls-is-number", "https://registry.npmjs.org/is-number/-/is-number-7.0.0.tgz").build(),
-            ArtifactSource::new("bls-picomatch", "https://registry.npmjs.org/picomatch/-/picomatch-2.3.1.tgz").build(),
+            source::new("bls-nodelib-fs-stat", "https://registry.npmjs.org/@nodelib/fs.stat/-/fs.stat-2.0.5.tgz"),
+            source::new("bls-nodelib-fs-walk", "https://registry.npmjs.org/@nodelib/fs.walk/-/fs.walk-1.2.8.tgz"),
+            source::new("bls-nodelib-fs-scandir", "https://registry.npmjs.org/@nodelib/fs.scandir/-/fs.scandir-2.1.5.tgz"),
+            source::new("bls-fastq", "https://registry.npmjs.org/fastq/-/fastq-1.20.1.tgz"),
+            source::new("bls-reusify", "https://registry.npmjs.org/reusify/-/reusify-1.1.0.tgz"),
+            source::new("bls-run-parallel", "https://registry.npmjs.org/run-parallel/-/run-parallel-1.2.0.tgz"),
+            source::new("bls-queue-microtask", "https://registry.npmjs.org/queue-microtask/-/queue-microtask-1.2.3.tgz"),
+            source::new("bls-glob-parent", "https://registry.npmjs.org/glob-parent/-/glob-parent-5.1.2.tgz"),
+            source::new("bls-is-glob", "https://registry.npmjs.org/is-glob/-/is-glob-4.0.3.tgz"),
+            source::new("bls-is-extglob", "https://registry.npmjs.org/is-extglob/-/is-extglob-2.1.1.tgz"),
+            source::new("bls-merge2", "https://registry.npmjs.org/merge2/-/merge2-1.4.1.tgz"),
+            source::new("bls-micromatch", "https://registry.npmjs.org/micromatch/-/micromatch-4.0.8.tgz"),
+            source::new("bls-braces", "https://registry.npmjs.org/braces/-/braces-3.0.3.tgz"),
+            source::new("bls-fill-range", "https://registry.npmjs.org/fill-range/-/fill-range-7.1.1.tgz"),
+            source::new("bls-to-regex-range", "https://registry.npmjs.org/to-regex-range/-/to-regex-range-5.0.1.tgz"),
+            source::new("bls-is-number", "https://registry.npmjs.org/is-number/-/is-number-7.0.0.tgz"),
+            source::new("bls-picomatch", "https://registry.npmjs.org/picomatch/-/picomatch-2.3.1.tgz"),
             // node-fetch transitive deps
-            ArtifactSource::new("bls-whatwg-url", "https://registry.npmjs.org/whatwg-url/-/whatwg-url-5.0.0.tgz").build(),
-            ArtifactSource::new("bls-tr46", "https://registry.npmjs.org/tr46/-/tr46-0.0.3.tgz").build(),
-            ArtifactSource::new("bls-webidl-conversions", "https://registry.npmjs.org/webidl-conversions/-/webidl-conversions-3.0.1.tgz").build(),
+            source::new("bls-whatwg-url", "https://registry.npmjs.org/whatwg-url/-/whatwg-url-5.0.0.tgz"),
+            source::new("bls-tr46", "https://registry.npmjs.org/tr46/-/tr46-0.0.3.tgz"),
+            source::new("bls-webidl-conversions", "https://registry.npmjs.org/webidl-conversions/-/webidl-conversions-3.0.1.tgz"),
             // turndown transitive deps
-            ArtifactSource::new("bls-mixmark-domino", "https://registry.npmjs.org/@mixmark-io/domino/-/domino-2.2.0.tgz").build(),
+            source::new("bls-mixmark-domino", "https://registry.npmjs.org/@mixmark-io/domino/-/domino-2.2.0.tgz"),
             // vscode-languageserver transitive deps
-            ArtifactSource::new("bls-vscode-languageserver-protocol", "https://registry.npmjs.org/vscode-languageserver-protocol/-/vscode-languageserver-protocol-3.17.2.tgz").build(),
-            ArtifactSource::new("bls-vscode-jsonrpc", "https://registry.npmjs.org/vscode-jsonrpc/-/vscode-jsonrpc-8.0.2.tgz").build(),
-            ArtifactSource::new("bls-vscode-languageserver-types", "https://registry.npmjs.org/vscode-languageserver-types/-/vscode-languageserver-types-3.17.2.tgz").build(),
+            source::new("bls-vscode-languageserver-protocol", "https://registry.npmjs.org/vscode-languageserver-protocol/-/vscode-languageserver-protocol-3.17.2.tgz"),
+            source::new("bls-vscode-jsonrpc", "https://registry.npmjs.org/vscode-jsonrpc/-/vscode-jsonrpc-8.0.2.tgz"),
+            source::new("bls-vscode-languageserver-types", "https://registry.npmjs.org/vscode-languageserver-types/-/vscode-languageserver-types-3.17.2.tgz"),
         ];
 
         let env_node = get_env_key(&node.to_string());
@@ -238,7 +270,7 @@ impl<'a> BashLanguageServer<'a> {
         let steps =
             vec![step::shell(context, vec![node.to_string()], vec![], step_script, vec![]).await?];
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let systems = self.systems();
 
         Artifact::new(name, steps, systems)
             .with_aliases(vec![format!("{name}:{source_version}")])
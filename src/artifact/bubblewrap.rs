@@ -0,0 +1,133 @@
+use crate::{
+    artifact::{libcap::Libcap, pkg_config::PkgConfig, ArtifactPackage},
+    builder::{autotools::AutotoolsPackage, prune},
+    fork, overrides, source, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Linux, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+pub struct Bubblewrap<'a> {
+    check: Option<bool>,
+    libcap: Option<&'a str>,
+    pkg_config: Option<&'a str>,
+    prune: bool,
+    version: Option<&'a str>,
+}
+
+impl<'a> Default for Bubblewrap<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Bubblewrap<'a> {
+    pub fn new() -> Self {
+        Self {
+            check: None,
+            libcap: None,
+            pkg_config: None,
+            prune: true,
+            version: None,
+        }
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    pub fn with_libcap(mut self, libcap: &'a str) -> Self {
+        self.libcap = Some(libcap);
+        self
+    }
+
+    pub fn with_pkg_config(mut self, pkg_config: &'a str) -> Self {
+        self.pkg_config = Some(pkg_config);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+
+    // Keeps the documentation and libtool archives `prune::DEFAULT` removes.
+    pub fn without_prune(mut self) -> Self {
+        self.prune = false;
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Bubblewrap<'a> {
+    fn name(&self) -> &str {
+        "bubblewrap"
+    }
+
+    // 0.10.0 is the last release with an autotools build; later ones only build with meson.
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::BUBBLEWRAP))
+    }
+
+    // bwrap sandboxes through Linux namespaces, which darwin has no equivalent of.
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Linux, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let (libcap, pkg_config) = tokio::try_join!(
+            fork::build(context, self.libcap, Libcap::new()),
+            fork::build(context, self.pkg_config, PkgConfig::new()),
+        )?;
+
+        let [libcap, pkg_config] = fork::join(context, [libcap, pkg_config]).await?;
+
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!(
+            "https://github.com/containers/bubblewrap/releases/download/v{version}/bubblewrap-{version}.tar.xz"
+        );
+
+        let source = source::new(name, &path);
+
+        let source_directory = format!("{name}-{version}");
+
+        // bwrap is installed without the setuid bit, so it needs unprivileged user namespaces on
+        // the host. The man pages would need xsltproc and the docbook stylesheets.
+        AutotoolsPackage::new(name, version, source, self.systems())
+            .with_artifact(&libcap)
+            .with_artifact(&pkg_config)
+            .with_check("bin/bwrap --version", &format!("bubblewrap {version}"))
+            .with_check_enabled(self.check)
+            .with_configure_flag("--disable-man")
+            .with_configure_flag("--disable-selinux")
+            .with_configure_flag(
+                "--with-bash-completion-dir=\"$VORPAL_OUTPUT/share/bash-completion/completions\"",
+            )
+            .with_configure_flag(
+                "--with-zsh-completion-dir=\"$VORPAL_OUTPUT/share/zsh/site-functions\"",
+            )
+            .with_prune(prune::DEFAULT)
+            .with_prune_enabled(self.prune)
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .build(context)
+            .await
+    }
+}
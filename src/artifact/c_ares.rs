@@ -0,0 +1,140 @@
+use crate::{
+    artifact::ArtifactPackage,
+    builder::{autotools::AutotoolsPackage, prune},
+    overrides, source,
+    variant::{self, Linkage, Variant},
+    versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+pub struct CAres<'a> {
+    linkage: Option<Linkage>,
+    prune: bool,
+    version: Option<&'a str>,
+}
+
+impl<'a> Default for CAres<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> CAres<'a> {
+    pub fn new() -> Self {
+        Self {
+            linkage: None,
+            prune: true,
+            version: None,
+        }
+    }
+
+    // Installs only shared or only static libraries; without it the `linkage` variable decides,
+    // see `variant::linkage`.
+    pub fn with_linkage(mut self, linkage: Linkage) -> Self {
+        self.linkage = Some(linkage);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Keeps the documentation and libtool archives `prune::DEFAULT` removes.
+    pub fn without_prune(mut self) -> Self {
+        self.prune = false;
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for CAres<'a> {
+    fn name(&self) -> &str {
+        "c-ares"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::C_ARES))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!(
+            "https://github.com/c-ares/c-ares/releases/download/v{version}/c-ares-{version}.tar.gz"
+        );
+
+        let source = source::new(name, &path);
+
+        let source_directory = format!("{name}-{version}");
+
+        let linkage = variant::linkage(context, self.linkage)?;
+
+        let [shared_flag, static_flag] = variant::configure_flags(linkage);
+
+        AutotoolsPackage::new(name, version, source, self.systems())
+            .with_configure_flag("--disable-tests")
+            .with_configure_flag(shared_flag)
+            .with_configure_flag(static_flag)
+            .with_prune(prune::DEFAULT)
+            .with_prune_enabled(self.prune)
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .with_variant(Variant::Full, linkage)
+            .build(context)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, MockContext};
+
+    #[tokio::test]
+    async fn build_follows_linkage_variable() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Darwin)
+            .with_variable(variant::VARIABLE, "static")
+            .build()
+            .await?;
+
+        let c_ares = CAres::new().build(context).await?;
+
+        assert!(testing::aliases(context, &c_ares)?
+            .contains(&format!("c-ares:{}-static", versions::C_ARES)));
+
+        let script = testing::artifact(context, &c_ares)?.steps[0]
+            .script
+            .clone()
+            .unwrap_or_default();
+
+        assert!(script.contains("--disable-shared"));
+        assert!(script.contains("--enable-static"));
+
+        let shared = CAres::new()
+            .with_linkage(Linkage::Shared)
+            .build(context)
+            .await?;
+
+        assert_eq!(
+            testing::aliases(context, &shared)?,
+            variant::aliases("c-ares", versions::C_ARES, Variant::Full, Linkage::Shared)
+        );
+
+        Ok(())
+    }
+}
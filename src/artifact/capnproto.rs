@@ -0,0 +1,105 @@
+use crate::{
+    artifact::{cmake, ArtifactPackage},
+    builder::cmake::CmakePackage,
+    context_cache, overrides, source, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct Capnproto<'a> {
+    check: Option<bool>,
+    cmake: Option<&'a str>,
+    version: Option<&'a str>,
+}
+
+impl<'a> Capnproto<'a> {
+    pub fn new() -> Self {
+        Self {
+            check: None,
+            cmake: None,
+            version: None,
+        }
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    pub fn with_cmake(mut self, cmake: &'a str) -> Self {
+        self.cmake = Some(cmake);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Capnproto<'a> {
+    fn name(&self) -> &str {
+        "capnproto"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::CAPNPROTO))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let cmake = match self.cmake {
+            Some(val) => val,
+            None => &context_cache::build(context, &cmake::Cmake::new()).await?,
+        };
+
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!("https://capnproto.org/capnproto-c++-{version}.tar.gz");
+
+        let source = source::new(name, &path);
+
+        let source_directory = format!("capnproto-c++-{version}");
+
+        let check_expected = format!("Cap'n Proto version {version}");
+
+        // The libraries stay static, as upstream builds them, so RPC users link them into their
+        // own binaries. TLS (kj-tls) and gzip streams (kj-gzip) are left out rather than picked up
+        // from the host.
+        CmakePackage::new(name, version, source, self.systems())
+            .with_check("bin/capnp --version", &check_expected)
+            .with_check_enabled(self.check)
+            .with_cmake(cmake)
+            .with_definition("BUILD_SHARED_LIBS=OFF")
+            .with_definition("BUILD_TESTING=OFF")
+            .with_definition("CMAKE_INSTALL_LIBDIR=lib")
+            .with_definition("CMAKE_POSITION_INDEPENDENT_CODE=ON")
+            .with_definition("WITH_OPENSSL=OFF")
+            .with_definition("WITH_ZLIB=OFF")
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .build(context)
+            .await
+    }
+}
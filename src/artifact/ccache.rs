@@ -0,0 +1,123 @@
+use crate::{
+    artifact::ArtifactPackage, builder::check, error::ArtifactError, overrides, source, target,
+    versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct Ccache<'a> {
+    check: Option<bool>,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
+
+impl<'a> Ccache<'a> {
+    pub fn new() -> Self {
+        Self {
+            check: None,
+            target: None,
+            version: None,
+        }
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Ccache<'a> {
+    fn name(&self) -> &str {
+        "ccache"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::CCACHE))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let name = self.name();
+        let version = self.version();
+
+        // The darwin release is one universal binary; the Linux ones are static and xz-compressed.
+        let (source_system, source_extension) = match system {
+            Aarch64Darwin | X8664Darwin => ("darwin", "tar.gz"),
+            Aarch64Linux => ("linux-aarch64", "tar.xz"),
+            X8664Linux => ("linux-x86_64", "tar.xz"),
+            system => {
+                return Err(ArtifactError::UnsupportedSystem {
+                    artifact: name.to_string(),
+                    system,
+                }
+                .into())
+            }
+        };
+
+        let source_dir = format!("ccache-{version}-{source_system}");
+        let path = format!(
+            "https://github.com/ccache/ccache/releases/download/v{version}/{source_dir}.{source_extension}"
+        );
+
+        let source = source::for_system(name, &path, system)?;
+
+        let step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT/bin\"
+            cp ./source/{name}/{source_dir}/ccache \"$VORPAL_OUTPUT/bin/ccache\"
+            chmod +x \"$VORPAL_OUTPUT/bin/ccache\"",
+        };
+
+        let mut steps = vec![target::shell(context, system, vec![], vec![], step_script).await?];
+
+        if check::enabled(context, system, self.check)? {
+            steps
+                .push(check::step(context, system, vec![], "bin/ccache --version", version).await?);
+        }
+
+        let systems = self.systems();
+
+        target::build(
+            context,
+            system,
+            name,
+            vec![format!("{name}:{version}")],
+            vec![source],
+            steps,
+            systems,
+        )
+        .await
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{
+    artifact::ArtifactPackage, builder::check, error::ArtifactError, overrides, source, target,
+    versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+// Tools of the toolkit installed next to `cfssl`; each is a separate release binary.
+const TOOLS: &[&str] = &["cfssl", "cfssljson"];
+
+#[derive(Default)]
+pub struct Cfssl<'a> {
+    check: Option<bool>,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
+
+impl<'a> Cfssl<'a> {
+    pub fn new() -> Self {
+        Self {
+            check: None,
+            target: None,
+            version: None,
+        }
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Cfssl<'a> {
+    fn name(&self) -> &str {
+        "cfssl"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::CFSSL))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let name = self.name();
+        let version = self.version();
+
+        let source_system = match system {
+            Aarch64Darwin => "darwin_arm64",
+            Aarch64Linux => "linux_arm64",
+            X8664Darwin => "darwin_amd64",
+            X8664Linux => "linux_amd64",
+            system => {
+                return Err(ArtifactError::UnsupportedSystem {
+                    artifact: name.to_string(),
+                    system,
+                }
+                .into())
+            }
+        };
+
+        let sources = TOOLS
+            .iter()
+            .map(|tool| {
+                let path = format!(
+                    "https://github.com/cloudflare/cfssl/releases/download/v{version}/{tool}_{version}_{source_system}"
+                );
+
+                source::for_system(tool, &path, system)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let copy_script = TOOLS
+            .iter()
+            .map(|tool| {
+                formatdoc! {"
+                    cp ./source/{tool}/{tool}_{version}_{source_system} \"$VORPAL_OUTPUT/bin/{tool}\"
+                    chmod +x \"$VORPAL_OUTPUT/bin/{tool}\"",
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT/bin\"
+            {copy_script}",
+        };
+
+        let mut steps = vec![target::shell(context, system, vec![], vec![], step_script).await?];
+
+        if check::enabled(context, system, self.check)? {
+            steps.push(check::step(context, system, vec![], "bin/cfssl version", version).await?);
+        }
+
+        let systems = self.systems();
+
+        target::build(
+            context,
+            system,
+            name,
+            vec![format!("{name}:{version}")],
+            sources,
+            steps,
+            systems,
+        )
+        .await
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{binary_artifact, versions};
+
+binary_artifact! {
+    Chezmoi {
+        name: "chezmoi",
+        version: versions::CHEZMOI,
+        repository: "twpayne/chezmoi",
+        asset: "chezmoi_{version}_{target}.tar.gz",
+        binaries: ["chezmoi" => "chezmoi"],
+        completions: {
+            Bash => "completions/chezmoi-completion.bash",
+            Fish => "completions/chezmoi.fish",
+            Zsh => "completions/chezmoi.zsh",
+        },
+        check: "bin/chezmoi --version" => "{version}",
+        targets: {
+            Aarch64Darwin => "darwin_arm64",
+            Aarch64Linux => "linux_arm64",
+            X8664Darwin => "darwin_amd64",
+            X8664Linux => "linux_amd64",
+        },
+    }
+}
@@ -1,37 +1,93 @@
+use crate::{
+    artifact::ArtifactPackage, builder::darwin_sign, error::ArtifactError, overrides, source,
+    target, versions,
+};
 use anyhow::Result;
+use async_trait::async_trait;
 use indoc::formatdoc;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
     context::ConfigContext,
 };
 
 #[derive(Default)]
-pub struct Cmake;
+pub struct Cmake<'a> {
+    darwin_sign: bool,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
 
-impl Cmake {
+impl<'a> Cmake<'a> {
     pub fn new() -> Self {
-        Self
+        Self {
+            darwin_sign: false,
+            target: None,
+            version: None,
+        }
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "cmake";
-        let version = "4.2.3";
+    // Clears the quarantine attribute and ad hoc signs the darwin binaries, see
+    // `darwin_sign::step`.
+    pub fn with_darwin_sign(mut self) -> Self {
+        self.darwin_sign = true;
+        self
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
 
-        let source_system = match context.get_system() {
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Cmake<'a> {
+    fn name(&self) -> &str {
+        "cmake"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::CMAKE))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let name = self.name();
+        let version = self.version();
+
+        let source_system = match system {
             Aarch64Darwin | X8664Darwin => "macos-universal",
             Aarch64Linux => "linux-aarch64",
             X8664Linux => "linux-x86_64",
-            _ => return Err(anyhow::anyhow!("Unsupported system for cmake artifact")),
+            system => {
+                return Err(ArtifactError::UnsupportedSystem {
+                    artifact: name.to_string(),
+                    system,
+                }
+                .into())
+            }
         };
 
         let path = format!(
             "https://github.com/Kitware/CMake/releases/download/v{version}/cmake-{version}-{source_system}.tar.gz"
         );
 
-        let source = ArtifactSource::new(name, &path).build();
+        let source = source::for_system(name, &path, system)?;
 
-        let script = match context.get_system() {
+        let script = match system {
             Aarch64Darwin | X8664Darwin => formatdoc! {"
                 mkdir -pv \"$VORPAL_OUTPUT/bin\"
                 cp -v ./source/{name}/{name}-{version}-{source_system}/CMake.app/Contents/bin/* \"$VORPAL_OUTPUT/bin/\"
@@ -44,14 +100,23 @@ impl Cmake {
             },
         };
 
-        let steps = vec![step::shell(context, vec![], vec![], script, vec![]).await?];
+        let mut steps = vec![target::shell(context, system, vec![], vec![], script).await?];
+
+        if self.darwin_sign {
+            steps.extend(darwin_sign::step(context, system).await?);
+        }
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let systems = self.systems();
 
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{version}")])
-            .with_sources(vec![source])
-            .build(context)
-            .await
+        target::build(
+            context,
+            system,
+            name,
+            vec![format!("{name}:{version}")],
+            vec![source],
+            steps,
+            systems,
+        )
+        .await
     }
 }
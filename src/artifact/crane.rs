@@ -1,38 +1,62 @@
+use crate::{artifact::ArtifactPackage, builder::go::GoPackage, overrides, versions};
 use anyhow::Result;
+use async_trait::async_trait;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{language::go::Go, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
     context::ConfigContext,
 };
 
 #[derive(Default)]
-pub struct Crane;
+pub struct Crane<'a> {
+    version: Option<&'a str>,
+}
 
-impl Crane {
+impl<'a> Crane<'a> {
     pub fn new() -> Self {
-        Self
+        Self { version: None }
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "crane";
-        let version = "0.20.7";
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
 
-        let source_path = format!(
-            "https://github.com/google/go-containerregistry/archive/refs/tags/v{version}.tar.gz"
-        );
-        let source = ArtifactSource::new(name, source_path.as_str()).build();
+#[async_trait]
+impl<'a> ArtifactPackage for Crane<'a> {
+    fn name(&self) -> &str {
+        "crane"
+    }
 
-        let build_directory = format!("./go-containerregistry-{version}");
-        let build_path = format!("./cmd/{name}");
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::CRANE))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name();
+        let version = self.version();
+
+        let build_path = format!("./cmd/{name}");
+        let version_ldflag =
+            format!("-X github.com/google/go-containerregistry/cmd/crane/cmd.Version=v{version}");
 
-        Go::new(name, systems)
-            .with_alias(format!("{name}:{version}"))
-            .with_build_directory(build_directory.as_str())
-            .with_build_path(build_path.as_str())
-            .with_source(source)
-            .build(context)
-            .await
+        GoPackage::new(
+            name,
+            "github.com/google/go-containerregistry",
+            version,
+            self.systems(),
+        )
+        .with_build_path(&build_path)
+        .with_ldflag(&version_ldflag)
+        .build(context)
+        .await
     }
 }
@@ -0,0 +1,18 @@
+use crate::{binary_artifact, versions};
+
+binary_artifact! {
+    Dbmate {
+        name: "dbmate",
+        version: versions::DBMATE,
+        repository: "amacneil/dbmate",
+        asset: "dbmate-{target}",
+        binaries: ["dbmate-{target}" => "dbmate"],
+        check: "bin/dbmate --version" => "dbmate version {version}",
+        targets: {
+            Aarch64Darwin => "macos-arm64",
+            Aarch64Linux => "linux-arm64",
+            X8664Darwin => "macos-amd64",
+            X8664Linux => "linux-amd64",
+        },
+    }
+}
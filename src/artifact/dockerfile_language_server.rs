@@ -1,8 +1,13 @@
+use crate::{artifact::ArtifactPackage, overrides, source, versions};
 use anyhow::Result;
+use async_trait::async_trait;
 use indoc::formatdoc;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{get_env_key, nodejs::NodeJS, step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::{get_env_key, nodejs::NodeJS, step, Artifact},
     context::ConfigContext,
 };
 
@@ -70,11 +75,15 @@ server.stdin.write(frame(initialize));
 #[derive(Default)]
 pub struct DockerfileLanguageServer<'a> {
     node: Option<&'a str>,
+    version: Option<&'a str>,
 }
 
 impl<'a> DockerfileLanguageServer<'a> {
     pub fn new() -> Self {
-        Self { node: None }
+        Self {
+            node: None,
+            version: None,
+        }
     }
 
     pub fn with_node(mut self, node: &'a str) -> Self {
@@ -82,14 +91,37 @@ impl<'a> DockerfileLanguageServer<'a> {
         self
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for DockerfileLanguageServer<'a> {
+    fn name(&self) -> &str {
+        "dockerfile-language-server-nodejs"
+    }
+
+    fn version(&self) -> &str {
+        self.version.unwrap_or(overrides::version(
+            self.name(),
+            versions::DOCKERFILE_LANGUAGE_SERVER,
+        ))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
         let node = match self.node {
             Some(val) => val,
             None => &NodeJS::new().build(context).await?,
         };
 
-        let name = "dockerfile-language-server-nodejs";
-        let source_version = "0.15.0";
+        let name = self.name();
+        let source_version = self.version();
 
         let source_path =
             format!("https://registry.npmjs.org/{name}/-/{name}-{source_version}.tgz");
@@ -104,22 +136,22 @@ impl<'a> DockerfileLanguageServer<'a> {
         // 3.17.3 (highest in the ^3.17.3 range used by dockerfile-ast and dockerfile-utils) so a
         // single copy satisfies all consumers in the flat layout.
         let sources = vec![
-            ArtifactSource::new(name, &source_path).build(),
+            source::new(name, &source_path),
             // Direct dependencies
-            ArtifactSource::new("dls-dockerfile-language-service", "https://registry.npmjs.org/dockerfile-language-service/-/dockerfile-language-service-0.16.1.tgz").build(),
-            ArtifactSource::new("dls-dockerfile-utils", "https://registry.npmjs.org/dockerfile-utils/-/dockerfile-utils-0.16.3.tgz").build(),
-            ArtifactSource::new("dls-vscode-languageserver", "https://registry.npmjs.org/vscode-languageserver/-/vscode-languageserver-8.0.2.tgz").build(),
-            ArtifactSource::new("dls-vscode-languageserver-textdocument", "https://registry.npmjs.org/vscode-languageserver-textdocument/-/vscode-languageserver-textdocument-1.0.12.tgz").build(),
+            source::new("dls-dockerfile-language-service", "https://registry.npmjs.org/dockerfile-language-service/-/dockerfile-language-service-0.16.1.tgz"),
+            source::new("dls-dockerfile-utils", "https://registry.npmjs.org/dockerfile-utils/-/dockerfile-utils-0.16.3.tgz"),
+            source::new("dls-vscode-languageserver", "https://registry.npmjs.org/vscode-languageserver/-/vscode-languageserver-8.0.2.tgz"),
+            source::new("dls-vscode-languageserver-textdocument", "https://registry.npmjs.org/vscode-languageserver-textdocument/-/vscode-languageserver-textdocument-1.0.12.tgz"),
             // Transitive dependencies
-            ArtifactSource::new("dls-dockerfile-ast", "https://registry.npmjs.org/dockerfile-ast/-/dockerfile-ast-0.7.1.tgz").build(),
-            ArtifactSource::new("dls-vscode-languageserver-protocol", "https://registry.npmjs.org/vscode-languageserver-protocol/-/vscode-languageserver-protocol-3.17.2.tgz").build(),
-            ArtifactSource::new("dls-vscode-languageserver-types", "https://registry.npmjs.org/vscode-languageserver-types/-/vscode-languageserver-types-3.17.3.tgz").build(),
-            ArtifactSource::new("dls-vscode-jsonrpc", "https://registry.npmjs.org/vscode-jsonrpc/-/vscode-jsonrpc-8.0.2.tgz").build(),
+            source::new("dls-dockerfile-ast", "https://registry.npmjs.org/dockerfile-ast/-/dockerfile-ast-0.7.1.tgz"),
+            source::new("dls-vscode-languageserver-protocol", "https://registry.npmjs.org/vscode-languageserver-protocol/-/vscode-languageserver-protocol-3.17.2.tgz"),
+            source::new("dls-vscode-languageserver-types", "https://registry.npmjs.org/vscode-languageserver-types/-/vscode-languageserver-types-3.17.3.tgz"),
+            source::new("dls-vscode-jsonrpc", "https://registry.npmjs.org/vscode-jsonrpc/-/vscode-jsonrpc-8.0.2.tgz"),
         ];
 
         let env_node = get_env_key(&node.to_string());
 
-        let pkg_dir = format!("$VORPAL_OUTPUT/lib/node_modules/dockerfile-language-server-nodejs");
+        let pkg_dir = "$VORPAL_OUTPUT/lib/node_modules/dockerfile-language-server-nodejs";
 
         let step_script = formatdoc! {"
             mkdir -pv \"{pkg_dir}\" \"$VORPAL_OUTPUT/bin\"
@@ -169,7 +201,7 @@ impl<'a> DockerfileLanguageServer<'a> {
         let steps =
             vec![step::shell(context, vec![node.to_string()], vec![], step_script, vec![]).await?];
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let systems = self.systems();
 
         Artifact::new("dockerfile-language-server", steps, systems)
             .with_aliases(vec![format!("dockerfile-language-server:{source_version}")])
@@ -0,0 +1,17 @@
+use crate::{binary_artifact, versions};
+
+binary_artifact! {
+    Doggo {
+        name: "doggo",
+        version: versions::DOGGO,
+        repository: "mr-karan/doggo",
+        asset: "doggo_{version}_{target}.tar.gz",
+        binaries: ["doggo_{version}_{target}/doggo" => "doggo"],
+        targets: {
+            Aarch64Darwin => "Darwin_arm64",
+            Aarch64Linux => "Linux_arm64",
+            X8664Darwin => "Darwin_x86_64",
+            X8664Linux => "Linux_x86_64",
+        },
+    }
+}
@@ -0,0 +1,16 @@
+use crate::{binary_artifact, versions};
+
+binary_artifact! {
+    DumbInit {
+        name: "dumb-init",
+        version: versions::DUMB_INIT,
+        repository: "Yelp/dumb-init",
+        asset: "dumb-init_{version}_{target}",
+        binaries: ["dumb-init_{version}_{target}" => "dumb-init"],
+        check: "bin/dumb-init --version" => "v{version}",
+        targets: {
+            Aarch64Linux => "aarch64",
+            X8664Linux => "x86_64",
+        },
+    }
+}
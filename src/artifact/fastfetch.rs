@@ -0,0 +1,23 @@
+use crate::{binary_artifact, versions};
+
+// Upstream ships a single universal archive for macOS.
+binary_artifact! {
+    Fastfetch {
+        name: "fastfetch",
+        version: versions::FASTFETCH,
+        repository: "fastfetch-cli/fastfetch",
+        tag: "{version}",
+        asset: "fastfetch-{target}.tar.gz",
+        binaries: [
+            "fastfetch-{target}/usr/bin/fastfetch" => "fastfetch",
+            "fastfetch-{target}/usr/bin/flashfetch" => "flashfetch",
+        ],
+        check: "bin/fastfetch --version" => "fastfetch {version}",
+        targets: {
+            Aarch64Darwin => "macos-universal",
+            Aarch64Linux => "linux-aarch64",
+            X8664Darwin => "macos-universal",
+            X8664Linux => "linux-amd64",
+        },
+    }
+}
@@ -1,20 +1,48 @@
-use crate::artifact::{pkg_config::PkgConfig, x264};
+use crate::{
+    artifact::{pkg_config::PkgConfig, x264, ArtifactPackage},
+    builder::{autotools::AutotoolsPackage, prune},
+    fork, overrides, source,
+    variant::{Linkage, Variant},
+    versions,
+};
 use anyhow::Result;
-use indoc::formatdoc;
+use async_trait::async_trait;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{get_env_key, step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
     context::ConfigContext,
 };
 
-#[derive(Default)]
 pub struct Ffmpeg<'a> {
+    prune: bool,
+    variant: Variant,
+    version: Option<&'a str>,
     x264: Option<&'a str>,
 }
 
+impl<'a> Default for Ffmpeg<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<'a> Ffmpeg<'a> {
     pub fn new() -> Self {
-        Self { x264: None }
+        Self {
+            prune: true,
+            variant: Variant::Full,
+            version: None,
+            x264: None,
+        }
+    }
+
+    // The minimal variant is an LGPL build without x264 or ffplay that does not probe the host for
+    // optional libraries.
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = variant;
+        self
     }
 
     pub fn with_x264(mut self, x264: &'a str) -> Self {
@@ -22,61 +50,73 @@ impl<'a> Ffmpeg<'a> {
         self
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let pkg_config = PkgConfig::new().build(context).await?;
-        let x264 = match self.x264 {
-            Some(val) => val,
-            None => &x264::X264::new().build(context).await?,
-        };
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
 
-        let name = "ffmpeg";
+    // Keeps the documentation and libtool archives `prune::DEFAULT` removes.
+    pub fn without_prune(mut self) -> Self {
+        self.prune = false;
+        self
+    }
+}
 
-        let version = "8.0.1";
+#[async_trait]
+impl<'a> ArtifactPackage for Ffmpeg<'a> {
+    fn name(&self) -> &str {
+        "ffmpeg"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::FFMPEG))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name();
+
+        let version = self.version();
 
         let source_path = format!("https://ffmpeg.org/releases/ffmpeg-{version}.tar.xz");
-        let source = ArtifactSource::new(name, &source_path).build();
-
-        let step_script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT\"
-
-            pushd ./source/{name}/ffmpeg-{version}
-
-            export PATH=\"{pkg_config}/bin:${{PATH:-}}\"
-            export CPPFLAGS=\"-I{x264}/include\"
-            export LDFLAGS=\"-L{x264}/lib -Wl,-rpath,{x264}/lib\"
-            export PKG_CONFIG_PATH=\"{x264}/lib/pkgconfig:${{PKG_CONFIG_PATH:-}}\"
-
-            ./configure \
-                --prefix=\"$VORPAL_OUTPUT\" \
-                --disable-doc \
-                --disable-debug \
-                --disable-x86asm \
-                --enable-gpl \
-                --enable-libx264
-
-            make -j$(nproc 2>/dev/null || sysctl -n hw.ncpu)
-            make install",
-            pkg_config = get_env_key(&pkg_config.to_string()),
-            x264 = get_env_key(&x264.to_string()),
+        let source = source::new(name, &source_path);
+
+        let source_directory = format!("ffmpeg-{version}");
+
+        let package = AutotoolsPackage::new(name, version, source, self.systems())
+            .with_configure_flag("--disable-doc")
+            .with_configure_flag("--disable-debug")
+            .with_configure_flag("--disable-x86asm")
+            .with_prune(prune::DEFAULT)
+            .with_prune_enabled(self.prune)
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .with_variant(self.variant, Linkage::default());
+
+        let package = match self.variant {
+            Variant::Full => {
+                let (pkg_config, x264) = tokio::try_join!(
+                    fork::build(context, None, PkgConfig::new()),
+                    fork::build(context, self.x264, x264::X264::new()),
+                )?;
+
+                let [pkg_config, x264] = fork::join(context, [pkg_config, x264]).await?;
+
+                package
+                    .with_artifact(&pkg_config)
+                    .with_artifact(&x264)
+                    .with_configure_flag("--enable-gpl")
+                    .with_configure_flag("--enable-libx264")
+            }
+            Variant::Minimal => package
+                .with_configure_flag("--disable-autodetect")
+                .with_configure_flag("--disable-ffplay"),
         };
 
-        let steps = vec![
-            step::shell(
-                context,
-                vec![x264.to_string(), pkg_config.to_string()],
-                vec![],
-                step_script,
-                vec![],
-            )
-            .await?,
-        ];
-
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
-
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{version}")])
-            .with_sources(vec![source])
-            .build(context)
-            .await
+        package.build(context).await
     }
 }
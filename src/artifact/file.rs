@@ -1,4 +1,6 @@
+use crate::artifact::ArtifactPackage;
 use anyhow::Result;
+use async_trait::async_trait;
 use indoc::formatdoc;
 use vorpal_sdk::{
     api::artifact::ArtifactSystem,
@@ -10,6 +12,7 @@ pub struct File<'a> {
     content: &'a str,
     name: &'a str,
     systems: Vec<ArtifactSystem>,
+    version: Option<&'a str>,
 }
 
 impl<'a> File<'a> {
@@ -18,10 +21,33 @@ impl<'a> File<'a> {
             content,
             name,
             systems,
+            version: None,
         }
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
+    // Tags the file as `name:version`, e.g. to pin a generated config to a release.
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for File<'a> {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    // Generated files have no upstream release to track unless given one.
+    fn version(&self) -> &str {
+        self.version.unwrap_or_default()
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        self.systems.clone()
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
         let step_script = formatdoc! {"
             #!/bin/bash
             set -euo pipefail
@@ -38,7 +64,13 @@ impl<'a> File<'a> {
 
         let step = step::shell(context, vec![], vec![], step_script, vec![]).await?;
 
-        Artifact::new(self.name, vec![step], self.systems)
+        let aliases = self
+            .version
+            .map(|version| vec![format!("{}:{version}", self.name)])
+            .unwrap_or_default();
+
+        Artifact::new(self.name, vec![step], self.systems())
+            .with_aliases(aliases)
             .build(context)
             .await
     }
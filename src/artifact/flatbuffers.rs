@@ -0,0 +1,104 @@
+use crate::{
+    artifact::{cmake, ArtifactPackage},
+    builder::cmake::CmakePackage,
+    context_cache, overrides, source, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct Flatbuffers<'a> {
+    check: Option<bool>,
+    cmake: Option<&'a str>,
+    version: Option<&'a str>,
+}
+
+impl<'a> Flatbuffers<'a> {
+    pub fn new() -> Self {
+        Self {
+            check: None,
+            cmake: None,
+            version: None,
+        }
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    pub fn with_cmake(mut self, cmake: &'a str) -> Self {
+        self.cmake = Some(cmake);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Flatbuffers<'a> {
+    fn name(&self) -> &str {
+        "flatbuffers"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::FLATBUFFERS))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let cmake = match self.cmake {
+            Some(val) => val,
+            None => &context_cache::build(context, &cmake::Cmake::new()).await?,
+        };
+
+        let name = self.name();
+        let version = self.version();
+
+        let path =
+            format!("https://github.com/google/flatbuffers/archive/refs/tags/v{version}.tar.gz");
+
+        let source = source::new(name, &path);
+
+        let source_directory = format!("{name}-{version}");
+
+        let check_expected = format!("flatc version {version}");
+
+        // Upstream only publishes flatc binaries for x86_64 Linux and macOS, so it is built from
+        // source everywhere. The C++ runtime is header-only and installed with flatc; the static
+        // flatbuffers library and tests are skipped.
+        CmakePackage::new(name, version, source, self.systems())
+            .with_check("bin/flatc --version", &check_expected)
+            .with_check_enabled(self.check)
+            .with_cmake(cmake)
+            .with_definition("CMAKE_INSTALL_LIBDIR=lib")
+            .with_definition("FLATBUFFERS_BUILD_FLATC=ON")
+            .with_definition("FLATBUFFERS_BUILD_FLATLIB=OFF")
+            .with_definition("FLATBUFFERS_BUILD_TESTS=OFF")
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .build(context)
+            .await
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{
+    artifact::ArtifactPackage,
+    builder::{autotools::AutotoolsPackage, prune},
+    overrides, source, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+pub struct Flex<'a> {
+    check: Option<bool>,
+    prune: bool,
+    version: Option<&'a str>,
+}
+
+impl<'a> Default for Flex<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Flex<'a> {
+    pub fn new() -> Self {
+        Self {
+            check: None,
+            prune: true,
+            version: None,
+        }
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+
+    // Keeps the documentation and libtool archives `prune::DEFAULT` removes.
+    pub fn without_prune(mut self) -> Self {
+        self.prune = false;
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Flex<'a> {
+    fn name(&self) -> &str {
+        "flex"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::FLEX))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!(
+            "https://github.com/westes/flex/releases/download/v{version}/flex-{version}.tar.gz"
+        );
+
+        let source = source::new(name, &path);
+
+        let source_directory = format!("{name}-{version}");
+
+        AutotoolsPackage::new(name, version, source, self.systems())
+            .with_check("bin/flex --version", &format!("flex {version}"))
+            .with_check_enabled(self.check)
+            .with_configure_flag("--disable-nls")
+            .with_prune(prune::DEFAULT)
+            .with_prune_enabled(self.prune)
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .build(context)
+            .await
+    }
+}
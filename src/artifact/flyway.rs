@@ -0,0 +1,126 @@
+use crate::{
+    artifact::{openjdk::Openjdk, ArtifactPackage},
+    builder::wrapper,
+    context_cache, overrides, source, target, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::get_env_key,
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct Flyway<'a> {
+    openjdk: Option<&'a str>,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
+
+impl<'a> Flyway<'a> {
+    pub fn new() -> Self {
+        Self {
+            openjdk: None,
+            target: None,
+            version: None,
+        }
+    }
+
+    pub fn with_openjdk(mut self, openjdk: &'a str) -> Self {
+        self.openjdk = Some(openjdk);
+        self
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Flyway<'a> {
+    fn name(&self) -> &str {
+        "flyway"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::FLYWAY))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let openjdk = match self.openjdk {
+            Some(val) => val.to_string(),
+            None if system == context.get_system() => {
+                context_cache::build(context, &Openjdk::new()).await?
+            }
+            None => Openjdk::new().with_target(system).build(context).await?,
+        };
+
+        let name = self.name();
+        let version = self.version();
+
+        // The platform-neutral distribution, without the JRE bundled into the per-system ones.
+        let path = format!(
+            "https://repo1.maven.org/maven2/org/flywaydb/flyway-commandline/{version}/flyway-commandline-{version}.tar.gz"
+        );
+
+        let source = source::new(name, &path);
+
+        let env_openjdk = get_env_key(&openjdk);
+
+        let java_home = match system {
+            Aarch64Darwin | X8664Darwin => format!("{env_openjdk}/Contents/Home"),
+            _ => env_openjdk,
+        };
+
+        // The launcher finds `lib/`, `drivers/` and `conf/` next to itself and only falls back to
+        // JAVA_HOME when there is no bundled `jre/`.
+        let wrapper_script = wrapper::wrap_binary(
+            "$VORPAL_OUTPUT/bin/flyway",
+            "\"$VORPAL_OUTPUT/libexec/flyway/flyway\"",
+            &[],
+            &[("JAVA_HOME", java_home.clone())],
+        );
+
+        let step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT/libexec\"
+
+            cp -Rv ./source/{name}/flyway-{version} \"$VORPAL_OUTPUT/libexec/flyway\"
+            chmod +x \"$VORPAL_OUTPUT/libexec/flyway/flyway\"
+
+            {wrapper_script}",
+        };
+
+        let steps = vec![target::shell(context, system, vec![openjdk], vec![], step_script).await?];
+
+        let systems = self.systems();
+
+        target::build(
+            context,
+            system,
+            name,
+            vec![format!("{name}:{version}")],
+            vec![source],
+            steps,
+            systems,
+        )
+        .await
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{
+    artifact::{fontconfig::Fontconfig, freetype::Freetype, harfbuzz::Harfbuzz, ArtifactPackage},
+    builder::toolset::Toolset,
+    context_cache, fork, overrides, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+// Font discovery, rasterization and shaping, for consumers such as ffmpeg's drawtext filter or
+// ImageMagick text rendering, merged into one prefix of symlinks. fontconfig and harfbuzz are
+// built against the bundle's freetype.
+#[derive(Default)]
+pub struct FontStack<'a> {
+    fontconfig: Option<&'a str>,
+    freetype: Option<&'a str>,
+    harfbuzz: Option<&'a str>,
+    version: Option<&'a str>,
+}
+
+impl<'a> FontStack<'a> {
+    pub fn new() -> Self {
+        Self {
+            fontconfig: None,
+            freetype: None,
+            harfbuzz: None,
+            version: None,
+        }
+    }
+
+    pub fn with_fontconfig(mut self, fontconfig: &'a str) -> Self {
+        self.fontconfig = Some(fontconfig);
+        self
+    }
+
+    pub fn with_freetype(mut self, freetype: &'a str) -> Self {
+        self.freetype = Some(freetype);
+        self
+    }
+
+    pub fn with_harfbuzz(mut self, harfbuzz: &'a str) -> Self {
+        self.harfbuzz = Some(harfbuzz);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for FontStack<'a> {
+    fn name(&self) -> &str {
+        "font-stack"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::FONT_STACK))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let freetype = match self.freetype {
+            Some(val) => val.to_string(),
+            None => context_cache::build(context, &Freetype::new()).await?,
+        };
+
+        let (fontconfig, harfbuzz) = tokio::try_join!(
+            fork::build_configured(
+                context,
+                self.fontconfig,
+                Fontconfig::new().with_freetype(&freetype)
+            ),
+            fork::build_configured(
+                context,
+                self.harfbuzz,
+                Harfbuzz::new().with_freetype(&freetype)
+            ),
+        )?;
+
+        let [fontconfig, harfbuzz] = fork::join(context, [fontconfig, harfbuzz]).await?;
+
+        let name = self.name();
+        let version = self.version();
+
+        Toolset::new(name, self.systems())
+            .with_aliases(vec![format!("{name}:{version}")])
+            .with_artifacts(vec![fontconfig, freetype, harfbuzz])
+            .with_directories(vec!["include", "lib"])
+            .build(context)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, MockContext};
+    use vorpal_sdk::api::artifact::ArtifactSystem::Aarch64Darwin;
+
+    #[tokio::test]
+    async fn build_shares_freetype() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Darwin).build().await?;
+
+        let font_stack = FontStack::new().build(context).await?;
+
+        assert_eq!(
+            testing::dependencies(context, &font_stack)?,
+            ["fontconfig", "freetype", "harfbuzz"]
+        );
+
+        let freetype = Freetype::new().build(context).await?;
+
+        let merged = testing::artifact(context, &font_stack)?.steps[0]
+            .artifacts
+            .clone();
+
+        for consumer in [
+            Fontconfig::new().build(context).await?,
+            Harfbuzz::new().build(context).await?,
+        ] {
+            let artifact = testing::artifact(context, &consumer)?;
+
+            assert!(artifact.steps[0].artifacts.contains(&freetype));
+            assert!(merged.contains(&consumer));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{
+    artifact::{freetype::Freetype, libxml2::Libxml2, pkg_config::PkgConfig, ArtifactPackage},
+    builder::{autotools::AutotoolsPackage, prune},
+    fork, overrides, source, target, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+pub struct Fontconfig<'a> {
+    check: Option<bool>,
+    freetype: Option<&'a str>,
+    libxml2: Option<&'a str>,
+    pkg_config: Option<&'a str>,
+    prune: bool,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
+
+impl<'a> Default for Fontconfig<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Fontconfig<'a> {
+    pub fn new() -> Self {
+        Self {
+            check: None,
+            freetype: None,
+            libxml2: None,
+            pkg_config: None,
+            prune: true,
+            target: None,
+            version: None,
+        }
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    pub fn with_freetype(mut self, freetype: &'a str) -> Self {
+        self.freetype = Some(freetype);
+        self
+    }
+
+    pub fn with_libxml2(mut self, libxml2: &'a str) -> Self {
+        self.libxml2 = Some(libxml2);
+        self
+    }
+
+    pub fn with_pkg_config(mut self, pkg_config: &'a str) -> Self {
+        self.pkg_config = Some(pkg_config);
+        self
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+
+    // Keeps the documentation, libtool archives and unused `var/` cache directory the build prunes.
+    pub fn without_prune(mut self) -> Self {
+        self.prune = false;
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Fontconfig<'a> {
+    fn name(&self) -> &str {
+        "fontconfig"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::FONTCONFIG))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let (freetype, libxml2, pkg_config) = tokio::try_join!(
+            fork::build_for(context, system, self.freetype, Freetype::new()),
+            fork::build_for(context, system, self.libxml2, Libxml2::new()),
+            fork::build_for(context, system, self.pkg_config, PkgConfig::new()),
+        )?;
+
+        let [freetype, libxml2, pkg_config] =
+            fork::join(context, [freetype, libxml2, pkg_config]).await?;
+
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!(
+            "https://www.freedesktop.org/software/fontconfig/release/fontconfig-{version}.tar.xz"
+        );
+
+        let source = source::new(name, &path);
+
+        let source_directory = format!("{name}-{version}");
+
+        let (default_fonts, add_fonts) = match system {
+            Aarch64Darwin | X8664Darwin => {
+                ("/System/Library/Fonts", "/Library/Fonts,~/Library/Fonts")
+            }
+            _ => ("/usr/share/fonts", "/usr/local/share/fonts"),
+        };
+
+        // The system cache directory would live in the read-only store, so no cache is generated
+        // at install time and it is pruned; the default fonts.conf also lists the per-user
+        // `$XDG_CACHE_HOME/fontconfig`, which fontconfig falls back to as the first writable cache
+        // directory. libxml2 replaces expat as the config parser.
+        AutotoolsPackage::new(name, version, source, self.systems())
+            .with_artifact(&freetype)
+            .with_artifact(&libxml2)
+            .with_artifact(&pkg_config)
+            .with_check("bin/fc-list --version", version)
+            .with_check_enabled(self.check)
+            .with_configure_flag("--disable-cache-build")
+            .with_configure_flag("--disable-docs")
+            .with_configure_flag("--disable-static")
+            .with_configure_flag("--enable-libxml2")
+            .with_configure_flag(&format!("--with-add-fonts={add_fonts}"))
+            .with_configure_flag(&format!("--with-default-fonts={default_fonts}"))
+            .with_prune(prune::DEFAULT)
+            .with_prune(&["var"])
+            .with_prune_enabled(self.prune)
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .with_target(system)
+            .build(context)
+            .await
+    }
+}
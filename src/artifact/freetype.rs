@@ -0,0 +1,113 @@
+use crate::{
+    artifact::{libpng::Libpng, zlib::Zlib, ArtifactPackage},
+    builder::{autotools::AutotoolsPackage, prune},
+    fork, overrides, source, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+pub struct Freetype<'a> {
+    libpng: Option<&'a str>,
+    prune: bool,
+    version: Option<&'a str>,
+    zlib: Option<&'a str>,
+}
+
+impl<'a> Default for Freetype<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Freetype<'a> {
+    pub fn new() -> Self {
+        Self {
+            libpng: None,
+            prune: true,
+            version: None,
+            zlib: None,
+        }
+    }
+
+    pub fn with_libpng(mut self, libpng: &'a str) -> Self {
+        self.libpng = Some(libpng);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    pub fn with_zlib(mut self, zlib: &'a str) -> Self {
+        self.zlib = Some(zlib);
+        self
+    }
+
+    // Keeps the documentation and libtool archives `prune::DEFAULT` removes.
+    pub fn without_prune(mut self) -> Self {
+        self.prune = false;
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Freetype<'a> {
+    fn name(&self) -> &str {
+        "freetype"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::FREETYPE))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let (libpng, zlib) = tokio::try_join!(
+            fork::build(context, self.libpng, Libpng::new()),
+            fork::build(context, self.zlib, Zlib::new()),
+        )?;
+
+        let [libpng, zlib] = fork::join(context, [libpng, zlib]).await?;
+
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!(
+            "https://downloads.sourceforge.net/project/freetype/freetype2/{version}/freetype-{version}.tar.xz"
+        );
+
+        let source = source::new(name, &path);
+
+        let source_directory = format!("{name}-{version}");
+
+        // HarfBuzz is built on top of FreeType, so FreeType is built without it to avoid the
+        // cycle; bzip2 and brotli (WOFF2) are disabled rather than picked up from the host.
+        AutotoolsPackage::new(name, version, source, self.systems())
+            .with_artifact(&libpng)
+            .with_artifact(&zlib)
+            .with_configure_flag("--disable-static")
+            .with_configure_flag("--with-brotli=no")
+            .with_configure_flag("--with-bzip2=no")
+            .with_configure_flag("--with-harfbuzz=no")
+            .with_configure_flag("--with-png=yes")
+            .with_configure_flag("--with-zlib=yes")
+            .with_prune(prune::DEFAULT)
+            .with_prune_enabled(self.prune)
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .build(context)
+            .await
+    }
+}
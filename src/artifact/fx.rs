@@ -0,0 +1,18 @@
+use crate::{binary_artifact, versions};
+
+binary_artifact! {
+    Fx {
+        name: "fx",
+        version: versions::FX,
+        repository: "antonmedv/fx",
+        tag: "{version}",
+        asset: "fx_{target}",
+        binaries: ["fx_{target}" => "fx"],
+        targets: {
+            Aarch64Darwin => "darwin_arm64",
+            Aarch64Linux => "linux_arm64",
+            X8664Darwin => "darwin_amd64",
+            X8664Linux => "linux_amd64",
+        },
+    }
+}
@@ -1,51 +1,17 @@
-use anyhow::Result;
-use indoc::formatdoc;
-use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
-    context::ConfigContext,
-};
-
-#[derive(Default)]
-pub struct Fzf;
-
-impl Fzf {
-    pub fn new() -> Self {
-        Self
-    }
-
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "fzf";
-        let version = "0.70.0";
-
-        let (source_os, source_arch) = match context.get_system() {
-            Aarch64Darwin => ("darwin", "arm64"),
-            Aarch64Linux => ("linux", "arm64"),
-            X8664Darwin => ("darwin", "amd64"),
-            X8664Linux => ("linux", "amd64"),
-            _ => return Err(anyhow::anyhow!("Unsupported system for {name} artifact")),
-        };
-
-        let source_path = format!(
-            "https://github.com/junegunn/fzf/releases/download/v{version}/{name}-{version}-{source_os}_{source_arch}.tar.gz"
-        );
-
-        let source = ArtifactSource::new(name, &source_path).build();
-
-        let step_script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT/bin\"
-            cp ./source/{name}/{name} \"$VORPAL_OUTPUT/bin/{name}\"
-            chmod +x \"$VORPAL_OUTPUT/bin/{name}\"",
-        };
-
-        let steps = vec![step::shell(context, vec![], vec![], step_script, vec![]).await?];
-
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
-
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{version}")])
-            .with_sources(vec![source])
-            .build(context)
-            .await
+use crate::{binary_artifact, versions};
+
+binary_artifact! {
+    Fzf {
+        name: "fzf",
+        version: versions::FZF,
+        repository: "junegunn/fzf",
+        asset: "fzf-{version}-{target}.tar.gz",
+        binaries: ["fzf" => "fzf"],
+        targets: {
+            Aarch64Darwin => "darwin_arm64",
+            Aarch64Linux => "linux_arm64",
+            X8664Darwin => "darwin_amd64",
+            X8664Linux => "linux_amd64",
+        },
     }
 }
@@ -0,0 +1,170 @@
+use crate::{artifact::ArtifactPackage, error::ArtifactError, target, versions};
+use anyhow::Result;
+use async_trait::async_trait;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::{
+        cpython::{Cpython, DEFAULT_PYTHON_VERSION},
+        get_env_key,
+        go::Go,
+        nodejs::NodeJS,
+    },
+    context::ConfigContext,
+};
+
+pub struct GhActionsToolCache<'a> {
+    go: bool,
+    node: bool,
+    python: bool,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
+
+impl<'a> Default for GhActionsToolCache<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> GhActionsToolCache<'a> {
+    pub fn new() -> Self {
+        Self {
+            go: true,
+            node: true,
+            python: true,
+            target: None,
+            version: None,
+        }
+    }
+
+    pub fn with_go(mut self, go: bool) -> Self {
+        self.go = go;
+        self
+    }
+
+    pub fn with_node(mut self, node: bool) -> Self {
+        self.node = node;
+        self
+    }
+
+    pub fn with_python(mut self, python: bool) -> Self {
+        self.python = python;
+        self
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    // Tags the cache as `name:version`, e.g. after the consumer's own release; the toolchains
+    // keep the versions pinned below.
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for GhActionsToolCache<'a> {
+    fn name(&self) -> &str {
+        "gh-actions-tool-cache"
+    }
+
+    // The cache only re-lays out toolchains pinned elsewhere, it has no release of its own
+    // unless given one.
+    fn version(&self) -> &str {
+        self.version.unwrap_or_default()
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let name = self.name();
+
+        // The SDK only builds go, node and python for the host.
+        if system != context.get_system() {
+            return Err(ArtifactError::UnsupportedSystem {
+                artifact: name.to_string(),
+                system,
+            }
+            .into());
+        }
+
+        let arch = match system {
+            Aarch64Darwin | Aarch64Linux => "arm64",
+            X8664Darwin | X8664Linux => "x64",
+            system => {
+                return Err(ArtifactError::UnsupportedSystem {
+                    artifact: name.to_string(),
+                    system,
+                }
+                .into())
+            }
+        };
+
+        // actions/toolkit tool-cache layout: `<tool>/<version>/<arch>` plus an `<arch>.complete`
+        // marker, which setup-go/setup-node/setup-python check before downloading anything. The
+        // go and node versions must track the pins inside the SDK builders.
+        let mut tools = vec![];
+
+        if self.go {
+            tools.push((
+                "go",
+                versions::GH_ACTIONS_TOOL_CACHE_GO,
+                Go::new().build(context).await?,
+            ));
+        }
+
+        if self.node {
+            tools.push((
+                "node",
+                versions::GH_ACTIONS_TOOL_CACHE_NODE,
+                NodeJS::new().build(context).await?,
+            ));
+        }
+
+        if self.python {
+            tools.push((
+                "Python",
+                DEFAULT_PYTHON_VERSION,
+                Cpython::new().build(context).await?,
+            ));
+        }
+
+        let step_script = tools
+            .iter()
+            .map(|(tool, version, artifact)| {
+                formatdoc! {"
+                    mkdir -pv \"$VORPAL_OUTPUT/{tool}/{version}/{arch}\"
+                    cp -pr \"{artifact}/.\" \"$VORPAL_OUTPUT/{tool}/{version}/{arch}\"
+                    touch \"$VORPAL_OUTPUT/{tool}/{version}/{arch}.complete\"",
+                    artifact = get_env_key(artifact),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        let step_artifacts = tools.into_iter().map(|(_, _, artifact)| artifact).collect();
+
+        let steps =
+            vec![target::shell(context, system, step_artifacts, vec![], step_script).await?];
+
+        let systems = self.systems();
+
+        let aliases = self
+            .version
+            .map(|version| vec![format!("{name}:{version}")])
+            .unwrap_or_default();
+
+        target::build(context, system, name, aliases, vec![], steps, systems).await
+    }
+}
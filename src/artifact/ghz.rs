@@ -0,0 +1,18 @@
+use crate::{binary_artifact, versions};
+
+binary_artifact! {
+    Ghz {
+        name: "ghz",
+        version: versions::GHZ,
+        repository: "bojand/ghz",
+        asset: "ghz-{target}.tar.gz",
+        binaries: ["ghz" => "ghz", "ghz-web" => "ghz-web"],
+        check: "bin/ghz --version" => "{version}",
+        targets: {
+            Aarch64Darwin => "darwin-arm64",
+            Aarch64Linux => "linux-arm64",
+            X8664Darwin => "darwin-x86_64",
+            X8664Linux => "linux-x86_64",
+        },
+    }
+}
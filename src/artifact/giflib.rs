@@ -0,0 +1,135 @@
+use crate::{
+    artifact::ArtifactPackage,
+    builder::{deterministic, jobs, musl, prune},
+    overrides, source, target,
+    variant::{self, Linkage, Variant},
+    versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct Giflib<'a> {
+    linkage: Option<Linkage>,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
+
+impl<'a> Giflib<'a> {
+    pub fn new() -> Self {
+        Self {
+            linkage: None,
+            target: None,
+            version: None,
+        }
+    }
+
+    // Installs only shared or only static libraries; without it the `linkage` variable decides,
+    // see `variant::linkage`.
+    pub fn with_linkage(mut self, linkage: Linkage) -> Self {
+        self.linkage = Some(linkage);
+        self
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Giflib<'a> {
+    fn name(&self) -> &str {
+        "giflib"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::GIFLIB))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let name = self.name();
+        let version = self.version();
+
+        let path =
+            format!("https://downloads.sourceforge.net/project/giflib/giflib-{version}.tar.gz");
+
+        let source = source::new(name, &path);
+
+        let linkage = variant::linkage(context, self.linkage)?;
+
+        let mut exports = vec![deterministic::script(system)];
+        let mut step_artifacts = vec![];
+
+        if linkage == Linkage::Musl {
+            let toolchain = musl::toolchain(context, system).await?;
+
+            exports.push(musl::script(&toolchain));
+
+            step_artifacts.push(toolchain);
+        }
+
+        let exports = exports.join("\n");
+
+        // The Makefile always builds both libraries, so the unwanted one is pruned.
+        let prune = match linkage {
+            Linkage::Shared => vec!["lib/*.a".to_string()],
+            Linkage::Static | Linkage::Musl => {
+                vec!["lib/*.so*".to_string(), "lib/*.dylib".to_string()]
+            }
+        };
+
+        let make_jobs = jobs::make_flag(jobs::limit(context, None)?);
+
+        // giflib has no configure script, and its default target also renders the documentation,
+        // which needs xmlto and ImageMagick; only the library, headers and utilities are built.
+        let step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT\"
+
+            {exports}
+
+            pushd ./source/{name}/{name}-{version}
+
+            sed -i.bak '/$(MAKE) -C doc/d' Makefile
+
+            make {make_jobs} PREFIX=\"$VORPAL_OUTPUT\" all
+            make PREFIX=\"$VORPAL_OUTPUT\" install-bin install-include install-lib",
+        };
+
+        let steps = vec![
+            target::shell(context, system, step_artifacts, vec![], step_script).await?,
+            prune::step(context, system, true, &prune).await?,
+        ];
+
+        target::build(
+            context,
+            system,
+            name,
+            vec![variant::alias(name, version, Variant::Full, linkage)],
+            vec![source],
+            steps,
+            self.systems(),
+        )
+        .await
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{binary_artifact, versions};
+
+binary_artifact! {
+    GitAbsorb {
+        name: "git-absorb",
+        version: versions::GIT_ABSORB,
+        repository: "tummychow/git-absorb",
+        tag: "{version}",
+        asset: "git-absorb-{version}-{target}.tar.gz",
+        binaries: ["git-absorb-{version}-{target}/git-absorb" => "git-absorb"],
+        targets: {
+            Aarch64Darwin => "aarch64-apple-darwin",
+            Aarch64Linux => "aarch64-unknown-linux-musl",
+            X8664Darwin => "x86_64-apple-darwin",
+            X8664Linux => "x86_64-unknown-linux-musl",
+        },
+    }
+}
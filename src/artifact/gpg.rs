@@ -1,19 +1,37 @@
-use crate::artifact::{libassuan, libgcrypt, libgpg_error, libksba, npth};
+use crate::{
+    artifact::{libassuan, libgcrypt, libgpg_error, libksba, npth, ArtifactPackage},
+    builder::{autotools::AutotoolsPackage, prune},
+    context_cache, fork, overrides,
+    source::SourceUrls,
+    variant::{Linkage, Variant},
+    versions,
+};
 use anyhow::Result;
-use indoc::formatdoc;
+use async_trait::async_trait;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{get_env_key, step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::get_env_key,
     context::ConfigContext,
 };
 
-#[derive(Default)]
 pub struct Gpg<'a> {
     libassuan: Option<&'a str>,
     libgcrypt: Option<&'a str>,
     libgpg_error: Option<&'a str>,
     libksba: Option<&'a str>,
     npth: Option<&'a str>,
+    prune: bool,
+    variant: Variant,
+    version: Option<&'a str>,
+}
+
+impl<'a> Default for Gpg<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<'a> Gpg<'a> {
@@ -24,6 +42,9 @@ impl<'a> Gpg<'a> {
             libgpg_error: None,
             libksba: None,
             npth: None,
+            prune: true,
+            variant: Variant::Full,
+            version: None,
         }
     }
 
@@ -52,105 +73,199 @@ impl<'a> Gpg<'a> {
         self
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
+    // The minimal variant keeps signing, verification and encryption but drops smartcard
+    // support, LDAP keyserver lookups, the SQLite-backed TOFU database and keyboxd, and the Web
+    // Key Service tools.
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Keeps the documentation and libtool archives `prune::DEFAULT` removes.
+    pub fn without_prune(mut self) -> Self {
+        self.prune = false;
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Gpg<'a> {
+    fn name(&self) -> &str {
+        "gpg"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::GPG))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
         let libgpg_error = match self.libgpg_error {
             Some(val) => val,
-            None => &libgpg_error::LibgpgError::new().build(context).await?,
+            None => &context_cache::build(context, &libgpg_error::LibgpgError::new()).await?,
         };
 
-        let libassuan = match self.libassuan {
-            Some(val) => val,
-            None => {
-                &libassuan::Libassuan::new()
-                    .with_libgpg_error(libgpg_error)
-                    .build(context)
-                    .await?
-            }
-        };
+        let (libassuan, libgcrypt, libksba, npth) = tokio::try_join!(
+            fork::build_configured(
+                context,
+                self.libassuan,
+                libassuan::Libassuan::new().with_libgpg_error(libgpg_error),
+            ),
+            fork::build_configured(
+                context,
+                self.libgcrypt,
+                libgcrypt::Libgcrypt::new().with_libgpg_error(libgpg_error),
+            ),
+            fork::build_configured(
+                context,
+                self.libksba,
+                libksba::Libksba::new().with_libgpg_error(libgpg_error),
+            ),
+            fork::build(context, self.npth, npth::Npth::new()),
+        )?;
 
-        let libgcrypt = match self.libgcrypt {
-            Some(val) => val,
-            None => {
-                &libgcrypt::Libgcrypt::new()
-                    .with_libgpg_error(libgpg_error)
-                    .build(context)
-                    .await?
-            }
-        };
+        let [libassuan, libgcrypt, libksba, npth] =
+            fork::join(context, [libassuan, libgcrypt, libksba, npth]).await?;
 
-        let libksba = match self.libksba {
-            Some(val) => val,
-            None => {
-                &libksba::Libksba::new()
-                    .with_libgpg_error(libgpg_error)
-                    .build(context)
-                    .await?
-            }
-        };
+        let name = self.name();
+        let version = self.version();
 
-        let npth = match self.npth {
-            Some(val) => val,
-            None => &npth::Npth::new().build(context).await?,
-        };
+        let file = format!("gnupg/gnupg-{version}.tar.bz2");
+        let path = format!("https://gnupg.org/ftp/gcrypt/{file}");
+        let mirror = format!("https://mirrors.dotsrc.org/gcrypt/{file}");
 
-        let name = "gpg";
-        let version = "2.5.16";
+        let source = SourceUrls::new(name, &path).with_mirror(&mirror).build();
 
-        let path = format!("https://gnupg.org/ftp/gcrypt/gnupg/gnupg-{version}.tar.bz2");
+        let source_directory = format!("gnupg-{version}");
 
-        let source = ArtifactSource::new(name, &path).build();
+        let libassuan_key = get_env_key(&libassuan.to_string());
+        let libgcrypt_key = get_env_key(&libgcrypt.to_string());
+        let libgpg_error_key = get_env_key(&libgpg_error.to_string());
+        let libksba_key = get_env_key(&libksba.to_string());
+        let npth_key = get_env_key(&npth.to_string());
 
-        let script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT\"
+        let mut package = AutotoolsPackage::new(name, version, source, self.systems())
+            .with_artifact(libgpg_error)
+            .with_artifact(&npth)
+            .with_artifact(&libgcrypt)
+            .with_artifact(&libassuan)
+            .with_artifact(&libksba)
+            .with_assert_linkage()
+            .with_assert_outputs(vec!["bin/gpg", "bin/gpg-agent", "bin/gpgconf"])
+            .with_configure_flag(&format!("--with-libgpg-error-prefix={libgpg_error_key}"))
+            .with_configure_flag(&format!("--with-npth-prefix={npth_key}"))
+            .with_configure_flag(&format!("--with-libgcrypt-prefix={libgcrypt_key}"))
+            .with_configure_flag(&format!("--with-libassuan-prefix={libassuan_key}"))
+            .with_configure_flag(&format!("--with-ksba-prefix={libksba_key}"))
+            .with_configure_flag("--disable-doc")
+            .with_dylib_audit()
+            .with_prune(prune::DEFAULT)
+            .with_prune_enabled(self.prune)
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .with_variant(self.variant, Linkage::default());
 
-            pushd ./source/{name}/gnupg-{version}
+        if self.variant == Variant::Minimal {
+            package = package
+                .with_configure_flag("--disable-card-support")
+                .with_configure_flag("--disable-ccid-driver")
+                .with_configure_flag("--disable-keyboxd")
+                .with_configure_flag("--disable-ldap")
+                .with_configure_flag("--disable-sqlite")
+                .with_configure_flag("--disable-wks-tools");
+        }
 
-            export PATH=\"{libgpg_error}/bin:{npth}/bin:{libgcrypt}/bin:{libassuan}/bin:{libksba}/bin:$PATH\"
-            export PKG_CONFIG_PATH=\"{libgpg_error}/lib/pkgconfig:{npth}/lib/pkgconfig:{libgcrypt}/lib/pkgconfig:{libassuan}/lib/pkgconfig:{libksba}/lib/pkgconfig\"
-            export CPPFLAGS=\"-I{libgpg_error}/include -I{npth}/include -I{libgcrypt}/include -I{libassuan}/include -I{libksba}/include\"
-            export LDFLAGS=\"-L{libgpg_error}/lib -L{npth}/lib -L{libgcrypt}/lib -L{libassuan}/lib -L{libksba}/lib -Wl,-rpath,{libgpg_error}/lib -Wl,-rpath,{npth}/lib -Wl,-rpath,{libgcrypt}/lib -Wl,-rpath,{libassuan}/lib -Wl,-rpath,{libksba}/lib\"
+        package.build(context).await
+    }
+}
 
-            ./configure \
-                --prefix=\"$VORPAL_OUTPUT\" \
-                --with-libgpg-error-prefix={libgpg_error} \
-                --with-npth-prefix={npth} \
-                --with-libgcrypt-prefix={libgcrypt} \
-                --with-libassuan-prefix={libassuan} \
-                --with-ksba-prefix={libksba} \
-                --disable-doc
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        aliases,
+        testing::{self, MockContext},
+        variant,
+    };
 
-            make
-            make install",
-            libassuan = get_env_key(&libassuan.to_string()),
-            libgcrypt = get_env_key(&libgcrypt.to_string()),
-            libgpg_error = get_env_key(&libgpg_error.to_string()),
-            libksba = get_env_key(&libksba.to_string()),
-            npth = get_env_key(&npth.to_string()),
-        };
+    #[tokio::test]
+    async fn build_registers_gnupg_libraries() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Darwin).build().await?;
 
-        let steps = vec![
-            step::shell(
-                context,
-                vec![
-                    libassuan.to_string(),
-                    libgcrypt.to_string(),
-                    libgpg_error.to_string(),
-                    libksba.to_string(),
-                    npth.to_string(),
-                ],
-                vec![],
-                script,
-                vec![],
-            )
-            .await?,
-        ];
-
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
-
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{version}")])
-            .with_sources(vec![source])
+        let gpg = Gpg::new().build(context).await?;
+
+        assert_eq!(
+            testing::dependencies(context, &gpg)?,
+            ["libassuan", "libgcrypt", "libgpg-error", "libksba", "npth"]
+        );
+        assert_eq!(
+            testing::aliases(context, &gpg)?,
+            aliases::versioned("gpg", versions::GPG)
+        );
+        assert_eq!(
+            testing::source_paths(context, &gpg)?,
+            [format!(
+                "https://gnupg.org/ftp/gcrypt/gnupg/gnupg-{}.tar.bz2",
+                versions::GPG
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_minimal_variant_is_suffixed() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Darwin).build().await?;
+
+        let gpg = Gpg::new()
+            .with_variant(Variant::Minimal)
             .build(context)
-            .await
+            .await?;
+
+        assert_eq!(
+            testing::aliases(context, &gpg)?,
+            variant::aliases("gpg", versions::GPG, Variant::Minimal, Linkage::Shared)
+        );
+        assert_ne!(gpg, Gpg::new().build(context).await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_shares_libgpg_error() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Darwin).build().await?;
+
+        let libgpg_error = libgpg_error::LibgpgError::new().build(context).await?;
+
+        let gpg = Gpg::new()
+            .with_libgpg_error(&libgpg_error)
+            .build(context)
+            .await?;
+
+        for dependency in testing::artifact(context, &gpg)?.steps[0].artifacts.iter() {
+            let name = testing::artifact(context, dependency)?.name;
+
+            if name == "libgpg-error" {
+                assert_eq!(dependency, &libgpg_error);
+            }
+
+            if name != "npth" && name != "libgpg-error" {
+                assert_eq!(
+                    testing::dependencies(context, dependency)?,
+                    ["libgpg-error"]
+                );
+            }
+        }
+
+        Ok(())
     }
 }
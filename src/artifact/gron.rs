@@ -0,0 +1,17 @@
+use crate::{binary_artifact, versions};
+
+binary_artifact! {
+    Gron {
+        name: "gron",
+        version: versions::GRON,
+        repository: "tomnomnom/gron",
+        asset: "gron-{target}-{version}.tgz",
+        binaries: ["gron" => "gron"],
+        targets: {
+            Aarch64Darwin => "darwin-arm64",
+            Aarch64Linux => "linux-arm64",
+            X8664Darwin => "darwin-amd64",
+            X8664Linux => "linux-amd64",
+        },
+    }
+}
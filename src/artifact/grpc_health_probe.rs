@@ -0,0 +1,18 @@
+use crate::{binary_artifact, versions};
+
+binary_artifact! {
+    GrpcHealthProbe {
+        name: "grpc-health-probe",
+        version: versions::GRPC_HEALTH_PROBE,
+        repository: "grpc-ecosystem/grpc-health-probe",
+        asset: "grpc_health_probe-{target}",
+        binaries: ["grpc_health_probe-{target}" => "grpc_health_probe"],
+        check: "bin/grpc_health_probe -version" => "{version}",
+        targets: {
+            Aarch64Darwin => "darwin-arm64",
+            Aarch64Linux => "linux-arm64",
+            X8664Darwin => "darwin-amd64",
+            X8664Linux => "linux-amd64",
+        },
+    }
+}
@@ -0,0 +1,246 @@
+use crate::{
+    artifact::{openssl::Openssl, pcre2::Pcre2, ArtifactPackage},
+    builder::{check, jobs, prune},
+    error::ArtifactError,
+    fork, overrides, source, target, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api,
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::get_env_key,
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct Haproxy<'a> {
+    check: Option<bool>,
+    config: Option<api::artifact::ArtifactSource>,
+    openssl: Option<&'a str>,
+    pcre2: Option<&'a str>,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
+
+impl<'a> Haproxy<'a> {
+    pub fn new() -> Self {
+        Self {
+            check: None,
+            config: None,
+            openssl: None,
+            pcre2: None,
+            target: None,
+            version: None,
+        }
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    // Bundles the `haproxy.cfg` found at the root of `config`, which bin/haproxy then loads by
+    // default. The configuration is validated at build time.
+    pub fn with_config(mut self, config: api::artifact::ArtifactSource) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn with_openssl(mut self, openssl: &'a str) -> Self {
+        self.openssl = Some(openssl);
+        self
+    }
+
+    pub fn with_pcre2(mut self, pcre2: &'a str) -> Self {
+        self.pcre2 = Some(pcre2);
+        self
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Haproxy<'a> {
+    fn name(&self) -> &str {
+        "haproxy"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::HAPROXY))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let (openssl, pcre2) = tokio::try_join!(
+            fork::build_for(context, system, self.openssl, Openssl::new()),
+            fork::build_for(context, system, self.pcre2, Pcre2::new()),
+        )?;
+
+        let [openssl, pcre2] = fork::join(context, [openssl, pcre2]).await?;
+
+        let name = self.name();
+        let version = self.version();
+
+        let Some((branch, _)) = version.rsplit_once('.') else {
+            return Err(ArtifactError::UnsupportedVersion {
+                artifact: name.to_string(),
+                version: version.to_string(),
+            }
+            .into());
+        };
+
+        let source_path =
+            format!("https://www.haproxy.org/download/{branch}/src/haproxy-{version}.tar.gz");
+
+        let source = source::new(name, &source_path);
+
+        let make_target = match system {
+            Aarch64Darwin | X8664Darwin => "osx",
+            Aarch64Linux | X8664Linux => "linux-glibc",
+            system => {
+                return Err(ArtifactError::UnsupportedSystem {
+                    artifact: name.to_string(),
+                    system,
+                }
+                .into())
+            }
+        };
+
+        let openssl_key = get_env_key(&openssl);
+        let pcre2_key = get_env_key(&pcre2);
+
+        let make_flags = [
+            format!("TARGET={make_target}"),
+            "USE_OPENSSL=1".to_string(),
+            format!("SSL_INC=\"{openssl_key}/include\""),
+            format!("SSL_LIB=\"{openssl_key}/lib\""),
+            "USE_PCRE2=1".to_string(),
+            "USE_PCRE2_JIT=1".to_string(),
+            format!("PCRE2DIR=\"{pcre2_key}\""),
+            format!("LDFLAGS=\"-Wl,-rpath,{openssl_key}/lib -Wl,-rpath,{pcre2_key}/lib\""),
+        ];
+
+        let step_artifacts = vec![openssl, pcre2];
+
+        let mut sources = vec![source];
+
+        let config_script = match self.config.clone() {
+            None => String::new(),
+            Some(config) => {
+                let script = formatdoc! {"
+                    mkdir -pv \"$VORPAL_OUTPUT/etc/{name}\" \"$VORPAL_OUTPUT/libexec\"
+                    cp ./source/{config}/haproxy.cfg \"$VORPAL_OUTPUT/etc/{name}/haproxy.cfg\"
+
+                    mv \"$VORPAL_OUTPUT/bin/{name}\" \"$VORPAL_OUTPUT/libexec/{name}\"
+
+                    cat << EOF > \"$VORPAL_OUTPUT/bin/{name}\"
+                    #!/bin/sh
+                    exec \"$VORPAL_OUTPUT/libexec/{name}\" -f \"$VORPAL_OUTPUT/etc/{name}/haproxy.cfg\" \"\\$@\"
+                    EOF
+
+                    chmod +x \"$VORPAL_OUTPUT/bin/{name}\"",
+                    config = config.name,
+                };
+
+                sources.push(config);
+
+                script
+            }
+        };
+
+        let make_jobs = jobs::make_flag(jobs::limit(context, None)?);
+
+        let step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT/bin\"
+
+            pushd ./source/{name}/{name}-{version}
+
+            make {make_jobs} \\
+                {make_flags}
+
+            make install \\
+                PREFIX=\"$VORPAL_OUTPUT\" \\
+                SBINDIR=\"$VORPAL_OUTPUT/bin\"
+
+            popd
+
+            {config_script}",
+            make_flags = make_flags.join(" \\\n    "),
+        };
+
+        let mut steps = vec![
+            target::shell(context, system, step_artifacts, vec![], step_script).await?,
+            prune::step(
+                context,
+                system,
+                true,
+                &["doc".to_string(), "share/man".to_string()],
+            )
+            .await?,
+        ];
+
+        if check::enabled(context, system, self.check)? {
+            steps.push(
+                check::step(
+                    context,
+                    system,
+                    vec![],
+                    "bin/haproxy -v",
+                    &format!("HAProxy version {version}"),
+                )
+                .await?,
+            );
+        }
+
+        // Validating the bundled config is part of building it, not an opt-in check.
+        if self.config.is_some() {
+            steps.push(
+                check::step(
+                    context,
+                    system,
+                    vec![],
+                    "bin/haproxy -c",
+                    "Configuration file is valid",
+                )
+                .await?,
+            );
+        }
+
+        target::build(
+            context,
+            system,
+            name,
+            vec![format!("{name}:{version}")],
+            sources,
+            steps,
+            self.systems(),
+        )
+        .await
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{
+    artifact::{cmake::Cmake, freetype::Freetype, ArtifactPackage},
+    builder::{cmake::CmakePackage, prune},
+    fork, overrides, source, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+pub struct Harfbuzz<'a> {
+    cmake: Option<&'a str>,
+    freetype: Option<&'a str>,
+    prune: bool,
+    version: Option<&'a str>,
+}
+
+impl<'a> Default for Harfbuzz<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Harfbuzz<'a> {
+    pub fn new() -> Self {
+        Self {
+            cmake: None,
+            freetype: None,
+            prune: true,
+            version: None,
+        }
+    }
+
+    pub fn with_cmake(mut self, cmake: &'a str) -> Self {
+        self.cmake = Some(cmake);
+        self
+    }
+
+    pub fn with_freetype(mut self, freetype: &'a str) -> Self {
+        self.freetype = Some(freetype);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Keeps the documentation and libtool archives `prune::DEFAULT` removes.
+    pub fn without_prune(mut self) -> Self {
+        self.prune = false;
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Harfbuzz<'a> {
+    fn name(&self) -> &str {
+        "harfbuzz"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::HARFBUZZ))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let (cmake, freetype) = tokio::try_join!(
+            fork::build(context, self.cmake, Cmake::new()),
+            fork::build(context, self.freetype, Freetype::new()),
+        )?;
+
+        let [cmake, freetype] = fork::join(context, [cmake, freetype]).await?;
+
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!(
+            "https://github.com/harfbuzz/harfbuzz/releases/download/{version}/harfbuzz-{version}.tar.xz"
+        );
+
+        let source = source::new(name, &path);
+
+        let source_directory = format!("{name}-{version}");
+
+        // Only the libraries are built: the hb-view/hb-shape utilities need glib and cairo, and
+        // glib, ICU and graphite integrations stay off so only FreeType is linked in.
+        CmakePackage::new(name, version, source, self.systems())
+            .with_artifact(&freetype)
+            .with_cmake(&cmake)
+            .with_definition("BUILD_SHARED_LIBS=ON")
+            .with_definition("CMAKE_INSTALL_LIBDIR=lib")
+            .with_definition("HB_BUILD_UTILS=OFF")
+            .with_definition("HB_HAVE_FREETYPE=ON")
+            .with_definition("HB_HAVE_GLIB=OFF")
+            .with_definition("HB_HAVE_GOBJECT=OFF")
+            .with_definition("HB_HAVE_GRAPHITE2=OFF")
+            .with_definition("HB_HAVE_ICU=OFF")
+            .with_prune(prune::DEFAULT)
+            .with_prune_enabled(self.prune)
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .build(context)
+            .await
+    }
+}
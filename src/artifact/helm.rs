@@ -1,51 +1,108 @@
+use crate::{
+    artifact::ArtifactPackage, builder::completions, error::ArtifactError, overrides, source,
+    target, versions,
+};
 use anyhow::Result;
+use async_trait::async_trait;
 use indoc::formatdoc;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
     context::ConfigContext,
 };
 
 #[derive(Default)]
-pub struct Helm;
+pub struct Helm<'a> {
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
 
-impl Helm {
+impl<'a> Helm<'a> {
     pub fn new() -> Self {
-        Self
+        Self {
+            target: None,
+            version: None,
+        }
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Helm<'a> {
+    fn name(&self) -> &str {
+        "helm"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::HELM))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "helm";
-        let source_version = "4.0.4";
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let name = self.name();
+        let source_version = self.version();
 
-        let source_system = match context.get_system() {
+        let source_system = match system {
             Aarch64Darwin => "darwin-arm64",
             Aarch64Linux => "linux-arm64",
             X8664Darwin => "darwin-amd64",
             X8664Linux => "linux-amd64",
-            _ => return Err(anyhow::anyhow!("Unsupported system for helm artifact")),
+            system => {
+                return Err(ArtifactError::UnsupportedSystem {
+                    artifact: name.to_string(),
+                    system,
+                }
+                .into())
+            }
         };
 
         let source_path =
             format!("https://get.helm.sh/helm-v{source_version}-{source_system}.tar.gz");
 
-        let source = ArtifactSource::new(name, &source_path).build();
+        let source = source::for_system(name, &source_path, system)?;
+
+        let completions_script = completions::generate("helm", "completion {shell}");
 
         let step_script = formatdoc! {"
             mkdir -pv \"$VORPAL_OUTPUT/bin\"
             pushd ./source/{name}/{source_system}
             cp helm \"$VORPAL_OUTPUT/bin/helm\"
-            chmod +x \"$VORPAL_OUTPUT/bin/helm\"",
+            chmod +x \"$VORPAL_OUTPUT/bin/helm\"
+            popd
+
+            {completions_script}",
         };
 
-        let steps = vec![step::shell(context, vec![], vec![], step_script, vec![]).await?];
+        let steps = vec![target::shell(context, system, vec![], vec![], step_script).await?];
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let systems = self.systems();
 
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{source_version}")])
-            .with_sources(vec![source])
-            .build(context)
-            .await
+        target::build(
+            context,
+            system,
+            name,
+            vec![format!("{name}:{source_version}")],
+            vec![source],
+            steps,
+            systems,
+        )
+        .await
     }
 }
@@ -0,0 +1,17 @@
+use crate::{binary_artifact, versions};
+
+binary_artifact! {
+    Hexyl {
+        name: "hexyl",
+        version: versions::HEXYL,
+        repository: "sharkdp/hexyl",
+        asset: "hexyl-v{version}-{target}.tar.gz",
+        binaries: ["hexyl-v{version}-{target}/hexyl" => "hexyl"],
+        targets: {
+            Aarch64Darwin => "aarch64-apple-darwin",
+            Aarch64Linux => "aarch64-unknown-linux-gnu",
+            X8664Darwin => "x86_64-apple-darwin",
+            X8664Linux => "x86_64-unknown-linux-musl",
+        },
+    }
+}
@@ -1,52 +1,17 @@
-use anyhow::Result;
-use indoc::formatdoc;
-use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
-    context::ConfigContext,
-};
-
-#[derive(Default)]
-pub struct Hunk;
-
-impl Hunk {
-    pub fn new() -> Self {
-        Self
-    }
-
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "hunk";
-        let source_version = "0.16.0";
-
-        let (source_os, source_arch) = match context.get_system() {
-            Aarch64Darwin => ("darwin", "arm64"),
-            Aarch64Linux => ("linux", "arm64"),
-            X8664Darwin => ("darwin", "x64"),
-            X8664Linux => ("linux", "x64"),
-            _ => return Err(anyhow::anyhow!("Unsupported system for hunk artifact")),
-        };
-
-        let source_path = format!(
-            "https://github.com/modem-dev/hunk/releases/download/v{source_version}/hunkdiff-{source_os}-{source_arch}.tar.gz"
-        );
-
-        let source = ArtifactSource::new(name, &source_path).build();
-
-        let step_script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT/bin\"
-            pushd ./source/{name}/hunkdiff-{source_os}-{source_arch}
-            cp hunk \"$VORPAL_OUTPUT/bin/hunk\"
-            chmod +x \"$VORPAL_OUTPUT/bin/hunk\"",
-        };
-
-        let steps = vec![step::shell(context, vec![], vec![], step_script, vec![]).await?];
-
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
-
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{source_version}")])
-            .with_sources(vec![source])
-            .build(context)
-            .await
+use crate::{binary_artifact, versions};
+
+binary_artifact! {
+    Hunk {
+        name: "hunk",
+        version: versions::HUNK,
+        repository: "modem-dev/hunk",
+        asset: "hunkdiff-{target}.tar.gz",
+        binaries: ["hunkdiff-{target}/hunk" => "hunk"],
+        targets: {
+            Aarch64Darwin => "darwin-arm64",
+            Aarch64Linux => "linux-arm64",
+            X8664Darwin => "darwin-x64",
+            X8664Linux => "linux-x64",
+        },
     }
 }
@@ -0,0 +1,116 @@
+use crate::{
+    artifact::ArtifactPackage,
+    builder::{autotools::AutotoolsPackage, prune},
+    error::ArtifactError,
+    overrides, source,
+    variant::{Linkage, Variant},
+    versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+pub struct Icu<'a> {
+    linkage: Linkage,
+    prune: bool,
+    version: Option<&'a str>,
+}
+
+impl<'a> Default for Icu<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Icu<'a> {
+    pub fn new() -> Self {
+        Self {
+            linkage: Linkage::Shared,
+            prune: true,
+            version: None,
+        }
+    }
+
+    // Static builds install `libicu*.a` instead of shared libraries, for consumers that embed ICU.
+    pub fn with_linkage(mut self, linkage: Linkage) -> Self {
+        self.linkage = linkage;
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Keeps the documentation and libtool archives `prune::DEFAULT` removes.
+    pub fn without_prune(mut self) -> Self {
+        self.prune = false;
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Icu<'a> {
+    fn name(&self) -> &str {
+        "icu"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::ICU))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name();
+        let version = self.version();
+
+        let tag = version.replace('.', "-");
+        let archive = version.replace('.', "_");
+
+        let path = format!(
+            "https://github.com/unicode-org/icu/releases/download/release-{tag}/icu4c-{archive}-src.tgz"
+        );
+
+        let source = source::new(name, &path);
+
+        // Only the common, i18n and data libraries (plus the tools that build the data) are kept.
+        let package = AutotoolsPackage::new(name, version, source, self.systems())
+            .with_configure_flag("--disable-extras")
+            .with_configure_flag("--disable-icuio")
+            .with_configure_flag("--disable-layoutex")
+            .with_configure_flag("--disable-samples")
+            .with_configure_flag("--disable-tests")
+            .with_prune(prune::DEFAULT)
+            .with_prune_enabled(self.prune)
+            .with_source_directory("icu/source")
+            .with_strip()
+            .with_variant(Variant::Full, self.linkage);
+
+        let package = match self.linkage {
+            Linkage::Shared => package.with_configure_flag("--disable-static"),
+            Linkage::Static => package
+                .with_configure_flag("--disable-shared")
+                .with_configure_flag("--enable-static"),
+            // ICU is C++, which the musl toolchain does not provide.
+            Linkage::Musl => {
+                return Err(ArtifactError::UnsupportedVariant {
+                    artifact: name.to_string(),
+                    variant: "musl".to_string(),
+                }
+                .into())
+            }
+        };
+
+        package.build(context).await
+    }
+}
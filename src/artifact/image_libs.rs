@@ -0,0 +1,189 @@
+use crate::{
+    artifact::{
+        giflib::Giflib, libjpeg_turbo::LibjpegTurbo, libpng::Libpng, libwebp::Libwebp,
+        ArtifactPackage,
+    },
+    builder::toolset::Toolset,
+    fork, overrides,
+    variant::{self, Linkage, Variant},
+    versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+// The core image codecs, for consumers such as ImageMagick or ffmpeg's image demuxers, merged
+// into one prefix of symlinks. libwebp is built against the same giflib, libjpeg-turbo and libpng
+// as the rest of the bundle.
+#[derive(Default)]
+pub struct ImageLibs<'a> {
+    giflib: Option<&'a str>,
+    libjpeg_turbo: Option<&'a str>,
+    libpng: Option<&'a str>,
+    libwebp: Option<&'a str>,
+    linkage: Option<Linkage>,
+    version: Option<&'a str>,
+}
+
+impl<'a> ImageLibs<'a> {
+    pub fn new() -> Self {
+        Self {
+            giflib: None,
+            libjpeg_turbo: None,
+            libpng: None,
+            libwebp: None,
+            linkage: None,
+            version: None,
+        }
+    }
+
+    pub fn with_giflib(mut self, giflib: &'a str) -> Self {
+        self.giflib = Some(giflib);
+        self
+    }
+
+    pub fn with_libjpeg_turbo(mut self, libjpeg_turbo: &'a str) -> Self {
+        self.libjpeg_turbo = Some(libjpeg_turbo);
+        self
+    }
+
+    pub fn with_libpng(mut self, libpng: &'a str) -> Self {
+        self.libpng = Some(libpng);
+        self
+    }
+
+    pub fn with_libwebp(mut self, libwebp: &'a str) -> Self {
+        self.libwebp = Some(libwebp);
+        self
+    }
+
+    // Builds every codec with only shared or only static libraries; without it the `linkage`
+    // variable decides, see `variant::linkage`.
+    pub fn with_linkage(mut self, linkage: Linkage) -> Self {
+        self.linkage = Some(linkage);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for ImageLibs<'a> {
+    fn name(&self) -> &str {
+        "image-libs"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::IMAGE_LIBS))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let linkage = variant::linkage(context, self.linkage)?;
+
+        let (giflib, libjpeg_turbo, libpng) = match self.linkage {
+            Some(linkage) => tokio::try_join!(
+                fork::build_configured(context, self.giflib, Giflib::new().with_linkage(linkage)),
+                fork::build_configured(
+                    context,
+                    self.libjpeg_turbo,
+                    LibjpegTurbo::new().with_linkage(linkage)
+                ),
+                fork::build_configured(context, self.libpng, Libpng::new().with_linkage(linkage)),
+            )?,
+            None => tokio::try_join!(
+                fork::build(context, self.giflib, Giflib::new()),
+                fork::build(context, self.libjpeg_turbo, LibjpegTurbo::new()),
+                fork::build(context, self.libpng, Libpng::new()),
+            )?,
+        };
+
+        let [giflib, libjpeg_turbo, libpng] =
+            fork::join(context, [giflib, libjpeg_turbo, libpng]).await?;
+
+        let libwebp = match self.libwebp {
+            Some(val) => val.to_string(),
+            None => {
+                Libwebp::new()
+                    .with_giflib(&giflib)
+                    .with_libjpeg_turbo(&libjpeg_turbo)
+                    .with_libpng(&libpng)
+                    .with_linkage(linkage)
+                    .build(context)
+                    .await?
+            }
+        };
+
+        let name = self.name();
+        let version = self.version();
+
+        Toolset::new(name, self.systems())
+            .with_aliases(vec![variant::alias(name, version, Variant::Full, linkage)])
+            .with_artifacts(vec![giflib, libjpeg_turbo, libpng, libwebp])
+            .with_directories(vec!["include", "lib"])
+            .build(context)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, MockContext};
+    use vorpal_sdk::api::artifact::ArtifactSystem::{Aarch64Darwin, X8664Linux};
+
+    #[tokio::test]
+    async fn build_shares_codecs_with_libwebp() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Darwin).build().await?;
+
+        let image_libs = ImageLibs::new().build(context).await?;
+
+        assert_eq!(
+            testing::dependencies(context, &image_libs)?,
+            ["giflib", "libjpeg-turbo", "libpng", "libwebp"]
+        );
+
+        let libwebp = Libwebp::new().build(context).await?;
+
+        assert!(testing::artifact(context, &image_libs)?.steps[0]
+            .artifacts
+            .contains(&libwebp));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_follows_linkage_variable() -> Result<()> {
+        let context = &mut MockContext::new(X8664Linux)
+            .with_variable(variant::VARIABLE, "static")
+            .build()
+            .await?;
+
+        let image_libs = ImageLibs::new().build(context).await?;
+
+        let artifacts = testing::artifact(context, &image_libs)?.steps[0]
+            .artifacts
+            .clone();
+
+        for digest in artifacts {
+            let aliases = testing::aliases(context, &digest)?;
+
+            assert!(aliases.iter().all(|alias| alias.ends_with("static")));
+        }
+
+        Ok(())
+    }
+}
@@ -1,51 +1,17 @@
-use anyhow::Result;
-use indoc::formatdoc;
-use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
-    context::ConfigContext,
-};
-
-#[derive(Default)]
-pub struct Jj;
-
-impl Jj {
-    pub fn new() -> Self {
-        Self
-    }
-
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "jj";
-        let source_version = "0.37.0";
-
-        let source_system = match context.get_system() {
+use crate::{binary_artifact, versions};
+
+binary_artifact! {
+    Jj {
+        name: "jj",
+        version: versions::JJ,
+        repository: "jj-vcs/jj",
+        asset: "jj-v{version}-{target}.tar.gz",
+        binaries: ["jj" => "jj"],
+        targets: {
             Aarch64Darwin => "aarch64-apple-darwin",
             Aarch64Linux => "aarch64-unknown-linux-musl",
             X8664Darwin => "x86_64-apple-darwin",
             X8664Linux => "x86_64-unknown-linux-musl",
-            _ => return Err(anyhow::anyhow!("Unsupported system for jj artifact")),
-        };
-
-        let source_path = format!(
-            "https://github.com/jj-vcs/jj/releases/download/v{source_version}/jj-v{source_version}-{source_system}.tar.gz"
-        );
-
-        let source = ArtifactSource::new(name, &source_path).build();
-
-        let step_script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT/bin\"
-            cp ./source/{name}/jj \"$VORPAL_OUTPUT/bin/jj\"
-            chmod +x \"$VORPAL_OUTPUT/bin/jj\"",
-        };
-
-        let steps = vec![step::shell(context, vec![], vec![], step_script, vec![]).await?];
-
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
-
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{source_version}")])
-            .with_sources(vec![source])
-            .build(context)
-            .await
+        },
     }
 }
@@ -0,0 +1,17 @@
+use crate::{binary_artifact, versions};
+
+// jless publishes no aarch64 linux build.
+binary_artifact! {
+    Jless {
+        name: "jless",
+        version: versions::JLESS,
+        repository: "PaulJuliusMartinez/jless",
+        asset: "jless-v{version}-{target}.zip",
+        binaries: ["jless" => "jless"],
+        targets: {
+            Aarch64Darwin => "aarch64-apple-darwin",
+            X8664Darwin => "x86_64-apple-darwin",
+            X8664Linux => "x86_64-unknown-linux-gnu",
+        },
+    }
+}
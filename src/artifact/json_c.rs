@@ -1,20 +1,30 @@
-use crate::artifact::cmake;
+use crate::{
+    artifact::{cmake, ArtifactPackage},
+    builder::cmake::CmakePackage,
+    context_cache, overrides, source, versions,
+};
 use anyhow::Result;
-use indoc::formatdoc;
+use async_trait::async_trait;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{get_env_key, step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
     context::ConfigContext,
 };
 
 #[derive(Default)]
 pub struct JsonC<'a> {
     cmake: Option<&'a str>,
+    version: Option<&'a str>,
 }
 
 impl<'a> JsonC<'a> {
     pub fn new() -> Self {
-        Self { cmake: None }
+        Self {
+            cmake: None,
+            version: None,
+        }
     }
 
     pub fn with_cmake(mut self, cmake: &'a str) -> Self {
@@ -22,50 +32,50 @@ impl<'a> JsonC<'a> {
         self
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let cmake = match self.cmake {
-            Some(val) => val,
-            None => &cmake::Cmake::new().build(context).await?,
-        };
-
-        let name = "json-c";
-        let version = "0.18";
-        let tag = "json-c-0.18-20240915";
-
-        let path = format!("https://github.com/json-c/json-c/archive/refs/tags/{tag}.tar.gz");
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
 
-        let source = ArtifactSource::new(name, &path).build();
+#[async_trait]
+impl<'a> ArtifactPackage for JsonC<'a> {
+    fn name(&self) -> &str {
+        "json-c"
+    }
 
-        let script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT\"
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::JSON_C))
+    }
 
-            BUILD_DIR=\"$(pwd)/build\"
-            mkdir -p \"$BUILD_DIR\"
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
 
-            pushd \"$BUILD_DIR\"
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let cmake = match self.cmake {
+            Some(val) => val,
+            None => &context_cache::build(context, &cmake::Cmake::new()).await?,
+        };
 
-            {cmake}/bin/cmake \
-                -DCMAKE_BUILD_TYPE=RELEASE \
-                -DCMAKE_INSTALL_PREFIX=\"$VORPAL_OUTPUT\" \
-                -DCMAKE_POLICY_VERSION_MINIMUM=3.5 \
-                -DBUILD_SHARED_LIBS=OFF \
-                -DBUILD_TESTING=OFF \
-                -DDISABLE_THREAD_LOCAL_STORAGE=ON \
-                \"$(pwd)/../source/{name}/json-c-{tag}\"
+        let name = self.name();
+        let version = self.version();
+        let tag = format!("json-c-{version}");
 
-            make -j$(nproc 2>/dev/null || sysctl -n hw.ncpu) install
-            popd",
-            cmake = get_env_key(&cmake.to_string()),
-        };
+        let path = format!("https://github.com/json-c/json-c/archive/refs/tags/{tag}.tar.gz");
 
-        let steps =
-            vec![step::shell(context, vec![cmake.to_string()], vec![], script, vec![]).await?];
+        let source = source::new(name, &path);
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let source_directory = format!("json-c-{tag}");
 
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{version}")])
-            .with_sources(vec![source])
+        CmakePackage::new(name, version, source, self.systems())
+            .with_cmake(cmake)
+            .with_definition("CMAKE_POLICY_VERSION_MINIMUM=3.5")
+            .with_definition("BUILD_SHARED_LIBS=OFF")
+            .with_definition("BUILD_TESTING=OFF")
+            .with_definition("DISABLE_THREAD_LOCAL_STORAGE=ON")
+            .with_source_directory(&source_directory)
             .build(context)
             .await
     }
@@ -1,52 +1,17 @@
-use anyhow::Result;
-use indoc::formatdoc;
-use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
-    context::ConfigContext,
-};
-
-#[derive(Default)]
-pub struct Kubeseal;
-
-impl Kubeseal {
-    pub fn new() -> Self {
-        Self
-    }
-
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "kubeseal";
-        let source_version = "0.34.0";
-
-        let source_system = match context.get_system() {
+use crate::{binary_artifact, versions};
+
+binary_artifact! {
+    Kubeseal {
+        name: "kubeseal",
+        version: versions::KUBESEAL,
+        repository: "bitnami-labs/sealed-secrets",
+        asset: "kubeseal-{version}-{target}.tar.gz",
+        binaries: ["kubeseal" => "kubeseal"],
+        targets: {
             Aarch64Darwin => "darwin-arm64",
             Aarch64Linux => "linux-arm64",
             X8664Darwin => "darwin-amd64",
             X8664Linux => "linux-amd64",
-            _ => return Err(anyhow::anyhow!("Unsupported system for kubeseal artifact")),
-        };
-
-        let source_path = format!(
-            "https://github.com/bitnami-labs/sealed-secrets/releases/download/v{source_version}/kubeseal-{source_version}-{source_system}.tar.gz"
-        );
-
-        let source = ArtifactSource::new(name, &source_path).build();
-
-        let step_script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT/bin\"
-            pushd ./source/{name}
-            cp kubeseal \"$VORPAL_OUTPUT/bin/kubeseal\"
-            chmod +x \"$VORPAL_OUTPUT/bin/kubeseal\"",
-        };
-
-        let steps = vec![step::shell(context, vec![], vec![], step_script, vec![]).await?];
-
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
-
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{source_version}")])
-            .with_sources(vec![source])
-            .build(context)
-            .await
+        },
     }
 }
@@ -0,0 +1,104 @@
+use crate::{
+    artifact::ArtifactPackage,
+    builder::{deterministic, jobs, prune},
+    overrides, source, target, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Linux, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct Libcap<'a> {
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
+
+impl<'a> Libcap<'a> {
+    pub fn new() -> Self {
+        Self {
+            target: None,
+            version: None,
+        }
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Libcap<'a> {
+    fn name(&self) -> &str {
+        "libcap"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::LIBCAP))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Linux, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!(
+            "https://mirrors.edge.kernel.org/pub/linux/libs/security/linux-privs/libcap2/libcap-{version}.tar.xz"
+        );
+
+        let source = source::new(name, &path);
+
+        let deterministic_script = deterministic::script(system);
+
+        let make_jobs = jobs::make_flag(jobs::limit(context, None)?);
+
+        // libcap has no configure script. The Go bindings and the PAM module are skipped, and
+        // `RAISE_SETFCAP=no` keeps install from setting file capabilities, which needs root.
+        let make_flags = "prefix=\"$VORPAL_OUTPUT\" lib=lib GOLANG=no PAM_CAP=no RAISE_SETFCAP=no";
+
+        let step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT\"
+
+            {deterministic_script}
+
+            pushd ./source/{name}/{name}-{version}
+
+            make {make_jobs} {make_flags}
+            make install {make_flags}",
+        };
+
+        let steps = vec![
+            target::shell(context, system, vec![], vec![], step_script).await?,
+            prune::step(context, system, true, &["share/man".to_string()]).await?,
+        ];
+
+        target::build(
+            context,
+            system,
+            name,
+            vec![format!("{name}:{version}")],
+            vec![source],
+            steps,
+            self.systems(),
+        )
+        .await
+    }
+}
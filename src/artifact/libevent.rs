@@ -1,47 +1,75 @@
+use crate::{
+    artifact::ArtifactPackage,
+    builder::{autotools::AutotoolsPackage, musl},
+    overrides, source,
+    variant::{Linkage, Variant},
+    versions,
+};
 use anyhow::Result;
-use indoc::formatdoc;
+use async_trait::async_trait;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
     context::ConfigContext,
 };
 
 #[derive(Default)]
-pub struct Libevent;
+pub struct Libevent<'a> {
+    version: Option<&'a str>,
+}
 
-impl Libevent {
+impl<'a> Libevent<'a> {
     pub fn new() -> Self {
-        Self
+        Self { version: None }
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Libevent<'a> {
+    fn name(&self) -> &str {
+        "libevent"
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "libevent";
-        let version = "2.1.12";
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::LIBEVENT))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name();
+        let version = self.version();
 
         let path = format!(
             "https://github.com/libevent/libevent/releases/download/release-{version}-stable/libevent-{version}-stable.tar.gz"
         );
 
-        let source = ArtifactSource::new(name, &path).build();
-
-        let script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT\"
-            pushd ./source/{name}/{name}-{version}-stable
-            ./configure \
-                --disable-openssl \
-                --enable-shared \
-                --prefix=\"$VORPAL_OUTPUT\"
-            make
-            make install",
-        };
+        let source = source::new(name, &path);
+
+        let source_directory = format!("{name}-{version}-stable");
 
-        let steps = vec![step::shell(context, vec![], vec![], script, vec![]).await?];
+        let linkage = musl::linkage(context, Linkage::Shared)?;
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let shared = match linkage {
+            Linkage::Musl => "--disable-shared",
+            _ => "--enable-shared",
+        };
 
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{version}")])
-            .with_sources(vec![source])
+        AutotoolsPackage::new(name, version, source, self.systems())
+            .with_configure_flag("--disable-openssl")
+            .with_configure_flag(shared)
+            .with_source_directory(&source_directory)
+            .with_variant(Variant::Full, linkage)
             .build(context)
             .await
     }
@@ -0,0 +1,86 @@
+use crate::{
+    artifact::ArtifactPackage,
+    builder::{autotools::AutotoolsPackage, prune},
+    overrides, source, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+pub struct Libffi<'a> {
+    prune: bool,
+    version: Option<&'a str>,
+}
+
+impl<'a> Default for Libffi<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Libffi<'a> {
+    pub fn new() -> Self {
+        Self {
+            prune: true,
+            version: None,
+        }
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Keeps the documentation and libtool archives `prune::DEFAULT` removes.
+    pub fn without_prune(mut self) -> Self {
+        self.prune = false;
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Libffi<'a> {
+    fn name(&self) -> &str {
+        "libffi"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::LIBFFI))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!(
+            "https://github.com/libffi/libffi/releases/download/v{version}/libffi-{version}.tar.gz"
+        );
+
+        let source = source::new(name, &path);
+
+        let source_directory = format!("{name}-{version}");
+
+        // Without `--disable-multi-os-directory`, Linux builds install into `lib64/` or
+        // `lib/../lib64` instead of the `lib/` every consumer links against.
+        AutotoolsPackage::new(name, version, source, self.systems())
+            .with_configure_flag("--disable-docs")
+            .with_configure_flag("--disable-multi-os-directory")
+            .with_prune(prune::DEFAULT)
+            .with_prune_enabled(self.prune)
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .build(context)
+            .await
+    }
+}
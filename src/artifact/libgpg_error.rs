@@ -1,46 +1,62 @@
+use crate::{
+    artifact::ArtifactPackage, builder::autotools::AutotoolsPackage, overrides, source::SourceUrls,
+    versions,
+};
 use anyhow::Result;
-use indoc::formatdoc;
+use async_trait::async_trait;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
     context::ConfigContext,
 };
 
 #[derive(Default)]
-pub struct LibgpgError;
+pub struct LibgpgError<'a> {
+    version: Option<&'a str>,
+}
 
-impl LibgpgError {
+impl<'a> LibgpgError<'a> {
     pub fn new() -> Self {
-        Self
+        Self { version: None }
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "libgpg-error";
-        let version = "1.56";
-
-        let path =
-            format!("https://gnupg.org/ftp/gcrypt/libgpg-error/libgpg-error-{version}.tar.bz2");
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
 
-        let source = ArtifactSource::new(name, &path).build();
+#[async_trait]
+impl<'a> ArtifactPackage for LibgpgError<'a> {
+    fn name(&self) -> &str {
+        "libgpg-error"
+    }
 
-        let script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT\"
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::LIBGPG_ERROR))
+    }
 
-            pushd ./source/{name}/libgpg-error-{version}
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
 
-            ./configure --prefix=\"$VORPAL_OUTPUT\"
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name();
+        let version = self.version();
 
-            make
-            make install",
-        };
+        let file = format!("libgpg-error/libgpg-error-{version}.tar.bz2");
+        let path = format!("https://gnupg.org/ftp/gcrypt/{file}");
+        let mirror = format!("https://mirrors.dotsrc.org/gcrypt/{file}");
 
-        let steps = vec![step::shell(context, vec![], vec![], script, vec![]).await?];
+        let source = SourceUrls::new(name, &path).with_mirror(&mirror).build();
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let source_directory = format!("libgpg-error-{version}");
 
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{version}")])
-            .with_sources(vec![source])
+        AutotoolsPackage::new(name, version, source, self.systems())
+            .with_source_directory(&source_directory)
             .build(context)
             .await
     }
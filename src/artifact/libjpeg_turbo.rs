@@ -0,0 +1,136 @@
+use crate::{
+    artifact::{cmake, ArtifactPackage},
+    builder::{cmake::CmakePackage, prune},
+    context_cache, overrides, source,
+    variant::{self, Linkage, Variant},
+    versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+pub struct LibjpegTurbo<'a> {
+    check: Option<bool>,
+    cmake: Option<&'a str>,
+    linkage: Option<Linkage>,
+    prune: bool,
+    version: Option<&'a str>,
+}
+
+impl<'a> Default for LibjpegTurbo<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> LibjpegTurbo<'a> {
+    pub fn new() -> Self {
+        Self {
+            check: None,
+            cmake: None,
+            linkage: None,
+            prune: true,
+            version: None,
+        }
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    pub fn with_cmake(mut self, cmake: &'a str) -> Self {
+        self.cmake = Some(cmake);
+        self
+    }
+
+    // Installs only shared or only static libraries; without it the `linkage` variable decides,
+    // see `variant::linkage`.
+    pub fn with_linkage(mut self, linkage: Linkage) -> Self {
+        self.linkage = Some(linkage);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+
+    // Keeps the documentation and libtool archives `prune::DEFAULT` removes.
+    pub fn without_prune(mut self) -> Self {
+        self.prune = false;
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for LibjpegTurbo<'a> {
+    fn name(&self) -> &str {
+        "libjpeg-turbo"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::LIBJPEG_TURBO))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let cmake = match self.cmake {
+            Some(val) => val,
+            None => &context_cache::build(context, &cmake::Cmake::new()).await?,
+        };
+
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!(
+            "https://github.com/libjpeg-turbo/libjpeg-turbo/releases/download/{version}/libjpeg-turbo-{version}.tar.gz"
+        );
+
+        let source = source::new(name, &path);
+
+        let source_directory = format!("{name}-{version}");
+
+        let check_expected = format!("libjpeg-turbo version {version}");
+
+        let linkage = variant::linkage(context, self.linkage)?;
+
+        let (enable_shared, enable_static) = match linkage {
+            Linkage::Shared => ("ENABLE_SHARED=ON", "ENABLE_STATIC=OFF"),
+            Linkage::Static | Linkage::Musl => ("ENABLE_SHARED=OFF", "ENABLE_STATIC=ON"),
+        };
+
+        // Without NASM the x86_64 SIMD extensions are skipped; the aarch64 ones need no assembler.
+        CmakePackage::new(name, version, source, self.systems())
+            .with_check("bin/cjpeg -version", &check_expected)
+            .with_check_enabled(self.check)
+            .with_cmake(cmake)
+            .with_definition("CMAKE_INSTALL_LIBDIR=lib")
+            .with_definition(enable_shared)
+            .with_definition(enable_static)
+            .with_prune(prune::DEFAULT)
+            .with_prune_enabled(self.prune)
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .with_variant(Variant::Full, linkage)
+            .build(context)
+            .await
+    }
+}
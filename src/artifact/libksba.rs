@@ -1,20 +1,33 @@
-use crate::artifact::libgpg_error;
+use crate::{
+    artifact::{libgpg_error, ArtifactPackage},
+    builder::autotools::AutotoolsPackage,
+    context_cache, overrides,
+    source::SourceUrls,
+    versions,
+};
 use anyhow::Result;
-use indoc::formatdoc;
+use async_trait::async_trait;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{get_env_key, step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::get_env_key,
     context::ConfigContext,
 };
 
 #[derive(Default)]
 pub struct Libksba<'a> {
     libgpg_error: Option<&'a str>,
+    version: Option<&'a str>,
 }
 
 impl<'a> Libksba<'a> {
     pub fn new() -> Self {
-        Self { libgpg_error: None }
+        Self {
+            libgpg_error: None,
+            version: None,
+        }
     }
 
     pub fn with_libgpg_error(mut self, libgpg_error: &'a str) -> Self {
@@ -22,51 +35,50 @@ impl<'a> Libksba<'a> {
         self
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let libgpg_error = match self.libgpg_error {
-            Some(val) => val,
-            None => &libgpg_error::LibgpgError::new().build(context).await?,
-        };
-
-        let name = "libksba";
-        let version = "1.6.7";
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
 
-        let path = format!("https://gnupg.org/ftp/gcrypt/libksba/libksba-{version}.tar.bz2");
+#[async_trait]
+impl<'a> ArtifactPackage for Libksba<'a> {
+    fn name(&self) -> &str {
+        "libksba"
+    }
 
-        let source = ArtifactSource::new(name, &path).build();
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::LIBKSBA))
+    }
 
-        let script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT\"
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
 
-            pushd ./source/{name}/libksba-{version}
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let libgpg_error = match self.libgpg_error {
+            Some(val) => val,
+            None => &context_cache::build(context, &libgpg_error::LibgpgError::new()).await?,
+        };
 
-            export PATH=\"{libgpg_error}/bin:$PATH\"
-            export CPPFLAGS=\"-I{libgpg_error}/include\"
-            export LDFLAGS=\"-L{libgpg_error}/lib -Wl,-rpath,{libgpg_error}/lib\"
+        let name = self.name();
+        let version = self.version();
 
-            ./configure --prefix=\"$VORPAL_OUTPUT\" --with-libgpg-error-prefix={libgpg_error}
+        let file = format!("libksba/libksba-{version}.tar.bz2");
+        let path = format!("https://gnupg.org/ftp/gcrypt/{file}");
+        let mirror = format!("https://mirrors.dotsrc.org/gcrypt/{file}");
 
-            make
-            make install",
-            libgpg_error = get_env_key(&libgpg_error.to_string()),
-        };
+        let source = SourceUrls::new(name, &path).with_mirror(&mirror).build();
 
-        let steps = vec![
-            step::shell(
-                context,
-                vec![libgpg_error.to_string()],
-                vec![],
-                script,
-                vec![],
-            )
-            .await?,
-        ];
+        let source_directory = format!("libksba-{version}");
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let libgpg_error_key = get_env_key(&libgpg_error.to_string());
 
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{version}")])
-            .with_sources(vec![source])
+        AutotoolsPackage::new(name, version, source, self.systems())
+            .with_artifact(libgpg_error)
+            .with_configure_flag(&format!("--with-libgpg-error-prefix={libgpg_error_key}"))
+            .with_source_directory(&source_directory)
             .build(context)
             .await
     }
@@ -0,0 +1,86 @@
+use crate::{
+    artifact::{flex::Flex, ArtifactPackage},
+    builder::autotools::AutotoolsPackage,
+    context_cache, overrides, source, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Linux, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct Libpcap<'a> {
+    flex: Option<&'a str>,
+    version: Option<&'a str>,
+}
+
+impl<'a> Libpcap<'a> {
+    pub fn new() -> Self {
+        Self {
+            flex: None,
+            version: None,
+        }
+    }
+
+    pub fn with_flex(mut self, flex: &'a str) -> Self {
+        self.flex = Some(flex);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Libpcap<'a> {
+    fn name(&self) -> &str {
+        "libpcap"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::LIBPCAP))
+    }
+
+    // macOS ships libpcap with the system, so this is only built for Linux devenvs.
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Linux, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let flex = match self.flex {
+            Some(val) => val,
+            None => &context_cache::build(context, &Flex::new()).await?,
+        };
+
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!("https://www.tcpdump.org/release/libpcap-{version}.tar.gz");
+
+        let source = source::new(name, &path);
+
+        let source_directory = format!("{name}-{version}");
+
+        // Capture tools link it through `with_artifact`, which exposes `pcap-config` on PATH and
+        // `libpcap.pc` to pkg-config. The static archive is kept for tools that link it in.
+        AutotoolsPackage::new(name, version, source, self.systems())
+            .with_artifact(flex)
+            .with_configure_flag("--disable-bluetooth")
+            .with_configure_flag("--disable-dbus")
+            .with_configure_flag("--disable-rdma")
+            .with_configure_flag("--without-libnl")
+            .with_prune(&["share/man"])
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .build(context)
+            .await
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{
+    artifact::{zlib::Zlib, ArtifactPackage},
+    builder::{autotools::AutotoolsPackage, prune},
+    context_cache, overrides, source,
+    variant::{self, Linkage, Variant},
+    versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+pub struct Libpng<'a> {
+    check: Option<bool>,
+    linkage: Option<Linkage>,
+    prune: bool,
+    version: Option<&'a str>,
+    zlib: Option<&'a str>,
+}
+
+impl<'a> Default for Libpng<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Libpng<'a> {
+    pub fn new() -> Self {
+        Self {
+            check: None,
+            linkage: None,
+            prune: true,
+            version: None,
+            zlib: None,
+        }
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    // Installs only shared or only static libraries; without it the `linkage` variable decides,
+    // see `variant::linkage`.
+    pub fn with_linkage(mut self, linkage: Linkage) -> Self {
+        self.linkage = Some(linkage);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    pub fn with_zlib(mut self, zlib: &'a str) -> Self {
+        self.zlib = Some(zlib);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+
+    // Keeps the documentation and libtool archives `prune::DEFAULT` removes.
+    pub fn without_prune(mut self) -> Self {
+        self.prune = false;
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Libpng<'a> {
+    fn name(&self) -> &str {
+        "libpng"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::LIBPNG))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let zlib = match self.zlib {
+            Some(val) => val,
+            None => &context_cache::build(context, &Zlib::new()).await?,
+        };
+
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!(
+            "https://downloads.sourceforge.net/project/libpng/libpng16/{version}/libpng-{version}.tar.xz"
+        );
+
+        let source = source::new(name, &path);
+
+        let linkage = variant::linkage(context, self.linkage)?;
+
+        let [shared_flag, static_flag] = variant::configure_flags(linkage);
+
+        let source_directory = format!("{name}-{version}");
+
+        AutotoolsPackage::new(name, version, source, self.systems())
+            .with_artifact(zlib)
+            .with_check("bin/libpng-config --version", version)
+            .with_check_enabled(self.check)
+            .with_configure_flag(shared_flag)
+            .with_configure_flag(static_flag)
+            .with_prune(prune::DEFAULT)
+            .with_prune_enabled(self.prune)
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .with_variant(Variant::Full, linkage)
+            .build(context)
+            .await
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{
+    artifact::{openssl::Openssl, zlib::Zlib, ArtifactPackage},
+    builder::{autotools::AutotoolsPackage, prune},
+    fork, overrides, source, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::get_env_key,
+    context::ConfigContext,
+};
+
+pub struct Libssh2<'a> {
+    openssl: Option<&'a str>,
+    prune: bool,
+    version: Option<&'a str>,
+    zlib: Option<&'a str>,
+}
+
+impl<'a> Default for Libssh2<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Libssh2<'a> {
+    pub fn new() -> Self {
+        Self {
+            openssl: None,
+            prune: true,
+            version: None,
+            zlib: None,
+        }
+    }
+
+    pub fn with_openssl(mut self, openssl: &'a str) -> Self {
+        self.openssl = Some(openssl);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    pub fn with_zlib(mut self, zlib: &'a str) -> Self {
+        self.zlib = Some(zlib);
+        self
+    }
+
+    // Keeps the documentation and libtool archives `prune::DEFAULT` removes.
+    pub fn without_prune(mut self) -> Self {
+        self.prune = false;
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Libssh2<'a> {
+    fn name(&self) -> &str {
+        "libssh2"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::LIBSSH2))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let (openssl, zlib) = tokio::try_join!(
+            fork::build(context, self.openssl, Openssl::new()),
+            fork::build(context, self.zlib, Zlib::new()),
+        )?;
+
+        let [openssl, zlib] = fork::join(context, [openssl, zlib]).await?;
+
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!("https://libssh2.org/download/libssh2-{version}.tar.gz");
+
+        let source = source::new(name, &path);
+
+        let source_directory = format!("{name}-{version}");
+
+        AutotoolsPackage::new(name, version, source, self.systems())
+            .with_artifact(&openssl)
+            .with_artifact(&zlib)
+            .with_configure_flag("--disable-docker-tests")
+            .with_configure_flag("--disable-examples-build")
+            .with_configure_flag("--disable-tests")
+            .with_configure_flag("--with-crypto=openssl")
+            .with_configure_flag(&format!("--with-libssl-prefix={}", get_env_key(&openssl)))
+            .with_configure_flag(&format!("--with-libz-prefix={}", get_env_key(&zlib)))
+            .with_configure_flag("--with-libz")
+            .with_prune(prune::DEFAULT)
+            .with_prune_enabled(self.prune)
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .build(context)
+            .await
+    }
+}
@@ -1,20 +1,28 @@
-use crate::artifact::cmake;
+use crate::{artifact::ArtifactPackage, builder::cmake::CmakePackage, overrides, source, versions};
 use anyhow::Result;
-use indoc::formatdoc;
+use async_trait::async_trait;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{get_env_key, step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
     context::ConfigContext,
 };
 
 #[derive(Default)]
 pub struct Libuv<'a> {
     cmake: Option<&'a str>,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
 }
 
 impl<'a> Libuv<'a> {
     pub fn new() -> Self {
-        Self { cmake: None }
+        Self {
+            cmake: None,
+            target: None,
+            version: None,
+        }
     }
 
     pub fn with_cmake(mut self, cmake: &'a str) -> Self {
@@ -22,47 +30,57 @@ impl<'a> Libuv<'a> {
         self
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let cmake = match self.cmake {
-            Some(val) => val,
-            None => &cmake::Cmake::new().build(context).await?,
-        };
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
 
-        let name = "libuv";
-        let version = "1.52.0";
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Libuv<'a> {
+    fn name(&self) -> &str {
+        "libuv"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::LIBUV))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name();
+        let version = self.version();
 
         let path = format!("https://github.com/libuv/libuv/archive/refs/tags/v{version}.tar.gz");
 
-        let source = ArtifactSource::new(name, &path).build();
-
-        let script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT\"
-
-            BUILD_DIR=\"$(pwd)/build\"
-            mkdir -p \"$BUILD_DIR\"
-
-            pushd \"$BUILD_DIR\"
-            {cmake}/bin/cmake \
-                -DCMAKE_BUILD_TYPE=RELEASE \
-                -DCMAKE_INSTALL_PREFIX=\"$VORPAL_OUTPUT\" \
-                -DCMAKE_C_FLAGS=\"-fPIC\" \
-                -DBUILD_TESTING=OFF \
-                -DLIBUV_BUILD_SHARED=OFF \
-                \"$(pwd)/../source/{name}/{name}-{version}\"
-            make -j$(nproc 2>/dev/null || sysctl -n hw.ncpu) install
-            popd",
-            cmake = get_env_key(&cmake.to_string()),
-        };
-
-        let steps =
-            vec![step::shell(context, vec![cmake.to_string()], vec![], script, vec![]).await?];
-
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
-
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{version}")])
-            .with_sources(vec![source])
-            .build(context)
-            .await
+        let source = source::new(name, &path);
+
+        let source_directory = format!("{name}-{version}");
+
+        // Without `with_cmake`, the builder picks the cmake for the host or the target.
+        let mut package = CmakePackage::new(name, version, source, self.systems())
+            .with_definition("CMAKE_C_FLAGS=\"-fPIC\"")
+            .with_definition("BUILD_TESTING=OFF")
+            .with_definition("LIBUV_BUILD_SHARED=OFF")
+            .with_source_directory(&source_directory);
+
+        if let Some(cmake) = self.cmake {
+            package = package.with_cmake(cmake);
+        }
+
+        if let Some(target) = self.target {
+            package = package.with_target(target);
+        }
+
+        package.build(context).await
     }
 }
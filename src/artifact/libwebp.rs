@@ -0,0 +1,154 @@
+use crate::{
+    artifact::{giflib::Giflib, libjpeg_turbo::LibjpegTurbo, libpng::Libpng, ArtifactPackage},
+    builder::{autotools::AutotoolsPackage, prune},
+    fork, overrides, source,
+    variant::{self, Linkage, Variant},
+    versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+pub struct Libwebp<'a> {
+    check: Option<bool>,
+    giflib: Option<&'a str>,
+    libjpeg_turbo: Option<&'a str>,
+    libpng: Option<&'a str>,
+    linkage: Option<Linkage>,
+    prune: bool,
+    version: Option<&'a str>,
+}
+
+impl<'a> Default for Libwebp<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Libwebp<'a> {
+    pub fn new() -> Self {
+        Self {
+            check: None,
+            giflib: None,
+            libjpeg_turbo: None,
+            libpng: None,
+            linkage: None,
+            prune: true,
+            version: None,
+        }
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    pub fn with_giflib(mut self, giflib: &'a str) -> Self {
+        self.giflib = Some(giflib);
+        self
+    }
+
+    pub fn with_libjpeg_turbo(mut self, libjpeg_turbo: &'a str) -> Self {
+        self.libjpeg_turbo = Some(libjpeg_turbo);
+        self
+    }
+
+    pub fn with_libpng(mut self, libpng: &'a str) -> Self {
+        self.libpng = Some(libpng);
+        self
+    }
+
+    // Installs only shared or only static libraries; without it the `linkage` variable decides,
+    // see `variant::linkage`.
+    pub fn with_linkage(mut self, linkage: Linkage) -> Self {
+        self.linkage = Some(linkage);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+
+    // Keeps the documentation and libtool archives `prune::DEFAULT` removes.
+    pub fn without_prune(mut self) -> Self {
+        self.prune = false;
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Libwebp<'a> {
+    fn name(&self) -> &str {
+        "libwebp"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::LIBWEBP))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let (giflib, libjpeg_turbo, libpng) = tokio::try_join!(
+            fork::build(context, self.giflib, Giflib::new()),
+            fork::build(context, self.libjpeg_turbo, LibjpegTurbo::new()),
+            fork::build(context, self.libpng, Libpng::new()),
+        )?;
+
+        let [giflib, libjpeg_turbo, libpng] =
+            fork::join(context, [giflib, libjpeg_turbo, libpng]).await?;
+
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!(
+            "https://storage.googleapis.com/downloads.webmproject.org/releases/webp/libwebp-{version}.tar.gz"
+        );
+
+        let source = source::new(name, &path);
+
+        let source_directory = format!("{name}-{version}");
+
+        let linkage = variant::linkage(context, self.linkage)?;
+
+        let [shared_flag, static_flag] = variant::configure_flags(linkage);
+
+        // The codecs let cwebp and gif2webp read PNG, JPEG and GIF input; TIFF is left out
+        // rather than picked up from the host.
+        AutotoolsPackage::new(name, version, source, self.systems())
+            .with_artifact(&giflib)
+            .with_artifact(&libjpeg_turbo)
+            .with_artifact(&libpng)
+            .with_check("bin/cwebp -version", version)
+            .with_check_enabled(self.check)
+            .with_configure_flag(shared_flag)
+            .with_configure_flag(static_flag)
+            .with_configure_flag("--disable-tiff")
+            .with_configure_flag("--enable-libwebpdemux")
+            .with_configure_flag("--enable-libwebpmux")
+            .with_prune(prune::DEFAULT)
+            .with_prune_enabled(self.prune)
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .with_variant(Variant::Full, linkage)
+            .build(context)
+            .await
+    }
+}
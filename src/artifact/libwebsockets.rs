@@ -1,9 +1,16 @@
-use crate::artifact::{cmake, libuv, mbedtls};
+use crate::{
+    artifact::{cmake, libuv, mbedtls, ArtifactPackage},
+    builder::cmake::CmakePackage,
+    fork, overrides, source, versions,
+};
 use anyhow::Result;
+use async_trait::async_trait;
 use indoc::formatdoc;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{get_env_key, step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
     context::ConfigContext,
 };
 
@@ -12,6 +19,7 @@ pub struct Libwebsockets<'a> {
     cmake: Option<&'a str>,
     libuv: Option<&'a str>,
     mbedtls: Option<&'a str>,
+    version: Option<&'a str>,
 }
 
 impl<'a> Libwebsockets<'a> {
@@ -20,6 +28,7 @@ impl<'a> Libwebsockets<'a> {
             cmake: None,
             libuv: None,
             mbedtls: None,
+            version: None,
         }
     }
 
@@ -38,91 +47,114 @@ impl<'a> Libwebsockets<'a> {
         self
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let cmake = match self.cmake {
-            Some(val) => val,
-            None => &cmake::Cmake::new().build(context).await?,
-        };
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
 
-        let libuv = match self.libuv {
-            Some(val) => val,
-            None => &libuv::Libuv::new().build(context).await?,
-        };
+#[async_trait]
+impl<'a> ArtifactPackage for Libwebsockets<'a> {
+    fn name(&self) -> &str {
+        "libwebsockets"
+    }
 
-        let mbedtls = match self.mbedtls {
-            Some(val) => val,
-            None => &mbedtls::Mbedtls::new().build(context).await?,
-        };
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::LIBWEBSOCKETS))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let (cmake, libuv, mbedtls) = tokio::try_join!(
+            fork::build(context, self.cmake, cmake::Cmake::new()),
+            fork::build(context, self.libuv, libuv::Libuv::new()),
+            fork::build(context, self.mbedtls, mbedtls::Mbedtls::new()),
+        )?;
+
+        let [cmake, libuv, mbedtls] = fork::join(context, [cmake, libuv, mbedtls]).await?;
 
-        let name = "libwebsockets";
-        let version = "4.5.2";
+        let name = self.name();
+        let version = self.version();
 
         let path =
             format!("https://github.com/warmcat/libwebsockets/archive/refs/tags/v{version}.tar.gz");
 
-        let source = ArtifactSource::new(name, &path).build();
+        let source = source::new(name, &path);
 
-        let cmake_prefix_path = format!(
-            "{};{}",
-            get_env_key(&libuv.to_string()),
-            get_env_key(&mbedtls.to_string()),
-        );
+        let source_directory = format!("{name}-{version}");
 
-        let script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT\"
-
-            LWS_SRC=\"$(pwd)/source/{name}/{name}-{version}\"
-
-            sed 's/ websockets_shared//g' \"$LWS_SRC/cmake/libwebsockets-config.cmake.in\" > \"$LWS_SRC/cmake/libwebsockets-config.cmake.in.tmp\"
-            mv \"$LWS_SRC/cmake/libwebsockets-config.cmake.in.tmp\" \"$LWS_SRC/cmake/libwebsockets-config.cmake.in\"
-
-            BUILD_DIR=\"$(pwd)/build\"
-            mkdir -p \"$BUILD_DIR\"
-
-            pushd \"$BUILD_DIR\"
-
-            {cmake}/bin/cmake \
-                -DCMAKE_BUILD_TYPE=RELEASE \
-                -DCMAKE_INSTALL_PREFIX=\"$VORPAL_OUTPUT\" \
-                -DCMAKE_FIND_LIBRARY_SUFFIXES=\".a\" \
-                -DCMAKE_PREFIX_PATH=\"{cmake_prefix_path}\" \
-                -DLWS_WITHOUT_TESTAPPS=ON \
-                -DLWS_WITH_MBEDTLS=ON \
-                -DLWS_WITH_LIBUV=ON \
-                -DLWS_STATIC_PIC=ON \
-                -DLWS_WITH_SHARED=OFF \
-                -DLWS_UNIX_SOCK=ON \
-                -DLWS_IPV6=ON \
-                -DLWS_ROLE_RAW_FILE=OFF \
-                -DLWS_WITH_HTTP2=ON \
-                -DLWS_WITH_HTTP_BASIC_AUTH=OFF \
-                -DLWS_WITH_UDP=OFF \
-                -DLWS_WITHOUT_CLIENT=ON \
-                -DLWS_WITHOUT_EXTENSIONS=OFF \
-                -DLWS_WITH_LEJP=OFF \
-                -DLWS_WITH_LEJP_CONF=OFF \
-                -DLWS_WITH_LWSAC=OFF \
-                -DLWS_WITH_SEQUENCER=OFF \
-                -DLWS_WITH_SYS_FAULT_INJECTION=OFF \
-                -DLWS_WITH_SYS_METRICS=OFF \
-                -DLWS_WITH_DLO=OFF \
-                \"$LWS_SRC\"
-
-            make -j$(nproc 2>/dev/null || sysctl -n hw.ncpu) install
-            popd",
-            cmake = get_env_key(&cmake.to_string()),
+        // Only the static library is built, so drop the shared target from the exported config.
+        let source_script = formatdoc! {"
+            sed 's/ websockets_shared//g' cmake/libwebsockets-config.cmake.in > cmake/libwebsockets-config.cmake.in.tmp
+            mv cmake/libwebsockets-config.cmake.in.tmp cmake/libwebsockets-config.cmake.in",
         };
 
-        let step_artifacts = vec![cmake.to_string(), libuv.to_string(), mbedtls.to_string()];
+        CmakePackage::new(name, version, source, self.systems())
+            .with_cmake(&cmake)
+            .with_artifact(&libuv)
+            .with_artifact(&mbedtls)
+            .with_definition("CMAKE_FIND_LIBRARY_SUFFIXES=\".a\"")
+            .with_definition("LWS_WITHOUT_TESTAPPS=ON")
+            .with_definition("LWS_WITH_MBEDTLS=ON")
+            .with_definition("LWS_WITH_LIBUV=ON")
+            .with_definition("LWS_STATIC_PIC=ON")
+            .with_definition("LWS_WITH_SHARED=OFF")
+            .with_definition("LWS_UNIX_SOCK=ON")
+            .with_definition("LWS_IPV6=ON")
+            .with_definition("LWS_ROLE_RAW_FILE=OFF")
+            .with_definition("LWS_WITH_HTTP2=ON")
+            .with_definition("LWS_WITH_HTTP_BASIC_AUTH=OFF")
+            .with_definition("LWS_WITH_UDP=OFF")
+            .with_definition("LWS_WITHOUT_CLIENT=ON")
+            .with_definition("LWS_WITHOUT_EXTENSIONS=OFF")
+            .with_definition("LWS_WITH_LEJP=OFF")
+            .with_definition("LWS_WITH_LEJP_CONF=OFF")
+            .with_definition("LWS_WITH_LWSAC=OFF")
+            .with_definition("LWS_WITH_SEQUENCER=OFF")
+            .with_definition("LWS_WITH_SYS_FAULT_INJECTION=OFF")
+            .with_definition("LWS_WITH_SYS_METRICS=OFF")
+            .with_definition("LWS_WITH_DLO=OFF")
+            .with_source_directory(&source_directory)
+            .with_source_script(&source_script)
+            .build(context)
+            .await
+    }
+}
 
-        let steps = vec![step::shell(context, step_artifacts, vec![], script, vec![]).await?];
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        aliases,
+        testing::{self, MockContext},
+    };
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+    #[tokio::test]
+    async fn build_links_libuv_and_mbedtls() -> Result<()> {
+        let context = &mut MockContext::new(X8664Darwin).build().await?;
 
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{version}")])
-            .with_sources(vec![source])
-            .build(context)
-            .await
+        let libwebsockets = Libwebsockets::new().build(context).await?;
+
+        assert_eq!(
+            testing::dependencies(context, &libwebsockets)?,
+            ["cmake", "libuv", "mbedtls"]
+        );
+        assert_eq!(
+            testing::source_paths(context, &libwebsockets)?,
+            [format!(
+                "https://github.com/warmcat/libwebsockets/archive/refs/tags/v{}.tar.gz",
+                versions::LIBWEBSOCKETS
+            )]
+        );
+        assert_eq!(
+            testing::aliases(context, &libwebsockets)?,
+            aliases::versioned("libwebsockets", versions::LIBWEBSOCKETS)
+        );
+
+        Ok(())
     }
 }
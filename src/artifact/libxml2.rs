@@ -0,0 +1,140 @@
+use crate::{
+    artifact::{zlib::Zlib, ArtifactPackage},
+    builder::{autotools::AutotoolsPackage, prune},
+    context_cache,
+    error::ArtifactError,
+    overrides, source, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::get_env_key,
+    context::ConfigContext,
+};
+
+pub struct Libxml2<'a> {
+    check: Option<bool>,
+    prune: bool,
+    version: Option<&'a str>,
+    xz: Option<&'a str>,
+    zlib: Option<&'a str>,
+}
+
+impl<'a> Default for Libxml2<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Libxml2<'a> {
+    pub fn new() -> Self {
+        Self {
+            check: None,
+            prune: true,
+            version: None,
+            xz: None,
+            zlib: None,
+        }
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Without an injected xz artifact, libxml2 is built without LZMA support.
+    pub fn with_xz(mut self, xz: &'a str) -> Self {
+        self.xz = Some(xz);
+        self
+    }
+
+    pub fn with_zlib(mut self, zlib: &'a str) -> Self {
+        self.zlib = Some(zlib);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+
+    // Keeps the documentation and libtool archives `prune::DEFAULT` removes.
+    pub fn without_prune(mut self) -> Self {
+        self.prune = false;
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Libxml2<'a> {
+    fn name(&self) -> &str {
+        "libxml2"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::LIBXML2))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let zlib = match self.zlib {
+            Some(val) => val,
+            None => &context_cache::build(context, &Zlib::new()).await?,
+        };
+
+        let name = self.name();
+        let version = self.version();
+
+        let Some((branch, _)) = version.rsplit_once('.') else {
+            return Err(ArtifactError::UnsupportedVersion {
+                artifact: name.to_string(),
+                version: version.to_string(),
+            }
+            .into());
+        };
+
+        let path =
+            format!("https://download.gnome.org/sources/libxml2/{branch}/libxml2-{version}.tar.xz");
+
+        let source = source::new(name, &path);
+
+        let source_directory = format!("{name}-{version}");
+
+        let zlib_key = get_env_key(&zlib.to_string());
+
+        let mut package = AutotoolsPackage::new(name, version, source, self.systems())
+            .with_artifact(zlib)
+            .with_check("bin/xml2-config --version", version)
+            .with_check_enabled(self.check)
+            .with_configure_flag("--without-python")
+            .with_configure_flag(&format!("--with-zlib={zlib_key}"))
+            .with_prune(prune::DEFAULT)
+            .with_prune_enabled(self.prune)
+            .with_source_directory(&source_directory)
+            .with_strip();
+
+        package = match self.xz {
+            Some(xz) => package
+                .with_artifact(xz)
+                .with_configure_flag(&format!("--with-lzma={}", get_env_key(&xz.to_string()))),
+            None => package.with_configure_flag("--without-lzma"),
+        };
+
+        package.build(context).await
+    }
+}
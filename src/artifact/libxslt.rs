@@ -0,0 +1,127 @@
+use crate::{
+    artifact::{libxml2::Libxml2, ArtifactPackage},
+    builder::{autotools::AutotoolsPackage, prune},
+    context_cache,
+    error::ArtifactError,
+    overrides, source, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::get_env_key,
+    context::ConfigContext,
+};
+
+pub struct Libxslt<'a> {
+    check: Option<bool>,
+    libxml2: Option<&'a str>,
+    prune: bool,
+    version: Option<&'a str>,
+}
+
+impl<'a> Default for Libxslt<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Libxslt<'a> {
+    pub fn new() -> Self {
+        Self {
+            check: None,
+            libxml2: None,
+            prune: true,
+            version: None,
+        }
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    pub fn with_libxml2(mut self, libxml2: &'a str) -> Self {
+        self.libxml2 = Some(libxml2);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+
+    // Keeps the documentation and libtool archives `prune::DEFAULT` removes.
+    pub fn without_prune(mut self) -> Self {
+        self.prune = false;
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Libxslt<'a> {
+    fn name(&self) -> &str {
+        "libxslt"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::LIBXSLT))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let libxml2 = match self.libxml2 {
+            Some(val) => val,
+            None => &context_cache::build(context, &Libxml2::new()).await?,
+        };
+
+        let name = self.name();
+        let version = self.version();
+
+        let Some((branch, _)) = version.rsplit_once('.') else {
+            return Err(ArtifactError::UnsupportedVersion {
+                artifact: name.to_string(),
+                version: version.to_string(),
+            }
+            .into());
+        };
+
+        let path =
+            format!("https://download.gnome.org/sources/libxslt/{branch}/libxslt-{version}.tar.xz");
+
+        let source = source::new(name, &path);
+
+        let source_directory = format!("{name}-{version}");
+
+        let libxml2_key = get_env_key(&libxml2.to_string());
+
+        // The EXSLT crypto functions would otherwise link whatever libgcrypt the host provides.
+        AutotoolsPackage::new(name, version, source, self.systems())
+            .with_artifact(libxml2)
+            .with_check("bin/xslt-config --version", version)
+            .with_check_enabled(self.check)
+            .with_configure_flag(&format!("--with-libxml-prefix={libxml2_key}"))
+            .with_configure_flag("--without-crypto")
+            .with_configure_flag("--without-python")
+            .with_prune(prune::DEFAULT)
+            .with_prune_enabled(self.prune)
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .build(context)
+            .await
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{
+    artifact::ArtifactPackage,
+    builder::{autotools::AutotoolsPackage, prune},
+    overrides, source, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+pub struct Libyaml<'a> {
+    prune: bool,
+    version: Option<&'a str>,
+}
+
+impl<'a> Default for Libyaml<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Libyaml<'a> {
+    pub fn new() -> Self {
+        Self {
+            prune: true,
+            version: None,
+        }
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Keeps the documentation and libtool archives `prune::DEFAULT` removes.
+    pub fn without_prune(mut self) -> Self {
+        self.prune = false;
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Libyaml<'a> {
+    fn name(&self) -> &str {
+        "libyaml"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::LIBYAML))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!(
+            "https://github.com/yaml/libyaml/releases/download/{version}/yaml-{version}.tar.gz"
+        );
+
+        let source = source::new(name, &path);
+
+        let source_directory = format!("yaml-{version}");
+
+        AutotoolsPackage::new(name, version, source, self.systems())
+            .with_prune(prune::DEFAULT)
+            .with_prune_enabled(self.prune)
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .build(context)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, MockContext};
+
+    #[tokio::test]
+    async fn prune_keeps_static_archives() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Linux).build().await?;
+
+        let pruned = Libyaml::new().build(context).await?;
+        let kept = Libyaml::new().without_prune().build(context).await?;
+
+        // The second step prunes and strips.
+        let pruned = testing::artifact(context, &pruned)?.steps[1]
+            .script
+            .clone()
+            .unwrap_or_default();
+        let kept = testing::artifact(context, &kept)?.steps[1]
+            .script
+            .clone()
+            .unwrap_or_default();
+
+        assert!(pruned.contains("rm -rf \"$VORPAL_OUTPUT\"/lib/*.la"));
+        assert!(!pruned.contains("lib/*.a"));
+        assert!(!kept.contains("rm -rf"));
+
+        Ok(())
+    }
+}
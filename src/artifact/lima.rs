@@ -1,36 +1,92 @@
+use crate::{
+    artifact::ArtifactPackage, builder::darwin_sign, error::ArtifactError, overrides, source,
+    target, versions,
+};
 use anyhow::Result;
+use async_trait::async_trait;
 use indoc::formatdoc;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
     context::ConfigContext,
 };
 
 #[derive(Default)]
-pub struct Lima;
+pub struct Lima<'a> {
+    darwin_sign: bool,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
 
-impl Lima {
+impl<'a> Lima<'a> {
     pub fn new() -> Self {
-        Self
+        Self {
+            darwin_sign: false,
+            target: None,
+            version: None,
+        }
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "lima";
-        let source_version = "2.0.3";
+    // Clears the quarantine attribute and ad hoc signs the darwin binaries, see
+    // `darwin_sign::step`.
+    pub fn with_darwin_sign(mut self) -> Self {
+        self.darwin_sign = true;
+        self
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
 
-        let source_system = match context.get_system() {
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Lima<'a> {
+    fn name(&self) -> &str {
+        "lima"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::LIMA))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let name = self.name();
+        let source_version = self.version();
+
+        let source_system = match system {
             Aarch64Darwin => "Darwin-arm64",
             Aarch64Linux => "Linux-aarch64",
             X8664Darwin => "Darwin-x86_64",
             X8664Linux => "Linux-x86_64",
-            _ => return Err(anyhow::anyhow!("Unsupported system for lima artifact")),
+            system => {
+                return Err(ArtifactError::UnsupportedSystem {
+                    artifact: name.to_string(),
+                    system,
+                }
+                .into())
+            }
         };
 
         let source_path = format!(
             "https://github.com/lima-vm/lima/releases/download/v{source_version}/lima-{source_version}-{source_system}.tar.gz"
         );
 
-        let source = ArtifactSource::new(name, &source_path).build();
+        let source = source::for_system(name, &source_path, system)?;
 
         let step_script = formatdoc! {"
             mkdir -pv \"$VORPAL_OUTPUT\"
@@ -41,14 +97,23 @@ impl Lima {
             chmod +x \"$VORPAL_OUTPUT/bin/\"*",
         };
 
-        let steps = vec![step::shell(context, vec![], vec![], step_script, vec![]).await?];
+        let mut steps = vec![target::shell(context, system, vec![], vec![], step_script).await?];
+
+        if self.darwin_sign {
+            steps.extend(darwin_sign::step(context, system).await?);
+        }
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let systems = self.systems();
 
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{source_version}")])
-            .with_sources(vec![source])
-            .build(context)
-            .await
+        target::build(
+            context,
+            system,
+            name,
+            vec![format!("{name}:{source_version}")],
+            vec![source],
+            steps,
+            systems,
+        )
+        .await
     }
 }
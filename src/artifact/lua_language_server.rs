@@ -1,50 +1,101 @@
+use crate::{artifact::ArtifactPackage, error::ArtifactError, overrides, source, target, versions};
 use anyhow::Result;
+use async_trait::async_trait;
 use indoc::formatdoc;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
     context::ConfigContext,
 };
 
 #[derive(Default)]
-pub struct LuaLanguageServer;
+pub struct LuaLanguageServer<'a> {
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
 
-impl LuaLanguageServer {
+impl<'a> LuaLanguageServer<'a> {
     pub fn new() -> Self {
-        Self
+        Self {
+            target: None,
+            version: None,
+        }
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "lua-language-server";
-        let version = "3.18.2";
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for LuaLanguageServer<'a> {
+    fn name(&self) -> &str {
+        "lua-language-server"
+    }
+
+    fn version(&self) -> &str {
+        self.version.unwrap_or(overrides::version(
+            self.name(),
+            versions::LUA_LANGUAGE_SERVER,
+        ))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let name = self.name();
+        let version = self.version();
 
-        let source_system = match context.get_system() {
+        let source_system = match system {
             Aarch64Darwin => "darwin-arm64",
             Aarch64Linux => "linux-arm64",
             X8664Darwin => "darwin-x64",
             X8664Linux => "linux-x64",
-            _ => return Err(anyhow::anyhow!("Unsupported system for {name} artifact")),
+            system => {
+                return Err(ArtifactError::UnsupportedSystem {
+                    artifact: name.to_string(),
+                    system,
+                }
+                .into())
+            }
         };
 
         let source_path = format!(
             "https://github.com/LuaLS/lua-language-server/releases/download/{version}/{name}-{version}-{source_system}.tar.gz"
         );
 
-        let source = ArtifactSource::new(name, &source_path).build();
+        let source = source::for_system(name, &source_path, system)?;
 
         let step_script = formatdoc! {"
             cp -r ./source/{name}/. \"$VORPAL_OUTPUT\"
             chmod +x \"$VORPAL_OUTPUT/bin/{name}\"",
         };
 
-        let steps = vec![step::shell(context, vec![], vec![], step_script, vec![]).await?];
+        let steps = vec![target::shell(context, system, vec![], vec![], step_script).await?];
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let systems = self.systems();
 
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{version}")])
-            .with_sources(vec![source])
-            .build(context)
-            .await
+        target::build(
+            context,
+            system,
+            name,
+            vec![format!("{name}:{version}")],
+            vec![source],
+            steps,
+            systems,
+        )
+        .await
     }
 }
@@ -1,20 +1,30 @@
-use crate::artifact::cmake;
+use crate::{
+    artifact::{cmake, ArtifactPackage},
+    builder::cmake::CmakePackage,
+    context_cache, overrides, source, versions,
+};
 use anyhow::Result;
-use indoc::formatdoc;
+use async_trait::async_trait;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{get_env_key, step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
     context::ConfigContext,
 };
 
 #[derive(Default)]
 pub struct Mbedtls<'a> {
     cmake: Option<&'a str>,
+    version: Option<&'a str>,
 }
 
 impl<'a> Mbedtls<'a> {
     pub fn new() -> Self {
-        Self { cmake: None }
+        Self {
+            cmake: None,
+            version: None,
+        }
     }
 
     pub fn with_cmake(mut self, cmake: &'a str) -> Self {
@@ -22,49 +32,49 @@ impl<'a> Mbedtls<'a> {
         self
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Mbedtls<'a> {
+    fn name(&self) -> &str {
+        "mbedtls"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::MBEDTLS))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
         let cmake = match self.cmake {
             Some(val) => val,
-            None => &cmake::Cmake::new().build(context).await?,
+            None => &context_cache::build(context, &cmake::Cmake::new()).await?,
         };
 
-        let name = "mbedtls";
-        let version = "3.6.5";
+        let name = self.name();
+        let version = self.version();
 
         let path = format!(
             "https://github.com/Mbed-TLS/mbedtls/releases/download/mbedtls-{version}/mbedtls-{version}.tar.bz2"
         );
 
-        let source = ArtifactSource::new(name, &path).build();
-
-        let script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT\"
-
-            BUILD_DIR=\"$(pwd)/build\"
-            mkdir -p \"$BUILD_DIR\"
-
-            pushd \"$BUILD_DIR\"
-
-            {cmake}/bin/cmake \
-                -DCMAKE_BUILD_TYPE=RELEASE \
-                -DCMAKE_INSTALL_PREFIX=\"$VORPAL_OUTPUT\" \
-                -DENABLE_TESTING=OFF \
-                -DUSE_SHARED_MBEDTLS_LIBRARY=OFF \
-                \"$(pwd)/../source/{name}/mbedtls-{version}\"
-
-            make -j$(nproc 2>/dev/null || sysctl -n hw.ncpu) install
-            popd",
-            cmake = get_env_key(&cmake.to_string()),
-        };
-
-        let steps =
-            vec![step::shell(context, vec![cmake.to_string()], vec![], script, vec![]).await?];
+        let source = source::new(name, &path);
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let source_directory = format!("mbedtls-{version}");
 
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{version}")])
-            .with_sources(vec![source])
+        CmakePackage::new(name, version, source, self.systems())
+            .with_cmake(cmake)
+            .with_definition("ENABLE_TESTING=OFF")
+            .with_definition("USE_SHARED_MBEDTLS_LIBRARY=OFF")
+            .with_source_directory(&source_directory)
             .build(context)
             .await
     }
@@ -0,0 +1,111 @@
+use crate::{artifact::ArtifactPackage, error::ArtifactError, overrides, source, target, versions};
+use anyhow::Result;
+use async_trait::async_trait;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct Mc<'a> {
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
+
+impl<'a> Mc<'a> {
+    pub fn new() -> Self {
+        Self {
+            target: None,
+            version: None,
+        }
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Mc<'a> {
+    fn name(&self) -> &str {
+        "mc"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::MC))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let name = self.name();
+        let source_version = self.version();
+
+        let source_system = match system {
+            Aarch64Darwin => "darwin-arm64",
+            Aarch64Linux => "linux-arm64",
+            X8664Darwin => "darwin-amd64",
+            X8664Linux => "linux-amd64",
+            system => {
+                return Err(ArtifactError::UnsupportedSystem {
+                    artifact: name.to_string(),
+                    system,
+                }
+                .into())
+            }
+        };
+
+        let source_path = format!(
+            "https://dl.min.io/client/mc/release/{source_system}/archive/mc.RELEASE.{source_version}"
+        );
+
+        let source = source::for_system(name, &source_path, system)?;
+
+        // mc defaults to ~/.mc for aliases and credentials; the wrapper points it at a directory
+        // local to the working tree unless MC_CONFIG_DIR is already set by the environment.
+        let step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT/bin\" \"$VORPAL_OUTPUT/libexec\"
+
+            cp ./source/{name}/mc.RELEASE.{source_version} \"$VORPAL_OUTPUT/libexec/mc\"
+            chmod +x \"$VORPAL_OUTPUT/libexec/mc\"
+
+            cat << EOF > \"$VORPAL_OUTPUT/bin/mc\"
+            #!/bin/sh
+            export MC_CONFIG_DIR=\"\\${{MC_CONFIG_DIR:-\\$PWD/.mc}}\"
+            exec \"$VORPAL_OUTPUT/libexec/mc\" --config-dir \"\\$MC_CONFIG_DIR\" \"\\$@\"
+            EOF
+
+            chmod +x \"$VORPAL_OUTPUT/bin/mc\"",
+        };
+
+        let steps = vec![target::shell(context, system, vec![], vec![], step_script).await?];
+
+        let systems = self.systems();
+
+        target::build(
+            context,
+            system,
+            name,
+            vec![format!("{name}:{source_version}")],
+            vec![source],
+            steps,
+            systems,
+        )
+        .await
+    }
+}
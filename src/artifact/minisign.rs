@@ -0,0 +1,101 @@
+use crate::{artifact::ArtifactPackage, error::ArtifactError, overrides, source, target, versions};
+use anyhow::Result;
+use async_trait::async_trait;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct Minisign<'a> {
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
+
+impl<'a> Minisign<'a> {
+    pub fn new() -> Self {
+        Self {
+            target: None,
+            version: None,
+        }
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Minisign<'a> {
+    fn name(&self) -> &str {
+        "minisign"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::MINISIGN))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let name = self.name();
+        let source_version = self.version();
+
+        // The linux archive carries one static binary per architecture, the macOS archive a single
+        // universal binary.
+        let (source_archive, source_binary) = match system {
+            Aarch64Darwin | X8664Darwin => ("macos.zip", "minisign-macos/minisign"),
+            Aarch64Linux => ("linux.tar.gz", "minisign-linux/aarch64/minisign"),
+            X8664Linux => ("linux.tar.gz", "minisign-linux/x86_64/minisign"),
+            system => {
+                return Err(ArtifactError::UnsupportedSystem {
+                    artifact: name.to_string(),
+                    system,
+                }
+                .into())
+            }
+        };
+
+        let source_path = format!(
+            "https://github.com/jedisct1/minisign/releases/download/{source_version}/minisign-{source_version}-{source_archive}"
+        );
+
+        let source = source::for_system(name, &source_path, system)?;
+
+        let step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT/bin\"
+            cp ./source/{name}/{source_binary} \"$VORPAL_OUTPUT/bin/minisign\"
+            chmod +x \"$VORPAL_OUTPUT/bin/minisign\"",
+        };
+
+        let steps = vec![target::shell(context, system, vec![], vec![], step_script).await?];
+
+        let systems = self.systems();
+
+        target::build(
+            context,
+            system,
+            name,
+            vec![format!("{name}:{source_version}")],
+            vec![source],
+            steps,
+            systems,
+        )
+        .await
+    }
+}
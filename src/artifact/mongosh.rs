@@ -0,0 +1,128 @@
+use crate::{
+    artifact::ArtifactPackage, builder::check, error::ArtifactError, overrides, source, target,
+    versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct Mongosh<'a> {
+    check: Option<bool>,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
+
+impl<'a> Mongosh<'a> {
+    pub fn new() -> Self {
+        Self {
+            check: None,
+            target: None,
+            version: None,
+        }
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Mongosh<'a> {
+    fn name(&self) -> &str {
+        "mongosh"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::MONGOSH))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let name = self.name();
+        let version = self.version();
+
+        // Darwin builds are published as zip archives, Linux builds as tarballs.
+        let (source_system, extension) = match system {
+            Aarch64Darwin => ("darwin-arm64", "zip"),
+            Aarch64Linux => ("linux-arm64", "tgz"),
+            X8664Darwin => ("darwin-x64", "zip"),
+            X8664Linux => ("linux-x64", "tgz"),
+            system => {
+                return Err(ArtifactError::UnsupportedSystem {
+                    artifact: name.to_string(),
+                    system,
+                }
+                .into())
+            }
+        };
+
+        let path = format!(
+            "https://downloads.mongodb.com/compass/mongosh-{version}-{source_system}.{extension}"
+        );
+
+        let source = source::for_system(name, &path, system)?;
+
+        // The release bundles Node.js into the `mongosh` executable; `bin` also holds the
+        // queryable encryption library it loads from next to itself.
+        let step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT/share/man/man1\"
+            pushd ./source/{name}/mongosh-{version}-{source_system}
+            cp -Rv bin \"$VORPAL_OUTPUT/\"
+            cp -v mongosh.1.gz \"$VORPAL_OUTPUT/share/man/man1/\"
+            chmod +x \"$VORPAL_OUTPUT/bin/mongosh\"",
+        };
+
+        let mut steps = vec![target::shell(context, system, vec![], vec![], step_script).await?];
+
+        if check::enabled(context, system, self.check)? {
+            steps.push(
+                check::step(context, system, vec![], "bin/mongosh --version", version).await?,
+            );
+        }
+
+        let systems = self.systems();
+
+        target::build(
+            context,
+            system,
+            name,
+            vec![format!("{name}:{version}")],
+            vec![source],
+            steps,
+            systems,
+        )
+        .await
+    }
+}
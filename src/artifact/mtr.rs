@@ -0,0 +1,118 @@
+use crate::{
+    artifact::{ncurses::Ncurses, ArtifactPackage},
+    builder::{autotools::AutotoolsPackage, prune},
+    context_cache, overrides, source, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::get_env_key,
+    context::ConfigContext,
+};
+
+pub struct Mtr<'a> {
+    check: Option<bool>,
+    ncurses: Option<&'a str>,
+    prune: bool,
+    version: Option<&'a str>,
+}
+
+impl<'a> Default for Mtr<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Mtr<'a> {
+    pub fn new() -> Self {
+        Self {
+            check: None,
+            ncurses: None,
+            prune: true,
+            version: None,
+        }
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    pub fn with_ncurses(mut self, ncurses: &'a str) -> Self {
+        self.ncurses = Some(ncurses);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+
+    // Keeps the documentation and libtool archives `prune::DEFAULT` removes.
+    pub fn without_prune(mut self) -> Self {
+        self.prune = false;
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Mtr<'a> {
+    fn name(&self) -> &str {
+        "mtr"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::MTR))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let ncurses = match self.ncurses {
+            Some(val) => val,
+            None => &context_cache::build(context, &Ncurses::new()).await?,
+        };
+
+        let name = self.name();
+        let version = self.version();
+
+        // The bitwizard tarball ships a generated configure script; GitHub tag archives do not.
+        let source_path = format!("https://www.bitwizard.nl/mtr/files/mtr-{version}.tar.gz");
+        let source = source::new(name, &source_path);
+
+        let source_directory = format!("{name}-{version}");
+
+        let ncurses_key = get_env_key(&ncurses.to_string());
+
+        // mtr installs into sbin/, which devenvs do not put on PATH. Sending probes still needs
+        // raw sockets, so mtr-packet must be run as root or granted CAP_NET_RAW by the user.
+        AutotoolsPackage::new(name, version, source, self.systems())
+            .with_artifact(ncurses)
+            .with_check("bin/mtr --version", &format!("mtr {version}"))
+            .with_check_enabled(self.check)
+            .with_configure_flag("--sbindir=\"$VORPAL_OUTPUT/bin\"")
+            .with_configure_flag("--without-gtk")
+            .with_cppflags(&format!("-I{ncurses_key}/include/ncursesw"))
+            .with_environment("LIBS", "-ltinfow")
+            .with_prune(prune::DEFAULT)
+            .with_prune_enabled(self.prune)
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .build(context)
+            .await
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{
+    artifact::ArtifactPackage, builder::autotools::AutotoolsPackage, overrides, source, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Linux, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct Musl<'a> {
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
+
+impl<'a> Musl<'a> {
+    pub fn new() -> Self {
+        Self {
+            target: None,
+            version: None,
+        }
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Musl<'a> {
+    fn name(&self) -> &str {
+        "musl"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::MUSL))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Linux, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!("https://musl.libc.org/releases/musl-{version}.tar.gz");
+
+        let source = source::new(name, &path);
+
+        let source_directory = format!("{name}-{version}");
+
+        // Only the static library and the `musl-gcc` wrapper are installed; the shared build would
+        // also install its dynamic loader under `/lib`, outside the output.
+        let mut package = AutotoolsPackage::new(name, version, source, self.systems())
+            .with_configure_flag("--disable-shared")
+            .with_source_directory(&source_directory);
+
+        if let Some(target) = self.target {
+            package = package.with_target(target);
+        }
+
+        package.build(context).await
+    }
+}
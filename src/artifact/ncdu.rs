@@ -0,0 +1,116 @@
+use crate::{
+    artifact::{ncurses::Ncurses, ArtifactPackage},
+    builder::{autotools::AutotoolsPackage, prune},
+    context_cache, overrides, source, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::get_env_key,
+    context::ConfigContext,
+};
+
+pub struct Ncdu<'a> {
+    check: Option<bool>,
+    ncurses: Option<&'a str>,
+    prune: bool,
+    version: Option<&'a str>,
+}
+
+impl<'a> Default for Ncdu<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Ncdu<'a> {
+    pub fn new() -> Self {
+        Self {
+            check: None,
+            ncurses: None,
+            prune: true,
+            version: None,
+        }
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    pub fn with_ncurses(mut self, ncurses: &'a str) -> Self {
+        self.ncurses = Some(ncurses);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+
+    // Keeps the documentation and libtool archives `prune::DEFAULT` removes.
+    pub fn without_prune(mut self) -> Self {
+        self.prune = false;
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Ncdu<'a> {
+    fn name(&self) -> &str {
+        "ncdu"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::NCDU))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let ncurses = match self.ncurses {
+            Some(val) => val,
+            None => &context_cache::build(context, &Ncurses::new()).await?,
+        };
+
+        let name = self.name();
+        let version = self.version();
+
+        // The 1.x series is the C implementation; 2.x is written in Zig.
+        let source_path = format!("https://dev.yorhel.nl/download/ncdu-{version}.tar.gz");
+        let source = source::new(name, &source_path);
+
+        let source_directory = format!("{name}-{version}");
+
+        let ncurses_key = get_env_key(&ncurses.to_string());
+
+        // ncurses is built with a separate terminfo library, which ncdu does not link by itself.
+        AutotoolsPackage::new(name, version, source, self.systems())
+            .with_artifact(ncurses)
+            .with_check("bin/ncdu -v", &format!("ncdu {version}"))
+            .with_check_enabled(self.check)
+            .with_configure_flag("--with-ncursesw")
+            .with_cppflags(&format!("-I{ncurses_key}/include/ncursesw"))
+            .with_environment("LIBS", "-ltinfow")
+            .with_prune(prune::DEFAULT)
+            .with_prune_enabled(self.prune)
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .build(context)
+            .await
+    }
+}
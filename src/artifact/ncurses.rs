@@ -1,46 +1,108 @@
+use crate::{
+    artifact::ArtifactPackage,
+    builder::{autotools::AutotoolsPackage, musl},
+    overrides, source,
+    variant::{Linkage, Variant},
+    versions,
+};
 use anyhow::Result;
-use indoc::formatdoc;
+use async_trait::async_trait;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
     context::ConfigContext,
 };
 
 #[derive(Default)]
-pub struct Ncurses;
+pub struct Ncurses<'a> {
+    linkage: Linkage,
+    variant: Variant,
+    version: Option<&'a str>,
+}
 
-impl Ncurses {
+impl<'a> Ncurses<'a> {
     pub fn new() -> Self {
-        Self
+        Self {
+            linkage: Linkage::Shared,
+            variant: Variant::Full,
+            version: None,
+        }
+    }
+
+    // Static builds install only the normal libraries, which shared builds also include. Musl
+    // builds are static and drop the C++ binding; the `libc` variable switches Linux builds to
+    // them, see `musl::linkage`.
+    pub fn with_linkage(mut self, linkage: Linkage) -> Self {
+        self.linkage = linkage;
+        self
+    }
+
+    // The minimal variant drops the C++ and Ada bindings and the test programs; tic and infocmp
+    // are kept since they install the terminfo database.
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = variant;
+        self
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "ncurses";
-        let version = "6.5-20250809";
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Ncurses<'a> {
+    fn name(&self) -> &str {
+        "ncurses"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::NCURSES))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name();
+        let version = self.version();
 
         let source_path = format!("https://sdk.vorpal.build/source/ncurses-{version}.tar.gz");
-        let source = ArtifactSource::new(name, &source_path).build();
-
-        let step_script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT\"
-            pushd ./source/{name}/{name}-{version}
-            ./configure \
-                --enable-pc-files \
-                --prefix=\"$VORPAL_OUTPUT\" \
-                --with-pkg-config-libdir=\"$VORPAL_OUTPUT/lib/pkgconfig\" \
-                --with-shared \
-                --with-termlib
-            make
-            make install",
+        let source = source::new(name, &source_path);
+
+        let source_directory = format!("{name}-{version}");
+
+        let linkage = musl::linkage(context, self.linkage)?;
+
+        let shared = match linkage {
+            Linkage::Shared => "--with-shared",
+            Linkage::Static | Linkage::Musl => "--without-shared",
         };
 
-        let steps = vec![step::shell(context, vec![], vec![], step_script, vec![]).await?];
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let mut package = AutotoolsPackage::new(name, version, source, self.systems())
+            .with_configure_flag("--enable-pc-files")
+            .with_configure_flag("--with-pkg-config-libdir=\"$VORPAL_OUTPUT/lib/pkgconfig\"")
+            .with_configure_flag(shared)
+            .with_configure_flag("--with-termlib")
+            .with_dylib_audit()
+            .with_prune(&["share/man"])
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .with_variant(self.variant, linkage);
+
+        if self.variant == Variant::Minimal {
+            package = package
+                .with_configure_flag("--without-ada")
+                .with_configure_flag("--without-cxx-binding")
+                .with_configure_flag("--without-tests");
+        } else if linkage == Linkage::Musl {
+            package = package.with_configure_flag("--without-cxx-binding");
+        }
 
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{version}")])
-            .with_sources(vec![source])
-            .build(context)
-            .await
+        package.build(context).await
     }
 }
@@ -1,50 +1,168 @@
+use crate::{
+    artifact::ArtifactPackage,
+    builder::darwin_sign,
+    channel::{self, Channel},
+    error::ArtifactError,
+    overrides, source, target, versions,
+};
 use anyhow::Result;
+use async_trait::async_trait;
 use indoc::formatdoc;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
     context::ConfigContext,
 };
 
 #[derive(Default)]
-pub struct Neovim;
+pub struct Neovim<'a> {
+    channel: Channel,
+    darwin_sign: bool,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
 
-impl Neovim {
+impl<'a> Neovim<'a> {
     pub fn new() -> Self {
-        Self
+        Self {
+            channel: Channel::Stable,
+            darwin_sign: false,
+            target: None,
+            version: None,
+        }
+    }
+
+    // `Channel::Nightly` installs the rolling `nightly` release instead of the pinned version;
+    // neovim publishes no release candidates.
+    pub fn with_channel(mut self, channel: Channel) -> Self {
+        self.channel = channel;
+        self
+    }
+
+    // Clears the quarantine attribute and ad hoc signs the darwin binaries, see
+    // `darwin_sign::step`.
+    pub fn with_darwin_sign(mut self) -> Self {
+        self.darwin_sign = true;
+        self
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Neovim<'a> {
+    fn name(&self) -> &str {
+        "neovim"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::NEOVIM))
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "neovim";
-        let source_version = "0.12.3";
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
 
-        let source_system = match context.get_system() {
+        let name = self.name();
+        let source_version = self.version();
+
+        let source_system = match system {
             Aarch64Darwin => "macos-arm64",
             Aarch64Linux => "linux-arm64",
             X8664Darwin => "macos-x86_64",
             X8664Linux => "linux-x86_64",
-            _ => return Err(anyhow::anyhow!("Unsupported system for neovim artifact")),
+            system => {
+                return Err(ArtifactError::UnsupportedSystem {
+                    artifact: name.to_string(),
+                    system,
+                }
+                .into())
+            }
+        };
+
+        let tag = match self.channel {
+            Channel::Stable => format!("v{source_version}"),
+            Channel::Nightly => "nightly".to_string(),
+            Channel::Rc => {
+                return Err(ArtifactError::UnsupportedVariant {
+                    artifact: name.to_string(),
+                    variant: self.channel.as_str().to_string(),
+                }
+                .into())
+            }
         };
 
         let source_path = format!(
-            "https://github.com/neovim/neovim/releases/download/v{source_version}/nvim-{source_system}.tar.gz"
+            "https://github.com/neovim/neovim/releases/download/{tag}/nvim-{source_system}.tar.gz"
         );
 
-        let source = ArtifactSource::new(name, &source_path).build();
+        let source = source::for_system(name, &source_path, system)?;
 
         let step_script = formatdoc! {"
             pushd ./source/{name}/nvim-{source_system}
             cp -Rv * \"$VORPAL_OUTPUT/.\"",
         };
 
-        let steps = vec![step::shell(context, vec![], vec![], step_script, vec![]).await?];
+        let mut steps = vec![target::shell(context, system, vec![], vec![], step_script).await?];
+
+        if self.darwin_sign {
+            steps.extend(darwin_sign::step(context, system).await?);
+        }
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let systems = self.systems();
 
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{source_version}")])
-            .with_sources(vec![source])
+        target::build(
+            context,
+            system,
+            name,
+            vec![channel::alias(name, source_version, self.channel)],
+            vec![source],
+            steps,
+            systems,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, MockContext};
+
+    #[tokio::test]
+    async fn build_nightly_channel() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Darwin).build().await?;
+
+        let neovim = Neovim::new()
+            .with_channel(Channel::Nightly)
+            .build(context)
+            .await?;
+
+        assert_eq!(testing::aliases(context, &neovim)?, ["neovim:nightly"]);
+        assert_eq!(
+            testing::source_paths(context, &neovim)?,
+            ["https://github.com/neovim/neovim/releases/download/nightly/nvim-macos-arm64.tar.gz"]
+        );
+        assert!(Neovim::new()
+            .with_channel(Channel::Rc)
             .build(context)
             .await
+            .is_err());
+
+        Ok(())
     }
 }
@@ -0,0 +1,102 @@
+use crate::{
+    artifact::ArtifactPackage,
+    builder::{autotools::AutotoolsPackage, prune},
+    overrides, source,
+    variant::{self, Linkage, Variant},
+    versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+pub struct Nghttp2<'a> {
+    linkage: Option<Linkage>,
+    prune: bool,
+    version: Option<&'a str>,
+}
+
+impl<'a> Default for Nghttp2<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Nghttp2<'a> {
+    pub fn new() -> Self {
+        Self {
+            linkage: None,
+            prune: true,
+            version: None,
+        }
+    }
+
+    // Installs only shared or only static libraries; without it the `linkage` variable decides,
+    // see `variant::linkage`.
+    pub fn with_linkage(mut self, linkage: Linkage) -> Self {
+        self.linkage = Some(linkage);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Keeps the documentation and libtool archives `prune::DEFAULT` removes.
+    pub fn without_prune(mut self) -> Self {
+        self.prune = false;
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Nghttp2<'a> {
+    fn name(&self) -> &str {
+        "nghttp2"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::NGHTTP2))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!(
+            "https://github.com/nghttp2/nghttp2/releases/download/v{version}/nghttp2-{version}.tar.xz"
+        );
+
+        let source = source::new(name, &path);
+
+        let source_directory = format!("{name}-{version}");
+
+        let linkage = variant::linkage(context, self.linkage)?;
+
+        let [shared_flag, static_flag] = variant::configure_flags(linkage);
+
+        // Only libnghttp2: the apps and hpack tools would pull in libev, jansson and openssl.
+        AutotoolsPackage::new(name, version, source, self.systems())
+            .with_configure_flag("--enable-lib-only")
+            .with_configure_flag(shared_flag)
+            .with_configure_flag(static_flag)
+            .with_prune(prune::DEFAULT)
+            .with_prune_enabled(self.prune)
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .with_variant(Variant::Full, linkage)
+            .build(context)
+            .await
+    }
+}
@@ -1,29 +1,57 @@
+use crate::{artifact::ArtifactPackage, overrides, source, versions};
 use anyhow::Result;
+use async_trait::async_trait;
 use indoc::formatdoc;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::{step, Artifact},
     context::ConfigContext,
 };
 
 #[derive(Default)]
-pub struct Nginx;
+pub struct Nginx<'a> {
+    version: Option<&'a str>,
+}
 
-impl Nginx {
+impl<'a> Nginx<'a> {
     pub fn new() -> Self {
-        Self
+        Self { version: None }
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Nginx<'a> {
+    fn name(&self) -> &str {
+        "nginx"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::NGINX))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "nginx";
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name();
 
-        let source_version = "1.27.5";
+        let source_version = self.version();
 
         let source_path = format!(
             "https://github.com/nginx/nginx/archive/refs/tags/release-{source_version}.tar.gz"
         );
 
-        let source = ArtifactSource::new(name, source_path.as_str()).build();
+        let source = source::new(name, source_path.as_str());
 
         let step_script = formatdoc! {"
             mkdir -pv \"$VORPAL_OUTPUT/bin\"
@@ -42,7 +70,7 @@ impl Nginx {
 
         let steps = vec![step::shell(context, vec![], vec![], step_script, vec![]).await?];
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let systems = self.systems();
 
         Artifact::new(name, steps, systems)
             .with_aliases(vec![format!("{name}:{source_version}")])
@@ -0,0 +1,169 @@
+use crate::{
+    artifact::{libpcap::Libpcap, openssl::Openssl, zlib::Zlib, ArtifactPackage},
+    builder::{autotools::AutotoolsPackage, prune},
+    context_cache, fork, overrides, source, target, versions,
+};
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::get_env_key,
+    context::ConfigContext,
+};
+
+pub struct Nmap<'a> {
+    check: Option<bool>,
+    libpcap: Option<&'a str>,
+    openssl: Option<&'a str>,
+    prune: bool,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+    zlib: Option<&'a str>,
+}
+
+impl<'a> Default for Nmap<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Nmap<'a> {
+    pub fn new() -> Self {
+        Self {
+            check: None,
+            libpcap: None,
+            openssl: None,
+            prune: true,
+            target: None,
+            version: None,
+            zlib: None,
+        }
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    pub fn with_libpcap(mut self, libpcap: &'a str) -> Self {
+        self.libpcap = Some(libpcap);
+        self
+    }
+
+    pub fn with_openssl(mut self, openssl: &'a str) -> Self {
+        self.openssl = Some(openssl);
+        self
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    pub fn with_zlib(mut self, zlib: &'a str) -> Self {
+        self.zlib = Some(zlib);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+
+    // Keeps the documentation and libtool archives `prune::DEFAULT` removes.
+    pub fn without_prune(mut self) -> Self {
+        self.prune = false;
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Nmap<'a> {
+    fn name(&self) -> &str {
+        "nmap"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::NMAP))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let (openssl, zlib) = tokio::try_join!(
+            fork::build_for(context, system, self.openssl, Openssl::new()),
+            fork::build_for(context, system, self.zlib, Zlib::new()),
+        )?;
+
+        let [openssl, zlib] = fork::join(context, [openssl, zlib]).await?;
+
+        // macOS ships libpcap with the system, which configure finds on its own.
+        let libpcap = match (self.libpcap, system) {
+            (Some(val), _) => Some(val.to_string()),
+            (None, Aarch64Linux | X8664Linux) if system == context.get_system() => {
+                Some(context_cache::build(context, &Libpcap::new()).await?)
+            }
+            (None, Aarch64Linux | X8664Linux) => {
+                bail!(
+                    "libpcap is only built for the host by default: pass one built for {}",
+                    system.as_str_name()
+                )
+            }
+            (None, _) => None,
+        };
+
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!("https://nmap.org/dist/nmap-{version}.tar.bz2");
+
+        let source = source::new(name, &path);
+
+        let source_directory = format!("{name}-{version}");
+
+        let check_expected = format!("Nmap version {version}");
+
+        // Lua, PCRE and libssh2 come from the copies bundled in the source tree. Zenmap and
+        // ndiff are Python applications and are left out.
+        let mut package = AutotoolsPackage::new(name, version, source, self.systems())
+            .with_artifact(&openssl)
+            .with_artifact(&zlib)
+            .with_check("bin/nmap --version", &check_expected)
+            .with_check_enabled(self.check)
+            .with_configure_flag("--with-liblua=included")
+            .with_configure_flag("--with-libpcre=included")
+            .with_configure_flag("--with-libssh2=included")
+            .with_configure_flag(&format!("--with-libz={}", get_env_key(&zlib)))
+            .with_configure_flag(&format!("--with-openssl={}", get_env_key(&openssl)))
+            .with_configure_flag("--without-ndiff")
+            .with_configure_flag("--without-zenmap")
+            .with_prune(prune::DEFAULT)
+            .with_prune_enabled(self.prune)
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .with_target(system);
+
+        if let Some(libpcap) = libpcap.as_ref() {
+            package = package
+                .with_artifact(libpcap)
+                .with_configure_flag(&format!("--with-libpcap={}", get_env_key(libpcap)));
+        }
+
+        package.build(context).await
+    }
+}
@@ -1,9 +1,19 @@
-use crate::artifact::{ncurses::Ncurses, pkg_config::PkgConfig, readline::Readline};
-use anyhow::Result;
+use crate::{
+    artifact::{ncurses::Ncurses, pkg_config::PkgConfig, readline::Readline, ArtifactPackage},
+    builder::{dependency_env::DependencyEnv, musl, wrapper},
+    fork, overrides, source, target,
+    variant::{self, Linkage, Variant},
+    versions,
+};
+use anyhow::{bail, Result};
+use async_trait::async_trait;
 use indoc::formatdoc;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{get_env_key, step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::get_env_key,
     context::ConfigContext,
 };
 
@@ -12,6 +22,8 @@ pub struct Nnn<'a> {
     ncurses: Option<&'a str>,
     pkg_config: Option<&'a str>,
     readline: Option<&'a str>,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
 }
 
 impl<'a> Nnn<'a> {
@@ -20,6 +32,8 @@ impl<'a> Nnn<'a> {
             ncurses: None,
             pkg_config: None,
             readline: None,
+            target: None,
+            version: None,
         }
     }
 
@@ -38,67 +52,174 @@ impl<'a> Nnn<'a> {
         self
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let ncurses = match self.ncurses {
-            Some(val) => val,
-            None => &Ncurses::new().build(context).await?,
-        };
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
 
-        let pkg_config = match self.pkg_config {
-            Some(val) => val,
-            None => &PkgConfig::new().build(context).await?,
-        };
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Nnn<'a> {
+    fn name(&self) -> &str {
+        "nnn"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::NNN))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let (ncurses, pkg_config) = tokio::try_join!(
+            fork::build_for(context, system, self.ncurses, Ncurses::new()),
+            fork::build_for(context, system, self.pkg_config, PkgConfig::new()),
+        )?;
+
+        let [ncurses, pkg_config] = fork::join(context, [ncurses, pkg_config]).await?;
 
         let readline = match self.readline {
             Some(val) => val,
-            None => &Readline::new().with_ncurses(ncurses).build(context).await?,
+            None if system != context.get_system() => bail!(
+                "readline is only built for the host by default: pass one built for {}",
+                system.as_str_name()
+            ),
+            None => {
+                &Readline::new()
+                    .with_ncurses(&ncurses)
+                    .build(context)
+                    .await?
+            }
         };
 
-        let name = "nnn";
-        let version = "5.1";
+        let name = self.name();
+        let version = self.version();
 
         let path = format!("https://github.com/jarun/nnn/archive/refs/tags/v{version}.tar.gz");
 
-        let source = ArtifactSource::new(name, &path).build();
+        let source = source::new(name, &path);
+
+        let ncurses_key = get_env_key(&ncurses.to_string());
+
+        let linkage = musl::linkage(context, Linkage::Shared)?;
+
+        let dependency_env = DependencyEnv::new(&[ncurses.as_str(), pkg_config.as_str(), readline])
+            .with_cppflags(&format!("-I{ncurses_key}/include/ncursesw"));
+
+        let dependency_script = dependency_env.script();
+
+        let mut step_artifacts = dependency_env.artifacts();
+
+        // nnn's Makefile links statically with `O_STATIC`, against the static musl builds of
+        // ncurses and readline.
+        let (musl_script, make_flags) = match linkage {
+            Linkage::Musl => {
+                let toolchain = musl::toolchain(context, system).await?;
+
+                let script = musl::script(&toolchain);
+
+                step_artifacts.push(toolchain);
+
+                (script, " O_STATIC=1")
+            }
+            _ => (String::new(), ""),
+        };
+
+        // ncurses only searches its own terminfo database by default, which lacks entries for
+        // newer terminal emulators that the host may already provide.
+        let wrapper_script = wrapper::wrap_binary(
+            "$VORPAL_OUTPUT/bin/nnn",
+            "\"$VORPAL_OUTPUT/libexec/nnn\"",
+            &[],
+            &[(
+                "TERMINFO_DIRS",
+                format!("\\${{TERMINFO_DIRS:+\\$TERMINFO_DIRS:}}{ncurses_key}/share/terminfo:/usr/share/terminfo"),
+            )],
+        );
 
         let script = formatdoc! {"
             mkdir -pv \"$VORPAL_OUTPUT\"
 
             pushd ./source/{name}/nnn-{version}
 
-            export PATH=\"{pkg_config}/bin:$PATH\"
-            export CPPFLAGS=\"-I{ncurses}/include -I{ncurses}/include/ncursesw -I{readline}/include\"
-            export LDFLAGS=\"-L{ncurses}/lib -L{readline}/lib -Wl,-rpath,{ncurses}/lib -Wl,-rpath,{readline}/lib\"
-            export PKG_CONFIG_PATH=\"{ncurses}/lib/pkgconfig:{readline}/lib/pkgconfig\"
+            {dependency_script}
+            {musl_script}
 
-            make PREFIX=\"$VORPAL_OUTPUT\"
-            make PREFIX=\"$VORPAL_OUTPUT\" install",
-            ncurses = get_env_key(&ncurses.to_string()),
-            pkg_config = get_env_key(&pkg_config.to_string()),
-            readline = get_env_key(&readline.to_string()),
+            make PREFIX=\"$VORPAL_OUTPUT\"{make_flags}
+            make PREFIX=\"$VORPAL_OUTPUT\"{make_flags} install
+
+            mkdir -pv \"$VORPAL_OUTPUT/libexec\"
+            mv -v \"$VORPAL_OUTPUT/bin/nnn\" \"$VORPAL_OUTPUT/libexec/nnn\"
+
+            {wrapper_script}",
         };
 
-        let steps = vec![
-            step::shell(
-                context,
-                vec![
-                    ncurses.to_string(),
-                    pkg_config.to_string(),
-                    readline.to_string(),
-                ],
-                vec![],
-                script,
-                vec![],
-            )
-            .await?,
-        ];
-
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
-
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{version}")])
-            .with_sources(vec![source])
+        let steps = vec![target::shell(context, system, step_artifacts, vec![], script).await?];
+
+        let systems = self.systems();
+
+        target::build(
+            context,
+            system,
+            name,
+            vec![variant::alias(name, version, Variant::Full, linkage)],
+            vec![source],
+            steps,
+            systems,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, MockContext};
+
+    #[tokio::test]
+    async fn build_links_readline_against_same_ncurses() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Darwin).build().await?;
+
+        let nnn = Nnn::new().build(context).await?;
+
+        assert_eq!(
+            testing::dependencies(context, &nnn)?,
+            ["ncurses", "pkg-config", "readline"]
+        );
+
+        let dependencies = testing::artifact(context, &nnn)?.steps[0].artifacts.clone();
+        let ncurses = &dependencies[0];
+        let readline = &dependencies[2];
+
+        assert!(testing::artifact(context, readline)?.steps[0]
+            .artifacts
+            .contains(ncurses));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_for_target_requires_dependencies() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Darwin).build().await?;
+
+        let error = Nnn::new()
+            .with_target(X8664Darwin)
             .build(context)
             .await
+            .unwrap_err();
+
+        assert!(error.to_string().contains("pass one built for"));
+
+        Ok(())
     }
 }
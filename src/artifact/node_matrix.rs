@@ -0,0 +1,261 @@
+use crate::{
+    artifact::ArtifactPackage,
+    builder::{check, wrapper},
+    error::ArtifactError,
+    source, target, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+// Node.js runtimes installed side by side, one per major, for projects that test against several
+// of them without a version manager. Each major gets `node<major>`, `npm<major>` and `npx<major>`,
+// and `node-matrix <major> [command...]` runs a command with that major first on PATH.
+pub struct NodeMatrix<'a> {
+    check: Option<bool>,
+    target: Option<ArtifactSystem>,
+    versions: Vec<&'a str>,
+}
+
+impl<'a> Default for NodeMatrix<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> NodeMatrix<'a> {
+    pub fn new() -> Self {
+        Self {
+            check: None,
+            target: None,
+            versions: vec![
+                versions::NODE_MATRIX_18,
+                versions::NODE_MATRIX_20,
+                versions::NODE_MATRIX_22,
+            ],
+        }
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    // Full versions to install, oldest first and at most one per major.
+    pub fn with_versions(mut self, versions: Vec<&'a str>) -> Self {
+        self.versions = versions;
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for NodeMatrix<'a> {
+    fn name(&self) -> &str {
+        "node-matrix"
+    }
+
+    // The newest runtime stands for the bundle; the others are only in its sources.
+    fn version(&self) -> &str {
+        self.versions.last().copied().unwrap_or_default()
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let name = self.name();
+        let version = self.version();
+
+        let source_system = match system {
+            Aarch64Darwin => "darwin-arm64",
+            Aarch64Linux => "linux-arm64",
+            X8664Darwin => "darwin-x64",
+            X8664Linux => "linux-x64",
+            system => {
+                return Err(ArtifactError::UnsupportedSystem {
+                    artifact: name.to_string(),
+                    system,
+                }
+                .into())
+            }
+        };
+
+        let mut runtimes: Vec<(&str, &str)> = vec![];
+
+        for runtime in self.versions.iter() {
+            let major = runtime.split('.').next().unwrap_or_default();
+
+            if major.is_empty() || runtimes.iter().any(|(other, _)| *other == major) {
+                return Err(ArtifactError::UnsupportedVersion {
+                    artifact: name.to_string(),
+                    version: runtime.to_string(),
+                }
+                .into());
+            }
+
+            runtimes.push((major, *runtime));
+        }
+
+        let sources = runtimes
+            .iter()
+            .map(|(major, runtime)| {
+                let path = format!(
+                    "https://nodejs.org/dist/v{runtime}/node-v{runtime}-{source_system}.tar.gz"
+                );
+
+                source::for_system(&format!("node{major}"), &path, system)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // npm and npx are scripts that run `node` from PATH, so every wrapper puts its own major
+        // first rather than whichever node the caller has.
+        let install_script = runtimes
+            .iter()
+            .map(|(major, runtime)| {
+                let libexec = format!("$VORPAL_OUTPUT/libexec/node{major}");
+
+                let wrapper_script = ["node", "npm", "npx"]
+                    .iter()
+                    .map(|tool| {
+                        wrapper::wrap_binary(
+                            &format!("$VORPAL_OUTPUT/bin/{tool}{major}"),
+                            &format!("\"{libexec}/bin/{tool}\""),
+                            &[format!("{libexec}/bin")],
+                            &[],
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n\n");
+
+                formatdoc! {"
+                    cp -pR ./source/node{major}/node-v{runtime}-{source_system} \"{libexec}\"
+
+                    {wrapper_script}",
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        let majors = runtimes
+            .iter()
+            .map(|(major, _)| *major)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        // Unquoted heredoc: the output path is fixed at build time, `\$` references are read
+        // when the selector runs.
+        let step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT/bin\" \"$VORPAL_OUTPUT/libexec\"
+
+            {install_script}
+
+            cat << EOF > \"$VORPAL_OUTPUT/bin/node-matrix\"
+            #!/bin/sh
+            if [ \\$# -eq 0 ]; then
+                echo \"usage: node-matrix <major> [command [args...]] (majors: {majors})\" >&2
+                exit 2
+            fi
+
+            major=\"\\$1\"
+            shift
+
+            if [ ! -d \"$VORPAL_OUTPUT/libexec/node\\$major\" ]; then
+                echo \"node-matrix: no Node.js \\$major (majors: {majors})\" >&2
+                exit 1
+            fi
+
+            export PATH=\"$VORPAL_OUTPUT/libexec/node\\$major/bin:\\$PATH\"
+
+            if [ \\$# -eq 0 ]; then
+                exec node
+            fi
+
+            exec \"\\$@\"
+            EOF
+
+            chmod +x \"$VORPAL_OUTPUT/bin/node-matrix\"",
+        };
+
+        let mut steps = vec![target::shell(context, system, vec![], vec![], step_script).await?];
+
+        if check::enabled(context, system, self.check)? {
+            for (major, runtime) in runtimes.iter() {
+                let command = format!("bin/node-matrix {major} node --version");
+
+                steps.push(check::step(context, system, vec![], &command, runtime).await?);
+            }
+        }
+
+        let systems = self.systems();
+
+        target::build(
+            context,
+            system,
+            name,
+            vec![format!("{name}:{version}")],
+            sources,
+            steps,
+            systems,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, MockContext};
+
+    #[tokio::test]
+    async fn build_installs_each_major() -> Result<()> {
+        let context = &mut MockContext::new(X8664Linux).build().await?;
+
+        let artifact = NodeMatrix::new().build(context).await?;
+
+        assert_eq!(
+            testing::source_paths(context, &artifact)?,
+            [
+                versions::NODE_MATRIX_18,
+                versions::NODE_MATRIX_20,
+                versions::NODE_MATRIX_22,
+            ]
+            .map(|runtime| format!(
+                "https://nodejs.org/dist/v{runtime}/node-v{runtime}-linux-x64.tar.gz"
+            ))
+        );
+
+        let result = NodeMatrix::new()
+            .with_versions(vec!["20.19.5", "20.18.0"])
+            .build(context)
+            .await;
+
+        assert!(result.is_err_and(|error| matches!(
+            error.downcast_ref::<ArtifactError>(),
+            Some(ArtifactError::UnsupportedVersion { .. })
+        )));
+
+        Ok(())
+    }
+}
@@ -1,45 +1,62 @@
+use crate::{
+    artifact::ArtifactPackage, builder::autotools::AutotoolsPackage, overrides, source::SourceUrls,
+    versions,
+};
 use anyhow::Result;
-use indoc::formatdoc;
+use async_trait::async_trait;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
     context::ConfigContext,
 };
 
 #[derive(Default)]
-pub struct Npth;
+pub struct Npth<'a> {
+    version: Option<&'a str>,
+}
 
-impl Npth {
+impl<'a> Npth<'a> {
     pub fn new() -> Self {
-        Self
+        Self { version: None }
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "npth";
-        let version = "1.8";
-
-        let path = format!("https://gnupg.org/ftp/gcrypt/npth/npth-{version}.tar.bz2");
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
 
-        let source = ArtifactSource::new(name, &path).build();
+#[async_trait]
+impl<'a> ArtifactPackage for Npth<'a> {
+    fn name(&self) -> &str {
+        "npth"
+    }
 
-        let script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT\"
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::NPTH))
+    }
 
-            pushd ./source/{name}/npth-{version}
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
 
-            ./configure --prefix=\"$VORPAL_OUTPUT\"
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name();
+        let version = self.version();
 
-            make
-            make install",
-        };
+        let file = format!("npth/npth-{version}.tar.bz2");
+        let path = format!("https://gnupg.org/ftp/gcrypt/{file}");
+        let mirror = format!("https://mirrors.dotsrc.org/gcrypt/{file}");
 
-        let steps = vec![step::shell(context, vec![], vec![], script, vec![]).await?];
+        let source = SourceUrls::new(name, &path).with_mirror(&mirror).build();
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let source_directory = format!("npth-{version}");
 
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{version}")])
-            .with_sources(vec![source])
+        AutotoolsPackage::new(name, version, source, self.systems())
+            .with_source_directory(&source_directory)
             .build(context)
             .await
     }
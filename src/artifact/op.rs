@@ -1,36 +1,80 @@
+use crate::{artifact::ArtifactPackage, error::ArtifactError, overrides, source, target, versions};
 use anyhow::Result;
+use async_trait::async_trait;
 use indoc::formatdoc;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
     context::ConfigContext,
 };
 
 #[derive(Default)]
-pub struct Op;
+pub struct Op<'a> {
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
 
-impl Op {
+impl<'a> Op<'a> {
     pub fn new() -> Self {
-        Self
+        Self {
+            target: None,
+            version: None,
+        }
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "op";
-        let source_version = "2.34.1";
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Op<'a> {
+    fn name(&self) -> &str {
+        "op"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::OP))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let name = self.name();
+        let source_version = self.version();
 
-        let source_system = match context.get_system() {
+        let source_system = match system {
             Aarch64Darwin => "darwin_arm64",
             Aarch64Linux => "linux_arm64",
             X8664Darwin => "darwin_amd64",
             X8664Linux => "linux_amd64",
-            _ => return Err(anyhow::anyhow!("Unsupported system for op artifact")),
+            system => {
+                return Err(ArtifactError::UnsupportedSystem {
+                    artifact: name.to_string(),
+                    system,
+                }
+                .into())
+            }
         };
 
         let source_path = format!(
             "https://cache.agilebits.com/dist/1P/op2/pkg/v{source_version}/op_{source_system}_v{source_version}.zip"
         );
 
-        let source = ArtifactSource::new(name, &source_path).build();
+        let source = source::for_system(name, &source_path, system)?;
 
         let step_script = formatdoc! {"
             mkdir -pv \"$VORPAL_OUTPUT/bin\"
@@ -39,14 +83,19 @@ impl Op {
             chmod +x \"$VORPAL_OUTPUT/bin/op\"",
         };
 
-        let steps = vec![step::shell(context, vec![], vec![], step_script, vec![]).await?];
+        let steps = vec![target::shell(context, system, vec![], vec![], step_script).await?];
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let systems = self.systems();
 
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{source_version}")])
-            .with_sources(vec![source])
-            .build(context)
-            .await
+        target::build(
+            context,
+            system,
+            name,
+            vec![format!("{name}:{source_version}")],
+            vec![source],
+            steps,
+            systems,
+        )
+        .await
     }
 }
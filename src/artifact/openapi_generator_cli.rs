@@ -1,20 +1,34 @@
-use crate::artifact::openjdk::Openjdk;
+use crate::{
+    artifact::{openjdk::Openjdk, ArtifactPackage},
+    builder::wrapper,
+    context_cache, overrides, source, target, versions,
+};
 use anyhow::Result;
+use async_trait::async_trait;
 use indoc::formatdoc;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{get_env_key, step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::get_env_key,
     context::ConfigContext,
 };
 
 #[derive(Default)]
 pub struct OpenapiGeneratorCli<'a> {
     openjdk: Option<&'a str>,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
 }
 
 impl<'a> OpenapiGeneratorCli<'a> {
     pub fn new() -> Self {
-        Self { openjdk: None }
+        Self {
+            openjdk: None,
+            target: None,
+            version: None,
+        }
     }
 
     pub fn with_openjdk(mut self, openjdk: &'a str) -> Self {
@@ -22,22 +36,67 @@ impl<'a> OpenapiGeneratorCli<'a> {
         self
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for OpenapiGeneratorCli<'a> {
+    fn name(&self) -> &str {
+        "openapi-generator-cli"
+    }
+
+    fn version(&self) -> &str {
+        self.version.unwrap_or(overrides::version(
+            self.name(),
+            versions::OPENAPI_GENERATOR_CLI,
+        ))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
         let openjdk = match self.openjdk {
-            Some(val) => val,
-            None => &Openjdk::new().build(context).await?,
+            Some(val) => val.to_string(),
+            None if system == context.get_system() => {
+                context_cache::build(context, &Openjdk::new()).await?
+            }
+            None => Openjdk::new().with_target(system).build(context).await?,
         };
 
-        let name = "openapi-generator-cli";
-        let source_version = "7.18.0";
+        let name = self.name();
+        let source_version = self.version();
 
         let source_path = format!(
             "https://repo1.maven.org/maven2/org/openapitools/openapi-generator-cli/{source_version}/openapi-generator-cli-{source_version}.jar"
         );
 
-        let source = ArtifactSource::new(name, &source_path).build();
+        let source = source::new(name, &source_path);
+
+        let env_openjdk = get_env_key(&openjdk);
 
-        let env_openjdk = get_env_key(&openjdk.to_string());
+        let java_home = match system {
+            Aarch64Darwin | X8664Darwin => format!("{env_openjdk}/Contents/Home"),
+            _ => env_openjdk,
+        };
+
+        let wrapper_script = wrapper::wrap_binary(
+            "$VORPAL_OUTPUT/bin/openapi-generator-cli",
+            &format!("\"{java_home}/bin/java\" -jar \"$VORPAL_OUTPUT/openapi-generator-cli.jar\""),
+            &[],
+            &[("JAVA_HOME", java_home.clone())],
+        );
 
         let step_script = formatdoc! {"
             mkdir -p \"$VORPAL_OUTPUT/bin\"
@@ -50,38 +109,36 @@ impl<'a> OpenapiGeneratorCli<'a> {
 
             mv -v ../openapi-generator-cli.jar \"$VORPAL_OUTPUT/openapi-generator-cli.jar\"
 
-            cat << 'EOF' > \"$VORPAL_OUTPUT/bin/openapi-generator-cli\"
-            #!/bin/sh
-            JAVA_HOME={env_openjdk}/Contents/Home
-            PATH=$JAVA_HOME/bin:$PATH
-            java -jar \"$VORPAL_OUTPUT/openapi-generator-cli.jar\" \"$@\"
-            EOF
-
-            chmod +x \"$VORPAL_OUTPUT/bin/openapi-generator-cli\""
+            {wrapper_script}"
         };
 
         let environments = [
-            format!("JAVA_HOME={env_openjdk}/Contents/Home"),
+            format!("JAVA_HOME={java_home}"),
             "PATH=$JAVA_HOME/bin:$PATH".to_string(),
         ];
 
         let steps = vec![
-            step::shell(
+            target::shell(
                 context,
-                vec![openjdk.to_string()],
+                system,
+                vec![openjdk],
                 environments.to_vec(),
                 step_script,
-                vec![],
             )
             .await?,
         ];
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
-
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{source_version}")])
-            .with_sources(vec![source])
-            .build(context)
-            .await
+        let systems = self.systems();
+
+        target::build(
+            context,
+            system,
+            name,
+            vec![format!("{name}:{source_version}")],
+            vec![source],
+            steps,
+            systems,
+        )
+        .await
     }
 }
@@ -1,37 +1,92 @@
+use crate::{artifact::ArtifactPackage, error::ArtifactError, overrides, source, target, versions};
 use anyhow::Result;
+use async_trait::async_trait;
 use indoc::formatdoc;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
     context::ConfigContext,
 };
 
 #[derive(Default)]
-pub struct Openjdk;
+pub struct Openjdk<'a> {
+    release: Option<&'a str>,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
 
-impl Openjdk {
+impl<'a> Openjdk<'a> {
     pub fn new() -> Self {
-        Self
+        Self {
+            release: None,
+            target: None,
+            version: None,
+        }
+    }
+
+    // download.java.net paths embed a per-release hash and build number alongside the version,
+    // so overriding the version also requires the matching "<hash>/<build>" release segment.
+    pub fn with_release(mut self, release: &'a str) -> Self {
+        self.release = Some(release);
+        self
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Openjdk<'a> {
+    fn name(&self) -> &str {
+        "openjdk"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::OPENJDK))
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "openjdk";
-        let source_version = "25.0.1";
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
 
-        let source_system = match context.get_system() {
+        let name = self.name();
+        let source_version = self.version();
+
+        let source_system = match system {
             Aarch64Darwin => "macos-aarch64",
             Aarch64Linux => "linux-aarch64",
             X8664Darwin => "macos-x64",
             X8664Linux => "linux-x64",
-            _ => return Err(anyhow::anyhow!("Unsupported system for openjdk artifact")),
+            system => {
+                return Err(ArtifactError::UnsupportedSystem {
+                    artifact: name.to_string(),
+                    system,
+                }
+                .into())
+            }
         };
 
+        let source_release = self.release.unwrap_or(versions::OPENJDK_RELEASE);
+
         let source_path = format!(
-            "https://download.java.net/java/GA/jdk25.0.1/2fbf10d8c78e40bd87641c434705079d/8/GPL/openjdk-{source_version}_{source_system}_bin.tar.gz"
+            "https://download.java.net/java/GA/jdk{source_version}/{source_release}/GPL/openjdk-{source_version}_{source_system}_bin.tar.gz"
         );
 
-        let source = ArtifactSource::new(name, &source_path).build();
-        let source_affix = match context.get_system() {
+        let source = source::for_system(name, &source_path, system)?;
+        let source_affix = match system {
             Aarch64Darwin | X8664Darwin => ".jdk",
             _ => "",
         };
@@ -41,14 +96,19 @@ impl Openjdk {
             cp -Rv * \"$VORPAL_OUTPUT/.\""
         };
 
-        let steps = vec![step::shell(context, vec![], vec![], step_script, vec![]).await?];
+        let steps = vec![target::shell(context, system, vec![], vec![], step_script).await?];
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let systems = self.systems();
 
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{source_version}")])
-            .with_sources(vec![source])
-            .build(context)
-            .await
+        target::build(
+            context,
+            system,
+            name,
+            vec![format!("{name}:{source_version}")],
+            vec![source],
+            steps,
+            systems,
+        )
+        .await
     }
 }
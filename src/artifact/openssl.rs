@@ -0,0 +1,137 @@
+use crate::{
+    artifact::ArtifactPackage,
+    builder::{check, jobs, prune},
+    overrides, source, target, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct Openssl<'a> {
+    check: Option<bool>,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
+
+impl<'a> Openssl<'a> {
+    pub fn new() -> Self {
+        Self {
+            check: None,
+            target: None,
+            version: None,
+        }
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Openssl<'a> {
+    fn name(&self) -> &str {
+        "openssl"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::OPENSSL))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!(
+            "https://github.com/openssl/openssl/releases/download/openssl-{version}/openssl-{version}.tar.gz"
+        );
+
+        let source = source::new(name, &path);
+
+        let make_jobs = jobs::make_flag(jobs::limit(context, None)?);
+
+        // OpenSSL uses its own Perl `Configure` (which detects the platform) rather than
+        // autotools. `install_sw` skips the several thousand generated manual pages, and the
+        // certificate directory stays empty so consumers point SSL_CERT_FILE at a bundle. The rpath
+        // keeps bin/openssl from picking up another libssl on Linux.
+        let step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT\"
+
+            pushd ./source/{name}/{name}-{version}
+
+            ./Configure \\
+                --prefix=\"$VORPAL_OUTPUT\" \\
+                --openssldir=\"$VORPAL_OUTPUT/ssl\" \\
+                --libdir=lib \\
+                \"-Wl,-rpath,$VORPAL_OUTPUT/lib\" \\
+                shared \\
+                no-tests
+
+            make {make_jobs}
+            make install_sw install_ssldirs",
+        };
+
+        let mut steps = vec![
+            target::shell(context, system, vec![], vec![], step_script).await?,
+            prune::step(context, system, true, &[]).await?,
+        ];
+
+        if check::enabled(context, system, self.check)? {
+            steps.push(
+                check::step(
+                    context,
+                    system,
+                    vec![],
+                    "bin/openssl version",
+                    &format!("OpenSSL {version}"),
+                )
+                .await?,
+            );
+        }
+
+        let systems = self.systems();
+
+        target::build(
+            context,
+            system,
+            name,
+            vec![format!("{name}:{version}")],
+            vec![source],
+            steps,
+            systems,
+        )
+        .await
+    }
+}
@@ -0,0 +1,16 @@
+use crate::{binary_artifact, versions};
+
+binary_artifact! {
+    Patchelf {
+        name: "patchelf",
+        version: versions::PATCHELF,
+        repository: "NixOS/patchelf",
+        tag: "{version}",
+        asset: "patchelf-{version}-{target}.tar.gz",
+        binaries: ["bin/patchelf" => "patchelf"],
+        targets: {
+            Aarch64Linux => "aarch64",
+            X8664Linux => "x86_64",
+        },
+    }
+}
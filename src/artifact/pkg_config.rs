@@ -1,48 +1,64 @@
+use crate::{
+    artifact::ArtifactPackage, builder::autotools::AutotoolsPackage, overrides, source, versions,
+};
 use anyhow::Result;
-use indoc::formatdoc;
+use async_trait::async_trait;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
     context::ConfigContext,
 };
 
 #[derive(Default)]
-pub struct PkgConfig;
+pub struct PkgConfig<'a> {
+    version: Option<&'a str>,
+}
 
-impl PkgConfig {
+impl<'a> PkgConfig<'a> {
     pub fn new() -> Self {
-        Self
+        Self { version: None }
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "pkg-config";
-
-        let source_version = "0.29.2";
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
 
-        let source_path = format!(
-            "https://pkgconfig.freedesktop.org/releases/pkg-config-{source_version}.tar.gz"
-        );
+#[async_trait]
+impl<'a> ArtifactPackage for PkgConfig<'a> {
+    fn name(&self) -> &str {
+        "pkg-config"
+    }
 
-        let source = ArtifactSource::new(name, source_path.as_str()).build();
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::PKG_CONFIG))
+    }
 
-        let step_script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT/bin\"
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
 
-            pushd ./source/{name}/pkg-config-{source_version}
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name();
 
-            CFLAGS=\"-std=gnu89 -Wno-error=int-conversion\" ./configure --prefix=$VORPAL_OUTPUT --with-internal-glib
+        let source_version = self.version();
 
-            make
-            make install",
-        };
+        let source_path = format!(
+            "https://pkgconfig.freedesktop.org/releases/pkg-config-{source_version}.tar.gz"
+        );
 
-        let steps = vec![step::shell(context, vec![], vec![], step_script, vec![]).await?];
+        let source = source::new(name, source_path.as_str());
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let source_directory = format!("pkg-config-{source_version}");
 
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{source_version}")])
-            .with_sources(vec![source])
+        AutotoolsPackage::new(name, source_version, source, self.systems())
+            .with_configure_flag("--with-internal-glib")
+            .with_environment("CFLAGS", "-std=gnu89 -Wno-error=int-conversion")
+            .with_source_directory(&source_directory)
             .build(context)
             .await
     }
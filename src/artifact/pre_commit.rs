@@ -0,0 +1,146 @@
+use crate::{
+    artifact::{uv::Uv, ArtifactPackage},
+    builder::wrapper,
+    context_cache, overrides, source, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::{cpython::Cpython, get_env_key, step, Artifact},
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct PreCommit<'a> {
+    python: Option<&'a str>,
+    uv: Option<&'a str>,
+    version: Option<&'a str>,
+}
+
+impl<'a> PreCommit<'a> {
+    pub fn new() -> Self {
+        Self {
+            python: None,
+            uv: None,
+            version: None,
+        }
+    }
+
+    pub fn with_python(mut self, python: &'a str) -> Self {
+        self.python = Some(python);
+        self
+    }
+
+    pub fn with_uv(mut self, uv: &'a str) -> Self {
+        self.uv = Some(uv);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for PreCommit<'a> {
+    fn name(&self) -> &str {
+        "pre-commit"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::PRE_COMMIT))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let python = match self.python {
+            Some(val) => val,
+            None => &Cpython::new().build(context).await?,
+        };
+
+        let uv = match self.uv {
+            Some(val) => val,
+            None => &context_cache::build(context, &Uv::new()).await?,
+        };
+
+        let name = self.name();
+        let version = self.version();
+
+        let source_path = format!(
+            "https://files.pythonhosted.org/packages/source/p/pre-commit/pre_commit-{version}.tar.gz"
+        );
+
+        let source = source::new(name, &source_path);
+
+        let env_python = get_env_key(&python.to_string());
+        let env_uv = get_env_key(&uv.to_string());
+
+        // Hook environments depend on each repository's config, so they stay in a user cache
+        // (namespaced per version to avoid mixing interpreters). The virtualenv seed wheels they
+        // are created from are shipped read-only inside the artifact instead of downloaded.
+        let wrapper_script = wrapper::wrap_binary(
+            &format!("$VORPAL_OUTPUT/bin/{name}"),
+            &format!("\"$VORPAL_OUTPUT/lib/{name}/bin/python\" -m pre_commit"),
+            &[],
+            &[
+                (
+                    "PRE_COMMIT_HOME",
+                    format!("\\${{PRE_COMMIT_HOME:-\\${{XDG_CACHE_HOME:-\\$HOME/.cache}}/pre-commit/vorpal-{version}}}"),
+                ),
+                ("VIRTUALENV_APP_DATA", "$VORPAL_OUTPUT/share/virtualenv".to_string()),
+                ("VIRTUALENV_READ_ONLY_APP_DATA", "1".to_string()),
+            ],
+        );
+
+        let step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT/bin\"
+
+            {env_uv}/bin/uv venv --python \"{env_python}/bin/python3\" \"$VORPAL_OUTPUT/lib/{name}\"
+
+            {env_uv}/bin/uv pip install \\
+                --python \"$VORPAL_OUTPUT/lib/{name}/bin/python\" \\
+                ./source/{name}/pre_commit-{version}
+
+            \"$VORPAL_OUTPUT/lib/{name}/bin/python\" -m virtualenv \\
+                --app-data \"$VORPAL_OUTPUT/share/virtualenv\" \\
+                \"$VORPAL_WORKSPACE/seed\"
+
+            {wrapper_script}"
+        };
+
+        let mut step_environments = vec!["UV_CACHE_DIR=$VORPAL_WORKSPACE/uv".to_string()];
+
+        if let Some(index) = source::mirrored(source::PYPI_INDEX) {
+            step_environments.push(format!("UV_DEFAULT_INDEX={index}"));
+        }
+
+        let steps = vec![
+            step::shell(
+                context,
+                vec![python.to_string(), uv.to_string()],
+                step_environments,
+                step_script,
+                vec![],
+            )
+            .await?,
+        ];
+
+        let systems = self.systems();
+
+        Artifact::new(name, steps, systems)
+            .with_aliases(vec![format!("{name}:{version}")])
+            .with_sources(vec![source])
+            .build(context)
+            .await
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    artifact::{abseil_cpp::AbseilCpp, cmake::Cmake, ArtifactPackage},
+    builder::cmake::CmakePackage,
+    fork, overrides, source, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct Protobuf<'a> {
+    abseil_cpp: Option<&'a str>,
+    cmake: Option<&'a str>,
+    version: Option<&'a str>,
+}
+
+impl<'a> Protobuf<'a> {
+    pub fn new() -> Self {
+        Self {
+            abseil_cpp: None,
+            cmake: None,
+            version: None,
+        }
+    }
+
+    pub fn with_abseil_cpp(mut self, abseil_cpp: &'a str) -> Self {
+        self.abseil_cpp = Some(abseil_cpp);
+        self
+    }
+
+    pub fn with_cmake(mut self, cmake: &'a str) -> Self {
+        self.cmake = Some(cmake);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Protobuf<'a> {
+    fn name(&self) -> &str {
+        "protobuf"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::PROTOBUF))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let (abseil_cpp, cmake) = tokio::try_join!(
+            fork::build(context, self.abseil_cpp, AbseilCpp::new()),
+            fork::build(context, self.cmake, Cmake::new()),
+        )?;
+
+        let [abseil_cpp, cmake] = fork::join(context, [abseil_cpp, cmake]).await?;
+
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!(
+            "https://github.com/protocolbuffers/protobuf/releases/download/v{version}/protobuf-{version}.tar.gz"
+        );
+
+        let source = source::new(name, &path);
+
+        let source_directory = format!("{name}-{version}");
+
+        // Only the C++ runtime (libprotobuf, libprotobuf-lite and libutf8_range) is built; protoc
+        // itself comes from the SDK's `Protoc`. Abseil is taken from the injected artifact rather
+        // than the bundled copy, with the same C++ standard so the two stay ABI compatible.
+        CmakePackage::new(name, version, source, self.systems())
+            .with_artifact(&abseil_cpp)
+            .with_cmake(&cmake)
+            .with_definition("CMAKE_CXX_STANDARD=17")
+            .with_definition("CMAKE_INSTALL_LIBDIR=lib")
+            .with_definition("CMAKE_POSITION_INDEPENDENT_CODE=ON")
+            .with_definition("protobuf_ABSL_PROVIDER=package")
+            .with_definition("protobuf_BUILD_LIBUPB=OFF")
+            .with_definition("protobuf_BUILD_PROTOC_BINARIES=OFF")
+            .with_definition("protobuf_BUILD_SHARED_LIBS=OFF")
+            .with_definition("protobuf_BUILD_TESTS=OFF")
+            .with_definition("protobuf_WITH_ZLIB=OFF")
+            .with_source_directory(&source_directory)
+            .build(context)
+            .await
+    }
+}
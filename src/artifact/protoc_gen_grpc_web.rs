@@ -0,0 +1,18 @@
+use crate::{binary_artifact, versions};
+
+binary_artifact! {
+    ProtocGenGrpcWeb {
+        name: "protoc-gen-grpc-web",
+        version: versions::PROTOC_GEN_GRPC_WEB,
+        repository: "grpc/grpc-web",
+        tag: "{version}",
+        asset: "protoc-gen-grpc-web-{version}-{target}",
+        binaries: ["protoc-gen-grpc-web-{version}-{target}" => "protoc-gen-grpc-web"],
+        targets: {
+            Aarch64Darwin => "darwin-aarch64",
+            Aarch64Linux => "linux-aarch64",
+            X8664Darwin => "darwin-x86_64",
+            X8664Linux => "linux-x86_64",
+        },
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{
+    artifact::{protoc_gen_grpc_web::ProtocGenGrpcWeb, ArtifactPackage},
+    context_cache, overrides, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::{
+        get_env_key, protoc::Protoc, protoc_gen_go::ProtocGenGo,
+        protoc_gen_go_grpc::ProtocGenGoGrpc, step, Artifact,
+    },
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct ProtocPlugins<'a> {
+    protoc: Option<&'a str>,
+    protoc_gen_go: Option<&'a str>,
+    protoc_gen_go_grpc: Option<&'a str>,
+    protoc_gen_grpc_web: Option<&'a str>,
+    version: Option<&'a str>,
+}
+
+impl<'a> ProtocPlugins<'a> {
+    pub fn new() -> Self {
+        Self {
+            protoc: None,
+            protoc_gen_go: None,
+            protoc_gen_go_grpc: None,
+            protoc_gen_grpc_web: None,
+            version: None,
+        }
+    }
+
+    pub fn with_protoc(mut self, protoc: &'a str) -> Self {
+        self.protoc = Some(protoc);
+        self
+    }
+
+    pub fn with_protoc_gen_go(mut self, protoc_gen_go: &'a str) -> Self {
+        self.protoc_gen_go = Some(protoc_gen_go);
+        self
+    }
+
+    pub fn with_protoc_gen_go_grpc(mut self, protoc_gen_go_grpc: &'a str) -> Self {
+        self.protoc_gen_go_grpc = Some(protoc_gen_go_grpc);
+        self
+    }
+
+    pub fn with_protoc_gen_grpc_web(mut self, protoc_gen_grpc_web: &'a str) -> Self {
+        self.protoc_gen_grpc_web = Some(protoc_gen_grpc_web);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for ProtocPlugins<'a> {
+    fn name(&self) -> &str {
+        "protoc-plugins"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::PROTOC_PLUGINS))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let protoc = match self.protoc {
+            Some(val) => val,
+            None => &Protoc::new().build(context).await?,
+        };
+
+        let protoc_gen_go = match self.protoc_gen_go {
+            Some(val) => val,
+            None => &ProtocGenGo::new().build(context).await?,
+        };
+
+        let protoc_gen_go_grpc = match self.protoc_gen_go_grpc {
+            Some(val) => val,
+            None => &ProtocGenGoGrpc::new().build(context).await?,
+        };
+
+        let protoc_gen_grpc_web = match self.protoc_gen_grpc_web {
+            Some(val) => val,
+            None => &context_cache::build(context, &ProtocGenGrpcWeb::new()).await?,
+        };
+
+        let name = self.name();
+        let version = self.version();
+
+        // protoc discovers plugins by looking up `protoc-gen-<name>` on PATH, so the compiler and
+        // every plugin are installed into a single bin/ to make codegen work from one PATH entry.
+        let script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT/bin\"
+
+            cp -v {protoc}/bin/protoc \"$VORPAL_OUTPUT/bin/protoc\"
+            cp -v {protoc_gen_go}/bin/protoc-gen-go \"$VORPAL_OUTPUT/bin/protoc-gen-go\"
+            cp -v {protoc_gen_go_grpc}/bin/protoc-gen-go-grpc \"$VORPAL_OUTPUT/bin/protoc-gen-go-grpc\"
+            cp -v {protoc_gen_grpc_web}/bin/protoc-gen-grpc-web \"$VORPAL_OUTPUT/bin/protoc-gen-grpc-web\"
+
+            chmod +x \"$VORPAL_OUTPUT/bin/\"*",
+            protoc = get_env_key(&protoc.to_string()),
+            protoc_gen_go = get_env_key(&protoc_gen_go.to_string()),
+            protoc_gen_go_grpc = get_env_key(&protoc_gen_go_grpc.to_string()),
+            protoc_gen_grpc_web = get_env_key(&protoc_gen_grpc_web.to_string()),
+        };
+
+        let steps = vec![
+            step::shell(
+                context,
+                vec![
+                    protoc.to_string(),
+                    protoc_gen_go.to_string(),
+                    protoc_gen_go_grpc.to_string(),
+                    protoc_gen_grpc_web.to_string(),
+                ],
+                vec![],
+                script,
+                vec![],
+            )
+            .await?,
+        ];
+
+        let systems = self.systems();
+
+        Artifact::new(name, steps, systems)
+            .with_aliases(vec![format!("{name}:{version}")])
+            .build(context)
+            .await
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{binary_artifact, versions};
+
+binary_artifact! {
+    Qsv {
+        name: "qsv",
+        version: versions::QSV,
+        repository: "dathere/qsv",
+        tag: "{version}",
+        asset: "qsv-{version}-{target}.zip",
+        binaries: ["qsv" => "qsv", "qsvlite" => "qsvlite"],
+        targets: {
+            Aarch64Darwin => "aarch64-apple-darwin",
+            Aarch64Linux => "aarch64-unknown-linux-gnu",
+            X8664Darwin => "x86_64-apple-darwin",
+            X8664Linux => "x86_64-unknown-linux-musl",
+        },
+    }
+}
@@ -1,20 +1,34 @@
-use crate::artifact::ncurses;
+use crate::{
+    artifact::{ncurses, ArtifactPackage},
+    builder::{autotools::AutotoolsPackage, musl},
+    context_cache, overrides,
+    source::SourceUrls,
+    variant::{Linkage, Variant},
+    versions,
+};
 use anyhow::Result;
-use indoc::formatdoc;
+use async_trait::async_trait;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{get_env_key, step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::get_env_key,
     context::ConfigContext,
 };
 
 #[derive(Default)]
 pub struct Readline<'a> {
     ncurses: Option<&'a str>,
+    version: Option<&'a str>,
 }
 
 impl<'a> Readline<'a> {
     pub fn new() -> Self {
-        Self { ncurses: None }
+        Self {
+            ncurses: None,
+            version: None,
+        }
     }
 
     pub fn with_ncurses(mut self, ncurses: &'a str) -> Self {
@@ -22,50 +36,57 @@ impl<'a> Readline<'a> {
         self
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Readline<'a> {
+    fn name(&self) -> &str {
+        "readline"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::READLINE))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
         let ncurses = match self.ncurses {
             Some(val) => val,
-            None => &ncurses::Ncurses::new().build(context).await?,
+            None => &context_cache::build(context, &ncurses::Ncurses::new()).await?,
         };
 
-        let name = "readline";
-        let version = "8.2";
+        let name = self.name();
+        let version = self.version();
 
         let path = format!("https://ftpmirror.gnu.org/readline/readline-{version}.tar.gz");
-        let source = ArtifactSource::new(name, &path).build();
+        let mirror = format!("https://ftp.gnu.org/gnu/readline/readline-{version}.tar.gz");
+        let source = SourceUrls::new(name, &path).with_mirror(&mirror).build();
 
-        let step_script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT\"
-            pushd ./source/{name}/{name}-{version}
+        let source_directory = format!("{name}-{version}");
 
-            export CPPFLAGS=\"-I{ncurses}/include -I{ncurses}/include/ncursesw\"
-            export LDFLAGS=\"-L{ncurses}/lib -Wl,-rpath,{ncurses}/lib\"
+        let ncurses_key = get_env_key(&ncurses.to_string());
 
-            ./configure \
-                --prefix=\"$VORPAL_OUTPUT\" \
-                --with-curses
+        let linkage = musl::linkage(context, Linkage::Shared)?;
 
-            make
-            make install",
-            ncurses = get_env_key(&ncurses.to_string()),
-        };
+        let mut package = AutotoolsPackage::new(name, version, source, self.systems())
+            .with_artifact(ncurses)
+            .with_configure_flag("--with-curses")
+            .with_cppflags(&format!("-I{ncurses_key}/include/ncursesw"))
+            .with_source_directory(&source_directory)
+            .with_variant(Variant::Full, linkage);
+
+        if linkage == Linkage::Musl {
+            package = package.with_configure_flag("--disable-shared");
+        }
 
-        let steps = vec![
-            step::shell(
-                context,
-                vec![ncurses.to_string()],
-                vec![],
-                step_script,
-                vec![],
-            )
-            .await?,
-        ];
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
-
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{version}")])
-            .with_sources(vec![source])
-            .build(context)
-            .await
+        package.build(context).await
     }
 }
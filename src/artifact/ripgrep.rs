@@ -1,52 +1,19 @@
-use anyhow::Result;
-use indoc::formatdoc;
-use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
-    context::ConfigContext,
-};
-
-#[derive(Default)]
-pub struct Ripgrep;
-
-impl Ripgrep {
-    pub fn new() -> Self {
-        Self
-    }
-
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "ripgrep";
-        let source_version = "14.1.1";
-
-        let source_system = match context.get_system() {
+use crate::{binary_artifact, versions};
+
+binary_artifact! {
+    Ripgrep {
+        name: "ripgrep",
+        version: versions::RIPGREP,
+        repository: "BurntSushi/ripgrep",
+        tag: "{version}",
+        asset: "ripgrep-{version}-{target}.tar.gz",
+        binaries: ["ripgrep-{version}-{target}/rg" => "rg"],
+        check: "bin/rg --version" => "ripgrep {version}",
+        targets: {
             Aarch64Darwin => "aarch64-apple-darwin",
             Aarch64Linux => "aarch64-unknown-linux-gnu",
             X8664Darwin => "x86_64-apple-darwin",
             X8664Linux => "x86_64-unknown-linux-musl",
-            _ => return Err(anyhow::anyhow!("Unsupported system for ripgrep artifact")),
-        };
-
-        let source_path = format!(
-            "https://github.com/BurntSushi/ripgrep/releases/download/{source_version}/ripgrep-{source_version}-{source_system}.tar.gz"
-        );
-
-        let source = ArtifactSource::new(name, &source_path).build();
-
-        let step_script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT/bin\"
-            pushd ./source/{name}
-            cp */rg \"$VORPAL_OUTPUT/bin/rg\"
-            chmod +x \"$VORPAL_OUTPUT/bin/rg\"",
-        };
-
-        let steps = vec![step::shell(context, vec![], vec![], step_script, vec![]).await?];
-
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
-
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{source_version}")])
-            .with_sources(vec![source])
-            .build(context)
-            .await
+        },
     }
 }
@@ -0,0 +1,17 @@
+use crate::{binary_artifact, versions};
+
+binary_artifact! {
+    S5cmd {
+        name: "s5cmd",
+        version: versions::S5CMD,
+        repository: "peak/s5cmd",
+        asset: "s5cmd_{version}_{target}.tar.gz",
+        binaries: ["s5cmd" => "s5cmd"],
+        targets: {
+            Aarch64Darwin => "macOS-arm64",
+            Aarch64Linux => "Linux-arm64",
+            X8664Darwin => "macOS-64bit",
+            X8664Linux => "Linux-64bit",
+        },
+    }
+}
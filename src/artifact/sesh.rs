@@ -1,51 +1,17 @@
-use anyhow::Result;
-use indoc::formatdoc;
-use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
-    context::ConfigContext,
-};
-
-#[derive(Default)]
-pub struct Sesh;
-
-impl Sesh {
-    pub fn new() -> Self {
-        Self
-    }
-
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "sesh";
-        let version = "2.24.2";
-
-        let (source_os, source_arch) = match context.get_system() {
-            Aarch64Darwin => ("Darwin", "arm64"),
-            Aarch64Linux => ("Linux", "arm64"),
-            X8664Darwin => ("Darwin", "x86_64"),
-            X8664Linux => ("Linux", "x86_64"),
-            _ => return Err(anyhow::anyhow!("Unsupported system for {name} artifact")),
-        };
-
-        let source_path = format!(
-            "https://github.com/joshmedeski/sesh/releases/download/v{version}/{name}_{source_os}_{source_arch}.tar.gz"
-        );
-
-        let source = ArtifactSource::new(name, &source_path).build();
-
-        let step_script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT/bin\"
-            cp ./source/{name}/{name} \"$VORPAL_OUTPUT/bin/{name}\"
-            chmod +x \"$VORPAL_OUTPUT/bin/{name}\"",
-        };
-
-        let steps = vec![step::shell(context, vec![], vec![], step_script, vec![]).await?];
-
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
-
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{version}")])
-            .with_sources(vec![source])
-            .build(context)
-            .await
+use crate::{binary_artifact, versions};
+
+binary_artifact! {
+    Sesh {
+        name: "sesh",
+        version: versions::SESH,
+        repository: "joshmedeski/sesh",
+        asset: "sesh_{target}.tar.gz",
+        binaries: ["sesh" => "sesh"],
+        targets: {
+            Aarch64Darwin => "Darwin_arm64",
+            Aarch64Linux => "Linux_arm64",
+            X8664Darwin => "Darwin_x86_64",
+            X8664Linux => "Linux_x86_64",
+        },
     }
 }
@@ -1,44 +1,61 @@
+use crate::{
+    artifact::ArtifactPackage, builder::autotools::AutotoolsPackage, overrides, source, versions,
+};
 use anyhow::Result;
-use indoc::formatdoc;
+use async_trait::async_trait;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
     context::ConfigContext,
 };
 
 #[derive(Default)]
-pub struct Sqlite3;
+pub struct Sqlite3<'a> {
+    version: Option<&'a str>,
+}
 
-impl Sqlite3 {
+impl<'a> Sqlite3<'a> {
     pub fn new() -> Self {
-        Self
+        Self { version: None }
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Sqlite3<'a> {
+    fn name(&self) -> &str {
+        "sqlite3"
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "sqlite3";
-        let version = "3.51.2";
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::SQLITE3))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name();
+        let version = self.version();
         let version_tag = "3510200";
         let year = "2026";
 
         let path = format!("https://www.sqlite.org/{year}/sqlite-autoconf-{version_tag}.tar.gz");
 
-        let source = ArtifactSource::new(name, &path).build();
-
-        let script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT\"
-            pushd ./source/{name}/sqlite-autoconf-{version_tag}
-            ./configure --prefix=\"$VORPAL_OUTPUT\"
-            make
-            make install",
-        };
-
-        let steps = vec![step::shell(context, vec![], vec![], script, vec![]).await?];
+        let source = source::new(name, &path);
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let source_directory = format!("sqlite-autoconf-{version_tag}");
 
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{version}")])
-            .with_sources(vec![source])
+        AutotoolsPackage::new(name, version, source, self.systems())
+            .with_source_directory(&source_directory)
             .build(context)
             .await
     }
@@ -0,0 +1,207 @@
+use crate::{
+    artifact::ArtifactPackage, builder::check, error::ArtifactError, overrides, source, target,
+    versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct Steampipe<'a> {
+    check: Option<bool>,
+    plugins: Vec<&'a str>,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
+
+impl<'a> Steampipe<'a> {
+    pub fn new() -> Self {
+        Self {
+            check: None,
+            plugins: vec![],
+            target: None,
+            version: None,
+        }
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    // Plugins installed into the artifact, as given to `steampipe plugin install` (e.g. `aws` or
+    // `turbot/gcp@1`), so the devenv can query them without a download on first use.
+    pub fn with_plugins(mut self, plugins: &[&'a str]) -> Self {
+        self.plugins.extend(plugins);
+        self
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Steampipe<'a> {
+    fn name(&self) -> &str {
+        "steampipe"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::STEAMPIPE))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let name = self.name();
+        let version = self.version();
+
+        let asset = match system {
+            Aarch64Darwin => "steampipe_darwin_arm64.zip",
+            Aarch64Linux => "steampipe_linux_arm64.tar.gz",
+            X8664Darwin => "steampipe_darwin_amd64.zip",
+            X8664Linux => "steampipe_linux_amd64.tar.gz",
+            system => {
+                return Err(ArtifactError::UnsupportedSystem {
+                    artifact: name.to_string(),
+                    system,
+                }
+                .into())
+            }
+        };
+
+        let path =
+            format!("https://github.com/turbot/steampipe/releases/download/v{version}/{asset}");
+
+        let source = source::for_system(name, &path, system)?;
+
+        let mut aliases = vec![format!("{name}:{version}")];
+
+        let (binary, step_script) = match self.plugins.is_empty() {
+            true => (
+                "bin/steampipe",
+                formatdoc! {"
+                    mkdir -pv \"$VORPAL_OUTPUT/bin\"
+                    cp -v ./source/{name}/steampipe \"$VORPAL_OUTPUT/bin/steampipe\"
+                    chmod +x \"$VORPAL_OUTPUT/bin/steampipe\"",
+                },
+            ),
+            false => {
+                let plugins = self.plugins.join(" ");
+
+                // Builds with plugins are tagged with them, e.g. `steampipe:2.1.0-aws-gcp`.
+                let suffix = self
+                    .plugins
+                    .iter()
+                    .map(|plugin| plugin.replace(|c: char| !c.is_ascii_alphanumeric(), "-"))
+                    .collect::<Vec<_>>()
+                    .join("-");
+
+                aliases = vec![format!("{name}:{version}-{suffix}")];
+
+                // Steampipe keeps its database, logs and plugins in one writable install
+                // directory, so the wrapper copies the bundled plugins and their connection
+                // configs into it without replacing what the user already has.
+                let script = formatdoc! {"
+                    mkdir -pv \"$VORPAL_OUTPUT/bin\" \"$VORPAL_OUTPUT/libexec\"
+                    cp -v ./source/{name}/steampipe \"$VORPAL_OUTPUT/libexec/steampipe\"
+                    chmod +x \"$VORPAL_OUTPUT/libexec/steampipe\"
+
+                    HOME=\"$VORPAL_WORKSPACE/home\" \"$VORPAL_OUTPUT/libexec/steampipe\" plugin install \\
+                        --install-dir \"$VORPAL_OUTPUT/share/steampipe\" \\
+                        {plugins}
+
+                    rm -rf \"$VORPAL_OUTPUT/share/steampipe/logs\"
+
+                    cat << EOF > \"$VORPAL_OUTPUT/bin/steampipe\"
+                    #!/bin/sh
+                    export STEAMPIPE_INSTALL_DIR=\"\\${{STEAMPIPE_INSTALL_DIR:-\\$HOME/.steampipe}}\"
+                    mkdir -p \"\\$STEAMPIPE_INSTALL_DIR\"
+                    for directory in config internal plugins; do
+                        if [ -d \"$VORPAL_OUTPUT/share/steampipe/\\$directory\" ]; then
+                            cp -Rn \"$VORPAL_OUTPUT/share/steampipe/\\$directory\" \"\\$STEAMPIPE_INSTALL_DIR/\"
+                            chmod -R u+w \"\\$STEAMPIPE_INSTALL_DIR/\\$directory\"
+                        fi
+                    done
+                    exec \"$VORPAL_OUTPUT/libexec/steampipe\" \"\\$@\"
+                    EOF
+
+                    chmod +x \"$VORPAL_OUTPUT/bin/steampipe\"",
+                };
+
+                ("libexec/steampipe", script)
+            }
+        };
+
+        let mut steps = vec![target::shell(context, system, vec![], vec![], step_script).await?];
+
+        if check::enabled(context, system, self.check)? {
+            steps.push(
+                check::step(
+                    context,
+                    system,
+                    vec![],
+                    &format!("{binary} --version"),
+                    &format!("v{version}"),
+                )
+                .await?,
+            );
+        }
+
+        let systems = self.systems();
+
+        target::build(context, system, name, aliases, vec![source], steps, systems).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, MockContext};
+
+    #[tokio::test]
+    async fn build_tags_plugins() -> Result<()> {
+        let context = &mut MockContext::new(X8664Linux).build().await?;
+
+        let steampipe = Steampipe::new()
+            .with_plugins(&["aws", "turbot/gcp@1"])
+            .build(context)
+            .await?;
+
+        assert_eq!(
+            testing::aliases(context, &steampipe)?,
+            [format!(
+                "steampipe:{}-aws-turbot-gcp-1",
+                versions::STEAMPIPE
+            )]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{
+    artifact::ArtifactPackage, builder::autotools::AutotoolsPackage, overrides, source::SourceUrls,
+    versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::get_env_key,
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct Stow<'a> {
+    check: Option<bool>,
+    perl: Option<&'a str>,
+    version: Option<&'a str>,
+}
+
+impl<'a> Stow<'a> {
+    pub fn new() -> Self {
+        Self {
+            check: None,
+            perl: None,
+            version: None,
+        }
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    // Without an injected perl artifact, stow is configured against the host's perl.
+    pub fn with_perl(mut self, perl: &'a str) -> Self {
+        self.perl = Some(perl);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Stow<'a> {
+    fn name(&self) -> &str {
+        "stow"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::STOW))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!("https://ftpmirror.gnu.org/stow/stow-{version}.tar.gz");
+        let mirror = format!("https://ftp.gnu.org/gnu/stow/stow-{version}.tar.gz");
+        let source = SourceUrls::new(name, &path).with_mirror(&mirror).build();
+
+        let source_directory = format!("{name}-{version}");
+
+        let mut package = AutotoolsPackage::new(name, version, source, self.systems())
+            .with_check("bin/stow --version", version)
+            .with_check_enabled(self.check)
+            .with_source_directory(&source_directory);
+
+        if let Some(perl) = self.perl {
+            let perl_key = get_env_key(&perl.to_string());
+
+            package = package
+                .with_artifact(perl)
+                .with_environment("PERL", &format!("{perl_key}/bin/perl"));
+        }
+
+        package.build(context).await
+    }
+}
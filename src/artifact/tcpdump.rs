@@ -0,0 +1,115 @@
+use crate::{
+    artifact::{libpcap::Libpcap, ArtifactPackage},
+    builder::{autotools::AutotoolsPackage, prune},
+    context_cache, overrides, source, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Linux, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+pub struct Tcpdump<'a> {
+    check: Option<bool>,
+    libpcap: Option<&'a str>,
+    prune: bool,
+    version: Option<&'a str>,
+}
+
+impl<'a> Default for Tcpdump<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Tcpdump<'a> {
+    pub fn new() -> Self {
+        Self {
+            check: None,
+            libpcap: None,
+            prune: true,
+            version: None,
+        }
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    pub fn with_libpcap(mut self, libpcap: &'a str) -> Self {
+        self.libpcap = Some(libpcap);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+
+    // Keeps the documentation and libtool archives `prune::DEFAULT` removes.
+    pub fn without_prune(mut self) -> Self {
+        self.prune = false;
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Tcpdump<'a> {
+    fn name(&self) -> &str {
+        "tcpdump"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::TCPDUMP))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Linux, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let libpcap = match self.libpcap {
+            Some(val) => val,
+            None => &context_cache::build(context, &Libpcap::new()).await?,
+        };
+
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!("https://www.tcpdump.org/release/tcpdump-{version}.tar.gz");
+
+        let source = source::new(name, &path);
+
+        let source_directory = format!("{name}-{version}");
+
+        // tcpdump installs into sbin/, which devenvs do not put on PATH.
+        AutotoolsPackage::new(name, version, source, self.systems())
+            .with_artifact(libpcap)
+            .with_check(
+                "bin/tcpdump --version",
+                &format!("tcpdump version {version}"),
+            )
+            .with_check_enabled(self.check)
+            .with_configure_flag("--sbindir=\"$VORPAL_OUTPUT/bin\"")
+            .with_configure_flag("--without-crypto")
+            .with_prune(prune::DEFAULT)
+            .with_prune_enabled(self.prune)
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .build(context)
+            .await
+    }
+}
@@ -1,36 +1,82 @@
+use crate::{
+    aliases, artifact::ArtifactPackage, error::ArtifactError, overrides, source, target, versions,
+};
 use anyhow::Result;
+use async_trait::async_trait;
 use indoc::formatdoc;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
     context::ConfigContext,
 };
 
 #[derive(Default)]
-pub struct Terraform;
+pub struct Terraform<'a> {
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
 
-impl Terraform {
+impl<'a> Terraform<'a> {
     pub fn new() -> Self {
-        Self
+        Self {
+            target: None,
+            version: None,
+        }
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Terraform<'a> {
+    fn name(&self) -> &str {
+        "terraform"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::TERRAFORM))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "terraform";
-        let source_version = "1.13.1";
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let name = self.name();
+        let source_version = self.version();
 
-        let source_system = match context.get_system() {
+        let source_system = match system {
             Aarch64Darwin => "darwin_arm64",
             Aarch64Linux => "linux_arm64",
             X8664Darwin => "darwin_amd64",
             X8664Linux => "linux_amd64",
-            _ => return Err(anyhow::anyhow!("Unsupported system for terraform artifact")),
+            system => {
+                return Err(ArtifactError::UnsupportedSystem {
+                    artifact: name.to_string(),
+                    system,
+                }
+                .into())
+            }
         };
 
         let source_path = format!(
             "https://releases.hashicorp.com/terraform/{source_version}/terraform_{source_version}_{source_system}.zip"
         );
 
-        let source = ArtifactSource::new(name, &source_path).build();
+        let source = source::for_system(name, &source_path, system)?;
 
         let step_script = formatdoc! {"
             mkdir -pv \"$VORPAL_OUTPUT/bin\"
@@ -39,14 +85,44 @@ impl Terraform {
             chmod +x \"$VORPAL_OUTPUT/bin/terraform\"",
         };
 
-        let steps = vec![step::shell(context, vec![], vec![], step_script, vec![]).await?];
+        let steps = vec![target::shell(context, system, vec![], vec![], step_script).await?];
+
+        // Teams pinning older releases next to the default one (`with_version("1.5.7")`) get
+        // `terraform:1.5` alongside `terraform:1.13`, see `aliases::for_version`.
+        let aliases = aliases::for_version(
+            name,
+            source_version,
+            overrides::version(name, versions::TERRAFORM),
+        );
+
+        let systems = self.systems();
+
+        target::build(context, system, name, aliases, vec![source], steps, systems).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, MockContext};
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+    #[tokio::test]
+    async fn build_pinned_versions_side_by_side() -> Result<()> {
+        let context = &mut MockContext::new(X8664Linux).build().await?;
 
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{source_version}")])
-            .with_sources(vec![source])
+        let pinned = Terraform::new()
+            .with_version("1.5.7")
             .build(context)
-            .await
+            .await?;
+        let default = Terraform::new().build(context).await?;
+
+        assert_ne!(pinned, default);
+        assert_eq!(
+            testing::aliases(context, &pinned)?,
+            ["terraform:1.5.7", "terraform:1.5"]
+        );
+        assert!(testing::aliases(context, &default)?.contains(&"terraform:latest".to_string()));
+
+        Ok(())
     }
 }
@@ -0,0 +1,16 @@
+use crate::{binary_artifact, versions};
+
+binary_artifact! {
+    Tini {
+        name: "tini",
+        version: versions::TINI,
+        repository: "krallin/tini",
+        asset: "tini-static-{target}",
+        binaries: ["tini-static-{target}" => "tini"],
+        check: "bin/tini --version" => "tini version {version}",
+        targets: {
+            Aarch64Linux => "arm64",
+            X8664Linux => "amd64",
+        },
+    }
+}
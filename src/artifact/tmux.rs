@@ -1,26 +1,47 @@
-use crate::artifact::{libevent::Libevent, ncurses::Ncurses};
+use crate::{
+    artifact::{libevent::Libevent, ncurses::Ncurses, ArtifactPackage},
+    builder::{autotools::AutotoolsPackage, musl},
+    fork, overrides, source, target,
+    variant::{Linkage, Variant},
+    versions,
+};
 use anyhow::Result;
-use indoc::formatdoc;
+use async_trait::async_trait;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{get_env_key, step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::get_env_key,
     context::ConfigContext,
 };
 
 #[derive(Default)]
 pub struct Tmux<'a> {
+    check: Option<bool>,
     libevent: Option<&'a str>,
     ncurses: Option<&'a str>,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
 }
 
 impl<'a> Tmux<'a> {
     pub fn new() -> Self {
         Self {
+            check: None,
             libevent: None,
             ncurses: None,
+            target: None,
+            version: None,
         }
     }
 
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
     pub fn with_libevent(mut self, libevent: &'a str) -> Self {
         self.libevent = Some(libevent);
         self
@@ -31,59 +52,81 @@ impl<'a> Tmux<'a> {
         self
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let libevent = match self.libevent {
-            Some(val) => val,
-            None => &Libevent::new().build(context).await?,
-        };
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Tmux<'a> {
+    fn name(&self) -> &str {
+        "tmux"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::TMUX))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let (libevent, ncurses) = tokio::try_join!(
+            fork::build_for(context, system, self.libevent, Libevent::new()),
+            fork::build_for(context, system, self.ncurses, Ncurses::new()),
+        )?;
 
-        let ncurses = match self.ncurses {
-            Some(val) => val,
-            None => &Ncurses::new().build(context).await?,
-        };
+        let [libevent, ncurses] = fork::join(context, [libevent, ncurses]).await?;
 
-        let name = "tmux";
-        let version = "3.5a";
+        let name = self.name();
+        let version = self.version();
 
         let path = format!(
             "https://github.com/tmux/tmux/releases/download/{version}/tmux-{version}.tar.gz"
         );
 
-        let source = ArtifactSource::new(name, &path).build();
+        let source = source::new(name, &path);
 
-        let script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT\"
+        let source_directory = format!("tmux-{version}");
 
-            pushd ./source/{name}/tmux-{version}
+        let ncurses_key = get_env_key(&ncurses.to_string());
 
-            export CPPFLAGS=\"-I{libevent}/include -I{ncurses}/include -I{ncurses}/include/ncursesw\"
-            export LDFLAGS=\"-L{libevent}/lib -L{ncurses}/lib -Wl,-rpath,{libevent}/lib -Wl,-rpath,{ncurses}/lib\"
+        // libevent and ncurses read the same `libc` variable, so musl builds link against their
+        // static musl libraries.
+        let linkage = musl::linkage(context, Linkage::Shared)?;
 
-            ./configure --disable-utf8proc --prefix=\"$VORPAL_OUTPUT\"
+        let mut package = AutotoolsPackage::new(name, version, source, self.systems())
+            .with_artifact(&libevent)
+            .with_artifact(&ncurses)
+            .with_check("bin/tmux -V", &format!("tmux {version}"))
+            .with_check_enabled(self.check)
+            .with_configure_flag("--disable-utf8proc")
+            .with_cppflags(&format!("-I{ncurses_key}/include/ncursesw"))
+            .with_dylib_audit()
+            .with_source_directory(&source_directory)
+            .with_target(system)
+            .with_variant(Variant::Full, linkage);
 
-            make
-            make install",
-            libevent = get_env_key(&libevent.to_string()),
-            ncurses = get_env_key(&ncurses.to_string()),
-        };
-
-        let steps = vec![
-            step::shell(
-                context,
-                vec![libevent.to_string(), ncurses.to_string()],
-                vec![],
-                script,
-                vec![],
-            )
-            .await?,
-        ];
-
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        if linkage == Linkage::Musl {
+            package = package.with_configure_flag("--enable-static");
+        }
 
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{version}")])
-            .with_sources(vec![source])
-            .build(context)
-            .await
+        package.build(context).await
     }
 }
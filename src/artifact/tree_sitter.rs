@@ -1,51 +1,17 @@
-use anyhow::Result;
-use indoc::formatdoc;
-use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
-    context::ConfigContext,
-};
-
-#[derive(Default)]
-pub struct TreeSitter;
-
-impl TreeSitter {
-    pub fn new() -> Self {
-        Self
-    }
-
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "tree-sitter";
-        let version = "0.26.9";
-
-        let source_platform = match context.get_system() {
+use crate::{binary_artifact, versions};
+
+binary_artifact! {
+    TreeSitter {
+        name: "tree-sitter",
+        version: versions::TREE_SITTER,
+        repository: "tree-sitter/tree-sitter",
+        asset: "tree-sitter-cli-{target}.zip",
+        binaries: ["tree-sitter" => "tree-sitter"],
+        targets: {
             Aarch64Darwin => "macos-arm64",
             Aarch64Linux => "linux-arm64",
             X8664Darwin => "macos-x64",
             X8664Linux => "linux-x64",
-            _ => return Err(anyhow::anyhow!("Unsupported system for {name} artifact")),
-        };
-
-        let source_path = format!(
-            "https://github.com/tree-sitter/tree-sitter/releases/download/v{version}/{name}-cli-{source_platform}.zip"
-        );
-
-        let source = ArtifactSource::new(name, &source_path).build();
-
-        let step_script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT/bin\"
-            cp ./source/{name}/{name} \"$VORPAL_OUTPUT/bin/{name}\"
-            chmod +x \"$VORPAL_OUTPUT/bin/{name}\"",
-        };
-
-        let steps = vec![step::shell(context, vec![], vec![], step_script, vec![]).await?];
-
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
-
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{version}")])
-            .with_sources(vec![source])
-            .build(context)
-            .await
+        },
     }
 }
@@ -1,9 +1,19 @@
-use crate::artifact::{cmake, json_c, libuv, libwebsockets, mbedtls};
+use crate::{
+    aliases,
+    artifact::{cmake, json_c, libuv, libwebsockets, mbedtls, ArtifactPackage},
+    builder::cmake::CmakePackage,
+    error::ArtifactError,
+    fork, overrides, source, target, versions,
+};
 use anyhow::Result;
+use async_trait::async_trait;
 use indoc::formatdoc;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{get_env_key, step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::get_env_key,
     context::ConfigContext,
 };
 
@@ -14,6 +24,8 @@ pub struct Ttyd<'a> {
     libuv: Option<&'a str>,
     libwebsockets: Option<&'a str>,
     mbedtls: Option<&'a str>,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
 }
 
 impl<'a> Ttyd<'a> {
@@ -24,6 +36,8 @@ impl<'a> Ttyd<'a> {
             libuv: None,
             libwebsockets: None,
             mbedtls: None,
+            target: None,
+            version: None,
         }
     }
 
@@ -52,114 +66,199 @@ impl<'a> Ttyd<'a> {
         self
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let cmake = match self.cmake {
-            Some(val) => val,
-            None => &cmake::Cmake::new().build(context).await?,
-        };
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
 
-        let json_c = match self.json_c {
-            Some(val) => val,
-            None => &json_c::JsonC::new().build(context).await?,
-        };
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    async fn build_source(
+        &self,
+        context: &mut ConfigContext,
+        system: ArtifactSystem,
+    ) -> Result<String> {
+        if system != context.get_system() {
+            // The darwin source build is only supported natively.
+            return Err(ArtifactError::UnsupportedSystem {
+                artifact: self.name().to_string(),
+                system,
+            }
+            .into());
+        }
+
+        let (cmake, json_c, libwebsockets, mbedtls) = tokio::try_join!(
+            fork::build(context, self.cmake, cmake::Cmake::new()),
+            fork::build(context, self.json_c, json_c::JsonC::new()),
+            fork::build(
+                context,
+                self.libwebsockets,
+                libwebsockets::Libwebsockets::new()
+            ),
+            fork::build(context, self.mbedtls, mbedtls::Mbedtls::new()),
+        )?;
+
+        let [cmake, json_c, libwebsockets, mbedtls] =
+            fork::join(context, [cmake, json_c, libwebsockets, mbedtls]).await?;
 
         let libuv = match self.libuv {
             Some(val) => val,
-            None => &libuv::Libuv::new().with_cmake(cmake).build(context).await?,
+            None => {
+                &libuv::Libuv::new()
+                    .with_cmake(&cmake)
+                    .build(context)
+                    .await?
+            }
         };
 
-        let libwebsockets = match self.libwebsockets {
-            Some(val) => val,
-            None => &libwebsockets::Libwebsockets::new().build(context).await?,
-        };
+        let name = self.name();
+        let version = self.version();
 
-        let mbedtls = match self.mbedtls {
-            Some(val) => val,
-            None => &mbedtls::Mbedtls::new().build(context).await?,
-        };
+        let path = format!("https://github.com/tsl0922/ttyd/archive/refs/tags/{version}.tar.gz");
 
-        let name = "ttyd";
-        let version = "1.7.7";
-
-        let (sources, step_script, step_artifacts) = match context.get_system() {
-            Aarch64Linux => {
-                let path = format!(
-                    "https://github.com/tsl0922/ttyd/releases/download/{version}/ttyd.aarch64"
-                );
-                let script = formatdoc! {"
-                    mkdir -pv \"$VORPAL_OUTPUT/bin\"
-                    cp ./source/{name}/ttyd.aarch64 \"$VORPAL_OUTPUT/bin/ttyd\"
-                    chmod +x \"$VORPAL_OUTPUT/bin/ttyd\""
-                };
-                let sources = vec![ArtifactSource::new(name, &path).build()];
-                (sources, script, vec![])
-            }
-            X8664Linux => {
-                let path = format!(
-                    "https://github.com/tsl0922/ttyd/releases/download/{version}/ttyd.x86_64"
-                );
-                let script = formatdoc! {"
-                    mkdir -pv \"$VORPAL_OUTPUT/bin\"
-                    cp ./source/{name}/ttyd.x86_64 \"$VORPAL_OUTPUT/bin/ttyd\"
-                    chmod +x \"$VORPAL_OUTPUT/bin/ttyd\""
-                };
-                let sources = vec![ArtifactSource::new(name, &path).build()];
-                (sources, script, vec![])
-            }
-            Aarch64Darwin | X8664Darwin => {
-                let ttyd_path =
-                    format!("https://github.com/tsl0922/ttyd/archive/refs/tags/{version}.tar.gz");
-
-                let script = formatdoc! {"
-                    mkdir -pv \"$VORPAL_OUTPUT/bin\"
-
-                    BUILD_DIR=\"$(pwd)/build\"
-                    mkdir -p \"$BUILD_DIR\"
-
-                    pushd \"$BUILD_DIR\"
-
-                    {cmake}/bin/cmake \
-                        -DCMAKE_INSTALL_PREFIX=\"$VORPAL_OUTPUT\" \
-                        -DCMAKE_PREFIX_PATH=\"{json_c};{libuv};{mbedtls};{libwebsockets}\" \
-                        -DCMAKE_BUILD_TYPE=RELEASE \
-                        -DLIBUV_INCLUDE_DIR=\"{libuv}/include\" \
-                        -DLIBUV_LIBRARY=\"{libuv}/lib/libuv.a\" \
-                        \"$(pwd)/../source/{name}/{name}-{version}\"
-
-                    make install
-                    popd
-
-                    chmod +x \"$VORPAL_OUTPUT/bin/ttyd\"",
-                    cmake = get_env_key(&cmake.to_string()),
-                    json_c = get_env_key(&json_c.to_string()),
-                    libuv = get_env_key(&libuv.to_string()),
-                    libwebsockets = get_env_key(&libwebsockets.to_string()),
-                    mbedtls = get_env_key(&mbedtls.to_string()),
-                };
-
-                let sources = vec![ArtifactSource::new(name, &ttyd_path).build()];
-
-                let artifacts = vec![
-                    cmake.to_string(),
-                    json_c.to_string(),
-                    libuv.to_string(),
-                    libwebsockets.to_string(),
-                    mbedtls.to_string(),
-                ];
-
-                (sources, script, artifacts)
+        let source_directory = format!("{name}-{version}");
+
+        let libuv_key = get_env_key(&libuv.to_string());
+
+        CmakePackage::new(name, version, source::new(name, &path), self.systems())
+            .with_cmake(&cmake)
+            .with_artifact(&json_c)
+            .with_artifact(libuv)
+            .with_artifact(&mbedtls)
+            .with_artifact(&libwebsockets)
+            .with_definition(&format!("LIBUV_INCLUDE_DIR=\"{libuv_key}/include\""))
+            .with_definition(&format!("LIBUV_LIBRARY=\"{libuv_key}/lib/libuv.a\""))
+            .with_source_directory(&source_directory)
+            .build(context)
+            .await
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Ttyd<'a> {
+    fn name(&self) -> &str {
+        "ttyd"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::TTYD))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let name = self.name();
+        let version = self.version();
+
+        // Linux uses the static binaries published upstream, which are already linked against
+        // musl, so the `libc` variable changes nothing here; darwin has none, so it is built from
+        // source against its dependencies.
+        let source_binary = match system {
+            Aarch64Linux => "ttyd.aarch64",
+            X8664Linux => "ttyd.x86_64",
+            Aarch64Darwin | X8664Darwin => return self.build_source(context, system).await,
+            system => {
+                return Err(ArtifactError::UnsupportedSystem {
+                    artifact: name.to_string(),
+                    system,
+                }
+                .into())
             }
-            _ => return Err(anyhow::anyhow!("Unsupported system for ttyd artifact")),
         };
 
-        let steps = vec![step::shell(context, step_artifacts, vec![], step_script, vec![]).await?];
+        let path =
+            format!("https://github.com/tsl0922/ttyd/releases/download/{version}/{source_binary}");
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT/bin\"
+            cp ./source/{name}/{source_binary} \"$VORPAL_OUTPUT/bin/ttyd\"
+            chmod +x \"$VORPAL_OUTPUT/bin/ttyd\""
+        };
 
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{version}")])
-            .with_sources(sources)
-            .build(context)
-            .await
+        let steps = vec![target::shell(context, system, vec![], vec![], step_script).await?];
+
+        target::build(
+            context,
+            system,
+            name,
+            aliases::versioned(name, version),
+            vec![source::new(name, &path)],
+            steps,
+            self.systems(),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error,
+        testing::{self, MockContext},
+    };
+
+    #[tokio::test]
+    async fn build_downloads_linux_binaries() -> Result<()> {
+        for (system, binary) in [(Aarch64Linux, "ttyd.aarch64"), (X8664Linux, "ttyd.x86_64")] {
+            let context = &mut MockContext::new(system).build().await?;
+
+            let ttyd = Ttyd::new().build(context).await?;
+
+            assert_eq!(
+                testing::source_paths(context, &ttyd)?,
+                [format!(
+                    "https://github.com/tsl0922/ttyd/releases/download/{}/{binary}",
+                    versions::TTYD
+                )]
+            );
+            assert_eq!(testing::dependencies(context, &ttyd)?, ["linux-vorpal"]);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_compiles_darwin_from_source() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Darwin).build().await?;
+
+        let ttyd = Ttyd::new().build(context).await?;
+
+        assert_eq!(
+            testing::dependencies(context, &ttyd)?,
+            ["cmake", "json-c", "libuv", "libwebsockets", "mbedtls"]
+        );
+        assert_eq!(
+            testing::source_paths(context, &ttyd)?,
+            [format!(
+                "https://github.com/tsl0922/ttyd/archive/refs/tags/{}.tar.gz",
+                versions::TTYD
+            )]
+        );
+        assert_eq!(
+            testing::aliases(context, &ttyd)?,
+            aliases::versioned("ttyd", versions::TTYD)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_rejects_cross_darwin_source() -> Result<()> {
+        let context = &mut MockContext::new(X8664Linux).build().await?;
+
+        let result = Ttyd::new().with_target(Aarch64Darwin).build(context).await;
+
+        assert!(result.is_err_and(|error| error::is_unsupported_system(&error)));
+
+        Ok(())
     }
 }
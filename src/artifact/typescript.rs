@@ -1,19 +1,28 @@
+use crate::{artifact::ArtifactPackage, overrides, source, versions};
 use anyhow::Result;
+use async_trait::async_trait;
 use indoc::formatdoc;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{get_env_key, nodejs::NodeJS, step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::{get_env_key, nodejs::NodeJS, step, Artifact},
     context::ConfigContext,
 };
 
 #[derive(Default)]
 pub struct Typescript<'a> {
     node: Option<&'a str>,
+    version: Option<&'a str>,
 }
 
 impl<'a> Typescript<'a> {
     pub fn new() -> Self {
-        Self { node: None }
+        Self {
+            node: None,
+            version: None,
+        }
     }
 
     pub fn with_node(mut self, node: &'a str) -> Self {
@@ -21,19 +30,40 @@ impl<'a> Typescript<'a> {
         self
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Typescript<'a> {
+    fn name(&self) -> &str {
+        "typescript"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::TYPESCRIPT))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
         let node = match self.node {
             Some(val) => val,
             None => &NodeJS::new().build(context).await?,
         };
 
-        let name = "typescript";
-        let source_version = "6.0.3";
+        let name = self.name();
+        let source_version = self.version();
 
         let source_path =
             format!("https://registry.npmjs.org/typescript/-/typescript-{source_version}.tgz");
 
-        let source = ArtifactSource::new(name, &source_path).build();
+        let source = source::new(name, &source_path);
 
         let env_node = get_env_key(&node.to_string());
 
@@ -62,7 +92,7 @@ impl<'a> Typescript<'a> {
         let steps =
             vec![step::shell(context, vec![node.to_string()], vec![], step_script, vec![]).await?];
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let systems = self.systems();
 
         Artifact::new(name, steps, systems)
             .with_aliases(vec![format!("{name}:{source_version}")])
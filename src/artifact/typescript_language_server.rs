@@ -1,9 +1,16 @@
-use crate::artifact::typescript::Typescript;
+use crate::{
+    artifact::{typescript::Typescript, ArtifactPackage},
+    overrides, source, versions,
+};
 use anyhow::Result;
+use async_trait::async_trait;
 use indoc::formatdoc;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{get_env_key, nodejs::NodeJS, step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::{get_env_key, nodejs::NodeJS, step, Artifact},
     context::ConfigContext,
 };
 
@@ -111,6 +118,7 @@ server.stdin.write(frame(didOpen));
 pub struct TypescriptLanguageServer<'a> {
     node: Option<&'a str>,
     typescript: Option<&'a str>,
+    version: Option<&'a str>,
 }
 
 impl<'a> TypescriptLanguageServer<'a> {
@@ -118,6 +126,7 @@ impl<'a> TypescriptLanguageServer<'a> {
         Self {
             node: None,
             typescript: None,
+            version: None,
         }
     }
 
@@ -131,7 +140,30 @@ impl<'a> TypescriptLanguageServer<'a> {
         self
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for TypescriptLanguageServer<'a> {
+    fn name(&self) -> &str {
+        "typescript-language-server"
+    }
+
+    fn version(&self) -> &str {
+        self.version.unwrap_or(overrides::version(
+            self.name(),
+            versions::TYPESCRIPT_LANGUAGE_SERVER,
+        ))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
         let node = match self.node {
             Some(val) => val,
             None => &NodeJS::new().build(context).await?,
@@ -142,14 +174,14 @@ impl<'a> TypescriptLanguageServer<'a> {
             None => &Typescript::new().with_node(node).build(context).await?,
         };
 
-        let name = "typescript-language-server";
-        let source_version = "5.3.0";
+        let name = self.name();
+        let source_version = self.version();
 
         let source_path = format!(
             "https://registry.npmjs.org/typescript-language-server/-/typescript-language-server-{source_version}.tgz"
         );
 
-        let source = ArtifactSource::new(name, &source_path).build();
+        let source = source::new(name, &source_path);
 
         let env_node = get_env_key(&node.to_string());
         let env_typescript = get_env_key(&typescript.to_string());
@@ -211,7 +243,7 @@ impl<'a> TypescriptLanguageServer<'a> {
             .await?,
         ];
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let systems = self.systems();
 
         Artifact::new(name, steps, systems)
             .with_aliases(vec![format!("{name}:{source_version}")])
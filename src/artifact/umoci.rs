@@ -1,37 +1,60 @@
+use crate::{artifact::ArtifactPackage, builder::go::GoPackage, overrides, versions};
 use anyhow::Result;
+use async_trait::async_trait;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{language::go::Go, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
     context::ConfigContext,
 };
 
 #[derive(Default)]
-pub struct Umoci;
+pub struct Umoci<'a> {
+    version: Option<&'a str>,
+}
 
-impl Umoci {
+impl<'a> Umoci<'a> {
     pub fn new() -> Self {
-        Self
+        Self { version: None }
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "umoci";
-        let version = "0.6.0";
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
 
-        let source_path =
-            format!("https://github.com/opencontainers/umoci/archive/refs/tags/v{version}.tar.gz");
-        let source = ArtifactSource::new(name, source_path.as_str()).build();
+#[async_trait]
+impl<'a> ArtifactPackage for Umoci<'a> {
+    fn name(&self) -> &str {
+        "umoci"
+    }
 
-        let build_directory = format!("./umoci-{version}");
-        let build_path = format!("./cmd/{name}");
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::UMOCI))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name();
+        let version = self.version();
+
+        let build_path = format!("./cmd/{name}");
 
-        Go::new(name, systems)
-            .with_alias(format!("{name}:{version}"))
-            .with_build_directory(build_directory.as_str())
-            .with_build_path(build_path.as_str())
-            .with_source(source)
-            .build(context)
-            .await
+        GoPackage::new(
+            name,
+            "github.com/opencontainers/umoci",
+            version,
+            self.systems(),
+        )
+        .with_build_path(&build_path)
+        .with_vendor()
+        .build(context)
+        .await
     }
 }
@@ -0,0 +1,69 @@
+use crate::{
+    artifact::ArtifactPackage, builder::autotools::AutotoolsPackage, overrides, source, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct UniversalCtags<'a> {
+    version: Option<&'a str>,
+}
+
+impl<'a> UniversalCtags<'a> {
+    pub fn new() -> Self {
+        Self { version: None }
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for UniversalCtags<'a> {
+    fn name(&self) -> &str {
+        "universal-ctags"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::UNIVERSAL_CTAGS))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!(
+            "https://github.com/universal-ctags/ctags/releases/download/v{version}/universal-ctags-{version}.tar.gz"
+        );
+
+        let source = source::new(name, &path);
+
+        // Optional parsers backed by libxml2, jansson, libyaml, libseccomp and pcre2 are disabled
+        // so configure cannot pick those libraries up from the build host.
+        let source_directory = format!("{name}-{version}");
+
+        AutotoolsPackage::new(name, version, source, self.systems())
+            .with_configure_flag("--disable-json")
+            .with_configure_flag("--disable-pcre2")
+            .with_configure_flag("--disable-seccomp")
+            .with_configure_flag("--disable-xml")
+            .with_configure_flag("--disable-yaml")
+            .with_source_directory(&source_directory)
+            .build(context)
+            .await
+    }
+}
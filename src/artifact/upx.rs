@@ -0,0 +1,136 @@
+use crate::{
+    artifact::ArtifactPackage, builder::cmake::CmakePackage, error::ArtifactError, overrides,
+    source, target, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct Upx<'a> {
+    cmake: Option<&'a str>,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
+
+impl<'a> Upx<'a> {
+    pub fn new() -> Self {
+        Self {
+            cmake: None,
+            target: None,
+            version: None,
+        }
+    }
+
+    pub fn with_cmake(mut self, cmake: &'a str) -> Self {
+        self.cmake = Some(cmake);
+        self
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Upx<'a> {
+    fn name(&self) -> &str {
+        "upx"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::UPX))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let name = self.name();
+        let version = self.version();
+
+        let (sources, step_script, step_artifacts) = match system {
+            Aarch64Linux | X8664Linux => {
+                let source_system = match system {
+                    Aarch64Linux => "arm64_linux",
+                    _ => "amd64_linux",
+                };
+
+                let path = format!(
+                    "https://github.com/upx/upx/releases/download/v{version}/upx-{version}-{source_system}.tar.xz"
+                );
+
+                let script = formatdoc! {"
+                    mkdir -pv \"$VORPAL_OUTPUT/bin\"
+                    cp ./source/{name}/upx-{version}-{source_system}/upx \"$VORPAL_OUTPUT/bin/upx\"
+                    chmod +x \"$VORPAL_OUTPUT/bin/upx\""
+                };
+
+                let sources = vec![source::for_system(name, &path, system)?];
+
+                (sources, script, vec![])
+            }
+            Aarch64Darwin | X8664Darwin => {
+                let path = format!(
+                    "https://github.com/upx/upx/releases/download/v{version}/upx-{version}-src.tar.xz"
+                );
+
+                let source_directory = format!("upx-{version}-src");
+
+                let source = source::for_system(name, &path, system)?;
+
+                // Without `with_cmake` the package builds cmake for the target itself.
+                let mut package = CmakePackage::new(name, version, source, self.systems())
+                    .with_definition("UPX_CONFIG_DISABLE_GITREV=ON")
+                    .with_source_directory(&source_directory)
+                    .with_target(system);
+
+                if let Some(cmake) = self.cmake {
+                    package = package.with_cmake(cmake);
+                }
+
+                return package.build(context).await;
+            }
+            system => {
+                return Err(ArtifactError::UnsupportedSystem {
+                    artifact: name.to_string(),
+                    system,
+                }
+                .into())
+            }
+        };
+
+        let steps =
+            vec![target::shell(context, system, step_artifacts, vec![], step_script).await?];
+
+        let systems = self.systems();
+
+        target::build(
+            context,
+            system,
+            name,
+            vec![format!("{name}:{version}")],
+            sources,
+            steps,
+            systems,
+        )
+        .await
+    }
+}
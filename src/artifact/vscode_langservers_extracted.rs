@@ -1,8 +1,13 @@
+use crate::{artifact::ArtifactPackage, overrides, source, versions};
 use anyhow::Result;
+use async_trait::async_trait;
 use indoc::formatdoc;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{get_env_key, nodejs::NodeJS, step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::{get_env_key, nodejs::NodeJS, step, Artifact},
     context::ConfigContext,
 };
 
@@ -70,11 +75,15 @@ server.stdin.write(frame(initialize));
 #[derive(Default)]
 pub struct VscodeLangserversExtracted<'a> {
     node: Option<&'a str>,
+    version: Option<&'a str>,
 }
 
 impl<'a> VscodeLangserversExtracted<'a> {
     pub fn new() -> Self {
-        Self { node: None }
+        Self {
+            node: None,
+            version: None,
+        }
     }
 
     pub fn with_node(mut self, node: &'a str) -> Self {
@@ -82,14 +91,37 @@ impl<'a> VscodeLangserversExtracted<'a> {
         self
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for VscodeLangserversExtracted<'a> {
+    fn name(&self) -> &str {
+        "vscode-langservers-extracted"
+    }
+
+    fn version(&self) -> &str {
+        self.version.unwrap_or(overrides::version(
+            self.name(),
+            versions::VSCODE_LANGSERVERS_EXTRACTED,
+        ))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
         let node = match self.node {
             Some(val) => val,
             None => &NodeJS::new().build(context).await?,
         };
 
-        let name = "vscode-langservers-extracted";
-        let source_version = "4.10.0";
+        let name = self.name();
+        let source_version = self.version();
 
         let source_path =
             format!("https://registry.npmjs.org/{name}/-/{name}-{source_version}.tgz");
@@ -102,37 +134,37 @@ impl<'a> VscodeLangserversExtracted<'a> {
         // locks sources by key globally, and bare npm names (e.g. "typescript") collide with other
         // artifacts' sources.
         let sources = vec![
-            ArtifactSource::new(name, &source_path).build(),
-            ArtifactSource::new("vlse-vscode-l10n", "https://registry.npmjs.org/@vscode/l10n/-/l10n-0.0.18.tgz").build(),
-            ArtifactSource::new("vlse-boolbase", "https://registry.npmjs.org/boolbase/-/boolbase-1.0.0.tgz").build(),
-            ArtifactSource::new("vlse-core-js", "https://registry.npmjs.org/core-js/-/core-js-3.49.0.tgz").build(),
-            ArtifactSource::new("vlse-css-select", "https://registry.npmjs.org/css-select/-/css-select-5.2.2.tgz").build(),
-            ArtifactSource::new("vlse-css-what", "https://registry.npmjs.org/css-what/-/css-what-6.2.2.tgz").build(),
-            ArtifactSource::new("vlse-dom-serializer", "https://registry.npmjs.org/dom-serializer/-/dom-serializer-2.0.0.tgz").build(),
-            ArtifactSource::new("vlse-domelementtype", "https://registry.npmjs.org/domelementtype/-/domelementtype-2.3.0.tgz").build(),
-            ArtifactSource::new("vlse-domhandler", "https://registry.npmjs.org/domhandler/-/domhandler-5.0.3.tgz").build(),
-            ArtifactSource::new("vlse-domutils", "https://registry.npmjs.org/domutils/-/domutils-3.2.2.tgz").build(),
-            ArtifactSource::new("vlse-entities", "https://registry.npmjs.org/entities/-/entities-4.5.0.tgz").build(),
-            ArtifactSource::new("vlse-he", "https://registry.npmjs.org/he/-/he-1.2.0.tgz").build(),
-            ArtifactSource::new("vlse-jsonc-parser", "https://registry.npmjs.org/jsonc-parser/-/jsonc-parser-3.3.1.tgz").build(),
-            ArtifactSource::new("vlse-node-html-parser", "https://registry.npmjs.org/node-html-parser/-/node-html-parser-6.1.15-0.tgz").build(),
-            ArtifactSource::new("vlse-nth-check", "https://registry.npmjs.org/nth-check/-/nth-check-2.1.1.tgz").build(),
-            ArtifactSource::new("vlse-picomatch", "https://registry.npmjs.org/picomatch/-/picomatch-2.3.2.tgz").build(),
-            ArtifactSource::new("vlse-regenerator-runtime", "https://registry.npmjs.org/regenerator-runtime/-/regenerator-runtime-0.13.11.tgz").build(),
-            ArtifactSource::new("vlse-request-light", "https://registry.npmjs.org/request-light/-/request-light-0.7.0.tgz").build(),
-            ArtifactSource::new("vlse-semver", "https://registry.npmjs.org/semver/-/semver-7.8.5.tgz").build(),
-            ArtifactSource::new("vlse-typescript", "https://registry.npmjs.org/typescript/-/typescript-4.9.5.tgz").build(),
-            ArtifactSource::new("vlse-vscode-css-languageservice", "https://registry.npmjs.org/vscode-css-languageservice/-/vscode-css-languageservice-6.3.10.tgz").build(),
-            ArtifactSource::new("vlse-vscode-html-languageservice", "https://registry.npmjs.org/vscode-html-languageservice/-/vscode-html-languageservice-5.6.2.tgz").build(),
-            ArtifactSource::new("vlse-vscode-json-languageservice", "https://registry.npmjs.org/vscode-json-languageservice/-/vscode-json-languageservice-5.7.2.tgz").build(),
-            ArtifactSource::new("vlse-vscode-jsonrpc", "https://registry.npmjs.org/vscode-jsonrpc/-/vscode-jsonrpc-9.0.0-next.1.tgz").build(),
-            ArtifactSource::new("vlse-vscode-languageserver", "https://registry.npmjs.org/vscode-languageserver/-/vscode-languageserver-10.0.1.tgz").build(),
-            ArtifactSource::new("vlse-vscode-languageserver-protocol", "https://registry.npmjs.org/vscode-languageserver-protocol/-/vscode-languageserver-protocol-3.18.1.tgz").build(),
-            ArtifactSource::new("vlse-vscode-languageserver-textdocument", "https://registry.npmjs.org/vscode-languageserver-textdocument/-/vscode-languageserver-textdocument-1.0.13.tgz").build(),
-            ArtifactSource::new("vlse-vscode-languageserver-types", "https://registry.npmjs.org/vscode-languageserver-types/-/vscode-languageserver-types-3.18.0.tgz").build(),
-            ArtifactSource::new("vlse-vscode-markdown-languageservice", "https://registry.npmjs.org/vscode-markdown-languageservice/-/vscode-markdown-languageservice-0.5.0-alpha.1.tgz").build(),
-            ArtifactSource::new("vlse-vscode-nls", "https://registry.npmjs.org/vscode-nls/-/vscode-nls-5.2.0.tgz").build(),
-            ArtifactSource::new("vlse-vscode-uri", "https://registry.npmjs.org/vscode-uri/-/vscode-uri-3.1.0.tgz").build(),
+            source::new(name, &source_path),
+            source::new("vlse-vscode-l10n", "https://registry.npmjs.org/@vscode/l10n/-/l10n-0.0.18.tgz"),
+            source::new("vlse-boolbase", "https://registry.npmjs.org/boolbase/-/boolbase-1.0.0.tgz"),
+            source::new("vlse-core-js", "https://registry.npmjs.org/core-js/-/core-js-3.49.0.tgz"),
+            source::new("vlse-css-select", "https://registry.npmjs.org/css-select/-/css-select-5.2.2.tgz"),
+            source::new("vlse-css-what", "https://registry.npmjs.org/css-what/-/css-what-6.2.2.tgz"),
+            source::new("vlse-dom-serializer", "https://registry.npmjs.org/dom-serializer/-/dom-serializer-2.0.0.tgz"),
+            source::new("vlse-domelementtype", "https://registry.npmjs.org/domelementtype/-/domelementtype-2.3.0.tgz"),
+            source::new("vlse-domhandler", "https://registry.npmjs.org/domhandler/-/domhandler-5.0.3.tgz"),
+            source::new("vlse-domutils", "https://registry.npmjs.org/domutils/-/domutils-3.2.2.tgz"),
+            source::new("vlse-entities", "https://registry.npmjs.org/entities/-/entities-4.5.0.tgz"),
+            source::new("vlse-he", "https://registry.npmjs.org/he/-/he-1.2.0.tgz"),
+            source::new("vlse-jsonc-parser", "https://registry.npmjs.org/jsonc-parser/-/jsonc-parser-3.3.1.tgz"),
+            source::new("vlse-node-html-parser", "https://registry.npmjs.org/node-html-parser/-/node-html-parser-6.1.15-0.tgz"),
+            source::new("vlse-nth-check", "https://registry.npmjs.org/nth-check/-/nth-check-2.1.1.tgz"),
+            source::new("vlse-picomatch", "https://registry.npmjs.org/picomatch/-/picomatch-2.3.2.tgz"),
+            source::new("vlse-regenerator-runtime", "https://registry.npmjs.org/regenerator-runtime/-/regenerator-runtime-0.13.11.tgz"),
+            source::new("vlse-request-light", "https://registry.npmjs.org/request-light/-/request-light-0.7.0.tgz"),
+            source::new("vlse-semver", "https://registry.npmjs.org/semver/-/semver-7.8.5.tgz"),
+            source::new("vlse-typescript", "https://registry.npmjs.org/typescript/-/typescript-4.9.5.tgz"),
+            source::new("vlse-vscode-css-languageservice", "https://registry.npmjs.org/vscode-css-languageservice/-/vscode-css-languageservice-6.3.10.tgz"),
+            source::new("vlse-vscode-html-languageservice", "https://registry.npmjs.org/vscode-html-languageservice/-/vscode-html-languageservice-5.6.2.tgz"),
+            source::new("vlse-vscode-json-languageservice", "https://registry.npmjs.org/vscode-json-languageservice/-/vscode-json-languageservice-5.7.2.tgz"),
+            source::new("vlse-vscode-jsonrpc", "https://registry.npmjs.org/vscode-jsonrpc/-/vscode-jsonrpc-9.0.0-next.1.tgz"),
+            source::new("vlse-vscode-languageserver", "https://registry.npmjs.org/vscode-languageserver/-/vscode-languageserver-10.0.1.tgz"),
+            source::new("vlse-vscode-languageserver-protocol", "https://registry.npmjs.org/vscode-languageserver-protocol/-/vscode-languageserver-protocol-3.18.1.tgz"),
+            source::new("vlse-vscode-languageserver-textdocument", "https://registry.npmjs.org/vscode-languageserver-textdocument/-/vscode-languageserver-textdocument-1.0.13.tgz"),
+            source::new("vlse-vscode-languageserver-types", "https://registry.npmjs.org/vscode-languageserver-types/-/vscode-languageserver-types-3.18.0.tgz"),
+            source::new("vlse-vscode-markdown-languageservice", "https://registry.npmjs.org/vscode-markdown-languageservice/-/vscode-markdown-languageservice-0.5.0-alpha.1.tgz"),
+            source::new("vlse-vscode-nls", "https://registry.npmjs.org/vscode-nls/-/vscode-nls-5.2.0.tgz"),
+            source::new("vlse-vscode-uri", "https://registry.npmjs.org/vscode-uri/-/vscode-uri-3.1.0.tgz"),
         ];
 
         let env_node = get_env_key(&node.to_string());
@@ -217,7 +249,7 @@ impl<'a> VscodeLangserversExtracted<'a> {
         let steps =
             vec![step::shell(context, vec![node.to_string()], vec![], step_script, vec![]).await?];
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let systems = self.systems();
 
         Artifact::new(name, steps, systems)
             .with_aliases(vec![format!("{name}:{source_version}")])
@@ -0,0 +1,56 @@
+use crate::{artifact::ArtifactPackage, builder::go::GoPackage, overrides, source, versions};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct WireguardGo<'a> {
+    version: Option<&'a str>,
+}
+
+impl<'a> WireguardGo<'a> {
+    pub fn new() -> Self {
+        Self { version: None }
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for WireguardGo<'a> {
+    fn name(&self) -> &str {
+        "wireguard-go"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::WIREGUARD_GO))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name();
+        let version = self.version();
+
+        let source_path =
+            format!("https://git.zx2c4.com/wireguard-go/snapshot/wireguard-go-{version}.tar.xz");
+        let source_directory = format!("wireguard-go-{version}");
+
+        GoPackage::new(name, "golang.zx2c4.com/wireguard", version, self.systems())
+            .with_source(source::new(name, &source_path), &source_directory)
+            .build(context)
+            .await
+    }
+}
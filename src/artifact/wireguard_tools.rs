@@ -0,0 +1,148 @@
+use crate::{
+    artifact::{wireguard_go, ArtifactPackage},
+    builder::jobs,
+    context_cache,
+    error::ArtifactError,
+    overrides, source, target, versions,
+};
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::get_env_key,
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct WireguardTools<'a> {
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+    wireguard_go: Option<&'a str>,
+}
+
+impl<'a> WireguardTools<'a> {
+    pub fn new() -> Self {
+        Self {
+            target: None,
+            version: None,
+            wireguard_go: None,
+        }
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    pub fn with_wireguard_go(mut self, wireguard_go: &'a str) -> Self {
+        self.wireguard_go = Some(wireguard_go);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for WireguardTools<'a> {
+    fn name(&self) -> &str {
+        "wireguard-tools"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::WIREGUARD_TOOLS))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let name = self.name();
+        let version = self.version();
+
+        // Linux uses the in-kernel module, darwin has no kernel implementation so wg-quick drives
+        // the wireguard-go userspace daemon, which is shipped next to it in bin/.
+        let (step_artifacts, userspace_script) = match system {
+            Aarch64Linux | X8664Linux => (vec![], String::new()),
+            Aarch64Darwin | X8664Darwin => {
+                let wireguard_go = match self.wireguard_go {
+                    Some(val) => val,
+                    None if system == context.get_system() => {
+                        &context_cache::build(context, &wireguard_go::WireguardGo::new()).await?
+                    }
+                    None => bail!(
+                        "wireguard-go is only built for the host by default: pass one built for {}",
+                        system.as_str_name()
+                    ),
+                };
+
+                let script = formatdoc! {"
+                    cp {wireguard_go}/bin/wireguard-go \"$VORPAL_OUTPUT/bin/wireguard-go\"
+                    chmod +x \"$VORPAL_OUTPUT/bin/wireguard-go\"",
+                    wireguard_go = get_env_key(&wireguard_go.to_string()),
+                };
+
+                (vec![wireguard_go.to_string()], script)
+            }
+            system => {
+                return Err(ArtifactError::UnsupportedSystem {
+                    artifact: name.to_string(),
+                    system,
+                }
+                .into())
+            }
+        };
+
+        let path = format!(
+            "https://git.zx2c4.com/wireguard-tools/snapshot/wireguard-tools-{version}.tar.xz"
+        );
+
+        let source = source::new(name, &path);
+
+        let make_jobs = jobs::make_flag(jobs::limit(context, None)?);
+
+        let step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT/bin\"
+
+            pushd ./source/{name}/wireguard-tools-{version}/src
+
+            make {make_jobs}
+            make install \
+                PREFIX=\"$VORPAL_OUTPUT\" \
+                SYSCONFDIR=\"$VORPAL_OUTPUT/etc\" \
+                WITH_BASHCOMPLETION=no \
+                WITH_SYSTEMDUNITS=no \
+                WITH_WGQUICK=yes
+
+            popd
+
+            {userspace_script}",
+        };
+
+        let steps =
+            vec![target::shell(context, system, step_artifacts, vec![], step_script).await?];
+
+        let systems = self.systems();
+
+        target::build(
+            context,
+            system,
+            name,
+            vec![format!("{name}:{version}")],
+            vec![source],
+            steps,
+            systems,
+        )
+        .await
+    }
+}
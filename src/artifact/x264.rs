@@ -1,56 +1,97 @@
+use crate::{
+    artifact::ArtifactPackage, builder::autotools::AutotoolsPackage, error::ArtifactError,
+    overrides, source, target, versions,
+};
 use anyhow::Result;
-use indoc::formatdoc;
+use async_trait::async_trait;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
     context::ConfigContext,
 };
 
 #[derive(Default)]
-pub struct X264;
+pub struct X264<'a> {
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
 
-impl X264 {
+impl<'a> X264<'a> {
     pub fn new() -> Self {
-        Self
+        Self {
+            target: None,
+            version: None,
+        }
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for X264<'a> {
+    fn name(&self) -> &str {
+        "x264"
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
-        let name = "x264";
-        let version = "20191217-2245-stable";
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::X264))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name();
+        let version = self.version();
 
         let source_path = format!(
             "https://download.videolan.org/pub/videolan/x264/snapshots/x264-snapshot-{version}.tar.bz2"
         );
-        let source = ArtifactSource::new(name, &source_path).build();
+        let source = source::new(name, &source_path);
 
         // x264's 2019 config.guess misdetects Apple Silicon.
-        let (host, asm_flag) = match context.get_system() {
-            Aarch64Darwin => ("aarch64-apple-darwin", ""),
-            Aarch64Linux => ("aarch64-unknown-linux-gnu", ""),
-            X8664Darwin => ("x86_64-apple-darwin", " --disable-asm"),
-            X8664Linux => ("x86_64-unknown-linux-gnu", " --disable-asm"),
-            _ => return Err(anyhow::anyhow!("Unsupported system for {name} artifact")),
-        };
+        let system = target::system(context, self.target);
 
-        let step_script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT\"
-
-            pushd ./source/{name}/{name}-snapshot-{version}
-
-            ./configure --prefix=\"$VORPAL_OUTPUT\" --enable-static --enable-pic --disable-cli{asm_flag} --host={host}
-
-            make -j$(nproc 2>/dev/null || sysctl -n hw.ncpu)
-            make install",
+        let (host, disable_asm) = match system {
+            Aarch64Darwin => ("aarch64-apple-darwin", false),
+            Aarch64Linux => ("aarch64-unknown-linux-gnu", false),
+            X8664Darwin => ("x86_64-apple-darwin", true),
+            X8664Linux => ("x86_64-unknown-linux-gnu", true),
+            system => {
+                return Err(ArtifactError::UnsupportedSystem {
+                    artifact: name.to_string(),
+                    system,
+                }
+                .into())
+            }
         };
 
-        let steps = vec![step::shell(context, vec![], vec![], step_script, vec![]).await?];
+        let source_directory = format!("{name}-snapshot-{version}");
+
+        let mut package = AutotoolsPackage::new(name, version, source, self.systems())
+            .with_configure_flag("--enable-static")
+            .with_configure_flag("--enable-pic")
+            .with_configure_flag("--disable-cli")
+            .with_configure_flag(&format!("--host={host}"))
+            .with_source_directory(&source_directory)
+            .with_target(system);
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        if disable_asm {
+            package = package.with_configure_flag("--disable-asm");
+        }
 
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{version}")])
-            .with_sources(vec![source])
-            .build(context)
-            .await
+        package.build(context).await
     }
 }
@@ -1,8 +1,13 @@
+use crate::{artifact::ArtifactPackage, overrides, source, versions};
 use anyhow::Result;
+use async_trait::async_trait;
 use indoc::formatdoc;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{get_env_key, nodejs::NodeJS, step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::{get_env_key, nodejs::NodeJS, step, Artifact},
     context::ConfigContext,
 };
 
@@ -70,11 +75,15 @@ server.stdin.write(frame(initialize));
 #[derive(Default)]
 pub struct YamlLanguageServer<'a> {
     node: Option<&'a str>,
+    version: Option<&'a str>,
 }
 
 impl<'a> YamlLanguageServer<'a> {
     pub fn new() -> Self {
-        Self { node: None }
+        Self {
+            node: None,
+            version: None,
+        }
     }
 
     pub fn with_node(mut self, node: &'a str) -> Self {
@@ -82,14 +91,37 @@ impl<'a> YamlLanguageServer<'a> {
         self
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for YamlLanguageServer<'a> {
+    fn name(&self) -> &str {
+        "yaml-language-server"
+    }
+
+    fn version(&self) -> &str {
+        self.version.unwrap_or(overrides::version(
+            self.name(),
+            versions::YAML_LANGUAGE_SERVER,
+        ))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
         let node = match self.node {
             Some(val) => val,
             None => &NodeJS::new().build(context).await?,
         };
 
-        let name = "yaml-language-server";
-        let source_version = "1.23.0";
+        let name = self.name();
+        let source_version = self.version();
 
         let source_path =
             format!("https://registry.npmjs.org/{name}/-/{name}-{source_version}.tgz");
@@ -106,27 +138,27 @@ impl<'a> YamlLanguageServer<'a> {
         // caret consumers) because vscode-languageserver-protocol@3.17.5 requires it EXACTLY; 3.17.5
         // is the only version that satisfies all three consumers under flat dedup.
         let sources = vec![
-            ArtifactSource::new(name, &source_path).build(),
-            ArtifactSource::new("yls-vscode-l10n", "https://registry.npmjs.org/@vscode/l10n/-/l10n-0.0.18.tgz").build(),
-            ArtifactSource::new("yls-ajv", "https://registry.npmjs.org/ajv/-/ajv-8.20.0.tgz").build(),
-            ArtifactSource::new("yls-ajv-draft-04", "https://registry.npmjs.org/ajv-draft-04/-/ajv-draft-04-1.0.0.tgz").build(),
-            ArtifactSource::new("yls-ajv-i18n", "https://registry.npmjs.org/ajv-i18n/-/ajv-i18n-4.2.0.tgz").build(),
-            ArtifactSource::new("yls-fast-deep-equal", "https://registry.npmjs.org/fast-deep-equal/-/fast-deep-equal-3.1.3.tgz").build(),
-            ArtifactSource::new("yls-fast-uri", "https://registry.npmjs.org/fast-uri/-/fast-uri-3.1.2.tgz").build(),
-            ArtifactSource::new("yls-json-schema-traverse", "https://registry.npmjs.org/json-schema-traverse/-/json-schema-traverse-1.0.0.tgz").build(),
-            ArtifactSource::new("yls-jsonc-parser", "https://registry.npmjs.org/jsonc-parser/-/jsonc-parser-3.3.1.tgz").build(),
-            ArtifactSource::new("yls-prettier", "https://registry.npmjs.org/prettier/-/prettier-3.8.4.tgz").build(),
-            ArtifactSource::new("yls-request-light", "https://registry.npmjs.org/request-light/-/request-light-0.5.8.tgz").build(),
-            ArtifactSource::new("yls-require-from-string", "https://registry.npmjs.org/require-from-string/-/require-from-string-2.0.2.tgz").build(),
-            ArtifactSource::new("yls-vscode-json-languageservice", "https://registry.npmjs.org/vscode-json-languageservice/-/vscode-json-languageservice-4.1.8.tgz").build(),
-            ArtifactSource::new("yls-vscode-jsonrpc", "https://registry.npmjs.org/vscode-jsonrpc/-/vscode-jsonrpc-8.2.0.tgz").build(),
-            ArtifactSource::new("yls-vscode-languageserver", "https://registry.npmjs.org/vscode-languageserver/-/vscode-languageserver-9.0.1.tgz").build(),
-            ArtifactSource::new("yls-vscode-languageserver-protocol", "https://registry.npmjs.org/vscode-languageserver-protocol/-/vscode-languageserver-protocol-3.17.5.tgz").build(),
-            ArtifactSource::new("yls-vscode-languageserver-textdocument", "https://registry.npmjs.org/vscode-languageserver-textdocument/-/vscode-languageserver-textdocument-1.0.13.tgz").build(),
-            ArtifactSource::new("yls-vscode-languageserver-types", "https://registry.npmjs.org/vscode-languageserver-types/-/vscode-languageserver-types-3.17.5.tgz").build(),
-            ArtifactSource::new("yls-vscode-nls", "https://registry.npmjs.org/vscode-nls/-/vscode-nls-5.2.0.tgz").build(),
-            ArtifactSource::new("yls-vscode-uri", "https://registry.npmjs.org/vscode-uri/-/vscode-uri-3.1.0.tgz").build(),
-            ArtifactSource::new("yls-yaml", "https://registry.npmjs.org/yaml/-/yaml-2.8.3.tgz").build(),
+            source::new(name, &source_path),
+            source::new("yls-vscode-l10n", "https://registry.npmjs.org/@vscode/l10n/-/l10n-0.0.18.tgz"),
+            source::new("yls-ajv", "https://registry.npmjs.org/ajv/-/ajv-8.20.0.tgz"),
+            source::new("yls-ajv-draft-04", "https://registry.npmjs.org/ajv-draft-04/-/ajv-draft-04-1.0.0.tgz"),
+            source::new("yls-ajv-i18n", "https://registry.npmjs.org/ajv-i18n/-/ajv-i18n-4.2.0.tgz"),
+            source::new("yls-fast-deep-equal", "https://registry.npmjs.org/fast-deep-equal/-/fast-deep-equal-3.1.3.tgz"),
+            source::new("yls-fast-uri", "https://registry.npmjs.org/fast-uri/-/fast-uri-3.1.2.tgz"),
+            source::new("yls-json-schema-traverse", "https://registry.npmjs.org/json-schema-traverse/-/json-schema-traverse-1.0.0.tgz"),
+            source::new("yls-jsonc-parser", "https://registry.npmjs.org/jsonc-parser/-/jsonc-parser-3.3.1.tgz"),
+            source::new("yls-prettier", "https://registry.npmjs.org/prettier/-/prettier-3.8.4.tgz"),
+            source::new("yls-request-light", "https://registry.npmjs.org/request-light/-/request-light-0.5.8.tgz"),
+            source::new("yls-require-from-string", "https://registry.npmjs.org/require-from-string/-/require-from-string-2.0.2.tgz"),
+            source::new("yls-vscode-json-languageservice", "https://registry.npmjs.org/vscode-json-languageservice/-/vscode-json-languageservice-4.1.8.tgz"),
+            source::new("yls-vscode-jsonrpc", "https://registry.npmjs.org/vscode-jsonrpc/-/vscode-jsonrpc-8.2.0.tgz"),
+            source::new("yls-vscode-languageserver", "https://registry.npmjs.org/vscode-languageserver/-/vscode-languageserver-9.0.1.tgz"),
+            source::new("yls-vscode-languageserver-protocol", "https://registry.npmjs.org/vscode-languageserver-protocol/-/vscode-languageserver-protocol-3.17.5.tgz"),
+            source::new("yls-vscode-languageserver-textdocument", "https://registry.npmjs.org/vscode-languageserver-textdocument/-/vscode-languageserver-textdocument-1.0.13.tgz"),
+            source::new("yls-vscode-languageserver-types", "https://registry.npmjs.org/vscode-languageserver-types/-/vscode-languageserver-types-3.17.5.tgz"),
+            source::new("yls-vscode-nls", "https://registry.npmjs.org/vscode-nls/-/vscode-nls-5.2.0.tgz"),
+            source::new("yls-vscode-uri", "https://registry.npmjs.org/vscode-uri/-/vscode-uri-3.1.0.tgz"),
+            source::new("yls-yaml", "https://registry.npmjs.org/yaml/-/yaml-2.8.3.tgz"),
         ];
 
         let env_node = get_env_key(&node.to_string());
@@ -198,7 +230,7 @@ impl<'a> YamlLanguageServer<'a> {
         let steps =
             vec![step::shell(context, vec![node.to_string()], vec![], step_script, vec![]).await?];
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let systems = self.systems();
 
         Artifact::new(name, steps, systems)
             .with_aliases(vec![format!("{name}:{source_version}")])
@@ -0,0 +1,195 @@
+use crate::{
+    artifact::ArtifactPackage,
+    builder::check,
+    channel::{self, Channel},
+    error::ArtifactError,
+    overrides, source, target, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct Zig<'a> {
+    channel: Channel,
+    check: Option<bool>,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
+
+impl<'a> Zig<'a> {
+    pub fn new() -> Self {
+        Self {
+            channel: Channel::Stable,
+            check: None,
+            target: None,
+            version: None,
+        }
+    }
+
+    // `Channel::Nightly` installs a master build, named by its dev version in `with_version`
+    // (e.g. `0.16.0-dev.1484+d0ba6642b`); zig publishes no release candidates. ziglang.org keeps
+    // only the latest master builds under `builds/`, so a pinned dev version stops downloading
+    // within weeks and must be bumped with the master build it tracks.
+    pub fn with_channel(mut self, channel: Channel) -> Self {
+        self.channel = channel;
+        self
+    }
+
+    // Runs the build-time check, see `check::enabled`.
+    pub fn with_check(mut self) -> Self {
+        self.check = Some(true);
+        self
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    // Skips the check even when the `check` variable turns checks on.
+    pub fn without_check(mut self) -> Self {
+        self.check = Some(false);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Zig<'a> {
+    fn name(&self) -> &str {
+        "zig"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::ZIG))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let name = self.name();
+        let version = self.version();
+
+        let source_system = match system {
+            Aarch64Darwin => "aarch64-macos",
+            Aarch64Linux => "aarch64-linux",
+            X8664Darwin => "x86_64-macos",
+            X8664Linux => "x86_64-linux",
+            system => {
+                return Err(ArtifactError::UnsupportedSystem {
+                    artifact: name.to_string(),
+                    system,
+                }
+                .into())
+            }
+        };
+
+        let source_directory = format!("zig-{source_system}-{version}");
+
+        // Releases are published under `download/` and master builds under `builds/`, so a
+        // version only fits its own channel.
+        let path = match self.channel {
+            Channel::Stable if !version.contains("-dev.") => {
+                format!("https://ziglang.org/download/{version}/{source_directory}.tar.xz")
+            }
+            Channel::Nightly if version.contains("-dev.") => {
+                format!("https://ziglang.org/builds/{source_directory}.tar.xz")
+            }
+            Channel::Stable | Channel::Nightly => {
+                return Err(ArtifactError::UnsupportedVersion {
+                    artifact: name.to_string(),
+                    version: version.to_string(),
+                }
+                .into())
+            }
+            Channel::Rc => {
+                return Err(ArtifactError::UnsupportedVariant {
+                    artifact: name.to_string(),
+                    variant: self.channel.as_str().to_string(),
+                }
+                .into())
+            }
+        };
+
+        let source = source::for_system(name, &path, system)?;
+
+        // `zig` finds its bundled `lib/` (libc headers and sources for every target) next to its
+        // resolved path, so the distribution is kept whole and only linked into `bin/`.
+        let step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT/bin\" \"$VORPAL_OUTPUT/libexec\"
+            cp -Rv ./source/{name}/{source_directory} \"$VORPAL_OUTPUT/libexec/zig\"
+            ln -sv ../libexec/zig/zig \"$VORPAL_OUTPUT/bin/zig\"",
+        };
+
+        let mut steps = vec![target::shell(context, system, vec![], vec![], step_script).await?];
+
+        if check::enabled(context, system, self.check)? {
+            steps.push(check::step(context, system, vec![], "bin/zig version", version).await?);
+        }
+
+        let systems = self.systems();
+
+        target::build(
+            context,
+            system,
+            name,
+            vec![channel::alias(name, version, self.channel)],
+            vec![source],
+            steps,
+            systems,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, MockContext};
+
+    #[tokio::test]
+    async fn build_nightly_channel() -> Result<()> {
+        let context = &mut MockContext::new(X8664Linux).build().await?;
+
+        let zig = Zig::new()
+            .with_channel(Channel::Nightly)
+            .with_version("0.16.0-dev.1484+d0ba6642b")
+            .build(context)
+            .await?;
+
+        assert_eq!(testing::aliases(context, &zig)?, ["zig:nightly"]);
+        assert_eq!(
+            testing::source_paths(context, &zig)?,
+            ["https://ziglang.org/builds/zig-x86_64-linux-0.16.0-dev.1484+d0ba6642b.tar.xz"]
+        );
+        assert!(Zig::new()
+            .with_channel(Channel::Nightly)
+            .build(context)
+            .await
+            .is_err());
+        assert!(Zig::new()
+            .with_channel(Channel::Rc)
+            .build(context)
+            .await
+            .is_err());
+
+        Ok(())
+    }
+}
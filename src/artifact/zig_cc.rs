@@ -0,0 +1,156 @@
+use crate::{
+    artifact::{zig::Zig, ArtifactPackage},
+    builder::wrapper,
+    context_cache, overrides, target, versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::get_env_key,
+    context::ConfigContext,
+};
+
+// Zig tools the shims cover, each under its own name. The compilers also get `-target`.
+const SHIMS: &[&str] = &["ar", "c++", "cc", "ranlib"];
+
+#[derive(Default)]
+pub struct ZigCc<'a> {
+    target: Option<ArtifactSystem>,
+    triple: Option<&'a str>,
+    version: Option<&'a str>,
+    zig: Option<&'a str>,
+}
+
+impl<'a> ZigCc<'a> {
+    pub fn new() -> Self {
+        Self {
+            target: None,
+            triple: None,
+            version: None,
+            zig: None,
+        }
+    }
+
+    // System the shims run on, not the one they compile for, see `with_triple`.
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    // Zig target the compilers build for, e.g. `x86_64-linux-musl`. Defaults to glibc Linux on
+    // the architecture the shims run on.
+    pub fn with_triple(mut self, triple: &'a str) -> Self {
+        self.triple = Some(triple);
+        self
+    }
+
+    // The zig release the shims wrap, built unless `with_zig` passes one.
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    pub fn with_zig(mut self, zig: &'a str) -> Self {
+        self.zig = Some(zig);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for ZigCc<'a> {
+    fn name(&self) -> &str {
+        "zig-cc"
+    }
+
+    // The shims are zig's own tools, so they carry its version.
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version("zig", versions::ZIG))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let zig = match (self.zig, self.version) {
+            (Some(val), _) => val.to_string(),
+            (None, None) if system == context.get_system() => {
+                context_cache::build(context, &Zig::new()).await?
+            }
+            (None, version) => {
+                let mut zig = Zig::new().with_target(system);
+
+                if let Some(version) = version {
+                    zig = zig.with_version(version);
+                }
+
+                zig.build(context).await?
+            }
+        };
+
+        let name = self.name();
+        let version = self.version();
+
+        let triple = match (self.triple, system) {
+            (Some(triple), _) => triple,
+            (None, Aarch64Darwin | Aarch64Linux) => "aarch64-linux-gnu",
+            (None, _) => "x86_64-linux-gnu",
+        };
+
+        let env_zig = get_env_key(&zig);
+
+        // Zig caches compiled libc and compiler-rt per user; the default under `$HOME` is not
+        // writable inside build steps, so the shims fall back to the temporary directory.
+        let cache = (
+            "ZIG_GLOBAL_CACHE_DIR",
+            "\\${ZIG_GLOBAL_CACHE_DIR:-\\${TMPDIR:-/tmp}/zig-cache}".to_string(),
+        );
+
+        let shim_scripts = SHIMS
+            .iter()
+            .map(|shim| {
+                let target = match *shim {
+                    "cc" | "c++" => format!(" -target {triple}"),
+                    _ => String::new(),
+                };
+
+                wrapper::wrap_binary(
+                    &format!("$VORPAL_OUTPUT/bin/{shim}"),
+                    &format!("\"{env_zig}/bin/zig\" {shim}{target}"),
+                    &[],
+                    std::slice::from_ref(&cache),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        let step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT/bin\"
+
+            {shim_scripts}",
+        };
+
+        let steps = vec![target::shell(context, system, vec![zig], vec![], step_script).await?];
+
+        let systems = self.systems();
+
+        target::build(
+            context,
+            system,
+            name,
+            vec![format!("{name}:{version}-{triple}")],
+            vec![],
+            steps,
+            systems,
+        )
+        .await
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{
+    artifact::ArtifactPackage,
+    builder::{autotools::AutotoolsPackage, musl},
+    overrides, source,
+    variant::{Linkage, Variant},
+    versions,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    context::ConfigContext,
+};
+
+#[derive(Default)]
+pub struct Zlib<'a> {
+    linkage: Linkage,
+    target: Option<ArtifactSystem>,
+    version: Option<&'a str>,
+}
+
+impl<'a> Zlib<'a> {
+    pub fn new() -> Self {
+        Self {
+            linkage: Linkage::Shared,
+            target: None,
+            version: None,
+        }
+    }
+
+    // Shared builds also install `libz.a`; static and musl builds install only that. The `libc`
+    // variable switches Linux builds to musl, see `musl::linkage`.
+    pub fn with_linkage(mut self, linkage: Linkage) -> Self {
+        self.linkage = linkage;
+        self
+    }
+
+    pub fn with_target(mut self, target: ArtifactSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Zlib<'a> {
+    fn name(&self) -> &str {
+        "zlib"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::ZLIB))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name();
+        let version = self.version();
+
+        let path = format!(
+            "https://github.com/madler/zlib/releases/download/v{version}/zlib-{version}.tar.gz"
+        );
+
+        let source = source::new(name, &path);
+
+        let source_directory = format!("{name}-{version}");
+
+        let linkage = musl::linkage(context, self.linkage)?;
+
+        // zlib ships its own configure script, which only understands the installation prefix and
+        // `--static`.
+        let mut package = AutotoolsPackage::new(name, version, source, self.systems())
+            .with_prune(&["share/man"])
+            .with_source_directory(&source_directory)
+            .with_strip()
+            .with_variant(Variant::Full, linkage);
+
+        if linkage != Linkage::Shared {
+            package = package.with_configure_flag("--static");
+        }
+
+        if let Some(target) = self.target {
+            package = package.with_target(target);
+        }
+
+        package.build(context).await
+    }
+}
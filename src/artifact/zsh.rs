@@ -1,20 +1,33 @@
-use crate::artifact::ncurses::Ncurses;
+use crate::{
+    artifact::{ncurses::Ncurses, ArtifactPackage},
+    builder::autotools::AutotoolsPackage,
+    context_cache, overrides,
+    source::SourceUrls,
+    versions,
+};
 use anyhow::Result;
-use indoc::formatdoc;
+use async_trait::async_trait;
 use vorpal_sdk::{
-    api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    artifact::{get_env_key, step, Artifact, ArtifactSource},
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::get_env_key,
     context::ConfigContext,
 };
 
 #[derive(Default)]
 pub struct Zsh<'a> {
     ncurses: Option<&'a str>,
+    version: Option<&'a str>,
 }
 
 impl<'a> Zsh<'a> {
     pub fn new() -> Self {
-        Self { ncurses: None }
+        Self {
+            ncurses: None,
+            version: None,
+        }
     }
 
     pub fn with_ncurses(mut self, ncurses: &'a str) -> Self {
@@ -22,45 +35,53 @@ impl<'a> Zsh<'a> {
         self
     }
 
-    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
+    pub fn with_version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[async_trait]
+impl<'a> ArtifactPackage for Zsh<'a> {
+    fn name(&self) -> &str {
+        "zsh"
+    }
+
+    fn version(&self) -> &str {
+        self.version
+            .unwrap_or(overrides::version(self.name(), versions::ZSH))
+    }
+
+    fn systems(&self) -> Vec<ArtifactSystem> {
+        vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+    }
+
+    async fn build(&self, context: &mut ConfigContext) -> Result<String> {
         let ncurses = match self.ncurses {
             Some(val) => val,
-            None => &Ncurses::new().build(context).await?,
+            None => &context_cache::build(context, &Ncurses::new()).await?,
         };
 
-        let name = "zsh";
-        let version = "5.9";
+        let name = self.name();
+        let version = self.version();
 
         let path = format!(
             "https://downloads.sourceforge.net/project/zsh/zsh/{version}/zsh-{version}.tar.xz"
         );
+        let mirror = format!("https://www.zsh.org/pub/zsh-{version}.tar.xz");
 
-        let source = ArtifactSource::new(name, &path).build();
-
-        let script = formatdoc! {"
-            mkdir -pv \"$VORPAL_OUTPUT\"
-
-            pushd ./source/{name}/zsh-{version}
-
-            export CFLAGS=\"-Wno-incompatible-pointer-types\"
-            export CPPFLAGS=\"-I{ncurses}/include -I{ncurses}/include/ncursesw\"
-            export LDFLAGS=\"-L{ncurses}/lib -Wl,-rpath,{ncurses}/lib\"
-
-            ./configure --prefix=\"$VORPAL_OUTPUT\"
-
-            make
-            make install",
-            ncurses = get_env_key(&ncurses.to_string()),
-        };
+        let source = SourceUrls::new(name, &path).with_mirror(&mirror).build();
 
-        let steps =
-            vec![step::shell(context, vec![ncurses.to_string()], vec![], script, vec![]).await?];
+        let source_directory = format!("zsh-{version}");
 
-        let systems = vec![Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
+        let ncurses_key = get_env_key(&ncurses.to_string());
 
-        Artifact::new(name, steps, systems)
-            .with_aliases(vec![format!("{name}:{version}")])
-            .with_sources(vec![source])
+        AutotoolsPackage::new(name, version, source, self.systems())
+            .with_artifact(ncurses)
+            .with_cppflags(&format!("-I{ncurses_key}/include/ncursesw"))
+            .with_dylib_audit()
+            .with_environment("CFLAGS", "-Wno-incompatible-pointer-types")
+            .with_source_directory(&source_directory)
             .build(context)
             .await
     }
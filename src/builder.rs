@@ -0,0 +1,22 @@
+pub mod autotools;
+pub mod cargo;
+pub mod check;
+#[cfg(feature = "build-tools")]
+pub mod cmake;
+pub mod compiler_cache;
+pub mod completions;
+pub mod darwin_sign;
+pub mod dependency_env;
+pub mod deterministic;
+pub mod dylib_audit;
+pub mod environment;
+pub mod github_release;
+pub mod go;
+pub mod jobs;
+pub mod musl;
+pub mod outputs;
+pub mod patch;
+pub mod prune;
+pub mod toolset;
+pub mod wrapper;
+pub mod zig_cc;
@@ -0,0 +1,353 @@
+use crate::{
+    builder::{
+        check, compiler_cache::CompilerCache, dependency_env::DependencyEnv, deterministic,
+        dylib_audit, jobs, musl, outputs, patch, prune, zig_cc,
+    },
+    target,
+    variant::{self, Linkage, Variant},
+};
+use anyhow::Result;
+use indoc::formatdoc;
+use vorpal_sdk::{api, api::artifact::ArtifactSystem, context::ConfigContext};
+
+pub struct AutotoolsPackage<'a> {
+    artifacts: Vec<String>,
+    assert_linkage: bool,
+    assert_outputs: Vec<String>,
+    check: Option<(&'a str, &'a str)>,
+    check_enabled: Option<bool>,
+    compiler_cache: Option<CompilerCache>,
+    configure_flags: Vec<String>,
+    cppflags: Vec<String>,
+    dylib_audit: bool,
+    environments: Vec<(String, String)>,
+    jobs: Option<usize>,
+    name: &'a str,
+    patches: Vec<String>,
+    prune: Vec<String>,
+    prune_enabled: bool,
+    source: api::artifact::ArtifactSource,
+    source_directory: &'a str,
+    strip: bool,
+    systems: Vec<ArtifactSystem>,
+    target: Option<ArtifactSystem>,
+    variant: (Variant, Linkage),
+    version: &'a str,
+    version_aliases: bool,
+}
+
+impl<'a> AutotoolsPackage<'a> {
+    pub fn new(
+        name: &'a str,
+        version: &'a str,
+        source: api::artifact::ArtifactSource,
+        systems: Vec<ArtifactSystem>,
+    ) -> Self {
+        Self {
+            artifacts: vec![],
+            assert_linkage: false,
+            assert_outputs: vec![],
+            check: None,
+            check_enabled: None,
+            compiler_cache: None,
+            configure_flags: vec![],
+            cppflags: vec![],
+            dylib_audit: false,
+            environments: vec![],
+            jobs: None,
+            name,
+            patches: vec![],
+            prune: vec![],
+            prune_enabled: true,
+            source,
+            source_directory: ".",
+            strip: false,
+            systems,
+            target: None,
+            variant: (Variant::default(), Linkage::default()),
+            version,
+            version_aliases: true,
+        }
+    }
+
+    // Dependencies are exposed to configure through `DependencyEnv`.
+    pub fn with_artifact(mut self, artifact: &str) -> Self {
+        self.artifacts.push(artifact.to_string());
+        self
+    }
+
+    // Fails the build when any executable or shared library links against /usr/local or Homebrew,
+    // see `outputs::step`.
+    pub fn with_assert_linkage(mut self) -> Self {
+        self.assert_linkage = true;
+        self
+    }
+
+    // Fails the build when any of `paths`, relative to the artifact output, was not installed.
+    pub fn with_assert_outputs(mut self, paths: Vec<&str>) -> Self {
+        self.assert_outputs
+            .extend(paths.iter().map(|path| path.to_string()));
+        self
+    }
+
+    pub fn with_check(mut self, command: &'a str, expected: &'a str) -> Self {
+        self.check = Some((command, expected));
+        self
+    }
+
+    // Turns the `with_check` step on or off, see `check::enabled`; `None` leaves it to the `check`
+    // variable.
+    pub fn with_check_enabled(mut self, check: Option<bool>) -> Self {
+        self.check_enabled = check;
+        self
+    }
+
+    // Wraps the C and C++ compilers with ccache or sccache.
+    pub fn with_compiler_cache(mut self, compiler_cache: CompilerCache) -> Self {
+        self.compiler_cache = Some(compiler_cache);
+        self
+    }
+
+    pub fn with_configure_flag(mut self, flag: &str) -> Self {
+        self.configure_flags.push(flag.to_string());
+        self
+    }
+
+    pub fn with_cppflags(mut self, cppflags: &str) -> Self {
+        self.cppflags.push(cppflags.to_string());
+        self
+    }
+
+    // Fails the darwin build when a Mach-O file loads a library from outside its dependencies,
+    // see `dylib_audit::step`.
+    pub fn with_dylib_audit(mut self) -> Self {
+        self.dylib_audit = true;
+        self
+    }
+
+    pub fn with_environment(mut self, key: &str, value: &str) -> Self {
+        self.environments.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    // Caps parallel jobs for this build, see `jobs::limit`.
+    pub fn with_jobs(mut self, jobs: usize) -> Self {
+        self.jobs = Some(jobs);
+        self
+    }
+
+    // Paths are relative to the repository root, e.g. `patches/<artifact>/fix.patch`.
+    pub fn with_patch(mut self, patch: &str) -> Self {
+        self.patches.push(patch.to_string());
+        self
+    }
+
+    // See `prune::DEFAULT` for the usual set of globs.
+    pub fn with_prune(mut self, globs: &[&str]) -> Self {
+        self.prune.extend(globs.iter().map(|glob| glob.to_string()));
+        self
+    }
+
+    // Turns the `with_prune` globs off, e.g. for an artifact's `without_prune`; stripping is
+    // unaffected.
+    pub fn with_prune_enabled(mut self, enabled: bool) -> Self {
+        self.prune_enabled = enabled;
+        self
+    }
+
+    pub fn with_source_directory(mut self, directory: &'a str) -> Self {
+        self.source_directory = directory;
+        self
+    }
+
+    pub fn with_strip(mut self) -> Self {
+        self.strip = true;
+        self
+    }
+
+    // Dependencies given with `with_artifact` must already be built for `system`. Other systems
+    // build inside their rootfs, or on the host with the `cross-cc` variable, see `zig_cc`.
+    pub fn with_target(mut self, system: ArtifactSystem) -> Self {
+        self.target = Some(system);
+        self
+    }
+
+    // Names the build in its aliases, see `variant::aliases`; the artifact passes the flags that
+    // select the variant itself. `Linkage::Musl` also builds with the musl toolchain.
+    pub fn with_variant(mut self, variant: Variant, linkage: Linkage) -> Self {
+        self.variant = (variant, linkage);
+        self
+    }
+
+    // See `aliases::versioned` for the aliases this drops.
+    pub fn without_version_aliases(mut self) -> Self {
+        self.version_aliases = false;
+        self
+    }
+
+    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        let name = self.name;
+        let version = self.version;
+
+        let (variant, linkage) = self.variant;
+
+        let aliases = match self.version_aliases {
+            true => variant::aliases(name, version, variant, linkage),
+            false => vec![variant::alias(name, version, variant, linkage)],
+        };
+
+        let mut dependency_env = DependencyEnv::new(&self.artifacts);
+
+        for cppflags in self.cppflags.iter() {
+            dependency_env = dependency_env.with_cppflags(cppflags);
+        }
+
+        let dependency_env = dependency_env.script();
+
+        let mut exports = vec![deterministic::script(system)];
+
+        if !dependency_env.is_empty() {
+            exports.push(dependency_env);
+        }
+
+        for (key, value) in self.environments.iter() {
+            exports.push(format!("export {key}=\"{value}\""));
+        }
+
+        let mut step_artifacts = self.artifacts.clone();
+
+        if linkage == Linkage::Musl {
+            let toolchain = musl::toolchain(context, system).await?;
+
+            exports.push(musl::script(&toolchain));
+
+            step_artifacts.push(toolchain);
+        }
+
+        // Zig cross builds run every step on the host; their binaries cannot run there, so the
+        // check is skipped.
+        let zig_cc = zig_cc::toolchain(context, system).await?;
+
+        let step_system = match zig_cc {
+            Some(_) => context.get_system(),
+            None => system,
+        };
+
+        let mut configure_flags = vec!["--prefix=\"$VORPAL_OUTPUT\"".to_string()];
+
+        if let Some(zig_cc) = zig_cc.as_ref() {
+            exports.push(zig_cc.script());
+            exports.push(zig_cc.configure_script());
+
+            configure_flags.push("$configure_host".to_string());
+
+            step_artifacts.extend(zig_cc.artifacts());
+        }
+
+        let mut binds = vec![];
+        let mut secrets = vec![];
+        let mut stop_script = String::new();
+
+        if let Some(compiler_cache) = self.compiler_cache {
+            let command = compiler_cache.command();
+
+            binds = compiler_cache.binds(context);
+            secrets = compiler_cache.secrets(context);
+
+            exports.push(compiler_cache.script(context));
+            exports.push(format!("export CC=\"{command} ${{CC:-cc}}\""));
+            exports.push(format!("export CXX=\"{command} ${{CXX:-c++}}\""));
+
+            step_artifacts.push(compiler_cache.artifact().to_string());
+
+            stop_script = compiler_cache.stop_script();
+        }
+
+        let exports = exports.join("\n");
+
+        configure_flags.extend(self.configure_flags.iter().cloned());
+
+        let configure_flags = configure_flags.join(" \\\n    ");
+
+        let patch_script = patch::script(name, self.source_directory, &self.patches);
+
+        let make_jobs = jobs::make_flag(jobs::limit(context, self.jobs)?);
+
+        let step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT\"
+
+            {patch_script}
+
+            pushd ./source/{name}/{source_directory}
+
+            {exports}
+
+            ./configure \\
+                {configure_flags}
+
+            make {make_jobs}
+            make install
+            {stop_script}",
+            source_directory = self.source_directory,
+        };
+
+        let mut steps = vec![
+            target::shell_with_binds(
+                context,
+                step_system,
+                step_artifacts,
+                vec![],
+                step_script,
+                &binds,
+                secrets,
+            )
+            .await?,
+        ];
+
+        let prune = match self.prune_enabled {
+            true => self.prune.as_slice(),
+            false => &[],
+        };
+
+        if self.strip || !prune.is_empty() {
+            steps.push(prune::step(context, step_system, self.strip, prune).await?);
+        }
+
+        if self.dylib_audit {
+            steps.extend(dylib_audit::step(context, system, &self.artifacts).await?);
+        }
+
+        if self.assert_linkage || !self.assert_outputs.is_empty() {
+            steps.push(
+                outputs::step(
+                    context,
+                    step_system,
+                    &self.assert_outputs,
+                    self.assert_linkage,
+                )
+                .await?,
+            );
+        }
+
+        if let Some((command, expected)) = self.check {
+            if check::enabled(context, system, self.check_enabled)? {
+                steps.push(check::step(context, system, self.artifacts, command, expected).await?);
+            }
+        }
+
+        target::build(
+            context,
+            system,
+            name,
+            aliases,
+            std::iter::once(self.source)
+                .chain(patch::source(name, &self.patches))
+                .collect(),
+            steps,
+            self.systems,
+        )
+        .await
+    }
+}
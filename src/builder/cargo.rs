@@ -0,0 +1,325 @@
+use crate::{
+    aliases,
+    builder::{check, jobs},
+    fork::{self, Fork},
+    source,
+};
+use anyhow::Result;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api,
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Linux, X8664Linux},
+    },
+    artifact::{get_env_key, rust_toolchain, rust_toolchain::RustToolchain, step, Artifact},
+    context::ConfigContext,
+};
+
+pub struct CargoPackage<'a> {
+    binaries: Vec<&'a str>,
+    check: Option<(&'a str, &'a str)>,
+    check_enabled: Option<bool>,
+    crate_name: Option<&'a str>,
+    default_features: bool,
+    features: Vec<String>,
+    jobs: Option<usize>,
+    name: &'a str,
+    rust_toolchain: Option<&'a str>,
+    source: Option<(api::artifact::ArtifactSource, &'a str)>,
+    source_ref: Option<(&'a str, &'a str)>,
+    static_linking: bool,
+    systems: Vec<ArtifactSystem>,
+    version: &'a str,
+    version_aliases: bool,
+}
+
+fn musl_target(system: ArtifactSystem) -> Option<&'static str> {
+    match system {
+        Aarch64Linux => Some("aarch64-unknown-linux-musl"),
+        X8664Linux => Some("x86_64-unknown-linux-musl"),
+        _ => None,
+    }
+}
+
+// The pinned toolchain only carries the host standard library, so static Linux builds pull the
+// matching musl rust-std into its own sysroot.
+async fn build_musl_std(context: &mut ConfigContext, target: &str) -> Result<String> {
+    let name = "rust-std-musl";
+    let version = rust_toolchain::version();
+
+    let path = format!("https://static.rust-lang.org/dist/rust-std-{version}-{target}.tar.gz");
+
+    let step_script = format!(
+        "cp -pr \"./source/{name}/rust-std-{version}-{target}/rust-std-{target}/.\" \"$VORPAL_OUTPUT\""
+    );
+
+    let steps = vec![step::shell(context, vec![], vec![], step_script, vec![]).await?];
+
+    Artifact::new(name, steps, vec![Aarch64Linux, X8664Linux])
+        .with_aliases(vec![format!("{name}:{version}")])
+        .with_sources(vec![source::new(name, &path)])
+        .build(context)
+        .await
+}
+
+impl<'a> CargoPackage<'a> {
+    pub fn new(name: &'a str, version: &'a str, systems: Vec<ArtifactSystem>) -> Self {
+        Self {
+            binaries: vec![],
+            check: None,
+            check_enabled: None,
+            crate_name: None,
+            default_features: true,
+            features: vec![],
+            jobs: None,
+            name,
+            rust_toolchain: None,
+            source: None,
+            source_ref: None,
+            static_linking: true,
+            systems,
+            version,
+            version_aliases: true,
+        }
+    }
+
+    pub fn with_binary(mut self, binary: &'a str) -> Self {
+        self.binaries.push(binary);
+        self
+    }
+
+    pub fn with_check(mut self, command: &'a str, expected: &'a str) -> Self {
+        self.check = Some((command, expected));
+        self
+    }
+
+    // Turns the `with_check` step on or off, see `check::enabled`; `None` leaves it to the `check`
+    // variable.
+    pub fn with_check_enabled(mut self, check: Option<bool>) -> Self {
+        self.check_enabled = check;
+        self
+    }
+
+    pub fn with_crate(mut self, crate_name: &'a str) -> Self {
+        self.crate_name = Some(crate_name);
+        self
+    }
+
+    pub fn with_feature(mut self, feature: &str) -> Self {
+        self.features.push(feature.to_string());
+        self
+    }
+
+    // Caps parallel jobs for this build, see `jobs::limit`.
+    pub fn with_jobs(mut self, jobs: usize) -> Self {
+        self.jobs = Some(jobs);
+        self
+    }
+
+    pub fn with_rust_toolchain(mut self, rust_toolchain: &'a str) -> Self {
+        self.rust_toolchain = Some(rust_toolchain);
+        self
+    }
+
+    // Replaces the crates.io archive, e.g. with a git tag archive; `directory` is the path of
+    // the package inside it.
+    pub fn with_source(
+        mut self,
+        source: api::artifact::ArtifactSource,
+        directory: &'a str,
+    ) -> Self {
+        self.source = Some((source, directory));
+        self
+    }
+
+    // Builds `repository` on GitHub at a branch or commit instead of the crates.io release, see
+    // `source::github_ref`. The build is tagged `name:<ref>` alone, as it is not the release
+    // `version` names.
+    pub fn with_source_ref(mut self, repository: &'a str, reference: &'a str) -> Self {
+        self.source_ref = Some((repository, reference));
+        self
+    }
+
+    pub fn without_default_features(mut self) -> Self {
+        self.default_features = false;
+        self
+    }
+
+    // For crates whose C dependencies cannot be linked against musl.
+    pub fn without_static_linking(mut self) -> Self {
+        self.static_linking = false;
+        self
+    }
+
+    // See `aliases::versioned` for the aliases this drops.
+    pub fn without_version_aliases(mut self) -> Self {
+        self.version_aliases = false;
+        self
+    }
+
+    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name;
+        let version = self.version;
+
+        let aliases = match (self.source_ref, self.version_aliases) {
+            (Some((_, reference)), _) => vec![format!("{name}:{reference}")],
+            (None, true) => aliases::versioned(name, version),
+            (None, false) => vec![format!("{name}:{version}")],
+        };
+
+        let (source, source_directory) = match (self.source, self.source_ref) {
+            (Some((source, directory)), _) => (source, directory.to_string()),
+            (None, Some((repository, reference))) => {
+                source::github_ref(name, repository, reference)?
+            }
+            (None, None) => {
+                let crate_name = self.crate_name.unwrap_or(name);
+                let path = format!(
+                    "https://static.crates.io/crates/{crate_name}/{crate_name}-{version}.crate"
+                );
+
+                (source::new(name, &path), format!("{crate_name}-{version}"))
+            }
+        };
+
+        let musl_target = musl_target(context.get_system()).filter(|_| self.static_linking);
+
+        // The toolchain and the musl standard library are independent, so both are prepared
+        // concurrently on forks of the context.
+        let (rust_toolchain, musl_std) = tokio::try_join!(
+            async {
+                if let Some(digest) = self.rust_toolchain {
+                    return Ok(Fork::resolved(digest));
+                }
+
+                let mut fork = context.clone();
+                let digest = RustToolchain::new().build(&mut fork).await?;
+
+                Ok::<_, anyhow::Error>(Fork::new(fork, digest))
+            },
+            async {
+                let Some(target) = musl_target else {
+                    return Ok(None);
+                };
+
+                let mut fork = context.clone();
+                let digest = build_musl_std(&mut fork, target).await?;
+
+                Ok(Some(Fork::new(fork, digest)))
+            },
+        )?;
+
+        let [rust_toolchain] = fork::join(context, [rust_toolchain]).await?;
+
+        let musl_std = match musl_std {
+            Some(musl_std) => {
+                let [musl_std] = fork::join(context, [musl_std]).await?;
+                Some(musl_std)
+            }
+            None => None,
+        };
+
+        let rust_toolchain_target = rust_toolchain::target(context.get_system())?;
+        let rust_toolchain_name =
+            format!("{}-{}", rust_toolchain::version(), rust_toolchain_target);
+        let rust_toolchain_env = get_env_key(&rust_toolchain);
+
+        let mut step_artifacts = vec![rust_toolchain.clone()];
+
+        let mut step_environments = vec![
+            "CARGO_HOME=$VORPAL_WORKSPACE/cargo".to_string(),
+            format!("PATH={rust_toolchain_env}/toolchains/{rust_toolchain_name}/bin"),
+            format!("RUSTUP_HOME={rust_toolchain_env}"),
+            format!("RUSTUP_TOOLCHAIN={rust_toolchain_name}"),
+        ];
+
+        let mut install_flags = vec!["--locked".to_string(), "--path .".to_string()];
+
+        if let (Some(target), Some(musl_std)) = (musl_target, musl_std) {
+            // Only target crates get the musl sysroot; build scripts and proc macros keep
+            // compiling against the host standard library.
+            step_environments.push(format!(
+                "CARGO_TARGET_{}_RUSTFLAGS=--sysroot {} -C target-feature=+crt-static",
+                target.to_uppercase().replace('-', "_"),
+                get_env_key(&musl_std),
+            ));
+
+            step_artifacts.push(musl_std);
+
+            install_flags.push(format!("--target {target}"));
+        }
+
+        if !self.default_features {
+            install_flags.push("--no-default-features".to_string());
+        }
+
+        if !self.features.is_empty() {
+            install_flags.push(format!("--features \"{}\"", self.features.join(",")));
+        }
+
+        if let Some(jobs) = jobs::limit(context, self.jobs)? {
+            install_flags.push(format!("--jobs {jobs}"));
+        }
+
+        for binary in self.binaries.iter() {
+            install_flags.push(format!("--bin {binary}"));
+        }
+
+        let mut step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT/bin\"
+
+            pushd ./source/{name}/{source_directory}
+
+            cargo install \\
+                --root \"$VORPAL_OUTPUT\" \\
+                {install_flags}
+
+            rm -f \"$VORPAL_OUTPUT/.crates.toml\" \"$VORPAL_OUTPUT/.crates2.json\"",
+            install_flags = install_flags.join(" \\\n    "),
+        };
+
+        // Dependencies come from the crates.io index, which a mirror replaces through cargo's
+        // source replacement; it has no environment variable.
+        if let Some(index) = source::mirrored(source::CRATES_IO_INDEX) {
+            step_script = formatdoc! {"
+                mkdir -pv \"$CARGO_HOME\"
+
+                cat > \"$CARGO_HOME/config.toml\" <<'EOF'
+                [source.crates-io]
+                replace-with = \"mirror\"
+
+                [source.mirror]
+                registry = \"sparse+{index}\"
+                EOF
+
+                {step_script}",
+            };
+        }
+
+        let mut steps = vec![
+            step::shell(
+                context,
+                step_artifacts,
+                step_environments,
+                step_script,
+                vec![],
+            )
+            .await?,
+        ];
+
+        if let Some((command, expected)) = self.check {
+            let system = context.get_system();
+
+            if check::enabled(context, system, self.check_enabled)? {
+                steps.push(check::step(context, system, vec![], command, expected).await?);
+            }
+        }
+
+        Artifact::new(name, steps, self.systems)
+            .with_aliases(aliases)
+            .with_sources(vec![source])
+            .build(context)
+            .await
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{error::ArtifactError, target};
+use anyhow::Result;
+use indoc::formatdoc;
+use vorpal_sdk::{api, api::artifact::ArtifactSystem, context::ConfigContext};
+
+// Config variable that turns on the checks of every artifact that defines one, e.g.
+// `--variable check=true` in CI. An artifact's own `with_check`/`without_check` wins over it.
+pub const VARIABLE: &str = "check";
+
+// Whether an artifact appends its check step. Checks are opt-in, and never run for a `system`
+// other than the host's, whose binaries the step cannot execute.
+pub fn enabled(
+    context: &ConfigContext,
+    system: ArtifactSystem,
+    check: Option<bool>,
+) -> Result<bool> {
+    if system != context.get_system() {
+        return Ok(false);
+    }
+
+    if let Some(check) = check {
+        return Ok(check);
+    }
+
+    match context.get_variable(VARIABLE).as_deref() {
+        None | Some("false") => Ok(false),
+        Some("true") => Ok(true),
+        Some(value) => Err(ArtifactError::InvalidVariable {
+            variable: VARIABLE.to_string(),
+            value: value.to_string(),
+        }
+        .into()),
+    }
+}
+
+// Runs `command` (relative to the artifact output, e.g. `bin/tool --version`) after the build
+// steps and fails the build unless it exits successfully and, when `expected` is not empty,
+// prints it. Callers append it only when `enabled` says so.
+pub async fn step(
+    context: &mut ConfigContext,
+    system: ArtifactSystem,
+    artifacts: Vec<String>,
+    command: &str,
+    expected: &str,
+) -> Result<api::artifact::ArtifactStep> {
+    let step_script = formatdoc! {"
+        if ! output=\"$(\"$VORPAL_OUTPUT\"/{command} 2>&1)\"; then
+            echo \"check failed: {command}\" >&2
+            echo \"$output\" >&2
+            exit 1
+        fi
+
+        echo \"$output\"
+
+        case \"$output\" in
+            *\"{expected}\"*) ;;
+            *)
+                echo \"check failed: expected '{expected}' in output of {command}\" >&2
+                exit 1
+                ;;
+        esac",
+    };
+
+    target::shell(context, system, artifacts, vec![], step_script).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockContext;
+    use vorpal_sdk::api::artifact::ArtifactSystem::{Aarch64Darwin, X8664Linux};
+
+    #[tokio::test]
+    async fn enabled_is_opt_in_and_host_only() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Darwin).build().await?;
+
+        assert!(!enabled(context, Aarch64Darwin, None)?);
+        assert!(enabled(context, Aarch64Darwin, Some(true))?);
+        assert!(!enabled(context, X8664Linux, Some(true))?);
+
+        let context = &mut MockContext::new(Aarch64Darwin)
+            .with_variable(VARIABLE, "true")
+            .build()
+            .await?;
+
+        assert!(enabled(context, Aarch64Darwin, None)?);
+        assert!(!enabled(context, Aarch64Darwin, Some(false))?);
+
+        let context = &mut MockContext::new(Aarch64Darwin)
+            .with_variable(VARIABLE, "yes")
+            .build()
+            .await?;
+
+        assert!(enabled(context, Aarch64Darwin, None).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,389 @@
+use crate::{
+    artifact::{cmake::Cmake, ArtifactPackage},
+    builder::{
+        check, compiler_cache::CompilerCache, deterministic, dylib_audit, jobs, musl, outputs,
+        patch, prune, zig_cc,
+    },
+    context_cache, target,
+    variant::{self, Linkage, Variant},
+};
+use anyhow::Result;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api, api::artifact::ArtifactSystem, artifact::get_env_key, context::ConfigContext,
+};
+
+pub struct CmakePackage<'a> {
+    artifacts: Vec<String>,
+    assert_linkage: bool,
+    assert_outputs: Vec<String>,
+    check: Option<(&'a str, &'a str)>,
+    check_enabled: Option<bool>,
+    cmake: Option<&'a str>,
+    compiler_cache: Option<CompilerCache>,
+    definitions: Vec<String>,
+    dylib_audit: bool,
+    jobs: Option<usize>,
+    name: &'a str,
+    patches: Vec<String>,
+    prune: Vec<String>,
+    prune_enabled: bool,
+    source: api::artifact::ArtifactSource,
+    source_directory: &'a str,
+    strip: bool,
+    source_scripts: Vec<String>,
+    systems: Vec<ArtifactSystem>,
+    target: Option<ArtifactSystem>,
+    variant: (Variant, Linkage),
+    version: &'a str,
+    version_aliases: bool,
+}
+
+impl<'a> CmakePackage<'a> {
+    pub fn new(
+        name: &'a str,
+        version: &'a str,
+        source: api::artifact::ArtifactSource,
+        systems: Vec<ArtifactSystem>,
+    ) -> Self {
+        Self {
+            artifacts: vec![],
+            assert_linkage: false,
+            assert_outputs: vec![],
+            check: None,
+            check_enabled: None,
+            cmake: None,
+            compiler_cache: None,
+            definitions: vec![],
+            dylib_audit: false,
+            jobs: None,
+            name,
+            patches: vec![],
+            prune: vec![],
+            prune_enabled: true,
+            source,
+            source_directory: ".",
+            strip: false,
+            source_scripts: vec![],
+            systems,
+            target: None,
+            variant: (Variant::default(), Linkage::default()),
+            version,
+            version_aliases: true,
+        }
+    }
+
+    // Dependencies are added to the step and to CMAKE_PREFIX_PATH so find_package() sees them.
+    pub fn with_artifact(mut self, artifact: &str) -> Self {
+        self.artifacts.push(artifact.to_string());
+        self
+    }
+
+    // Fails the build when any executable or shared library links against /usr/local or Homebrew,
+    // see `outputs::step`.
+    pub fn with_assert_linkage(mut self) -> Self {
+        self.assert_linkage = true;
+        self
+    }
+
+    // Fails the build when any of `paths`, relative to the artifact output, was not installed.
+    pub fn with_assert_outputs(mut self, paths: Vec<&str>) -> Self {
+        self.assert_outputs
+            .extend(paths.iter().map(|path| path.to_string()));
+        self
+    }
+
+    pub fn with_check(mut self, command: &'a str, expected: &'a str) -> Self {
+        self.check = Some((command, expected));
+        self
+    }
+
+    // Turns the `with_check` step on or off, see `check::enabled`; `None` leaves it to the `check`
+    // variable.
+    pub fn with_check_enabled(mut self, check: Option<bool>) -> Self {
+        self.check_enabled = check;
+        self
+    }
+
+    pub fn with_cmake(mut self, cmake: &'a str) -> Self {
+        self.cmake = Some(cmake);
+        self
+    }
+
+    // Runs the C and C++ compilers through ccache or sccache as the compiler launcher.
+    pub fn with_compiler_cache(mut self, compiler_cache: CompilerCache) -> Self {
+        self.compiler_cache = Some(compiler_cache);
+        self
+    }
+
+    pub fn with_definition(mut self, definition: &str) -> Self {
+        self.definitions.push(definition.to_string());
+        self
+    }
+
+    // Fails the darwin build when a Mach-O file loads a library from outside its dependencies,
+    // see `dylib_audit::step`.
+    pub fn with_dylib_audit(mut self) -> Self {
+        self.dylib_audit = true;
+        self
+    }
+
+    // Caps parallel jobs for this build, see `jobs::limit`.
+    pub fn with_jobs(mut self, jobs: usize) -> Self {
+        self.jobs = Some(jobs);
+        self
+    }
+
+    // Paths are relative to the repository root, e.g. `patches/<artifact>/fix.patch`.
+    pub fn with_patch(mut self, patch: &str) -> Self {
+        self.patches.push(patch.to_string());
+        self
+    }
+
+    // See `prune::DEFAULT` for the usual set of globs.
+    pub fn with_prune(mut self, globs: &[&str]) -> Self {
+        self.prune.extend(globs.iter().map(|glob| glob.to_string()));
+        self
+    }
+
+    // Turns the `with_prune` globs off, e.g. for an artifact's `without_prune`; stripping is
+    // unaffected.
+    pub fn with_prune_enabled(mut self, enabled: bool) -> Self {
+        self.prune_enabled = enabled;
+        self
+    }
+
+    pub fn with_source_directory(mut self, directory: &'a str) -> Self {
+        self.source_directory = directory;
+        self
+    }
+
+    // Runs inside the source directory before configuring, for in-tree fixups.
+    pub fn with_source_script(mut self, script: &str) -> Self {
+        self.source_scripts.push(script.to_string());
+        self
+    }
+
+    pub fn with_strip(mut self) -> Self {
+        self.strip = true;
+        self
+    }
+
+    // Dependencies given with `with_artifact` must already be built for `system`. Other systems
+    // build inside their rootfs, or on the host with the `cross-cc` variable, see `zig_cc`.
+    pub fn with_target(mut self, system: ArtifactSystem) -> Self {
+        self.target = Some(system);
+        self
+    }
+
+    // Names the build in its aliases, see `variant::aliases`; the artifact passes the flags that
+    // select the variant itself. `Linkage::Musl` also builds with the musl toolchain, which has no
+    // C++ compiler.
+    pub fn with_variant(mut self, variant: Variant, linkage: Linkage) -> Self {
+        self.variant = (variant, linkage);
+        self
+    }
+
+    // See `aliases::versioned` for the aliases this drops.
+    pub fn without_version_aliases(mut self) -> Self {
+        self.version_aliases = false;
+        self
+    }
+
+    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
+        let system = target::system(context, self.target);
+
+        // Zig cross builds run every step, cmake included, on the host; their binaries cannot run
+        // there, so the check is skipped.
+        let zig_cc = zig_cc::toolchain(context, system).await?;
+
+        let step_system = match zig_cc {
+            Some(_) => context.get_system(),
+            None => system,
+        };
+
+        let cmake = match self.cmake {
+            Some(val) => val.to_string(),
+            None if step_system == context.get_system() => {
+                context_cache::build(context, &Cmake::new()).await?
+            }
+            None => Cmake::new().with_target(system).build(context).await?,
+        };
+
+        let name = self.name;
+        let version = self.version;
+
+        let (variant, linkage) = self.variant;
+
+        let aliases = match self.version_aliases {
+            true => variant::aliases(name, version, variant, linkage),
+            false => vec![variant::alias(name, version, variant, linkage)],
+        };
+
+        let mut definitions = vec![
+            "CMAKE_BUILD_TYPE=RELEASE".to_string(),
+            "CMAKE_INSTALL_PREFIX=\"$VORPAL_OUTPUT\"".to_string(),
+        ];
+
+        if !self.artifacts.is_empty() {
+            let prefix_path = self
+                .artifacts
+                .iter()
+                .map(get_env_key)
+                .collect::<Vec<_>>()
+                .join(";");
+
+            definitions.push(format!("CMAKE_PREFIX_PATH=\"{prefix_path}\""));
+        }
+
+        let mut musl_artifact = None;
+        let mut musl_script = String::new();
+
+        if linkage == Linkage::Musl {
+            let toolchain = musl::toolchain(context, system).await?;
+
+            musl_script = musl::script(&toolchain);
+            musl_artifact = Some(toolchain);
+        }
+
+        let mut zig_cc_artifacts = vec![];
+
+        // The definitions name the shims directly, so the build needs no exports.
+        if let Some(zig_cc) = zig_cc.as_ref() {
+            definitions.extend(zig_cc.cmake_definitions());
+
+            zig_cc_artifacts = zig_cc.artifacts();
+        }
+
+        let mut cache_artifacts = vec![];
+        let mut binds = vec![];
+        let mut secrets = vec![];
+        let mut cache_script = String::new();
+        let mut stop_script = String::new();
+
+        if let Some(compiler_cache) = self.compiler_cache {
+            let command = compiler_cache.command();
+
+            binds = compiler_cache.binds(context);
+            secrets = compiler_cache.secrets(context);
+
+            definitions.push(format!("CMAKE_C_COMPILER_LAUNCHER=\"{command}\""));
+            definitions.push(format!("CMAKE_CXX_COMPILER_LAUNCHER=\"{command}\""));
+
+            cache_artifacts.push(compiler_cache.artifact().to_string());
+            cache_script = compiler_cache.script(context);
+            stop_script = compiler_cache.stop_script();
+        }
+
+        definitions.extend(self.definitions);
+
+        let definitions = definitions
+            .iter()
+            .map(|definition| format!("-D{definition} \\"))
+            .collect::<Vec<_>>()
+            .join("\n    ");
+
+        let source_scripts = self.source_scripts.join("\n");
+
+        let patch_script = patch::script(name, self.source_directory, &self.patches);
+
+        let deterministic_script = deterministic::script(system);
+
+        let make_jobs = jobs::make_flag(jobs::limit(context, self.jobs)?);
+
+        let step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT\"
+
+            {deterministic_script}
+            {musl_script}
+            {cache_script}
+
+            {patch_script}
+
+            SOURCE_DIR=\"$(pwd)/source/{name}/{source_directory}\"
+
+            pushd \"$SOURCE_DIR\"
+            {source_scripts}
+            popd
+
+            BUILD_DIR=\"$(pwd)/build\"
+            mkdir -p \"$BUILD_DIR\"
+
+            pushd \"$BUILD_DIR\"
+
+            {cmake}/bin/cmake \\
+                {definitions}
+                \"$SOURCE_DIR\"
+
+            make {make_jobs} install
+            {stop_script}
+            popd",
+            cmake = get_env_key(&cmake),
+            source_directory = self.source_directory,
+        };
+
+        let step_artifacts = std::iter::once(cmake)
+            .chain(self.artifacts.iter().cloned())
+            .chain(cache_artifacts)
+            .chain(musl_artifact)
+            .chain(zig_cc_artifacts)
+            .collect();
+
+        let mut steps = vec![
+            target::shell_with_binds(
+                context,
+                step_system,
+                step_artifacts,
+                vec![],
+                step_script,
+                &binds,
+                secrets,
+            )
+            .await?,
+        ];
+
+        let prune = match self.prune_enabled {
+            true => self.prune.as_slice(),
+            false => &[],
+        };
+
+        if self.strip || !prune.is_empty() {
+            steps.push(prune::step(context, step_system, self.strip, prune).await?);
+        }
+
+        if self.dylib_audit {
+            steps.extend(dylib_audit::step(context, system, &self.artifacts).await?);
+        }
+
+        if self.assert_linkage || !self.assert_outputs.is_empty() {
+            steps.push(
+                outputs::step(
+                    context,
+                    step_system,
+                    &self.assert_outputs,
+                    self.assert_linkage,
+                )
+                .await?,
+            );
+        }
+
+        if let Some((command, expected)) = self.check {
+            if check::enabled(context, system, self.check_enabled)? {
+                steps.push(check::step(context, system, self.artifacts, command, expected).await?);
+            }
+        }
+
+        target::build(
+            context,
+            system,
+            name,
+            aliases,
+            std::iter::once(self.source)
+                .chain(patch::source(name, &self.patches))
+                .collect(),
+            steps,
+            self.systems,
+        )
+        .await
+    }
+}
@@ -0,0 +1,167 @@
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::ArtifactStepSecret, artifact::get_env_key, context::ConfigContext,
+};
+
+// Config variable naming the cache directory for builds that do not set one with
+// `with_directory`, e.g. `--variable compiler-cache-dir=/var/cache/vorpal` on a worker that
+// keeps it between builds. The directory must exist on the worker; Linux steps bind it into
+// their sandbox, see `target::shell_with_binds`.
+pub const VARIABLE: &str = "compiler-cache-dir";
+
+// Secret carrying the host cache directory, so the path stays out of the step and its digest.
+const DIRECTORY_SECRET: &str = "VORPAL_COMPILER_CACHE_DIR";
+
+// A ccache or sccache artifact that `AutotoolsPackage` and `CmakePackage` put in front of the C
+// and C++ compilers. Cached builds have different digests from uncached ones, since the wrapper
+// is part of the step script, but the host directory and backend settings are passed as step
+// secrets, so workers with different caches still agree on digests.
+pub struct CompilerCache {
+    artifact: String,
+    binary: &'static str,
+    directory: Option<String>,
+    directory_key: &'static str,
+    environments: Vec<(String, String)>,
+}
+
+impl CompilerCache {
+    pub fn ccache(artifact: &str) -> Self {
+        Self {
+            artifact: artifact.to_string(),
+            binary: "ccache",
+            directory: None,
+            directory_key: "CCACHE_DIR",
+            environments: vec![],
+        }
+    }
+
+    pub fn sccache(artifact: &str) -> Self {
+        Self {
+            artifact: artifact.to_string(),
+            binary: "sccache",
+            directory: None,
+            directory_key: "SCCACHE_DIR",
+            environments: vec![],
+        }
+    }
+
+    // Host cache directory, bound into the build step. Without it (or the `compiler-cache-dir`
+    // variable) the cache lives in the step workspace and only helps within a single build.
+    pub fn with_directory(mut self, directory: &str) -> Self {
+        self.directory = Some(directory.to_string());
+        self
+    }
+
+    // Extra settings, e.g. `SCCACHE_BUCKET` and `SCCACHE_REGION` for sccache's S3 backend, or
+    // credentials; they reach the step as secrets.
+    pub fn with_environment(mut self, key: &str, value: &str) -> Self {
+        self.environments.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn artifact(&self) -> &str {
+        &self.artifact
+    }
+
+    pub fn command(&self) -> String {
+        format!("{}/bin/{}", get_env_key(&self.artifact), self.binary)
+    }
+
+    fn host_directory(&self, context: &ConfigContext) -> Option<String> {
+        self.directory
+            .clone()
+            .or_else(|| context.get_variable(VARIABLE))
+    }
+
+    // Host directories the build step must bind for the cache to outlive it, named by the secret
+    // that holds them.
+    pub fn binds(&self, context: &ConfigContext) -> Vec<String> {
+        self.host_directory(context)
+            .map(|_| format!("${DIRECTORY_SECRET}"))
+            .into_iter()
+            .collect()
+    }
+
+    // The host directory and settings, for the build step's secrets.
+    pub fn secrets(&self, context: &ConfigContext) -> Vec<ArtifactStepSecret> {
+        self.host_directory(context)
+            .map(|directory| (DIRECTORY_SECRET.to_string(), directory))
+            .into_iter()
+            .chain(self.environments.iter().cloned())
+            .map(|(name, value)| ArtifactStepSecret { name, value })
+            .collect()
+    }
+
+    // Points the cache at the host directory secret, or the step workspace without one. The
+    // settings need no exports, as secrets are already in the step's environment.
+    pub fn script(&self, context: &ConfigContext) -> String {
+        let directory = match self.host_directory(context) {
+            Some(_) => format!("${DIRECTORY_SECRET}"),
+            None => "$VORPAL_WORKSPACE/compiler-cache".to_string(),
+        };
+
+        formatdoc! {"
+            export {directory_key}=\"{directory}\"
+            mkdir -p \"${directory_key}\"",
+            directory_key = self.directory_key,
+        }
+    }
+
+    // sccache compiles through a background server, which is stopped so it does not outlive the
+    // step.
+    pub fn stop_script(&self) -> String {
+        match self.binary {
+            "sccache" => format!("\"{}\" --stop-server || true", self.command()),
+            _ => String::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockContext;
+    use anyhow::Result;
+    use vorpal_sdk::api::artifact::ArtifactSystem::Aarch64Linux;
+
+    #[tokio::test]
+    async fn script_prefers_explicit_directory() -> Result<()> {
+        let context = MockContext::new(Aarch64Linux)
+            .with_variable(VARIABLE, "/var/cache/vorpal")
+            .build()
+            .await?;
+
+        let sccache =
+            CompilerCache::sccache("sccache-digest").with_environment("SCCACHE_BUCKET", "cache");
+
+        assert!(sccache
+            .script(&context)
+            .contains("export SCCACHE_DIR=\"$VORPAL_COMPILER_CACHE_DIR\""));
+        assert!(!sccache.script(&context).contains("/var/cache/vorpal"));
+        assert!(!sccache.script(&context).contains("SCCACHE_BUCKET"));
+
+        let ccache = CompilerCache::ccache("ccache-digest").with_directory("/tmp/ccache");
+
+        assert_eq!(ccache.binds(&context), ["$VORPAL_COMPILER_CACHE_DIR"]);
+        assert_eq!(
+            ccache.secrets(&context),
+            [ArtifactStepSecret {
+                name: "VORPAL_COMPILER_CACHE_DIR".to_string(),
+                value: "/tmp/ccache".to_string(),
+            }]
+        );
+        assert!(ccache.stop_script().is_empty());
+
+        let context = MockContext::new(Aarch64Linux).build().await?;
+
+        let sccache = CompilerCache::sccache("sccache-digest");
+
+        assert!(sccache.binds(&context).is_empty());
+        assert!(sccache.secrets(&context).is_empty());
+        assert!(sccache
+            .script(&context)
+            .contains("export SCCACHE_DIR=\"$VORPAL_WORKSPACE/compiler-cache\""));
+
+        Ok(())
+    }
+}
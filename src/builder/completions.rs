@@ -0,0 +1,123 @@
+use indoc::formatdoc;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Shell {
+    Bash,
+    Fish,
+    Zsh,
+}
+
+impl Shell {
+    pub const ALL: [Shell; 3] = [Shell::Bash, Shell::Fish, Shell::Zsh];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Shell::Bash => "bash",
+            Shell::Fish => "fish",
+            Shell::Zsh => "zsh",
+        }
+    }
+
+    // Where bash-completion, fish and zsh (through an `fpath` entry) look up completions for
+    // `command`, relative to the artifact output. `Toolset` links these through `share`.
+    pub fn destination(self, command: &str) -> String {
+        match self {
+            Shell::Bash => format!("share/bash-completion/completions/{command}"),
+            Shell::Fish => format!("share/fish/vendor_completions.d/{command}.fish"),
+            Shell::Zsh => format!("share/zsh/site-functions/_{command}"),
+        }
+    }
+}
+
+// `share/man/man<section>/<page>`, with the section read from the file name (`tool.1` or
+// `tool.1.gz`); pages without a numeric section go to `man1`.
+pub fn man_page_destination(path: &str) -> String {
+    let page = path.rsplit('/').next().unwrap_or(path);
+
+    let section = page
+        .trim_end_matches(".gz")
+        .rsplit_once('.')
+        .map(|(_, section)| section)
+        .filter(|section| section.starts_with(|c: char| c.is_ascii_digit()))
+        .unwrap_or("1");
+
+    format!("share/man/man{section}/{page}")
+}
+
+// Script that installs completions and man pages shipped with a release, e.g. the
+// `completions/` and `manpages/` directories in goreleaser archives. Paths are relative to the
+// directory the script runs in.
+pub fn install(command: &str, completions: &[(Shell, String)], man_pages: &[String]) -> String {
+    completions
+        .iter()
+        .map(|(shell, path)| (path, shell.destination(command)))
+        .chain(
+            man_pages
+                .iter()
+                .map(|path| (path, man_page_destination(path))),
+        )
+        .map(|(path, destination)| {
+            formatdoc! {"
+                mkdir -pv \"$(dirname \"$VORPAL_OUTPUT/{destination}\")\"
+                cp -v {path} \"$VORPAL_OUTPUT/{destination}\"",
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// Script that generates completions for every shell by running the installed `bin/<command>`
+// with `arguments`, where `{shell}` expands to the shell name (e.g. `completion {shell}` for
+// cobra-based tools). HOME points at a scratch directory since some tools create their config
+// directory on startup.
+pub fn generate(command: &str, arguments: &str) -> String {
+    let scripts = Shell::ALL
+        .iter()
+        .map(|shell| {
+            formatdoc! {"
+                mkdir -pv \"$(dirname \"$VORPAL_OUTPUT/{destination}\")\"
+                HOME=\"$COMPLETIONS_HOME\" \"$VORPAL_OUTPUT/bin/{command}\" {arguments} > \"$VORPAL_OUTPUT/{destination}\"",
+                arguments = arguments.replace("{shell}", shell.as_str()),
+                destination = shell.destination(command),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    formatdoc! {"
+        COMPLETIONS_HOME=\"$(mktemp -d)\"
+        {scripts}
+        rm -rf \"$COMPLETIONS_HOME\"",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn man_page_destination_uses_section() {
+        assert_eq!(
+            man_page_destination("gum_0.17.0_Linux_x86_64/manpages/gum.1.gz"),
+            "share/man/man1/gum.1.gz"
+        );
+        assert_eq!(
+            man_page_destination("docs/tool.conf.5"),
+            "share/man/man5/tool.conf.5"
+        );
+        assert_eq!(man_page_destination("tool.man"), "share/man/man1/tool.man");
+    }
+
+    #[test]
+    fn generate_expands_shell() {
+        let script = generate("kubectl", "completion {shell}");
+
+        for shell in Shell::ALL {
+            assert!(script.contains(&format!(
+                "\"$VORPAL_OUTPUT/bin/kubectl\" completion {} > \"$VORPAL_OUTPUT/{}\"",
+                shell.as_str(),
+                shell.destination("kubectl")
+            )));
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::target;
+use anyhow::Result;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api,
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, X8664Darwin},
+    },
+    context::ConfigContext,
+};
+
+// Step for prebuilt darwin artifacts that Gatekeeper refuses to run: it clears the
+// `com.apple.quarantine` attribute a download may have left on the output and ad hoc signs its
+// Mach-O executables and libraries, replacing signatures that did not survive the copy. Other
+// systems need no step.
+pub async fn step(
+    context: &mut ConfigContext,
+    system: ArtifactSystem,
+) -> Result<Option<api::artifact::ArtifactStep>> {
+    if !matches!(system, Aarch64Darwin | X8664Darwin) {
+        return Ok(None);
+    }
+
+    let step_script = formatdoc! {"
+        xattr -r -d com.apple.quarantine \"$VORPAL_OUTPUT\" 2>/dev/null || true
+
+        find \"$VORPAL_OUTPUT\" -type f \\( -perm -u+x -o -name '*.dylib' -o -name '*.so' \\) | while read -r file; do
+            if file -b \"$file\" | grep -q 'Mach-O'; then
+                codesign --force --sign - \"$file\"
+            fi
+        done",
+    };
+
+    Ok(Some(
+        target::shell(context, system, vec![], vec![], step_script).await?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockContext;
+    use vorpal_sdk::api::artifact::ArtifactSystem::Aarch64Linux;
+
+    #[tokio::test]
+    async fn step_is_darwin_only() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Linux).build().await?;
+
+        assert!(step(context, Aarch64Linux).await?.is_none());
+
+        let context = &mut MockContext::new(Aarch64Darwin).build().await?;
+
+        assert!(step(context, Aarch64Darwin).await?.is_some());
+
+        Ok(())
+    }
+}
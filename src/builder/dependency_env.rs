@@ -0,0 +1,104 @@
+use vorpal_sdk::artifact::get_env_key;
+
+// Build environment for a list of dependency artifacts: their `bin` on PATH, `lib/pkgconfig` on
+// PKG_CONFIG_PATH, `include` in CPPFLAGS and `lib` in LDFLAGS, with an rpath so the installed
+// binaries keep resolving them. `AutotoolsPackage` exports it for its `with_artifact`
+// dependencies; hand-written steps export `script` and pass `artifacts` to the step.
+pub struct DependencyEnv {
+    artifacts: Vec<String>,
+    cppflags: Vec<String>,
+}
+
+impl DependencyEnv {
+    pub fn new<S: AsRef<str>>(artifacts: &[S]) -> Self {
+        Self {
+            artifacts: artifacts
+                .iter()
+                .map(|artifact| artifact.as_ref().to_string())
+                .collect(),
+            cppflags: vec![],
+        }
+    }
+
+    // Appended after the dependencies' include directories, e.g. `-I<ncurses>/include/ncursesw`.
+    pub fn with_cppflags(mut self, cppflags: &str) -> Self {
+        self.cppflags.push(cppflags.to_string());
+        self
+    }
+
+    pub fn artifacts(&self) -> Vec<String> {
+        self.artifacts.clone()
+    }
+
+    // Export lines, empty when there is nothing to export. PATH and PKG_CONFIG_PATH keep the
+    // values already set, while CPPFLAGS and LDFLAGS are replaced.
+    pub fn script(&self) -> String {
+        let keys = self.artifacts.iter().map(get_env_key).collect::<Vec<_>>();
+
+        let mut exports = vec![];
+
+        if !keys.is_empty() {
+            let path: String = keys.iter().map(|key| format!("{key}/bin:")).collect();
+            let pkg_config_path: String = keys
+                .iter()
+                .map(|key| format!("{key}/lib/pkgconfig:"))
+                .collect();
+
+            exports.push(format!("export PATH=\"{path}${{PATH:-}}\""));
+            exports.push(format!(
+                "export PKG_CONFIG_PATH=\"{pkg_config_path}${{PKG_CONFIG_PATH:-}}\""
+            ));
+        }
+
+        let cppflags = keys
+            .iter()
+            .map(|key| format!("-I{key}/include"))
+            .chain(self.cppflags.iter().cloned())
+            .collect::<Vec<_>>();
+
+        if !cppflags.is_empty() {
+            exports.push(format!("export CPPFLAGS=\"{}\"", cppflags.join(" ")));
+        }
+
+        let ldflags = keys
+            .iter()
+            .map(|key| format!("-L{key}/lib"))
+            .chain(keys.iter().map(|key| format!("-Wl,-rpath,{key}/lib")))
+            .collect::<Vec<_>>();
+
+        if !ldflags.is_empty() {
+            exports.push(format!("export LDFLAGS=\"{}\"", ldflags.join(" ")));
+        }
+
+        exports.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn script_covers_every_dependency() {
+        let ncurses = get_env_key(&"ncurses-digest".to_string());
+        let readline = get_env_key(&"readline-digest".to_string());
+
+        let env = DependencyEnv::new(&["ncurses-digest", "readline-digest"])
+            .with_cppflags(&format!("-I{ncurses}/include/ncursesw"));
+
+        let script = env.script();
+
+        assert!(script.contains(&format!(
+            "export CPPFLAGS=\"-I{ncurses}/include -I{readline}/include -I{ncurses}/include/ncursesw\""
+        )));
+        assert!(script.contains(&format!(
+            "export LDFLAGS=\"-L{ncurses}/lib -L{readline}/lib -Wl,-rpath,{ncurses}/lib -Wl,-rpath,{readline}/lib\""
+        )));
+        assert_eq!(env.artifacts(), ["ncurses-digest", "readline-digest"]);
+    }
+
+    #[test]
+    fn script_is_empty_without_dependencies() {
+        assert!(DependencyEnv::new::<&str>(&[]).script().is_empty());
+    }
+}
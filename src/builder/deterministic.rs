@@ -0,0 +1,49 @@
+use indoc::{formatdoc, indoc};
+use vorpal_sdk::api::artifact::{
+    ArtifactSystem,
+    ArtifactSystem::{Aarch64Darwin, X8664Darwin},
+};
+
+// 1980-01-01, the earliest timestamp zip archives can store.
+pub const SOURCE_DATE_EPOCH: u64 = 315532800;
+
+// Shell prologue for source builds so their outputs do not depend on the build machine: a fixed
+// timestamp, locale, timezone and umask, and static archives written without member timestamps
+// or owners (`D` for GNU ar, `ZERO_AR_DATE` for Apple's). The reusable builders always include it;
+// artifacts with hand-written build scripts should put it before configuring.
+pub fn script(system: ArtifactSystem) -> String {
+    let archives = match system {
+        Aarch64Darwin | X8664Darwin => "export ZERO_AR_DATE=1",
+        _ => indoc! {"
+            export AR_FLAGS=\"crD\"
+            export ARFLAGS=\"crD\""
+        },
+    };
+
+    formatdoc! {"
+        export SOURCE_DATE_EPOCH={SOURCE_DATE_EPOCH}
+        export LC_ALL=C
+        export TZ=UTC
+        umask 022
+        {archives}",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vorpal_sdk::api::artifact::ArtifactSystem::X8664Linux;
+
+    #[test]
+    fn script_picks_archive_flags_per_system() {
+        let darwin = script(Aarch64Darwin);
+        let linux = script(X8664Linux);
+
+        assert!(darwin.contains("export SOURCE_DATE_EPOCH=315532800"));
+        assert!(darwin.contains("export ZERO_AR_DATE=1"));
+        assert!(!darwin.contains("AR_FLAGS"));
+
+        assert!(linux.contains("export AR_FLAGS=\"crD\""));
+        assert!(!linux.contains("ZERO_AR_DATE"));
+    }
+}
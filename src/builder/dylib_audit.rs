@@ -0,0 +1,107 @@
+use crate::target;
+use anyhow::Result;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api,
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, X8664Darwin},
+    },
+    artifact::get_env_key,
+    context::ConfigContext,
+};
+
+// Load paths that are always present on macOS, plus the relative forms that resolve through the
+// rpaths checked alongside them.
+const SYSTEM_PATTERNS: &[&str] = &[
+    "/usr/lib/*",
+    "/System/Library/*",
+    "@executable_path/*",
+    "@loader_path/*",
+    "@rpath/*",
+];
+
+// Step for darwin source builds, which pick up whatever dylibs configure finds on the build
+// machine. It reads the load commands of every Mach-O file in the output with `otool` and fails
+// unless each library and rpath is a system one or lies inside the output or one of `artifacts`,
+// the closure the step was built with. Stricter than `outputs::step`'s linkage check, which only
+// rejects known host prefixes. Other systems need no step.
+pub async fn step(
+    context: &mut ConfigContext,
+    system: ArtifactSystem,
+    artifacts: &[String],
+) -> Result<Option<api::artifact::ArtifactStep>> {
+    if !matches!(system, Aarch64Darwin | X8664Darwin) {
+        return Ok(None);
+    }
+
+    let system_patterns = SYSTEM_PATTERNS.join("|");
+
+    let closure = std::iter::once("\"$VORPAL_OUTPUT\"".to_string())
+        .chain(
+            artifacts
+                .iter()
+                .map(|artifact| format!("\"{}\"", get_env_key(artifact))),
+        )
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let step_script = formatdoc! {"
+        failed=0
+
+        find \"$VORPAL_OUTPUT\" -type f \\( -perm -u+x -o -name '*.dylib' -o -name '*.so' \\) > \"$VORPAL_WORKSPACE/dylib-audit.list\"
+
+        while read -r file; do
+            if ! file -b \"$file\" | grep -q 'Mach-O'; then
+                continue
+            fi
+
+            references=\"$(otool -L \"$file\" | tail -n +2 | awk '{{print $1}}'; otool -l \"$file\" | awk '/cmd LC_RPATH/ {{ getline; getline; print $2 }}')\"
+
+            for reference in $references; do
+                case \"$reference\" in
+                    {system_patterns}) continue ;;
+                esac
+
+                resolved=0
+
+                for prefix in {closure}; do
+                    case \"$reference\" in
+                        \"$prefix\"/*) resolved=1 ;;
+                    esac
+                done
+
+                if [ \"$resolved\" = 0 ]; then
+                    echo \"${{file#$VORPAL_OUTPUT/}} loads $reference from outside the artifact closure\" >&2
+                    failed=1
+                fi
+            done
+        done < \"$VORPAL_WORKSPACE/dylib-audit.list\"
+
+        exit \"$failed\"",
+    };
+
+    Ok(Some(
+        target::shell(context, system, artifacts.to_vec(), vec![], step_script).await?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockContext;
+    use vorpal_sdk::api::artifact::ArtifactSystem::Aarch64Linux;
+
+    #[tokio::test]
+    async fn step_is_darwin_only() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Linux).build().await?;
+
+        assert!(step(context, Aarch64Linux, &[]).await?.is_none());
+
+        let context = &mut MockContext::new(Aarch64Darwin).build().await?;
+
+        assert!(step(context, Aarch64Darwin, &[]).await?.is_some());
+
+        Ok(())
+    }
+}
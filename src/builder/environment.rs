@@ -0,0 +1,177 @@
+use anyhow::Result;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::ArtifactSystem,
+    artifact::{get_env_key, step, Artifact},
+    context::ConfigContext,
+};
+
+// The SDK's `DevelopmentEnvironment` with enter hooks: `bin/activate` backs up and exports the
+// environments, puts every artifact's `bin` on PATH, defines `deactivate` and then runs the
+// hooks. Without hooks the script, and so the digest, is the SDK's.
+pub struct DevelopmentEnvironment<'a> {
+    artifacts: Vec<String>,
+    environments: Vec<String>,
+    hooks: Vec<String>,
+    name: &'a str,
+    systems: Vec<ArtifactSystem>,
+}
+
+impl<'a> DevelopmentEnvironment<'a> {
+    pub fn new(name: &'a str, systems: Vec<ArtifactSystem>) -> Self {
+        Self {
+            artifacts: vec![],
+            environments: vec![],
+            hooks: vec![],
+            name,
+            systems,
+        }
+    }
+
+    pub fn with_artifacts(mut self, artifacts: Vec<String>) -> Self {
+        self.artifacts.extend(artifacts);
+        self
+    }
+
+    // `KEY=value` pairs exported as written; a `PATH` entry is prepended to the artifacts' bins.
+    pub fn with_environments(mut self, environments: Vec<String>) -> Self {
+        self.environments.extend(environments);
+        self
+    }
+
+    // Commands run in order once the environment is active, e.g. `eval "$(direnv hook bash)"`.
+    // `deactivate` does not undo them.
+    pub fn with_hooks(mut self, hooks: Vec<String>) -> Self {
+        self.hooks.extend(hooks);
+        self
+    }
+
+    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
+        let mut backups = vec![
+            "export VORPAL_SHELL_BACKUP_PATH=\"$PATH\"".to_string(),
+            "export VORPAL_SHELL_BACKUP_PS1=\"$PS1\"".to_string(),
+            "export VORPAL_SHELL_BACKUP_VORPAL_SHELL=\"$VORPAL_SHELL\"".to_string(),
+        ];
+
+        let mut exports = vec![
+            format!("export PS1=\"({}) $PS1\"", self.name),
+            "export VORPAL_SHELL=\"1\"".to_string(),
+        ];
+
+        let mut restores = vec![
+            "export PATH=\"$VORPAL_SHELL_BACKUP_PATH\"".to_string(),
+            "export PS1=\"$VORPAL_SHELL_BACKUP_PS1\"".to_string(),
+            "export VORPAL_SHELL=\"$VORPAL_SHELL_BACKUP_VORPAL_SHELL\"".to_string(),
+        ];
+
+        let mut unsets = vec![
+            "unset VORPAL_SHELL_BACKUP_PATH".to_string(),
+            "unset VORPAL_SHELL_BACKUP_PS1".to_string(),
+            "unset VORPAL_SHELL_BACKUP_VORPAL_SHELL".to_string(),
+        ];
+
+        let mut path = self
+            .artifacts
+            .iter()
+            .map(|artifact| format!("{}/bin", get_env_key(artifact)))
+            .collect::<Vec<_>>()
+            .join(":");
+
+        for environment in self.environments.iter() {
+            let (key, value) = environment.split_once('=').unwrap_or((environment, ""));
+
+            if key == "PATH" {
+                path = format!("{value}:{path}");
+                continue;
+            }
+
+            backups.push(format!("export VORPAL_SHELL_BACKUP_{key}=\"${key}\""));
+            exports.push(format!("export {environment}"));
+            restores.push(format!("export {key}=\"$VORPAL_SHELL_BACKUP_{key}\""));
+            unsets.push(format!("unset VORPAL_SHELL_BACKUP_{key}"));
+        }
+
+        exports.push(format!("export PATH={path}:$PATH"));
+
+        let hooks = match self.hooks.is_empty() {
+            true => String::new(),
+            false => format!("\n{}\n", self.hooks.join("\n")),
+        };
+
+        // Quoted heredoc: env keys and `$PATH` are read when the script is activated.
+        let step_script = formatdoc! {"
+            mkdir -p $VORPAL_WORKSPACE/bin
+
+            cat > bin/activate << \"EOF\"
+            #!/bin/bash
+
+            {backups}
+            {exports}
+
+            deactivate(){{
+            {restores}
+            {unsets}
+            }}
+            {hooks}
+            exec \"$@\"
+            EOF
+
+            chmod +x $VORPAL_WORKSPACE/bin/activate
+
+            mkdir -p $VORPAL_OUTPUT/bin
+
+            cp -pr bin \"$VORPAL_OUTPUT\"",
+            backups = backups.join("\n"),
+            exports = exports.join("\n"),
+            restores = restores.join("\n"),
+            unsets = unsets.join("\n"),
+        };
+
+        let steps = vec![step::shell(context, self.artifacts, vec![], step_script, vec![]).await?];
+
+        Artifact::new(self.name, steps, self.systems)
+            .build(context)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, MockContext};
+    use vorpal_sdk::api::artifact::ArtifactSystem::Aarch64Darwin;
+
+    #[tokio::test]
+    async fn build_matches_sdk_without_hooks() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Darwin).build().await?;
+
+        let environments = vec!["KUBECONFIG=\"$PWD/.kube/config\"".to_string()];
+
+        let sdk = vorpal_sdk::artifact::DevelopmentEnvironment::new("dev", vec![Aarch64Darwin])
+            .with_environments(environments.clone())
+            .build(context)
+            .await?;
+
+        let dev = DevelopmentEnvironment::new("dev", vec![Aarch64Darwin])
+            .with_environments(environments.clone())
+            .build(context)
+            .await?;
+
+        assert_eq!(dev, sdk);
+
+        let hooked = DevelopmentEnvironment::new("dev", vec![Aarch64Darwin])
+            .with_environments(environments)
+            .with_hooks(vec!["eval \"$(starship init bash)\"".to_string()])
+            .build(context)
+            .await?;
+
+        let script = testing::artifact(context, &hooked)?.steps[0]
+            .script
+            .clone()
+            .unwrap_or_default();
+
+        assert!(script.contains("}\n\neval \"$(starship init bash)\"\n\nexec \"$@\""));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,462 @@
+use crate::{
+    aliases,
+    builder::{
+        check,
+        completions::{self, Shell},
+        darwin_sign,
+    },
+    error::ArtifactError,
+    source, target,
+};
+use anyhow::Result;
+use indoc::formatdoc;
+use vorpal_sdk::{api::artifact::ArtifactSystem, context::ConfigContext};
+
+pub struct GithubReleaseBinary<'a> {
+    asset: &'a str,
+    assets: Vec<(&'a str, &'a str)>,
+    binaries: Vec<(&'a str, &'a str)>,
+    check: Option<(&'a str, &'a str)>,
+    check_enabled: Option<bool>,
+    completion_arguments: Option<&'a str>,
+    completions: Vec<(Shell, &'a str)>,
+    darwin_sign: bool,
+    default_version: Option<&'a str>,
+    files: Vec<(&'a str, &'a str)>,
+    man_pages: Vec<&'a str>,
+    name: &'a str,
+    repository: &'a str,
+    systems: Vec<ArtifactSystem>,
+    tag: &'a str,
+    target: Option<ArtifactSystem>,
+    targets: Vec<(ArtifactSystem, &'a str)>,
+    version: &'a str,
+    version_aliases: bool,
+}
+
+impl<'a> GithubReleaseBinary<'a> {
+    pub fn new(
+        name: &'a str,
+        repository: &'a str,
+        version: &'a str,
+        systems: Vec<ArtifactSystem>,
+    ) -> Self {
+        Self {
+            asset: "",
+            assets: vec![],
+            binaries: vec![],
+            check: None,
+            check_enabled: None,
+            completion_arguments: None,
+            completions: vec![],
+            darwin_sign: false,
+            default_version: None,
+            files: vec![],
+            man_pages: vec![],
+            name,
+            repository,
+            systems,
+            tag: "v{version}",
+            target: None,
+            targets: vec![],
+            version,
+            version_aliases: true,
+        }
+    }
+
+    // Templates below expand `{version}` and `{target}`, the latter being the string registered
+    // with `with_system` for the system being built (the host unless `with_target` is set).
+
+    pub fn with_asset(mut self, asset: &'a str) -> Self {
+        self.asset = asset;
+        self
+    }
+
+    // Asset template for releases of another major version, whose archive layout differs from the
+    // one `with_asset` describes.
+    pub fn with_major_asset(mut self, major: &'a str, asset: &'a str) -> Self {
+        self.assets.push((major, asset));
+        self
+    }
+
+    pub fn with_binary(mut self, path: &'a str, binary: &'a str) -> Self {
+        self.binaries.push((path, binary));
+        self
+    }
+
+    pub fn with_check(mut self, command: &'a str, expected: &'a str) -> Self {
+        self.check = Some((command, expected));
+        self
+    }
+
+    // Turns the `with_check` step on or off, see `check::enabled`; `None` leaves it to the `check`
+    // variable.
+    pub fn with_check_enabled(mut self, check: Option<bool>) -> Self {
+        self.check_enabled = check;
+        self
+    }
+
+    // Installs a completion script shipped in the archive where `shell` looks it up for the
+    // first binary.
+    pub fn with_completion(mut self, shell: Shell, path: &'a str) -> Self {
+        self.completions.push((shell, path));
+        self
+    }
+
+    // Generates completions for every shell by running the first binary with `arguments`, see
+    // `completions::generate`.
+    pub fn with_completion_command(mut self, arguments: &'a str) -> Self {
+        self.completion_arguments = Some(arguments);
+        self
+    }
+
+    // Clears the quarantine attribute and ad hoc signs the installed files on darwin, see
+    // `darwin_sign::step`.
+    pub fn with_darwin_sign(mut self) -> Self {
+        self.darwin_sign = true;
+        self
+    }
+
+    // Version the artifact builds when none is pinned. Other versions get `aliases::pinned` so
+    // several of them coexist in one config, and are rejected when their major version differs
+    // from the default one and has no `with_major_asset` template, since `with_asset` was only
+    // written against the default release.
+    pub fn with_default_version(mut self, version: &'a str) -> Self {
+        self.default_version = Some(version);
+        self
+    }
+
+    // Installs any other non-executable file at `destination`, relative to the artifact output.
+    pub fn with_file(mut self, path: &'a str, destination: &'a str) -> Self {
+        self.files.push((path, destination));
+        self
+    }
+
+    pub fn with_man_page(mut self, path: &'a str) -> Self {
+        self.man_pages.push(path);
+        self
+    }
+
+    pub fn with_system(mut self, system: ArtifactSystem, target: &'a str) -> Self {
+        self.targets.push((system, target));
+        self
+    }
+
+    pub fn with_tag(mut self, tag: &'a str) -> Self {
+        self.tag = tag;
+        self
+    }
+
+    pub fn with_target(mut self, system: ArtifactSystem) -> Self {
+        self.target = Some(system);
+        self
+    }
+
+    // See `aliases::versioned` for the aliases this drops.
+    pub fn without_version_aliases(mut self) -> Self {
+        self.version_aliases = false;
+        self
+    }
+
+    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
+        let name = self.name;
+        let version = self.version;
+
+        let default_version = self.default_version.unwrap_or(version);
+
+        let aliases = match self.version_aliases {
+            true => aliases::for_version(name, version, default_version),
+            false => vec![format!("{name}:{version}")],
+        };
+
+        let major = aliases::major(version);
+
+        let asset = match self
+            .assets
+            .iter()
+            .find(|(asset_major, _)| Some(*asset_major) == major)
+        {
+            Some((_, asset)) => *asset,
+            None if major == aliases::major(default_version) => self.asset,
+            // Only the default version's major has a default asset template.
+            None => {
+                return Err(ArtifactError::UnsupportedVersion {
+                    artifact: name.to_string(),
+                    version: version.to_string(),
+                }
+                .into())
+            }
+        };
+
+        let system = target::system(context, self.target);
+
+        let Some((_, target)) = self
+            .targets
+            .iter()
+            .find(|(target_system, _)| *target_system == system)
+        else {
+            return Err(ArtifactError::UnsupportedSystem {
+                artifact: name.to_string(),
+                system,
+            }
+            .into());
+        };
+
+        let expand = |template: &str| {
+            template
+                .replace("{target}", target)
+                .replace("{version}", version)
+        };
+
+        let path = format!(
+            "https://github.com/{}/releases/download/{}/{}",
+            self.repository,
+            expand(self.tag),
+            expand(asset),
+        );
+
+        let source = source::for_system(name, &path, system)?;
+
+        let copy_script = self
+            .binaries
+            .iter()
+            .map(|(path, binary)| {
+                formatdoc! {"
+                    cp ./source/{name}/{path} \"$VORPAL_OUTPUT/bin/{binary}\"
+                    chmod +x \"$VORPAL_OUTPUT/bin/{binary}\"",
+                    path = expand(path),
+                }
+            })
+            .chain(self.files.iter().map(|(path, destination)| {
+                formatdoc! {"
+                    mkdir -pv \"$(dirname \"$VORPAL_OUTPUT/{destination}\")\"
+                    cp ./source/{name}/{path} \"$VORPAL_OUTPUT/{destination}\"",
+                    path = expand(path),
+                }
+            }))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let command = self
+            .binaries
+            .first()
+            .map(|(_, binary)| *binary)
+            .unwrap_or(name);
+
+        let install_script = completions::install(
+            command,
+            &self
+                .completions
+                .iter()
+                .map(|(shell, path)| (*shell, format!("./source/{name}/{}", expand(path))))
+                .collect::<Vec<_>>(),
+            &self
+                .man_pages
+                .iter()
+                .map(|path| format!("./source/{name}/{}", expand(path)))
+                .collect::<Vec<_>>(),
+        );
+
+        let generate_script = self
+            .completion_arguments
+            .map(|arguments| completions::generate(command, arguments))
+            .unwrap_or_default();
+
+        let step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT/bin\"
+            {copy_script}
+            {install_script}
+            {generate_script}",
+        };
+
+        let mut steps = vec![target::shell(context, system, vec![], vec![], step_script).await?];
+
+        if self.darwin_sign {
+            steps.extend(darwin_sign::step(context, system).await?);
+        }
+
+        if let Some((command, expected)) = self.check {
+            if check::enabled(context, system, self.check_enabled)? {
+                steps.push(
+                    check::step(context, system, vec![], &expand(command), &expand(expected))
+                        .await?,
+                );
+            }
+        }
+
+        target::build(
+            context,
+            system,
+            name,
+            aliases,
+            vec![source],
+            steps,
+            self.systems,
+        )
+        .await
+    }
+}
+
+#[macro_export]
+macro_rules! binary_artifact {
+    (
+        $artifact:ident {
+            name: $name:literal,
+            version: $version:expr,
+            repository: $repository:literal,
+            $(tag: $tag:literal,)?
+            asset: $asset:literal,
+            $(major_assets: {$($major:literal => $major_asset:literal),+ $(,)?},)?
+            binaries: [$($path:literal => $binary:literal),+ $(,)?],
+            $(files: [$($file:literal => $destination:literal),+ $(,)?],)?
+            $(completions: {$($shell:ident => $completion:literal),+ $(,)?},)?
+            $(completion_command: $arguments:literal,)?
+            $(man_pages: [$($man_page:literal),+ $(,)?],)?
+            $(check: $command:literal => $expected:literal,)?
+            targets: {$($system:ident => $target:literal),+ $(,)?} $(,)?
+        }
+    ) => {
+        #[derive(Default)]
+        pub struct $artifact<'a> {
+            check: Option<bool>,
+            darwin_sign: bool,
+            target: Option<::vorpal_sdk::api::artifact::ArtifactSystem>,
+            version: Option<&'a str>,
+        }
+
+        impl<'a> $artifact<'a> {
+            pub fn new() -> Self {
+                Self {
+                    check: None,
+                    darwin_sign: false,
+                    target: None,
+                    version: None,
+                }
+            }
+
+            // Runs the `check:` command, when the artifact has one, after installing; see
+            // `check::enabled`.
+            pub fn with_check(mut self) -> Self {
+                self.check = Some(true);
+                self
+            }
+
+            pub fn with_darwin_sign(mut self) -> Self {
+                self.darwin_sign = true;
+                self
+            }
+
+            pub fn with_target(
+                mut self,
+                target: ::vorpal_sdk::api::artifact::ArtifactSystem,
+            ) -> Self {
+                self.target = Some(target);
+                self
+            }
+
+            pub fn with_version(mut self, version: &'a str) -> Self {
+                self.version = Some(version);
+                self
+            }
+
+            // Skips the check even when the `check` variable turns checks on.
+            pub fn without_check(mut self) -> Self {
+                self.check = Some(false);
+                self
+            }
+        }
+
+        #[::async_trait::async_trait]
+        impl<'a> $crate::artifact::ArtifactPackage for $artifact<'a> {
+            fn name(&self) -> &str {
+                $name
+            }
+
+            fn version(&self) -> &str {
+                self.version
+                    .unwrap_or($crate::overrides::version($name, $version))
+            }
+
+            fn systems(&self) -> Vec<::vorpal_sdk::api::artifact::ArtifactSystem> {
+                vec![$(::vorpal_sdk::api::artifact::ArtifactSystem::$system),+]
+            }
+
+            fn upstream(&self) -> Option<$crate::upstream::Upstream> {
+                Some($crate::upstream::Upstream::Github($repository))
+            }
+
+            async fn build(
+                &self,
+                context: &mut ::vorpal_sdk::context::ConfigContext,
+            ) -> ::anyhow::Result<String> {
+                let mut package = $crate::builder::github_release::GithubReleaseBinary::new(
+                    self.name(),
+                    $repository,
+                    self.version(),
+                    self.systems(),
+                )
+                $(.with_tag($tag))?
+                .with_asset($asset)
+                $($(.with_major_asset($major, $major_asset))+)?
+                .with_default_version($crate::overrides::version($name, $version))
+                $(.with_binary($path, $binary))+
+                $($(.with_file($file, $destination))+)?
+                $($(.with_completion(
+                    $crate::builder::completions::Shell::$shell,
+                    $completion,
+                ))+)?
+                $(.with_completion_command($arguments))?
+                $($(.with_man_page($man_page))+)?
+                $(.with_check($command, $expected))?
+                .with_check_enabled(self.check)
+                $(.with_system(::vorpal_sdk::api::artifact::ArtifactSystem::$system, $target))+;
+
+                if self.darwin_sign {
+                    package = package.with_darwin_sign();
+                }
+
+                if let Some(target) = self.target {
+                    package = package.with_target(target);
+                }
+
+                package.build(context).await
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, MockContext};
+    use vorpal_sdk::api::artifact::ArtifactSystem::X8664Linux;
+
+    fn package<'a>(version: &'a str) -> GithubReleaseBinary<'a> {
+        GithubReleaseBinary::new("tool", "owner/tool", version, vec![X8664Linux])
+            .with_asset("tool_{version}_{target}.tar.gz")
+            .with_major_asset("1", "tool-{target}.tar.gz")
+            .with_binary("tool", "tool")
+            .with_default_version("2.4.0")
+            .with_system(X8664Linux, "linux_amd64")
+    }
+
+    #[tokio::test]
+    async fn build_validates_major_versions() -> Result<()> {
+        let context = &mut MockContext::new(X8664Linux).build().await?;
+
+        let pinned = package("1.9.2").build(context).await?;
+
+        assert_eq!(
+            testing::aliases(context, &pinned)?,
+            ["tool:1.9.2", "tool:1.9"]
+        );
+        assert_eq!(
+            testing::source_paths(context, &pinned)?,
+            ["https://github.com/owner/tool/releases/download/v1.9.2/tool-linux_amd64.tar.gz"]
+        );
+        assert!(package("2.1.0").build(context).await.is_ok());
+        assert!(package("3.0.0").build(context).await.is_err());
+
+        Ok(())
+    }
+}
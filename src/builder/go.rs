@@ -0,0 +1,295 @@
+use crate::{
+    aliases,
+    builder::{check, jobs},
+    source,
+};
+use anyhow::{bail, Result};
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api,
+    api::artifact::ArtifactSystem,
+    artifact::{
+        get_env_key,
+        go::Go,
+        language::go::{get_goarch, get_goos},
+        step, Artifact,
+    },
+    context::ConfigContext,
+};
+
+pub struct GoPackage<'a> {
+    artifacts: Vec<String>,
+    binary: Option<&'a str>,
+    build_path: &'a str,
+    check: Option<(&'a str, &'a str)>,
+    check_enabled: Option<bool>,
+    environments: Vec<String>,
+    go: Option<&'a str>,
+    jobs: Option<usize>,
+    ldflags: Vec<String>,
+    module: &'a str,
+    name: &'a str,
+    source: Option<(api::artifact::ArtifactSource, &'a str)>,
+    source_ref: Option<&'a str>,
+    systems: Vec<ArtifactSystem>,
+    tag: Option<&'a str>,
+    tags: Vec<String>,
+    vendor: bool,
+    version: &'a str,
+    version_aliases: bool,
+}
+
+impl<'a> GoPackage<'a> {
+    pub fn new(
+        name: &'a str,
+        module: &'a str,
+        version: &'a str,
+        systems: Vec<ArtifactSystem>,
+    ) -> Self {
+        Self {
+            artifacts: vec![],
+            binary: None,
+            build_path: ".",
+            check: None,
+            check_enabled: None,
+            environments: vec![],
+            go: None,
+            jobs: None,
+            ldflags: vec![],
+            module,
+            name,
+            source: None,
+            source_ref: None,
+            systems,
+            tag: None,
+            tags: vec![],
+            vendor: false,
+            version,
+            version_aliases: true,
+        }
+    }
+
+    pub fn with_artifact(mut self, artifact: &str) -> Self {
+        self.artifacts.push(artifact.to_string());
+        self
+    }
+
+    pub fn with_binary(mut self, binary: &'a str) -> Self {
+        self.binary = Some(binary);
+        self
+    }
+
+    pub fn with_build_path(mut self, path: &'a str) -> Self {
+        self.build_path = path;
+        self
+    }
+
+    pub fn with_build_tag(mut self, tag: &str) -> Self {
+        self.tags.push(tag.to_string());
+        self
+    }
+
+    pub fn with_check(mut self, command: &'a str, expected: &'a str) -> Self {
+        self.check = Some((command, expected));
+        self
+    }
+
+    // Turns the `with_check` step on or off, see `check::enabled`; `None` leaves it to the `check`
+    // variable.
+    pub fn with_check_enabled(mut self, check: Option<bool>) -> Self {
+        self.check_enabled = check;
+        self
+    }
+
+    pub fn with_environment(mut self, key: &str, value: &str) -> Self {
+        self.environments.push(format!("{key}={value}"));
+        self
+    }
+
+    pub fn with_go(mut self, go: &'a str) -> Self {
+        self.go = Some(go);
+        self
+    }
+
+    // Caps parallel jobs for this build, see `jobs::limit`.
+    pub fn with_jobs(mut self, jobs: usize) -> Self {
+        self.jobs = Some(jobs);
+        self
+    }
+
+    pub fn with_ldflag(mut self, flag: &str) -> Self {
+        self.ldflags.push(flag.to_string());
+        self
+    }
+
+    // Overrides the archive derived from the module path, for modules not hosted on GitHub.
+    pub fn with_source(
+        mut self,
+        source: api::artifact::ArtifactSource,
+        directory: &'a str,
+    ) -> Self {
+        self.source = Some((source, directory));
+        self
+    }
+
+    // Builds the module's GitHub repository at a branch or commit instead of the release tag, see
+    // `source::github_ref`. The build is tagged `name:<ref>` alone, as it is not the release
+    // `version` names.
+    pub fn with_source_ref(mut self, reference: &'a str) -> Self {
+        self.source_ref = Some(reference);
+        self
+    }
+
+    pub fn with_tag(mut self, tag: &'a str) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    // Builds strictly from the vendor/ directory shipped in the source, without reaching
+    // the module proxy.
+    pub fn with_vendor(mut self) -> Self {
+        self.vendor = true;
+        self
+    }
+
+    // See `aliases::versioned` for the aliases this drops.
+    pub fn without_version_aliases(mut self) -> Self {
+        self.version_aliases = false;
+        self
+    }
+
+    fn github_source(&self) -> Result<(api::artifact::ArtifactSource, String)> {
+        let Some(repository) = self.module.strip_prefix("github.com/") else {
+            bail!("go module '{}' requires an explicit source", self.module);
+        };
+
+        let Some((_, repo)) = repository.split_once('/') else {
+            bail!("invalid go module path '{}'", self.module);
+        };
+
+        if let Some(reference) = self.source_ref {
+            return source::github_ref(self.name, repository, reference);
+        }
+
+        let version = self.version;
+        let tag = match self.tag {
+            Some(tag) => tag.replace("{version}", version),
+            None => format!("v{version}"),
+        };
+
+        let path = format!("https://github.com/{repository}/archive/refs/tags/{tag}.tar.gz");
+
+        // GitHub drops the leading "v" of version tags in the archive's top-level directory.
+        let directory_version = match tag.strip_prefix('v') {
+            Some(rest) if rest.starts_with(|c: char| c.is_ascii_digit()) => rest,
+            _ => tag.as_str(),
+        };
+
+        Ok((
+            source::new(self.name, &path),
+            format!("{repo}-{directory_version}"),
+        ))
+    }
+
+    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
+        let go = match self.go {
+            Some(val) => val.to_string(),
+            None => Go::new().build(context).await?,
+        };
+
+        let (source, source_directory) = match self.source {
+            Some((source, directory)) => (source, directory.to_string()),
+            None => self.github_source()?,
+        };
+
+        let name = self.name;
+        let version = self.version;
+
+        let aliases = match (self.source_ref, self.version_aliases) {
+            (Some(reference), _) => vec![format!("{name}:{reference}")],
+            (None, true) => aliases::versioned(name, version),
+            (None, false) => vec![format!("{name}:{version}")],
+        };
+
+        let binary = self.binary.unwrap_or(name);
+
+        let ldflags = ["-s", "-w"]
+            .iter()
+            .map(|flag| flag.to_string())
+            .chain(self.ldflags)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let parallelism = match jobs::limit(context, self.jobs)? {
+            Some(jobs) => format!("-p {jobs} "),
+            None => String::new(),
+        };
+
+        let tags = match self.tags.is_empty() {
+            true => String::new(),
+            false => format!("-tags {} ", self.tags.join(",")),
+        };
+
+        // The module cache is read-only and would otherwise outlive the step.
+        let clean = match self.vendor {
+            true => "",
+            false => "go clean -modcache",
+        };
+
+        let step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT/bin\"
+
+            pushd ./source/{name}/{source_directory}
+
+            go build -trimpath {parallelism}{tags}-ldflags \"{ldflags}\" -o \"$VORPAL_OUTPUT/bin/{binary}\" {build_path}
+
+            {clean}",
+            build_path = self.build_path,
+        };
+
+        let mut step_environments = vec![
+            format!("GOARCH={}", get_goarch(context.get_system())?),
+            "GOCACHE=$VORPAL_WORKSPACE/go/cache".to_string(),
+            format!("GOOS={}", get_goos(context.get_system())?),
+            "GOPATH=$VORPAL_WORKSPACE/go".to_string(),
+            "GOTOOLCHAIN=local".to_string(),
+            format!("PATH={}/bin", get_env_key(&go)),
+        ];
+
+        if self.vendor {
+            step_environments.push("GOFLAGS=-mod=vendor".to_string());
+            step_environments.push("GOPROXY=off".to_string());
+        } else if let Some(proxy) = source::mirrored(source::GO_PROXY) {
+            step_environments.push(format!("GOPROXY={proxy}"));
+        }
+
+        step_environments.extend(self.environments);
+
+        let step_artifacts = std::iter::once(go).chain(self.artifacts).collect();
+
+        let mut steps = vec![
+            step::shell(
+                context,
+                step_artifacts,
+                step_environments,
+                step_script,
+                vec![],
+            )
+            .await?,
+        ];
+
+        if let Some((command, expected)) = self.check {
+            let system = context.get_system();
+
+            if check::enabled(context, system, self.check_enabled)? {
+                steps.push(check::step(context, system, vec![], command, expected).await?);
+            }
+        }
+
+        Artifact::new(name, steps, self.systems)
+            .with_aliases(aliases)
+            .with_sources(vec![source])
+            .build(context)
+            .await
+    }
+}
@@ -0,0 +1,54 @@
+use crate::error::ArtifactError;
+use anyhow::{bail, Result};
+use vorpal_sdk::context::ConfigContext;
+
+// Config variable that caps parallelism for every build that does not set its own limit, e.g.
+// `--variable build-jobs=2` on runners with little memory.
+pub const VARIABLE: &str = "build-jobs";
+
+// The limit for one build: its own `with_jobs` value, else the `build-jobs` variable, else none
+// (one job per CPU).
+pub fn limit(context: &ConfigContext, jobs: Option<usize>) -> Result<Option<usize>> {
+    let jobs = match jobs {
+        Some(jobs) => Some(jobs),
+        None => match context.get_variable(VARIABLE) {
+            Some(value) => match value.parse() {
+                Ok(jobs) => Some(jobs),
+                Err(_) => {
+                    return Err(ArtifactError::InvalidVariable {
+                        variable: VARIABLE.to_string(),
+                        value,
+                    }
+                    .into())
+                }
+            },
+            None => None,
+        },
+    };
+
+    if jobs == Some(0) {
+        bail!("build jobs must be at least 1");
+    }
+
+    Ok(jobs)
+}
+
+// `-j` flag for make. Without a limit the CPU count comes from `getconf`, which Linux and macOS
+// both provide, unlike `nproc` or `sysctl`.
+pub fn make_flag(limit: Option<usize>) -> String {
+    match limit {
+        Some(jobs) => format!("-j{jobs}"),
+        None => "-j\"$(getconf _NPROCESSORS_ONLN)\"".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn make_flag_falls_back_to_cpu_count() {
+        assert_eq!(make_flag(Some(2)), "-j2");
+        assert_eq!(make_flag(None), "-j\"$(getconf _NPROCESSORS_ONLN)\"");
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{
+    artifact::{musl::Musl, ArtifactPackage},
+    context_cache,
+    error::ArtifactError,
+    variant::Linkage,
+};
+use anyhow::Result;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Linux, X8664Linux},
+    },
+    artifact::get_env_key,
+    context::ConfigContext,
+};
+
+// Config variable that switches Linux builds of the artifacts that support it to fully static
+// binaries linked against musl, e.g. `--variable libc=musl` for `FROM scratch` images.
+// Dependencies read the same variable, so the whole graph is linked the same way.
+pub const VARIABLE: &str = "libc";
+
+// `Linkage::Musl` on Linux when the `libc` variable asks for it, otherwise the artifact's own
+// linkage. Other systems keep their native C library.
+pub fn linkage(context: &ConfigContext, linkage: Linkage) -> Result<Linkage> {
+    match context.get_variable(VARIABLE).as_deref() {
+        None | Some("glibc") => Ok(linkage),
+        Some("musl") => match context.get_system() {
+            Aarch64Linux | X8664Linux => Ok(Linkage::Musl),
+            _ => Ok(linkage),
+        },
+        Some(value) => Err(ArtifactError::InvalidVariable {
+            variable: VARIABLE.to_string(),
+            value: value.to_string(),
+        }
+        .into()),
+    }
+}
+
+// The musl toolchain for `system`, built natively or with the target's rootfs.
+pub async fn toolchain(context: &mut ConfigContext, system: ArtifactSystem) -> Result<String> {
+    if !matches!(system, Aarch64Linux | X8664Linux) {
+        return Err(ArtifactError::UnsupportedSystem {
+            artifact: "musl".to_string(),
+            system,
+        }
+        .into());
+    }
+
+    match system == context.get_system() {
+        true => context_cache::build(context, &Musl::new()).await,
+        false => Musl::new().with_target(system).build(context).await,
+    }
+}
+
+// Exports `musl-gcc` as the C compiler and links statically, so the binaries need neither a
+// loader nor shared libraries at runtime. `musl-gcc` only wraps C; there is no C++ library.
+pub fn script(toolchain: &str) -> String {
+    formatdoc! {"
+        export CC=\"{toolchain}/bin/musl-gcc\"
+        export LDFLAGS=\"${{LDFLAGS:-}} -static\"",
+        toolchain = get_env_key(&toolchain.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockContext;
+    use vorpal_sdk::api::artifact::ArtifactSystem::Aarch64Darwin;
+
+    #[tokio::test]
+    async fn linkage_follows_variable_on_linux() -> Result<()> {
+        let context = MockContext::new(X8664Linux)
+            .with_variable(VARIABLE, "musl")
+            .build()
+            .await?;
+
+        assert_eq!(linkage(&context, Linkage::Shared)?, Linkage::Musl);
+
+        let context = MockContext::new(Aarch64Darwin)
+            .with_variable(VARIABLE, "musl")
+            .build()
+            .await?;
+
+        assert_eq!(linkage(&context, Linkage::Static)?, Linkage::Static);
+
+        let context = MockContext::new(X8664Linux)
+            .with_variable(VARIABLE, "uclibc")
+            .build()
+            .await?;
+
+        assert!(linkage(&context, Linkage::Shared).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+use crate::target;
+use anyhow::Result;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api,
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, X8664Darwin},
+    },
+    context::ConfigContext,
+};
+
+// Prefixes of package managers on the build host. A reference into one of them only resolves on
+// machines that happen to have the same library installed there.
+const HOST_PREFIXES: &[&str] = &["/usr/local/", "/opt/homebrew/", "/home/linuxbrew/"];
+
+// Runs after the build steps and fails the build when any of `paths` (relative to the artifact
+// output, e.g. `lib/libgcrypt.a`) is missing, so a file that silently stopped being installed is
+// caught where it is built. With `linkage`, it also lists the libraries every executable and
+// shared library loads (`ldd`, or `otool -L` on darwin) and fails on any under `HOST_PREFIXES`.
+pub async fn step(
+    context: &mut ConfigContext,
+    system: ArtifactSystem,
+    paths: &[String],
+    linkage: bool,
+) -> Result<api::artifact::ArtifactStep> {
+    let missing_script = paths
+        .iter()
+        .map(|path| {
+            formatdoc! {"
+                if [ ! -e \"$VORPAL_OUTPUT/{path}\" ]; then
+                    echo \"missing output: {path}\" >&2
+                    failed=1
+                fi"
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let linkage_script = match linkage {
+        true => {
+            let list = match system {
+                Aarch64Darwin | X8664Darwin => "otool -L",
+                _ => "ldd",
+            };
+
+            let pattern = HOST_PREFIXES.join("|");
+
+            formatdoc! {"
+                find \"$VORPAL_OUTPUT\" -type f \\( -perm -u+x -o -name '*.so*' -o -name '*.dylib' \\) > \"$VORPAL_WORKSPACE/outputs.list\"
+
+                while read -r file; do
+                    if escaped=\"$({list} \"$file\" 2>/dev/null | grep -E '{pattern}')\"; then
+                        echo \"host library referenced by ${{file#$VORPAL_OUTPUT/}}:\" >&2
+                        echo \"$escaped\" >&2
+                        failed=1
+                    fi
+                done < \"$VORPAL_WORKSPACE/outputs.list\"",
+            }
+        }
+        false => String::new(),
+    };
+
+    let step_script = formatdoc! {"
+        failed=0
+
+        {missing_script}
+
+        {linkage_script}
+
+        exit \"$failed\"",
+    };
+
+    target::shell(context, system, vec![], vec![], step_script).await
+}
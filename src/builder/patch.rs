@@ -0,0 +1,42 @@
+use indoc::formatdoc;
+use vorpal_sdk::{api, artifact::ArtifactSource};
+
+// Patches are files in this repository (conventionally `patches/<artifact>/*.patch`), shipped
+// to the step as a local source and applied with `patch -p1` from the source directory.
+
+fn source_name(name: &str) -> String {
+    format!("{name}-patches")
+}
+
+pub fn source(name: &str, patches: &[String]) -> Option<api::artifact::ArtifactSource> {
+    if patches.is_empty() {
+        return None;
+    }
+
+    let source = ArtifactSource::new(&source_name(name), ".")
+        .with_includes(patches.to_vec())
+        .build();
+
+    Some(source)
+}
+
+// Expects to run from the workspace root, before changing into the source directory.
+pub fn script(name: &str, source_directory: &str, patches: &[String]) -> String {
+    if patches.is_empty() {
+        return String::new();
+    }
+
+    let apply = patches
+        .iter()
+        .map(|patch| format!("patch -p1 < \"$PATCHES_DIR/{patch}\""))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    formatdoc! {"
+        PATCHES_DIR=\"$(pwd)/source/{patches_source}\"
+        pushd ./source/{name}/{source_directory}
+        {apply}
+        popd",
+        patches_source = source_name(name),
+    }
+}
@@ -0,0 +1,45 @@
+use crate::target;
+use anyhow::Result;
+use indoc::formatdoc;
+use vorpal_sdk::{api, api::artifact::ArtifactSystem, context::ConfigContext};
+
+// Documentation and libtool archives that runtime consumers of an artifact never need. Static
+// archives stay, since consumers may link against them.
+pub const DEFAULT: &[&str] = &["lib/*.la", "share/doc", "share/info", "share/man"];
+
+// Removes `prune` globs (relative to the artifact output) and, with `strip`, strips executables
+// in `bin/` and shared libraries in `lib/`. Stripping invalidates macOS code signatures, so
+// binaries are re-signed ad hoc there.
+pub async fn step(
+    context: &mut ConfigContext,
+    system: ArtifactSystem,
+    strip: bool,
+    prune: &[String],
+) -> Result<api::artifact::ArtifactStep> {
+    let prune_script = prune
+        .iter()
+        .map(|glob| format!("rm -rf \"$VORPAL_OUTPUT\"/{glob}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let strip_script = match strip {
+        false => String::new(),
+        true => formatdoc! {"
+            find \"$VORPAL_OUTPUT/bin\" \"$VORPAL_OUTPUT/lib\" -type f \\( -perm -u+x -o -name '*.so*' -o -name '*.dylib' \\) 2>/dev/null | while read -r file; do
+                if [ \"$(uname -s)\" = \"Darwin\" ]; then
+                    strip -x \"$file\" 2>/dev/null && codesign --force --sign - \"$file\" 2>/dev/null || true
+                else
+                    strip --strip-unneeded \"$file\" 2>/dev/null || true
+                fi
+            done",
+        },
+    };
+
+    let step_script = formatdoc! {"
+        {prune_script}
+
+        {strip_script}",
+    };
+
+    target::shell(context, system, vec![], vec![], step_script).await
+}
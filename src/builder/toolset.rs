@@ -0,0 +1,161 @@
+use crate::target;
+use anyhow::{bail, Result};
+use indoc::formatdoc;
+use vorpal_sdk::{api::artifact::ArtifactSystem, artifact::get_env_key, context::ConfigContext};
+
+// Directories merged from every artifact: executables, shared data (man pages, zsh/fish
+// completions) and bash completions.
+const DIRECTORIES: &[&str] = &["bin", "etc/bash_completion.d", "share"];
+
+// Merges several artifacts into one output of symlinks, so a single env key on PATH stands in for
+// all of them. When two artifacts provide the same path, the one added first wins.
+pub struct Toolset<'a> {
+    aliases: Vec<String>,
+    artifacts: Vec<String>,
+    directories: Vec<&'a str>,
+    name: &'a str,
+    systems: Vec<ArtifactSystem>,
+    target: Option<ArtifactSystem>,
+}
+
+impl<'a> Toolset<'a> {
+    pub fn new(name: &'a str, systems: Vec<ArtifactSystem>) -> Self {
+        Self {
+            aliases: vec![],
+            artifacts: vec![],
+            directories: vec![],
+            name,
+            systems,
+            target: None,
+        }
+    }
+
+    // The merged artifacts, which must ship alongside the toolset for its links to resolve.
+    pub fn artifacts(&self) -> &[String] {
+        &self.artifacts
+    }
+
+    pub fn with_aliases(mut self, aliases: Vec<String>) -> Self {
+        self.aliases.extend(aliases);
+        self
+    }
+
+    pub fn with_artifact(mut self, artifact: &str) -> Self {
+        self.artifacts.push(artifact.to_string());
+        self
+    }
+
+    pub fn with_artifacts(mut self, artifacts: Vec<String>) -> Self {
+        self.artifacts.extend(artifacts);
+        self
+    }
+
+    // Merges these on top of `DIRECTORIES`, e.g. `include` and `lib` for a bundle of libraries.
+    pub fn with_directories(mut self, directories: Vec<&'a str>) -> Self {
+        self.directories.extend(directories);
+        self
+    }
+
+    // Artifacts given with `with_artifact` must already be built for `system`.
+    pub fn with_target(mut self, system: ArtifactSystem) -> Self {
+        self.target = Some(system);
+        self
+    }
+
+    pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
+        if self.artifacts.is_empty() {
+            bail!("toolset '{}' has no artifacts", self.name);
+        }
+
+        let system = target::system(context, self.target);
+
+        let artifacts = self
+            .artifacts
+            .iter()
+            .map(|artifact| format!("\"{}\"", get_env_key(artifact)))
+            .collect::<Vec<_>>()
+            .join(" \\\n    ");
+
+        let directories = DIRECTORIES
+            .iter()
+            .chain(self.directories.iter())
+            .copied()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT\"
+
+            for artifact in \\
+                {artifacts}; do
+                for directory in {directories}; do
+                    [ -d \"$artifact/$directory\" ] || continue
+
+                    (cd \"$artifact\" && find \"$directory\" \\( -type f -o -type l \\)) | while read -r file; do
+                        if [ -e \"$VORPAL_OUTPUT/$file\" ] || [ -L \"$VORPAL_OUTPUT/$file\" ]; then
+                            continue
+                        fi
+
+                        mkdir -p \"$VORPAL_OUTPUT/$(dirname \"$file\")\"
+                        ln -s \"$artifact/$file\" \"$VORPAL_OUTPUT/$file\"
+                    done
+                done
+            done",
+        };
+
+        let steps = vec![
+            target::shell(context, system, self.artifacts.clone(), vec![], step_script).await?,
+        ];
+
+        target::build(
+            context,
+            system,
+            self.name,
+            self.aliases,
+            vec![],
+            steps,
+            self.systems,
+        )
+        .await
+    }
+}
+
+#[cfg(all(test, feature = "library"))]
+mod tests {
+    use super::*;
+    use crate::{
+        artifact::{pcre2::Pcre2, zlib::Zlib, ArtifactPackage},
+        testing::{self, MockContext},
+    };
+    use vorpal_sdk::api::artifact::ArtifactSystem::Aarch64Darwin;
+
+    #[tokio::test]
+    async fn build_depends_on_every_artifact() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Darwin).build().await?;
+
+        let pcre2 = Pcre2::new().build(context).await?;
+        let zlib = Zlib::new().build(context).await?;
+
+        let toolset = Toolset::new("toolset", vec![Aarch64Darwin])
+            .with_artifacts(vec![zlib, pcre2])
+            .build(context)
+            .await?;
+
+        assert_eq!(testing::dependencies(context, &toolset)?, ["pcre2", "zlib"]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_rejects_empty_toolset() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Darwin).build().await?;
+
+        let result = Toolset::new("toolset", vec![Aarch64Darwin])
+            .build(context)
+            .await;
+
+        assert!(result.is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use indoc::formatdoc;
+
+// Script that writes an executable `wrapper` which prepends `paths` to PATH, exports
+// `environments` and execs `command` (already quoted, e.g. `"<java>" -jar "<jar>"`) with the
+// wrapper's arguments appended. Env keys and `$VORPAL_OUTPUT` are expanded while the script runs,
+// so the wrapper only holds store paths; escape a reference as `\\$VAR` to have the wrapper read
+// it at run time instead.
+pub fn wrap_binary(
+    wrapper: &str,
+    command: &str,
+    paths: &[String],
+    environments: &[(&str, String)],
+) -> String {
+    let mut exports = environments
+        .iter()
+        .map(|(key, value)| format!("export {key}=\"{value}\""))
+        .collect::<Vec<_>>();
+
+    if !paths.is_empty() {
+        exports.push(format!("export PATH=\"{}:\\$PATH\"", paths.join(":")));
+    }
+
+    let exports = exports.join("\n");
+
+    formatdoc! {"
+        mkdir -pv \"$(dirname \"{wrapper}\")\"
+
+        cat << EOF > \"{wrapper}\"
+        #!/bin/sh
+        {exports}
+        exec {command} \"\\$@\"
+        EOF
+
+        chmod +x \"{wrapper}\"",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrap_binary_exports_before_exec() {
+        let script = wrap_binary(
+            "$VORPAL_OUTPUT/bin/tool",
+            "\"$VORPAL_OUTPUT/libexec/tool\"",
+            &["$VORPAL_ARTIFACT_jdk/bin".to_string()],
+            &[("JAVA_HOME", "$VORPAL_ARTIFACT_jdk".to_string())],
+        );
+
+        let exports = [
+            "export JAVA_HOME=\"$VORPAL_ARTIFACT_jdk\"",
+            "export PATH=\"$VORPAL_ARTIFACT_jdk/bin:\\$PATH\"",
+            "exec \"$VORPAL_OUTPUT/libexec/tool\" \"\\$@\"",
+        ]
+        .join("\n");
+
+        assert!(script.contains(&exports));
+        assert!(script.ends_with("chmod +x \"$VORPAL_OUTPUT/bin/tool\""));
+    }
+}
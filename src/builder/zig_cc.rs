@@ -0,0 +1,165 @@
+use crate::{
+    artifact::{zig::Zig, zig_cc::ZigCc, ArtifactPackage},
+    context_cache,
+    error::ArtifactError,
+};
+use anyhow::Result;
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Linux, X8664Linux},
+    },
+    artifact::get_env_key,
+    context::ConfigContext,
+};
+
+// Config variable that makes `AutotoolsPackage` and `CmakePackage` cross build with zig instead of
+// inside the target's rootfs, e.g. `--variable cross-cc=zig` to produce Linux artifacts on macOS
+// hosts, which cannot run the rootfs. Experimental: every step runs on the host, so build checks
+// are skipped, and builds that need more than a C toolchain may not cross compile.
+pub const VARIABLE: &str = "cross-cc";
+
+// `ZigCc` shims for `system` and the zig they run, put in front of a build's C toolchain.
+pub struct Toolchain {
+    triple: &'static str,
+    zig: String,
+    zig_cc: String,
+}
+
+impl Toolchain {
+    // The shims exec zig from its store path, so both are step artifacts.
+    pub fn artifacts(&self) -> Vec<String> {
+        vec![self.zig_cc.clone(), self.zig.clone()]
+    }
+
+    // Exports the shims as the C toolchain. `CHOST` is for configure scripts that autoconf did
+    // not generate, such as zlib's; autoconf ones get `--host` from `configure_script`.
+    pub fn script(&self) -> String {
+        let bin = format!("{}/bin", get_env_key(&self.zig_cc));
+
+        formatdoc! {"
+            export AR=\"{bin}/ar\"
+            export CC=\"{bin}/cc\"
+            export CHOST=\"{triple}\"
+            export CXX=\"{bin}/c++\"
+            export RANLIB=\"{bin}/ranlib\"",
+            triple = self.triple,
+        }
+    }
+
+    // Sets `$configure_host` to `--host` for autoconf configure scripts, which otherwise try to
+    // run the binaries they compile. Other scripts may reject the flag, so it is left empty.
+    pub fn configure_script(&self) -> String {
+        formatdoc! {"
+            configure_host=\"\"
+
+            if grep -q 'Generated by GNU Autoconf' ./configure; then
+                configure_host=\"--host={triple}\"
+            fi",
+            triple = self.triple,
+        }
+    }
+
+    // CMake does not read `AR` or `RANLIB`, and only cross compiles with a system name set.
+    pub fn cmake_definitions(&self) -> Vec<String> {
+        let bin = format!("{}/bin", get_env_key(&self.zig_cc));
+
+        let processor = self.triple.split('-').next().unwrap_or_default();
+
+        vec![
+            format!("CMAKE_AR=\"{bin}/ar\""),
+            format!("CMAKE_C_COMPILER=\"{bin}/cc\""),
+            format!("CMAKE_CXX_COMPILER=\"{bin}/c++\""),
+            format!("CMAKE_RANLIB=\"{bin}/ranlib\""),
+            "CMAKE_SYSTEM_NAME=Linux".to_string(),
+            format!("CMAKE_SYSTEM_PROCESSOR={processor}"),
+        ]
+    }
+}
+
+// Zig target for glibc Linux artifacts on `system`; zig cross builds only target Linux.
+pub fn triple(system: ArtifactSystem) -> Result<&'static str> {
+    match system {
+        Aarch64Linux => Ok("aarch64-linux-gnu"),
+        X8664Linux => Ok("x86_64-linux-gnu"),
+        system => Err(ArtifactError::UnsupportedSystem {
+            artifact: "zig-cc".to_string(),
+            system,
+        }
+        .into()),
+    }
+}
+
+// The zig toolchain when the `cross-cc` variable asks for it and `system` is not the host's;
+// `None` keeps native builds and rootfs cross builds as they are.
+pub async fn toolchain(
+    context: &mut ConfigContext,
+    system: ArtifactSystem,
+) -> Result<Option<Toolchain>> {
+    match context.get_variable(VARIABLE).as_deref() {
+        None => return Ok(None),
+        Some("zig") => {}
+        Some(value) => {
+            return Err(ArtifactError::InvalidVariable {
+                variable: VARIABLE.to_string(),
+                value: value.to_string(),
+            }
+            .into())
+        }
+    }
+
+    if system == context.get_system() {
+        return Ok(None);
+    }
+
+    let triple = triple(system)?;
+
+    let zig = context_cache::build(context, &Zig::new()).await?;
+    let zig_cc = ZigCc::new()
+        .with_triple(triple)
+        .with_zig(&zig)
+        .build(context)
+        .await?;
+
+    Ok(Some(Toolchain {
+        triple,
+        zig,
+        zig_cc,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, MockContext};
+    use vorpal_sdk::api::artifact::ArtifactSystem::Aarch64Darwin;
+
+    #[tokio::test]
+    async fn toolchain_only_for_cross_builds() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Darwin).build().await?;
+
+        assert!(toolchain(context, X8664Linux).await?.is_none());
+
+        let context = &mut MockContext::new(Aarch64Darwin)
+            .with_variable(VARIABLE, "zig")
+            .build()
+            .await?;
+
+        assert!(toolchain(context, Aarch64Darwin).await?.is_none());
+
+        let toolchain = toolchain(context, X8664Linux)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("no zig toolchain for x86_64-linux"))?;
+
+        assert_eq!(
+            testing::aliases(context, &toolchain.zig_cc)?,
+            [format!("zig-cc:{}-x86_64-linux-gnu", crate::versions::ZIG)]
+        );
+        assert!(toolchain
+            .cmake_definitions()
+            .contains(&"CMAKE_SYSTEM_PROCESSOR=x86_64".to_string()));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,435 @@
+#[cfg(feature = "library")]
+use crate::artifact::{
+    abseil_cpp::AbseilCpp, c_ares::CAres, capnproto::Capnproto, font_stack::FontStack,
+    fontconfig::Fontconfig, freetype::Freetype, giflib::Giflib, harfbuzz::Harfbuzz, icu::Icu,
+    image_libs::ImageLibs, json_c::JsonC, libcap::Libcap, libevent::Libevent, libffi::Libffi,
+    libjpeg_turbo::LibjpegTurbo, libpcap::Libpcap, libpng::Libpng, libssh2::Libssh2, libuv::Libuv,
+    libwebp::Libwebp, libwebsockets::Libwebsockets, libxml2::Libxml2, libxslt::Libxslt,
+    libyaml::Libyaml, mbedtls::Mbedtls, ncurses::Ncurses, nghttp2::Nghttp2, oniguruma::Oniguruma,
+    openssl::Openssl, pcre2::Pcre2, protobuf::Protobuf, readline::Readline, zlib::Zlib,
+};
+#[cfg(feature = "terminal")]
+use crate::artifact::{
+    abtop::Abtop, b3sum::B3sum, bat::Bat, bottom::Bottom, delta::Delta, direnv::Direnv,
+    fastfetch::Fastfetch, fd::Fd, fx::Fx, fzf::Fzf, glow::Glow, gron::Gron, gum::Gum, hexyl::Hexyl,
+    jless::Jless, jq::Jq, minisign::Minisign, ncdu::Ncdu, nnn::Nnn, qsv::Qsv, ripgrep::Ripgrep,
+    sesh::Sesh, starship::Starship, tmux::Tmux, ttyd::Ttyd, vhs::Vhs, yq::Yq, zoxide::Zoxide,
+    zsh::Zsh,
+};
+#[cfg(feature = "cloud")]
+use crate::artifact::{
+    ansible::Ansible, awscli2::Awscli2, doppler::Doppler, mc::Mc, op::Op, s5cmd::S5cmd,
+    steampipe::Steampipe, terraform::Terraform,
+};
+#[cfg(feature = "kubernetes")]
+use crate::artifact::{
+    argocd::Argocd, eksctl::Eksctl, fluxcd::Fluxcd, helm::Helm, k9s::K9s, kind::Kind, kn::Kn,
+    kubectl::Kubectl, kubeseal::Kubeseal, talosctl::Talosctl, virtctl::Virtctl,
+};
+#[cfg(feature = "database")]
+use crate::artifact::{
+    atlas::Atlas, dbmate::Dbmate, flyway::Flyway, mongosh::Mongosh, sqlite3::Sqlite3,
+};
+#[cfg(feature = "language-server")]
+use crate::artifact::{
+    bash_language_server::BashLanguageServer, dockerfile_language_server::DockerfileLanguageServer,
+    lua_language_server::LuaLanguageServer, terraform_ls::TerraformLs,
+    typescript_language_server::TypescriptLanguageServer,
+    vscode_langservers_extracted::VscodeLangserversExtracted,
+    yaml_language_server::YamlLanguageServer,
+};
+#[cfg(feature = "development")]
+use crate::artifact::{
+    beads::Beads, chezmoi::Chezmoi, cue::Cue, gh_actions_tool_cache::GhActionsToolCache, ghz::Ghz,
+    git_absorb::GitAbsorb, golangci_lint::GolangciLint, herdr::Herdr, hunk::Hunk, jbang::Jbang,
+    jj::Jj, lazygit::Lazygit, lima::Lima, neovim::Neovim, node_matrix::NodeMatrix,
+    openapi_generator_cli::OpenapiGeneratorCli, opencode::Opencode, openjdk::Openjdk, pi::Pi,
+    pre_commit::PreCommit, protoc_gen_grpc_web::ProtocGenGrpcWeb, protoc_plugins::ProtocPlugins,
+    stow::Stow, tree_sitter::TreeSitter, typescript::Typescript, universal_ctags::UniversalCtags,
+    uv::Uv,
+};
+#[cfg(feature = "container")]
+use crate::artifact::{
+    bubblewrap::Bubblewrap, crane::Crane, dumb_init::DumbInit, grpc_health_probe::GrpcHealthProbe,
+    skopeo::Skopeo, tini::Tini, umoci::Umoci,
+};
+#[cfg(feature = "build-tools")]
+use crate::artifact::{
+    ccache::Ccache, cmake::Cmake, flatbuffers::Flatbuffers, flex::Flex, just::Just, musl::Musl,
+    patchelf::Patchelf, pkg_config::PkgConfig, sccache::Sccache, upx::Upx, zig::Zig, zig_cc::ZigCc,
+};
+#[cfg(feature = "network")]
+use crate::artifact::{
+    cfssl::Cfssl, doggo::Doggo, haproxy::Haproxy, mtr::Mtr, nginx::Nginx, nmap::Nmap,
+    tcpdump::Tcpdump, wireguard_go::WireguardGo, wireguard_tools::WireguardTools,
+};
+#[cfg(feature = "media")]
+use crate::artifact::{ffmpeg::Ffmpeg, x264::X264};
+#[cfg(feature = "gnupg")]
+use crate::artifact::{
+    gpg::Gpg, libassuan::Libassuan, libgcrypt::Libgcrypt, libgpg_error::LibgpgError,
+    libksba::Libksba, npth::Npth,
+};
+use crate::{artifact::ArtifactPackage, context_cache, source};
+use anyhow::Result;
+use vorpal_sdk::context::ConfigContext;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Category {
+    BuildTools,
+    Cloud,
+    Container,
+    Database,
+    Development,
+    Gnupg,
+    Kubernetes,
+    LanguageServer,
+    Library,
+    Media,
+    Network,
+    Terminal,
+}
+
+impl Category {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Category::BuildTools => "build-tools",
+            Category::Cloud => "cloud",
+            Category::Container => "container",
+            Category::Database => "database",
+            Category::Development => "development",
+            Category::Gnupg => "gnupg",
+            Category::Kubernetes => "kubernetes",
+            Category::LanguageServer => "language-server",
+            Category::Library => "library",
+            Category::Media => "media",
+            Category::Network => "network",
+            Category::Terminal => "terminal",
+        }
+    }
+}
+
+pub struct CatalogGroup {
+    pub category: Category,
+    pub packages: Vec<Box<dyn ArtifactPackage>>,
+}
+
+pub fn all() -> Vec<CatalogGroup> {
+    vec![
+        #[cfg(feature = "build-tools")]
+        CatalogGroup {
+            category: Category::BuildTools,
+            packages: vec![
+                Box::new(Ccache::new()),
+                Box::new(Cmake::new()),
+                Box::new(Flatbuffers::new()),
+                Box::new(Flex::new()),
+                Box::new(Just::new()),
+                Box::new(Musl::new()),
+                Box::new(Patchelf::new()),
+                Box::new(PkgConfig::new()),
+                Box::new(Sccache::new()),
+                Box::new(Upx::new()),
+                Box::new(Zig::new()),
+                Box::new(ZigCc::new()),
+            ],
+        },
+        #[cfg(feature = "cloud")]
+        CatalogGroup {
+            category: Category::Cloud,
+            packages: vec![
+                Box::new(Ansible::new()),
+                Box::new(Awscli2::new()),
+                Box::new(Doppler::new()),
+                Box::new(Mc::new()),
+                Box::new(Op::new()),
+                Box::new(S5cmd::new()),
+                Box::new(Steampipe::new()),
+                Box::new(Terraform::new()),
+            ],
+        },
+        #[cfg(feature = "container")]
+        CatalogGroup {
+            category: Category::Container,
+            packages: vec![
+                Box::new(Bubblewrap::new()),
+                Box::new(Crane::new()),
+                Box::new(DumbInit::new()),
+                Box::new(GrpcHealthProbe::new()),
+                Box::new(Skopeo::new()),
+                Box::new(Tini::new()),
+                Box::new(Umoci::new()),
+            ],
+        },
+        #[cfg(feature = "database")]
+        CatalogGroup {
+            category: Category::Database,
+            packages: vec![
+                Box::new(Atlas::new()),
+                Box::new(Dbmate::new()),
+                Box::new(Flyway::new()),
+                Box::new(Mongosh::new()),
+                Box::new(Sqlite3::new()),
+            ],
+        },
+        #[cfg(feature = "development")]
+        CatalogGroup {
+            category: Category::Development,
+            packages: vec![
+                Box::new(Beads::new()),
+                Box::new(Chezmoi::new()),
+                Box::new(Cue::new()),
+                Box::new(GhActionsToolCache::new()),
+                Box::new(Ghz::new()),
+                Box::new(GitAbsorb::new()),
+                Box::new(GolangciLint::new()),
+                Box::new(Herdr::new()),
+                Box::new(Hunk::new()),
+                Box::new(Jbang::new()),
+                Box::new(Jj::new()),
+                Box::new(Lazygit::new()),
+                Box::new(Lima::new()),
+                Box::new(Neovim::new()),
+                Box::new(NodeMatrix::new()),
+                Box::new(OpenapiGeneratorCli::new()),
+                Box::new(Opencode::new()),
+                Box::new(Openjdk::new()),
+                Box::new(Pi::new()),
+                Box::new(PreCommit::new()),
+                Box::new(ProtocGenGrpcWeb::new()),
+                Box::new(ProtocPlugins::new()),
+                Box::new(Stow::new()),
+                Box::new(TreeSitter::new()),
+                Box::new(Typescript::new()),
+                Box::new(UniversalCtags::new()),
+                Box::new(Uv::new()),
+            ],
+        },
+        #[cfg(feature = "gnupg")]
+        CatalogGroup {
+            category: Category::Gnupg,
+            packages: vec![
+                Box::new(Gpg::new()),
+                Box::new(Libassuan::new()),
+                Box::new(Libgcrypt::new()),
+                Box::new(LibgpgError::new()),
+                Box::new(Libksba::new()),
+                Box::new(Npth::new()),
+            ],
+        },
+        #[cfg(feature = "kubernetes")]
+        CatalogGroup {
+            category: Category::Kubernetes,
+            packages: vec![
+                Box::new(Argocd::new()),
+                Box::new(Eksctl::new()),
+                Box::new(Fluxcd::new()),
+                Box::new(Helm::new()),
+                Box::new(K9s::new()),
+                Box::new(Kind::new()),
+                Box::new(Kn::new()),
+                Box::new(Kubectl::new()),
+                Box::new(Kubeseal::new()),
+                Box::new(Talosctl::new()),
+                Box::new(Virtctl::new()),
+            ],
+        },
+        #[cfg(feature = "language-server")]
+        CatalogGroup {
+            category: Category::LanguageServer,
+            packages: vec![
+                Box::new(BashLanguageServer::new()),
+                Box::new(DockerfileLanguageServer::new()),
+                Box::new(LuaLanguageServer::new()),
+                Box::new(TerraformLs::new()),
+                Box::new(TypescriptLanguageServer::new()),
+                Box::new(VscodeLangserversExtracted::new()),
+                Box::new(YamlLanguageServer::new()),
+            ],
+        },
+        #[cfg(feature = "library")]
+        CatalogGroup {
+            category: Category::Library,
+            packages: vec![
+                Box::new(AbseilCpp::new()),
+                Box::new(CAres::new()),
+                Box::new(Capnproto::new()),
+                Box::new(FontStack::new()),
+                Box::new(Fontconfig::new()),
+                Box::new(Freetype::new()),
+                Box::new(Giflib::new()),
+                Box::new(Harfbuzz::new()),
+                Box::new(Icu::new()),
+                Box::new(ImageLibs::new()),
+                Box::new(JsonC::new()),
+                Box::new(Libcap::new()),
+                Box::new(Libevent::new()),
+                Box::new(Libffi::new()),
+                Box::new(LibjpegTurbo::new()),
+                Box::new(Libpcap::new()),
+                Box::new(Libpng::new()),
+                Box::new(Libssh2::new()),
+                Box::new(Libuv::new()),
+                Box::new(Libwebp::new()),
+                Box::new(Libwebsockets::new()),
+                Box::new(Libxml2::new()),
+                Box::new(Libxslt::new()),
+                Box::new(Libyaml::new()),
+                Box::new(Mbedtls::new()),
+                Box::new(Ncurses::new()),
+                Box::new(Nghttp2::new()),
+                Box::new(Oniguruma::new()),
+                Box::new(Openssl::new()),
+                Box::new(Pcre2::new()),
+                Box::new(Protobuf::new()),
+                Box::new(Readline::new()),
+                Box::new(Zlib::new()),
+            ],
+        },
+        #[cfg(feature = "media")]
+        CatalogGroup {
+            category: Category::Media,
+            packages: vec![Box::new(Ffmpeg::new()), Box::new(X264::new())],
+        },
+        #[cfg(feature = "network")]
+        CatalogGroup {
+            category: Category::Network,
+            packages: vec![
+                Box::new(Cfssl::new()),
+                Box::new(Doggo::new()),
+                Box::new(Haproxy::new()),
+                Box::new(Mtr::new()),
+                Box::new(Nginx::new()),
+                Box::new(Nmap::new()),
+                Box::new(Tcpdump::new()),
+                Box::new(WireguardGo::new()),
+                Box::new(WireguardTools::new()),
+            ],
+        },
+        #[cfg(feature = "terminal")]
+        CatalogGroup {
+            category: Category::Terminal,
+            packages: vec![
+                Box::new(Abtop::new()),
+                Box::new(B3sum::new()),
+                Box::new(Bat::new()),
+                Box::new(Bottom::new()),
+                Box::new(Delta::new()),
+                Box::new(Direnv::new()),
+                Box::new(Fastfetch::new()),
+                Box::new(Fd::new()),
+                Box::new(Fx::new()),
+                Box::new(Fzf::new()),
+                Box::new(Glow::new()),
+                Box::new(Gron::new()),
+                Box::new(Gum::new()),
+                Box::new(Hexyl::new()),
+                Box::new(Jless::new()),
+                Box::new(Jq::new()),
+                Box::new(Minisign::new()),
+                Box::new(Ncdu::new()),
+                Box::new(Nnn::new()),
+                Box::new(Qsv::new()),
+                Box::new(Ripgrep::new()),
+                Box::new(Sesh::new()),
+                Box::new(Starship::new()),
+                Box::new(Tmux::new()),
+                Box::new(Ttyd::new()),
+                Box::new(Vhs::new()),
+                Box::new(Yq::new()),
+                Box::new(Zoxide::new()),
+                Box::new(Zsh::new()),
+            ],
+        },
+    ]
+}
+
+pub async fn build<F>(context: &mut ConfigContext, filter: F) -> Result<Vec<String>>
+where
+    F: Fn(Category, &dyn ArtifactPackage) -> bool,
+{
+    let system = context.get_system();
+
+    let mut artifacts = vec![];
+    let mut offline_error = None;
+
+    for group in all() {
+        for package in group.packages {
+            if !package.systems().contains(&system) {
+                continue;
+            }
+
+            if !filter(group.category, package.as_ref()) {
+                continue;
+            }
+
+            match context_cache::build(context, package.as_ref()).await {
+                Ok(digest) => artifacts.push(digest),
+                // Keep going offline so the error lists every missing source, not just the first.
+                Err(error) if source::is_offline() => {
+                    offline_error.get_or_insert(error);
+                }
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    source::check_offline()?;
+
+    if let Some(error) = offline_error {
+        return Err(error);
+    }
+
+    Ok(artifacts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, MockContext};
+    use anyhow::Context;
+    use vorpal_sdk::api::artifact::ArtifactSystem::{
+        Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux,
+    };
+
+    // Every package builds on each system it lists, and its aliases carry the version it reports,
+    // so `with_version` and version overrides reach the tags.
+    #[tokio::test]
+    async fn catalog_builds_are_tagged_with_their_version() -> Result<()> {
+        for system in [Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux] {
+            let context = &mut MockContext::new(system).build().await?;
+
+            for group in all() {
+                for package in group.packages {
+                    if !package.systems().contains(&system) {
+                        continue;
+                    }
+
+                    let name = package.name();
+                    let version = package.version();
+
+                    let digest = context_cache::build(context, package.as_ref())
+                        .await
+                        .with_context(|| format!("{name} on {}", system.as_str_name()))?;
+
+                    // Packages without a release of their own have nothing to tag.
+                    if version.is_empty() {
+                        continue;
+                    }
+
+                    let aliases = testing::aliases(context, &digest)?;
+
+                    assert!(
+                        aliases
+                            .iter()
+                            .any(|alias| alias.contains(&format!(":{version}"))),
+                        "{name} on {}: {aliases:?} do not carry {version}",
+                        system.as_str_name()
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
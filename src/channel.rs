@@ -0,0 +1,47 @@
+// Release channel of a prebuilt artifact (neovim and zig). `Stable` is the release pinned in
+// `versions`; the others are upstream's pre-release builds, tagged by channel alone
+// (`neovim:nightly`). neovim's nightly moves without a version bump, while zig's master builds are
+// picked by their dev version. Each artifact maps the channels its upstream publishes to a
+// download URL and rejects the rest. just and helix are left out: neither publishes pre-release
+// binaries, only tagged releases, so a channel would have nothing to download.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Channel {
+    #[default]
+    Stable,
+    Nightly,
+    Rc,
+}
+
+impl Channel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Channel::Stable => "stable",
+            Channel::Nightly => "nightly",
+            Channel::Rc => "rc",
+        }
+    }
+}
+
+// `name:version` for stable builds and `name:<channel>` for the others, which have no fixed
+// version.
+pub fn alias(name: &str, version: &str, channel: Channel) -> String {
+    match channel {
+        Channel::Stable => format!("{name}:{version}"),
+        _ => format!("{name}:{}", channel.as_str()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alias_names_pre_release_channels() {
+        assert_eq!(alias("neovim", "0.12.3", Channel::Stable), "neovim:0.12.3");
+        assert_eq!(
+            alias("neovim", "0.12.3", Channel::Nightly),
+            "neovim:nightly"
+        );
+        assert_eq!(alias("neovim", "0.12.3", Channel::Rc), "neovim:rc");
+    }
+}
@@ -0,0 +1,226 @@
+use anyhow::{bail, Result};
+use reqwest::{header, Client};
+use serde_json::{json, Value};
+use std::{env, sync::Arc};
+use tokio::{sync::Semaphore, task::JoinSet};
+use vorpal_artifacts::{
+    error::ArtifactError,
+    registry,
+    upstream::{self, Upstream},
+};
+
+// Upstreams are queried a few at a time so unauthenticated GitHub requests are not throttled
+// straight away; set GITHUB_TOKEN to lift the limit.
+const CONCURRENCY: usize = 8;
+
+struct Report {
+    current: String,
+    latest: Result<Option<String>>,
+    name: String,
+}
+
+impl Report {
+    fn status(&self) -> &'static str {
+        match &self.latest {
+            Ok(Some(latest)) if upstream::is_newer(latest, &self.current) => "outdated",
+            Ok(Some(_)) => "current",
+            Ok(None) => "unknown",
+            Err(_) => "error",
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        let mut value = json!({
+            "name": self.name,
+            "current": self.current,
+            "status": self.status(),
+        });
+
+        match &self.latest {
+            Ok(Some(latest)) => value["latest"] = json!(upstream::normalize(latest)),
+            Ok(None) => {}
+            Err(error) => value["error"] = json!(format!("{error:#}")),
+        }
+
+        value
+    }
+}
+
+async fn get_json(client: &Client, url: &str) -> Result<Value> {
+    let mut request = client.get(url);
+
+    if url.starts_with("https://api.github.com/") {
+        request = request.header(header::ACCEPT, "application/vnd.github+json");
+
+        if let Ok(token) = env::var("GITHUB_TOKEN") {
+            request = request.bearer_auth(token);
+        }
+    }
+
+    Ok(request.send().await?.error_for_status()?.json().await?)
+}
+
+fn json_string(value: &Value, key: &str, url: &str) -> Result<String> {
+    match value[key].as_str() {
+        Some(value) => Ok(value.to_string()),
+        None => bail!("missing '{key}' in {url}"),
+    }
+}
+
+// Scans an index page for `<prefix><version>.tar` links, skipping pre-releases such as `-rc1`.
+fn directory_latest(body: &str, prefix: &str) -> Option<String> {
+    body.match_indices(prefix)
+        .filter_map(|(index, _)| {
+            let rest = &body[index + prefix.len()..];
+            let end = rest.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+            let version = rest[..end].trim_end_matches('.');
+
+            let is_archive = rest[version.len()..].starts_with(".tar");
+
+            (!version.is_empty() && is_archive).then(|| version.to_string())
+        })
+        .reduce(
+            |latest, version| match upstream::is_newer(&version, &latest) {
+                true => version,
+                false => latest,
+            },
+        )
+}
+
+async fn latest(client: &Client, upstream: Upstream) -> Result<Option<String>> {
+    match upstream {
+        Upstream::Directory { url, prefix } => {
+            let body = client
+                .get(url)
+                .send()
+                .await?
+                .error_for_status()?
+                .text()
+                .await?;
+
+            Ok(directory_latest(&body, prefix))
+        }
+
+        Upstream::Github(repository) => {
+            let url = format!("https://api.github.com/repos/{repository}/releases/latest");
+            let release = get_json(client, &url).await?;
+
+            json_string(&release, "tag_name", &url).map(Some)
+        }
+
+        Upstream::Hashicorp(product) => {
+            let url = format!("https://api.releases.hashicorp.com/v1/releases/{product}/latest");
+            let release = get_json(client, &url).await?;
+
+            json_string(&release, "version", &url).map(Some)
+        }
+
+        Upstream::Npm(package) => {
+            let url = format!("https://registry.npmjs.org/{package}/latest");
+            let release = get_json(client, &url).await?;
+
+            json_string(&release, "version", &url).map(Some)
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let mut json_output = false;
+    let mut names = vec![];
+
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--json" => json_output = true,
+            "-h" | "--help" => {
+                println!("usage: check-updates [--json] [artifact...]");
+                return Ok(());
+            }
+            _ if arg.starts_with('-') => bail!("unknown option: {arg}"),
+            _ => names.push(arg),
+        }
+    }
+
+    let packages = registry::all()
+        .into_iter()
+        .filter(|package| names.is_empty() || names.iter().any(|name| name == package.name()))
+        .collect::<Vec<_>>();
+
+    if let Some(name) = names.iter().find(|name| {
+        !packages
+            .iter()
+            .any(|package| package.name() == name.as_str())
+    }) {
+        return Err(ArtifactError::UnknownArtifact {
+            artifact: name.to_string(),
+        }
+        .into());
+    }
+
+    let client = Client::builder()
+        .user_agent(concat!(
+            env!("CARGO_PKG_NAME"),
+            "/",
+            env!("CARGO_PKG_VERSION")
+        ))
+        .build()?;
+
+    let semaphore = Arc::new(Semaphore::new(CONCURRENCY));
+
+    let mut tasks = JoinSet::new();
+
+    for package in packages.iter() {
+        let client = client.clone();
+        let current = package.version().to_string();
+        let name = package.name().to_string();
+        let semaphore = semaphore.clone();
+        let upstream = package.upstream();
+
+        tasks.spawn(async move {
+            let latest = match upstream {
+                Some(upstream) => {
+                    let _permit = semaphore.acquire().await?;
+                    latest(&client, upstream).await
+                }
+                None => Ok(None),
+            };
+
+            Ok::<_, anyhow::Error>(Report {
+                current,
+                latest,
+                name,
+            })
+        });
+    }
+
+    let mut reports = vec![];
+
+    while let Some(report) = tasks.join_next().await {
+        reports.push(report??);
+    }
+
+    reports.sort_by(|a, b| a.name.cmp(&b.name));
+
+    if json_output {
+        let reports = reports.iter().map(Report::to_json).collect::<Vec<_>>();
+
+        println!("{}", serde_json::to_string_pretty(&reports)?);
+
+        return Ok(());
+    }
+
+    for report in reports.iter() {
+        match &report.latest {
+            Ok(Some(latest)) if report.status() == "outdated" => println!(
+                "{}: {} -> {}",
+                report.name,
+                report.current,
+                upstream::normalize(latest)
+            ),
+            Err(error) => eprintln!("{}: {error:#}", report.name),
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use crate::{
+    artifact::ArtifactPackage,
+    builder::{check, compiler_cache, jobs, musl, zig_cc},
+    variant,
+};
+use anyhow::{anyhow, Result};
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, Mutex},
+};
+use vorpal_sdk::context::ConfigContext;
+
+// Digests of packages built with their default options. Keys include the context's path,
+// namespace, system and every variable in `VARIABLES`, and an entry is only used by a context
+// whose store holds the artifact, so one context never hands out another's digest.
+static DIGESTS: LazyLock<Mutex<HashMap<String, String>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+// Variables that change what a package's default configuration builds.
+const VARIABLES: &[&str] = &[
+    check::VARIABLE,
+    compiler_cache::VARIABLE,
+    jobs::VARIABLE,
+    musl::VARIABLE,
+    variant::VARIABLE,
+    zig_cc::VARIABLE,
+];
+
+fn key(context: &ConfigContext, package: &dyn ArtifactPackage) -> String {
+    let variables = VARIABLES
+        .iter()
+        .map(|name| {
+            let value = context.get_variable(name).unwrap_or_default();
+
+            format!("{name}={value}")
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+
+    format!(
+        "{}:{}:{:?}:{}:{}:{variables}",
+        context.get_artifact_context_path().display(),
+        context.get_artifact_namespace(),
+        context.get_system(),
+        package.name(),
+        package.version(),
+    )
+}
+
+// Only pass packages in their default configuration; anything customised through `with_*`
+// would be cached under the same name and version as the default build.
+pub async fn build(context: &mut ConfigContext, package: &dyn ArtifactPackage) -> Result<String> {
+    let key = key(context, package);
+
+    let cached = DIGESTS
+        .lock()
+        .map_err(|error| anyhow!("context cache is poisoned: {error}"))?
+        .get(&key)
+        .filter(|digest| context.get_artifact(digest).is_some())
+        .cloned();
+
+    if let Some(digest) = cached {
+        return Ok(digest);
+    }
+
+    let digest = package.build(context).await?;
+
+    DIGESTS
+        .lock()
+        .map_err(|error| anyhow!("context cache is poisoned: {error}"))?
+        .insert(key, digest.clone());
+
+    Ok(digest)
+}
+
+#[cfg(all(test, feature = "library"))]
+mod tests {
+    use super::*;
+    use crate::{artifact::zlib::Zlib, testing::MockContext};
+    use vorpal_sdk::api::artifact::ArtifactSystem::Aarch64Darwin;
+
+    #[tokio::test]
+    async fn build_is_scoped_to_context_and_variables() -> Result<()> {
+        let first = &mut MockContext::new(Aarch64Darwin).build().await?;
+        let second = &mut MockContext::new(Aarch64Darwin).build().await?;
+
+        let digest = build(first, &Zlib::new()).await?;
+
+        assert_eq!(build(second, &Zlib::new()).await?, digest);
+        assert!(second.get_artifact(&digest).is_some());
+
+        let jobs = &mut MockContext::new(Aarch64Darwin)
+            .with_variable(jobs::VARIABLE, "2")
+            .build()
+            .await?;
+
+        assert_ne!(build(jobs, &Zlib::new()).await?, digest);
+
+        Ok(())
+    }
+}
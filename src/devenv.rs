@@ -0,0 +1,259 @@
+use crate::{context_cache, error::ArtifactError, registry};
+use anyhow::{bail, Context, Result};
+use std::{collections::BTreeMap, fs, path::Path};
+use vorpal_sdk::context::ConfigContext;
+
+// File a project keeps next to its Vorpal config to pick the tools of its development
+// environment by catalog name, with the version each must be at, and what activating the
+// environment sets up besides PATH:
+//
+//   [tools]
+//   ripgrep = "14"
+//   terraform = "1.13"
+//
+//   [env]
+//   KUBECONFIG = "$PWD/.kube/config"
+//
+//   [hooks]
+//   enter = ['eval "$(starship init bash)"']
+//
+// A version matches the catalog version it prefixes on whole components, so "1.13" accepts
+// 1.13.4 but not 1.130.0; "*" accepts any. When the catalog version does not match, the newest
+// matching version in `versions::PINNED` is built instead (`terraform = "1.5"`); other versions
+// go through the versions file, see `overrides`. Env values are expanded by the activation script, and `deactivate` restores
+// them; enter hooks run after the exports, in order, and are not undone.
+pub const PATH: &str = "devenv.toml";
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Config {
+    pub env: BTreeMap<String, String>,
+    pub hooks: Vec<String>,
+    pub tools: BTreeMap<String, String>,
+}
+
+fn strings<'a>(key: &str, table: &'a toml::Table) -> Result<Vec<(&'a String, &'a str)>> {
+    table
+        .iter()
+        .map(|(name, value)| match value.as_str() {
+            Some(value) => Ok((name, value)),
+            None => bail!("'{key}.{name}' must be a string"),
+        })
+        .collect()
+}
+
+fn parse(contents: &str) -> Result<Config> {
+    let table = contents.parse::<toml::Table>()?;
+
+    let mut config = Config::default();
+
+    for (key, value) in table {
+        let Some(value) = value.as_table() else {
+            bail!("'{key}' must be a table");
+        };
+
+        match key.as_str() {
+            "env" => {
+                for (name, value) in strings(&key, value)? {
+                    // The activation script builds PATH from the tools, which is where binaries
+                    // belong.
+                    if name == "PATH" || name.is_empty() || name.contains(['=', ' ']) {
+                        bail!("'env.{name}' is not a variable the environment can set");
+                    }
+
+                    config.env.insert(name.clone(), value.to_string());
+                }
+            }
+            "hooks" => {
+                for (name, hooks) in value {
+                    let hooks = hooks
+                        .as_array()
+                        .filter(|_| name == "enter")
+                        .and_then(|hooks| {
+                            hooks
+                                .iter()
+                                .map(|hook| hook.as_str())
+                                .collect::<Option<Vec<_>>>()
+                        });
+
+                    let Some(hooks) = hooks else {
+                        bail!("'hooks.{name}' must be 'enter', a list of commands");
+                    };
+
+                    config.hooks.extend(hooks.into_iter().map(str::to_string));
+                }
+            }
+            "tools" => {
+                for (name, version) in strings(&key, value)? {
+                    config.tools.insert(name.clone(), version.to_string());
+                }
+            }
+            _ => bail!("unknown key '{key}' (expected 'env', 'hooks' or 'tools')"),
+        }
+    }
+
+    Ok(config)
+}
+
+fn matches(version: &str, requirement: &str) -> bool {
+    if requirement == "*" {
+        return true;
+    }
+
+    version
+        .strip_prefix(requirement)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(['.', '-', '+']))
+}
+
+// `None` when the project has no devenv file, so the environment keeps its built-in tools only.
+pub fn load(path: impl AsRef<Path>) -> Result<Option<Config>> {
+    let path = path.as_ref();
+
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("failed to read devenv file {}", path.display()))?;
+
+    let config =
+        parse(&contents).with_context(|| format!("invalid devenv file {}", path.display()))?;
+
+    Ok(Some(config))
+}
+
+impl Config {
+    // `KEY="value"` pairs for the activation script, which exports them as written; quotes and
+    // backslashes are escaped so only `$` expansions apply.
+    pub fn environments(&self) -> Vec<String> {
+        self.env
+            .iter()
+            .map(|(key, value)| {
+                let value = value.replace('\\', "\\\\").replace('"', "\\\"");
+
+                format!("{key}=\"{value}\"")
+            })
+            .collect()
+    }
+
+    // Builds every tool through the registry, in name order, at the catalog or pinned version its
+    // requirement matches.
+    pub async fn build(&self, context: &mut ConfigContext) -> Result<Vec<String>> {
+        let mut artifacts = vec![];
+
+        for (name, requirement) in self.tools.iter() {
+            let Some(package) = registry::get(name) else {
+                return Err(ArtifactError::UnknownArtifact {
+                    artifact: name.clone(),
+                }
+                .into());
+            };
+
+            if matches(package.version(), requirement) {
+                artifacts.push(context_cache::build(context, package.as_ref()).await?);
+
+                continue;
+            }
+
+            let pinned = registry::pinned(name)
+                .into_iter()
+                .rev()
+                .find(|version| matches(version, requirement))
+                .and_then(|version| registry::get_pinned(name, version));
+
+            let Some(package) = pinned else {
+                return Err(ArtifactError::UnsupportedVersion {
+                    artifact: name.clone(),
+                    version: requirement.clone(),
+                }
+                .into());
+            };
+
+            artifacts.push(context_cache::build(context, package.as_ref()).await?);
+        }
+
+        Ok(artifacts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "cloud")]
+    use crate::testing::{self, MockContext};
+    use indoc::indoc;
+    #[cfg(feature = "cloud")]
+    use vorpal_sdk::api::artifact::ArtifactSystem::X8664Linux;
+
+    #[test]
+    fn parse_reads_tools() -> Result<()> {
+        let config = parse("[tools]\nripgrep = \"14\"\nterraform = \"1.13\"\n")?;
+
+        assert_eq!(
+            config.tools,
+            BTreeMap::from([
+                ("ripgrep".to_string(), "14".to_string()),
+                ("terraform".to_string(), "1.13".to_string()),
+            ])
+        );
+        assert!(parse("ripgrep = \"14\"").is_err());
+        assert!(parse("[tools]\nripgrep = 14").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_reads_env_and_hooks() -> Result<()> {
+        let config = parse(indoc! {r#"
+            [env]
+            KUBECONFIG = "$PWD/.kube/config"
+            GREETING = 'say "hi"'
+
+            [hooks]
+            enter = ['eval "$(starship init bash)"']
+        "#})?;
+
+        assert_eq!(
+            config.environments(),
+            [
+                "GREETING=\"say \\\"hi\\\"\"",
+                "KUBECONFIG=\"$PWD/.kube/config\"",
+            ]
+        );
+        assert_eq!(config.hooks, ["eval \"$(starship init bash)\""]);
+        assert!(parse("[env]\nPATH = \"/usr/bin\"").is_err());
+        assert!(parse("[hooks]\nexit = [\"echo bye\"]").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn matches_whole_components() {
+        assert!(matches("1.13.4", "1.13"));
+        assert!(matches("1.13.4", "1.13.4"));
+        assert!(matches("0.18-20240915", "0.18"));
+        assert!(matches("2.28.0", "*"));
+        assert!(!matches("1.130.0", "1.13"));
+        assert!(!matches("1.12.2", "1.13"));
+    }
+
+    #[cfg(feature = "cloud")]
+    #[tokio::test]
+    async fn build_uses_pinned_versions() -> Result<()> {
+        let context = &mut MockContext::new(X8664Linux).build().await?;
+
+        let config = parse("[tools]\nterraform = \"1.5\"\n")?;
+
+        let artifacts = config.build(context).await?;
+
+        assert_eq!(
+            testing::aliases(context, &artifacts[0])?,
+            ["terraform:1.5.7", "terraform:1.5"]
+        );
+
+        let config = parse("[tools]\nterraform = \"1.4\"\n")?;
+
+        assert!(config.build(context).await.is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,1401 @@
+pub fn get(path: &str) -> Option<&'static str> {
+    DIGESTS
+        .iter()
+        .find(|(source_path, _)| *source_path == path)
+        .map(|(_, digest)| *digest)
+}
+
+const DIGESTS: &[(&str, &str)] = &[
+    (
+        "https://awscli.amazonaws.com/AWSCLIV2-2.33.1.pkg",
+        "7debfa42bc88109f226700e6c2620ef19497d51932d9d6d8859791fd5f470b20",
+    ),
+    (
+        "https://awscli.amazonaws.com/awscli-exe-linux-aarch64-2.33.1.zip",
+        "b850f52138bd952312da5cb2edb589f3dcf0d5a57f69016c331462991eeebfce",
+    ),
+    (
+        "https://awscli.amazonaws.com/awscli-exe-linux-x86_64-2.33.1.zip",
+        "9cc123be6401193a29659b9aaf65f3fa6be770ff0fc9fbdd4f41cc6ec666c294",
+    ),
+    (
+        "https://cache.agilebits.com/dist/1P/op2/pkg/v2.34.1/op_darwin_amd64_v2.34.1.zip",
+        "4b4c271aff8607e0c536fef9b2581825a323582fb0fb319188d4e31fe4297e91",
+    ),
+    (
+        "https://cache.agilebits.com/dist/1P/op2/pkg/v2.34.1/op_darwin_arm64_v2.34.1.zip",
+        "a178768e4a235c4bccf1350b81c2af0225e7f8029cc73a1d58d7c22ee72be769",
+    ),
+    (
+        "https://cache.agilebits.com/dist/1P/op2/pkg/v2.34.1/op_linux_amd64_v2.34.1.zip",
+        "fca044d451c67e845426d41ea7b42137d450bc98323b7725ec5f47c9a7dde461",
+    ),
+    (
+        "https://cache.agilebits.com/dist/1P/op2/pkg/v2.34.1/op_linux_arm64_v2.34.1.zip",
+        "178e679d6164bf41d23c8b5eef9b96247abfe8e2ddf005e35ddd50c38a05ff57",
+    ),
+    (
+        "https://dl.k8s.io/release/v1.35.0/bin/darwin/amd64/kubectl",
+        "347f52af38adce644c19e20c33970ba505f699865118b0e9dfe65c6e5a51b50d",
+    ),
+    (
+        "https://dl.k8s.io/release/v1.35.0/bin/darwin/arm64/kubectl",
+        "d1f8429a0744371b107f4d1a6259f6468f5ceaebb3bd209edf0a811820a2cc2d",
+    ),
+    (
+        "https://dl.k8s.io/release/v1.35.0/bin/linux/amd64/kubectl",
+        "b3ccb81e850fc635e8391d5e46e3b322f4b9b805cc99a8e91766c0718012af3c",
+    ),
+    (
+        "https://dl.k8s.io/release/v1.35.0/bin/linux/arm64/kubectl",
+        "657c440a8ef456d50c475d93c353d30f0ff844da965eac51f7de32aa79114059",
+    ),
+    (
+        "https://download.java.net/java/GA/jdk25.0.1/2fbf10d8c78e40bd87641c434705079d/8/GPL/openjdk-25.0.1_linux-aarch64_bin.tar.gz",
+        "b285b7e878eff9dda0dde3d6cb3d78ddb28355b6d39b907f53f56210672e88d3",
+    ),
+    (
+        "https://download.java.net/java/GA/jdk25.0.1/2fbf10d8c78e40bd87641c434705079d/8/GPL/openjdk-25.0.1_linux-x64_bin.tar.gz",
+        "44e3ba14d14663e83d28194c409bf8e27cb7597fdb14aa26c155b6ba9c69ee4d",
+    ),
+    (
+        "https://download.java.net/java/GA/jdk25.0.1/2fbf10d8c78e40bd87641c434705079d/8/GPL/openjdk-25.0.1_macos-aarch64_bin.tar.gz",
+        "af75905894be8d3cc29e9494a352f60fcdaa62b1993281619061a5097eaf6d7b",
+    ),
+    (
+        "https://download.java.net/java/GA/jdk25.0.1/2fbf10d8c78e40bd87641c434705079d/8/GPL/openjdk-25.0.1_macos-x64_bin.tar.gz",
+        "153a7228184064a3df7badb5be88889e28b4207745154a44158058adce9f40a5",
+    ),
+    (
+        "https://download.videolan.org/pub/videolan/x264/snapshots/x264-snapshot-20191217-2245-stable.tar.bz2",
+        "e9ffd69a8892266c0e5872036c6783683b494b3fcd34c5617ba8fe8666fb8efc",
+    ),
+    (
+        "https://downloads.sourceforge.net/project/zsh/zsh/5.9/zsh-5.9.tar.xz",
+        "375c3a34bd2233f8aa0f49573d2e57947d63e50668aefcd4988ce769bf017f9d",
+    ),
+    (
+        "https://ffmpeg.org/releases/ffmpeg-8.0.1.tar.xz",
+        "721afb68d781b501c854d9d6193e357415bda37c27fb1051bd73e15ad6d3b309",
+    ),
+    (
+        "https://ftpmirror.gnu.org/readline/readline-8.2.tar.gz",
+        "bd548669e2e324cf1450a04897779301c20f6ccb161f2f6ede7584cab56ce83f",
+    ),
+    (
+        "https://get.helm.sh/helm-v4.0.4-darwin-amd64.tar.gz",
+        "8c47e48f689bd861177c77171f491f4301e938ce39e8d6c1e88f27af7c347e7b",
+    ),
+    (
+        "https://get.helm.sh/helm-v4.0.4-darwin-arm64.tar.gz",
+        "17b33b5b816e1353b1b4d9071b1306960e5670e430fa5df4b4ea57f7100f85f4",
+    ),
+    (
+        "https://get.helm.sh/helm-v4.0.4-linux-amd64.tar.gz",
+        "1ea0d7ec3aa3ba0671ffc52ed88dc2676766d823f0930921802045af1eb78c05",
+    ),
+    (
+        "https://get.helm.sh/helm-v4.0.4-linux-arm64.tar.gz",
+        "0ac4e0c87f93fcd2aafe9c16e55a294b80dc2fcd1e49162f86f9a436f5452779",
+    ),
+    (
+        "https://github.com/BurntSushi/ripgrep/releases/download/14.1.1/ripgrep-14.1.1-aarch64-apple-darwin.tar.gz",
+        "eb0c1779060a651e1288e5a3a6f3d5e3460a2955b64f9d71feefa444ceb52b54",
+    ),
+    (
+        "https://github.com/BurntSushi/ripgrep/releases/download/14.1.1/ripgrep-14.1.1-aarch64-unknown-linux-gnu.tar.gz",
+        "c6e2bc6e6daf12082671e8f6a9f76ace407976d3886f7a504159faac1383f721",
+    ),
+    (
+        "https://github.com/BurntSushi/ripgrep/releases/download/14.1.1/ripgrep-14.1.1-x86_64-apple-darwin.tar.gz",
+        "bf85900423b680dfa12bf3a240013135dda16b531becb392d3c0a18d0cd150c9",
+    ),
+    (
+        "https://github.com/BurntSushi/ripgrep/releases/download/14.1.1/ripgrep-14.1.1-x86_64-unknown-linux-musl.tar.gz",
+        "57d61ac3324ba6486b798c8e9dfbab9835a595a8d4ace6342886a20b50fde13a",
+    ),
+    (
+        "https://github.com/ClementTsang/bottom/releases/download/0.11.1/bottom_aarch64-apple-darwin.tar.gz",
+        "baefba5765f348b83567b41101960588d2ae04512ecaf4f65ecd01ad3b67dfcc",
+    ),
+    (
+        "https://github.com/ClementTsang/bottom/releases/download/0.11.1/bottom_aarch64-unknown-linux-gnu.tar.gz",
+        "ca2abfdadc1c5df2c87cf8fbcf56ef0d7b38ac29277ace74d4ff257c516d3894",
+    ),
+    (
+        "https://github.com/ClementTsang/bottom/releases/download/0.11.1/bottom_x86_64-apple-darwin.tar.gz",
+        "ac639e7311b3eb2fcc8d22c9b0bb82424081965404a9f760dede1ab64cfa630d",
+    ),
+    (
+        "https://github.com/ClementTsang/bottom/releases/download/0.11.1/bottom_x86_64-unknown-linux-musl.tar.gz",
+        "21dff7913b3ac0da4e7c8f0381f54fb56ec9c9046b8d92dc181e7e9aa9410b21",
+    ),
+    (
+        "https://github.com/DopplerHQ/cli/releases/download/3.75.1/doppler_3.75.1_linux_amd64.tar.gz",
+        "74c431039e5395ef554a718efb927e237cc732c11b9f7b87821f6b92ef37a6b7",
+    ),
+    (
+        "https://github.com/DopplerHQ/cli/releases/download/3.75.1/doppler_3.75.1_linux_arm64.tar.gz",
+        "dfbd6548b0d00423291ab72c41ae2b4ee8b501df44b93ce1b75eaed95971352f",
+    ),
+    (
+        "https://github.com/DopplerHQ/cli/releases/download/3.75.1/doppler_3.75.1_macOS_amd64.tar.gz",
+        "f0b43420b8238bcacaa8aadb37f2a99447d1690f2e4f817877b595c396c53628",
+    ),
+    (
+        "https://github.com/DopplerHQ/cli/releases/download/3.75.1/doppler_3.75.1_macOS_arm64.tar.gz",
+        "cc2860da598bb056f8f1a11a781f56ddf194ab3b2b5976a881b93465ec0d2274",
+    ),
+    (
+        "https://github.com/Kitware/CMake/releases/download/v4.2.3/cmake-4.2.3-linux-aarch64.tar.gz",
+        "b7372c141bccb118027d96f2bc63e635dce4874c89049f21c60c6b63d2ee3a13",
+    ),
+    (
+        "https://github.com/Kitware/CMake/releases/download/v4.2.3/cmake-4.2.3-linux-x86_64.tar.gz",
+        "c73e599fbaabcea7a51019217810d30f2bea1be3f6feccd5abba962bccaa3b18",
+    ),
+    (
+        "https://github.com/Kitware/CMake/releases/download/v4.2.3/cmake-4.2.3-macos-universal.tar.gz",
+        "0a581d07b2545bc00e9c2ea0c52f2d353ceb5dd365eeeecb5dbe1b8599a61eff",
+    ),
+    (
+        "https://github.com/LuaLS/lua-language-server/releases/download/3.18.2/lua-language-server-3.18.2-darwin-arm64.tar.gz",
+        "0af75d2d31b1c1918cc0f47403b724da62ae01e936ca9fa161d1f262e8631884",
+    ),
+    (
+        "https://github.com/LuaLS/lua-language-server/releases/download/3.18.2/lua-language-server-3.18.2-darwin-x64.tar.gz",
+        "16e84fa912c9386868e6027dc87c1bc058effc92ac188c0fa083562bdd2cf133",
+    ),
+    (
+        "https://github.com/LuaLS/lua-language-server/releases/download/3.18.2/lua-language-server-3.18.2-linux-arm64.tar.gz",
+        "8c1c4785285b8c93cd25e897f71cf56ff10c6f4e58f1463f7e78cec1c079f796",
+    ),
+    (
+        "https://github.com/LuaLS/lua-language-server/releases/download/3.18.2/lua-language-server-3.18.2-linux-x64.tar.gz",
+        "e006a0dc68d63ac00cd4b6fbf3934026e18ef7e260506e54f28e886b0b8d2e31",
+    ),
+    (
+        "https://github.com/Mbed-TLS/mbedtls/releases/download/mbedtls-3.6.5/mbedtls-3.6.5.tar.bz2",
+        "70e07a39da6c6bd8c4591ab5d4ecf5fa7d37c566697dbdb478f46934f8961138",
+    ),
+    (
+        "https://github.com/ajeetdsouza/zoxide/releases/download/v0.9.9/zoxide-0.9.9-aarch64-apple-darwin.tar.gz",
+        "cc76b3bfa120e009ea5489f6d77157695534ad4818f0b3d33080beeb98b7fe7b",
+    ),
+    (
+        "https://github.com/ajeetdsouza/zoxide/releases/download/v0.9.9/zoxide-0.9.9-aarch64-unknown-linux-musl.tar.gz",
+        "47b8a1a033172a56350915aa964408bc9bbceef17a4f9f41e35248163efd2c62",
+    ),
+    (
+        "https://github.com/ajeetdsouza/zoxide/releases/download/v0.9.9/zoxide-0.9.9-x86_64-apple-darwin.tar.gz",
+        "595444b57520961a27b2f53a13f146ab8927058666370719553a6f879af798a2",
+    ),
+    (
+        "https://github.com/ajeetdsouza/zoxide/releases/download/v0.9.9/zoxide-0.9.9-x86_64-unknown-linux-musl.tar.gz",
+        "4824d9bc01ae86b77f9e235c59bf6a80f8e5e95683c10a27c987a13784867351",
+    ),
+    (
+        "https://github.com/anomalyco/opencode/releases/download/v1.17.11/opencode-darwin-arm64.zip",
+        "da43578becb9b9900d84d2d1f26d655ee94f96b9f2b735f5f58359066539c610",
+    ),
+    (
+        "https://github.com/anomalyco/opencode/releases/download/v1.17.11/opencode-darwin-x64.zip",
+        "dc26baaac75339aa9d15f658351f0fd016baf32b242c4defb945fa4d11e16337",
+    ),
+    (
+        "https://github.com/anomalyco/opencode/releases/download/v1.17.11/opencode-linux-arm64-musl.tar.gz",
+        "8f48cad569516606d5c0ca41010a0c424780b3ebc892849eef0b44cdd55c9908",
+    ),
+    (
+        "https://github.com/anomalyco/opencode/releases/download/v1.17.11/opencode-linux-x64-musl.tar.gz",
+        "1f7198c640e6d4506fdc1fd62b2ece6358a7b88bcfc51f6e091f196adf28174e",
+    ),
+    (
+        "https://github.com/argoproj/argo-cd/releases/download/v3.2.3/argocd-darwin-amd64",
+        "1ef724533580a0011ff20f172119065214470aa7fca80eb10c9e11a26489f6cc",
+    ),
+    (
+        "https://github.com/argoproj/argo-cd/releases/download/v3.2.3/argocd-darwin-arm64",
+        "bd4b2683005fe932123093d357e8bb2c38048e0371cd27156ebb8a83de8a9bd5",
+    ),
+    (
+        "https://github.com/argoproj/argo-cd/releases/download/v3.2.3/argocd-linux-amd64",
+        "fd3e8c5a2e3ea125133dcdd8cd24208ea3a9f17156e89def83449ef655295f54",
+    ),
+    (
+        "https://github.com/argoproj/argo-cd/releases/download/v3.2.3/argocd-linux-arm64",
+        "62e67821d0a32b3d22b7a8efb821ab068e6eb2e1202cc81448ec0fffb8a0e775",
+    ),
+    (
+        "https://github.com/astral-sh/uv/releases/download/0.10.11/uv-aarch64-apple-darwin.tar.gz",
+        "4424c54e6a6c23457dfc8121089748d0572c7a7aaded6feb415309bfc39ef840",
+    ),
+    (
+        "https://github.com/astral-sh/uv/releases/download/0.10.11/uv-aarch64-unknown-linux-gnu.tar.gz",
+        "77e01ee0538175aa9a83d28e7435aba1160b350355ed225c5fac9c6cdbcd2de2",
+    ),
+    (
+        "https://github.com/astral-sh/uv/releases/download/0.10.11/uv-x86_64-apple-darwin.tar.gz",
+        "d9c7000b2fbd546120d0b6420715414390084491df5c471938b0cbdca7d039f7",
+    ),
+    (
+        "https://github.com/astral-sh/uv/releases/download/0.10.11/uv-x86_64-unknown-linux-gnu.tar.gz",
+        "7597d68b306d295e7d8093eed3ac0551aa349366597cd306e481291a93ce8e24",
+    ),
+    (
+        "https://github.com/bitnami-labs/sealed-secrets/releases/download/v0.34.0/kubeseal-0.34.0-darwin-amd64.tar.gz",
+        "7e49bc7e4af82131776ede4cc6bbcff2cdaaa1ccdafea7df81f577c9d0bfad94",
+    ),
+    (
+        "https://github.com/bitnami-labs/sealed-secrets/releases/download/v0.34.0/kubeseal-0.34.0-darwin-arm64.tar.gz",
+        "5e29215d8bc7eb4713d6cb7c609bd8d0a4c8c2648b009492ea59135569359a77",
+    ),
+    (
+        "https://github.com/bitnami-labs/sealed-secrets/releases/download/v0.34.0/kubeseal-0.34.0-linux-amd64.tar.gz",
+        "1090807578d6f784e8e790dd730d7cdb683e34f285c253244affc0b1f38f7b8d",
+    ),
+    (
+        "https://github.com/bitnami-labs/sealed-secrets/releases/download/v0.34.0/kubeseal-0.34.0-linux-arm64.tar.gz",
+        "0284edc86e4d0d5f5a5d15f439eef4abbf7adb7d5d98fceecd93d6eb49af2f08",
+    ),
+    (
+        "https://github.com/casey/just/releases/download/1.45.0/just-1.45.0-aarch64-apple-darwin.tar.gz",
+        "8f34d86872a3a379ca58b96f1d5c2cf06076212fc8c1f8e4edbc794c523f4f49",
+    ),
+    (
+        "https://github.com/casey/just/releases/download/1.45.0/just-1.45.0-aarch64-unknown-linux-musl.tar.gz",
+        "52f7c625632197fe658fa5a6cd8fd4743efb2009618c9756c91914ca12a8a67b",
+    ),
+    (
+        "https://github.com/casey/just/releases/download/1.45.0/just-1.45.0-x86_64-apple-darwin.tar.gz",
+        "612686fbcf969b1c473f16b2bf87210d234a9d3204d3e7d55994b080091e39af",
+    ),
+    (
+        "https://github.com/casey/just/releases/download/1.45.0/just-1.45.0-x86_64-unknown-linux-musl.tar.gz",
+        "52cf02b0cae7ca331c7ae345e233a216cf1e9d2ac2cfe17e00895ca3715a21af",
+    ),
+    (
+        "https://github.com/charmbracelet/glow/releases/download/v2.1.1/glow_2.1.1_Darwin_arm64.tar.gz",
+        "74524e358516c2e398b77a9f7ac6341d09c68adb6dd60ee132444c7c3d61d515",
+    ),
+    (
+        "https://github.com/charmbracelet/glow/releases/download/v2.1.1/glow_2.1.1_Darwin_x86_64.tar.gz",
+        "65deff5aa0a7b86e80a1599ec5dff955222a9fcc1caf16f7d18bdf5cd5cbff21",
+    ),
+    (
+        "https://github.com/charmbracelet/glow/releases/download/v2.1.1/glow_2.1.1_Linux_arm64.tar.gz",
+        "2685298f1cf101e04213efe44ccd321e4e4b051052759acb763d9886911d0aeb",
+    ),
+    (
+        "https://github.com/charmbracelet/glow/releases/download/v2.1.1/glow_2.1.1_Linux_x86_64.tar.gz",
+        "7833c90bc227017d943b2eb53d12947345ef03ac616d658508f310c804714361",
+    ),
+    (
+        "https://github.com/charmbracelet/gum/releases/download/v0.17.0/gum_0.17.0_Darwin_arm64.tar.gz",
+        "ae3c5778aaf3e3c06757b7c1cf11771a6dddf96b215d1fc21d34aac4104f4eec",
+    ),
+    (
+        "https://github.com/charmbracelet/gum/releases/download/v0.17.0/gum_0.17.0_Darwin_x86_64.tar.gz",
+        "f31d1695674158aa6afba938a3e69dd22d3acb069c11e2bfb907d638db36b8b8",
+    ),
+    (
+        "https://github.com/charmbracelet/gum/releases/download/v0.17.0/gum_0.17.0_Linux_arm64.tar.gz",
+        "a441c10b766ecb2ae6188252485d6d2844551bc7990ea2caf15709df7a4fd13d",
+    ),
+    (
+        "https://github.com/charmbracelet/gum/releases/download/v0.17.0/gum_0.17.0_Linux_x86_64.tar.gz",
+        "e2d44a169585e48a2cfe2a7ee2b80a5b582b50ddf2aef97181cf0e84ad9333c2",
+    ),
+    (
+        "https://github.com/charmbracelet/vhs/releases/download/v0.10.0/vhs_0.10.0_Darwin_arm64.tar.gz",
+        "9546938042c798bcee980f06270330e6dbf03c40faab5e2bafff1eaa94c720d5",
+    ),
+    (
+        "https://github.com/charmbracelet/vhs/releases/download/v0.10.0/vhs_0.10.0_Darwin_x86_64.tar.gz",
+        "98212c466a76e2b9982cfd1a1e0d8ec505d54a313d0646f5d578a677aa83dc1b",
+    ),
+    (
+        "https://github.com/charmbracelet/vhs/releases/download/v0.10.0/vhs_0.10.0_Linux_arm64.tar.gz",
+        "9ad607b0a00a08eb41d3ca0c39e7c896f5eee7e6c21b3cfec233d901f7735ecd",
+    ),
+    (
+        "https://github.com/charmbracelet/vhs/releases/download/v0.10.0/vhs_0.10.0_Linux_x86_64.tar.gz",
+        "dd2886fe8104f119c13d63bdc36cde501a4e08a3fce5ac65c9f7fd2e3e1da09a",
+    ),
+    (
+        "https://github.com/containers/skopeo/archive/refs/tags/v1.21.0.tar.gz",
+        "70de9e62ca875d9d8c4c698fcc8bc27a3bf952ff949eb6a15c6bfdc66a7b003f",
+    ),
+    (
+        "https://github.com/cue-lang/cue/releases/download/v0.15.1/cue_v0.15.1_darwin_amd64.tar.gz",
+        "3f91419fbc916466ae835787957507e12e7150069ec8890fa7f0d7ba29880acb",
+    ),
+    (
+        "https://github.com/cue-lang/cue/releases/download/v0.15.1/cue_v0.15.1_darwin_arm64.tar.gz",
+        "b22048682013f1d2e1e3fe2ecadf6fb4c1c87232283d18f07d8e148b3791d68e",
+    ),
+    (
+        "https://github.com/cue-lang/cue/releases/download/v0.15.1/cue_v0.15.1_linux_amd64.tar.gz",
+        "2bc26da522822b50fe3cfbba1cd035e8fa03fb0b6205710f197ae4101f1a602e",
+    ),
+    (
+        "https://github.com/cue-lang/cue/releases/download/v0.15.1/cue_v0.15.1_linux_arm64.tar.gz",
+        "6cf9a17adc98198f721a514c131b297fbd904e4003a4e446dc49cdb336ccd571",
+    ),
+    (
+        "https://github.com/dandavison/delta/releases/download/0.18.2/delta-0.18.2-aarch64-apple-darwin.tar.gz",
+        "5d91e97d17f534ad0edbebcc1d424d22163b1c93d0555d325c76094d5a047a98",
+    ),
+    (
+        "https://github.com/dandavison/delta/releases/download/0.18.2/delta-0.18.2-aarch64-unknown-linux-gnu.tar.gz",
+        "9bfb678781bd433a9a619a0ed4522d7d88cf4617d72f308ae5b77226dc569df8",
+    ),
+    (
+        "https://github.com/dandavison/delta/releases/download/0.18.2/delta-0.18.2-x86_64-apple-darwin.tar.gz",
+        "8a5d725368b6c23cb0863a72e69a001b520cc8d70c670a08e47c9ce805ddb3ae",
+    ),
+    (
+        "https://github.com/dandavison/delta/releases/download/0.18.2/delta-0.18.2-x86_64-unknown-linux-musl.tar.gz",
+        "a787e0c934950fda32f65abb8039d5da945226c69fd4cc1e0984b08d6996c13d",
+    ),
+    (
+        "https://github.com/derailed/k9s/releases/download/v0.50.18/k9s_Darwin_amd64.tar.gz",
+        "5acd1bad92df3b14460144651a3a128a87346b1f064a22f26f0a5251431cdb29",
+    ),
+    (
+        "https://github.com/derailed/k9s/releases/download/v0.50.18/k9s_Darwin_arm64.tar.gz",
+        "c27c31b3c2caab9209b54d9e5e61cbd8c39a112c84e076c3a1f6c376fa05e1b3",
+    ),
+    (
+        "https://github.com/derailed/k9s/releases/download/v0.50.18/k9s_Linux_amd64.tar.gz",
+        "fcc42954aea2023650adcd8f73e1a186e1dff84b8563692536f593f8eb4d6d8c",
+    ),
+    (
+        "https://github.com/derailed/k9s/releases/download/v0.50.18/k9s_Linux_arm64.tar.gz",
+        "e5dcfc8309f033309143d492e088503699387911b7ac223a707d6aef27a72be5",
+    ),
+    (
+        "https://github.com/direnv/direnv/releases/download/v2.37.1/direnv.darwin-amd64",
+        "3a9dfebde7d15e91c1a89e58b720da76c093ba77714767217db3af19514cd599",
+    ),
+    (
+        "https://github.com/direnv/direnv/releases/download/v2.37.1/direnv.darwin-arm64",
+        "8204ab746effc28aa1dd329c50b585e86ff322607f255bea09e2bd654b4fcdb8",
+    ),
+    (
+        "https://github.com/direnv/direnv/releases/download/v2.37.1/direnv.linux-amd64",
+        "7a2107aa88782cc841a4fb65ec5ba43d2bdcde400337d87cdd930b3dd35b66b1",
+    ),
+    (
+        "https://github.com/direnv/direnv/releases/download/v2.37.1/direnv.linux-arm64",
+        "0f9955b53d1017d2f64fa78943b48ab0d4e50b39f8aa3838b7e4b1ea2e6d121c",
+    ),
+    (
+        "https://github.com/earendil-works/pi/releases/download/v0.80.2/pi-darwin-arm64.tar.gz",
+        "1404305e5eeac508537f22ab754602d053bc12ce35ee2805158ebfb09654daa4",
+    ),
+    (
+        "https://github.com/earendil-works/pi/releases/download/v0.80.2/pi-darwin-x64.tar.gz",
+        "afe432c4e2bd6f51b6df3d407cc672d426df0ea07518349e84c5be879abde12d",
+    ),
+    (
+        "https://github.com/earendil-works/pi/releases/download/v0.80.2/pi-linux-arm64.tar.gz",
+        "2a6cf632d666fa3ae8f55056fc9753088b140bedfa307ac8313f540755d8d19d",
+    ),
+    (
+        "https://github.com/earendil-works/pi/releases/download/v0.80.2/pi-linux-x64.tar.gz",
+        "d7df08e1afe43896577591952f67979a1203076d124bedbb0d81e256710c3b85",
+    ),
+    (
+        "https://github.com/eksctl-io/eksctl/releases/download/v0.227.0/eksctl_Darwin_amd64.tar.gz",
+        "653013d379e9863c80f470779e0b45a27c974068df800ae02433f4bed22acc59",
+    ),
+    (
+        "https://github.com/eksctl-io/eksctl/releases/download/v0.227.0/eksctl_Darwin_arm64.tar.gz",
+        "93c22873b006f32c25aa94395da5821253be4a8ff9c57349f55a0d1f170e70a8",
+    ),
+    (
+        "https://github.com/eksctl-io/eksctl/releases/download/v0.227.0/eksctl_Linux_amd64.tar.gz",
+        "70c151fc28326b2cb0229851f6ba7046b912ef9df27a40c4f657b5ca22ebd520",
+    ),
+    (
+        "https://github.com/eksctl-io/eksctl/releases/download/v0.227.0/eksctl_Linux_arm64.tar.gz",
+        "71b8c71f407d54880597c676585e2d3b6231760b3381b7aea50c9c1741bdae79",
+    ),
+    (
+        "https://github.com/fluxcd/flux2/releases/download/v2.7.5/flux_2.7.5_darwin_amd64.tar.gz",
+        "0c665dec2180214b14bd2f870100cffe2e0bba9b2fcfcec5fc5efe4f5804b275",
+    ),
+    (
+        "https://github.com/fluxcd/flux2/releases/download/v2.7.5/flux_2.7.5_darwin_arm64.tar.gz",
+        "ecedc4b5837e842153c6f8b1723b39481a847c980d8c79f80a50d15d29cc30d2",
+    ),
+    (
+        "https://github.com/fluxcd/flux2/releases/download/v2.7.5/flux_2.7.5_linux_amd64.tar.gz",
+        "f3e1e375e03e68a70f1ca70fe30977a65ebb9778f11748d668e8457a9d329053",
+    ),
+    (
+        "https://github.com/fluxcd/flux2/releases/download/v2.7.5/flux_2.7.5_linux_arm64.tar.gz",
+        "9f1fa13565b6a7ced7ca3bfc5f5d4799c9d15e59b87aad4aa9318b11ce4f0608",
+    ),
+    (
+        "https://github.com/golangci/golangci-lint/releases/download/v2.11.4/golangci-lint-2.11.4-darwin-amd64.tar.gz",
+        "7fbeb3df596edb8fd267a6f0ecd6918c0e38fd58c9ef5338d4a6552add6db5be",
+    ),
+    (
+        "https://github.com/golangci/golangci-lint/releases/download/v2.11.4/golangci-lint-2.11.4-darwin-arm64.tar.gz",
+        "1c7922040b10da6bed94acccb83cb97a2d852240f8739d3ebebbe7e78826826d",
+    ),
+    (
+        "https://github.com/golangci/golangci-lint/releases/download/v2.11.4/golangci-lint-2.11.4-linux-amd64.tar.gz",
+        "4824586ede63079005d76765dfa75ce6cdbf5ee47c5791903f78e5505e248927",
+    ),
+    (
+        "https://github.com/golangci/golangci-lint/releases/download/v2.11.4/golangci-lint-2.11.4-linux-arm64.tar.gz",
+        "50833ac6509b1be7054f34fcc3c840efc5ef11b56f2186a36a97b9817bb61834",
+    ),
+    (
+        "https://github.com/google/go-containerregistry/archive/refs/tags/v0.20.7.tar.gz",
+        "e61f88c967db41f46cd0b7852b7dbedeef4f286fae7613fbb8aff7aa0a35d848",
+    ),
+    (
+        "https://github.com/graykode/abtop/releases/download/v0.5.1/abtop-aarch64-apple-darwin.tar.xz",
+        "5a287568e0a5c5d801e3bbb024171aa95caac3ef466db976dedebb978e65a63a",
+    ),
+    (
+        "https://github.com/graykode/abtop/releases/download/v0.5.1/abtop-aarch64-unknown-linux-gnu.tar.xz",
+        "91cc7be7c349efd09e1cdba3d142f3db07ce773d29952bfa379f679187cd8710",
+    ),
+    (
+        "https://github.com/graykode/abtop/releases/download/v0.5.1/abtop-x86_64-apple-darwin.tar.xz",
+        "1445a1c5e5c9ac48013df7ff54192d7c24217db2d34ee32b4a26ca2dc6f89301",
+    ),
+    (
+        "https://github.com/graykode/abtop/releases/download/v0.5.1/abtop-x86_64-unknown-linux-gnu.tar.xz",
+        "cde45bbf10a89411338119628b0519f70dd56f306fa153771d4474cb4784c42b",
+    ),
+    (
+        "https://github.com/jarun/nnn/archive/refs/tags/v5.1.tar.gz",
+        "a0a9e0cff52847ffdd91673adc7dffbda2177e67a87d7fdba5717c898ea0518f",
+    ),
+    (
+        "https://github.com/jesseduffield/lazygit/releases/download/v0.44.1/lazygit_0.44.1_Darwin_arm64.tar.gz",
+        "42dd9183e9692a5a73ad4dfa109f06e67c1c7cd172afdb3d66a1fc4a5ee4c892",
+    ),
+    (
+        "https://github.com/jesseduffield/lazygit/releases/download/v0.44.1/lazygit_0.44.1_Darwin_x86_64.tar.gz",
+        "7b7d802c593d98dcf73332090fa66191cd1caca2eaa0de8c5c23367cd52241f0",
+    ),
+    (
+        "https://github.com/jesseduffield/lazygit/releases/download/v0.44.1/lazygit_0.44.1_Linux_arm64.tar.gz",
+        "754ecef628fe8e8f1978129ff1799d698a293bab3b518406f202da7ec1d1c663",
+    ),
+    (
+        "https://github.com/jesseduffield/lazygit/releases/download/v0.44.1/lazygit_0.44.1_Linux_x86_64.tar.gz",
+        "a96d8b341ccf9fe456c43ae04ecbc08a885f344d6f1ea5e6b805de3cb0ba8b53",
+    ),
+    (
+        "https://github.com/jj-vcs/jj/releases/download/v0.37.0/jj-v0.37.0-aarch64-apple-darwin.tar.gz",
+        "1550c0eb485b0887aa2bfbc1b071836773d3c53459b2fa0bba8b8a4ce68c4523",
+    ),
+    (
+        "https://github.com/jj-vcs/jj/releases/download/v0.37.0/jj-v0.37.0-aarch64-unknown-linux-musl.tar.gz",
+        "17e80c16083a0f57d4e372c38b56467ca0e4b764433eddd71bd2a0a8f8c2120e",
+    ),
+    (
+        "https://github.com/jj-vcs/jj/releases/download/v0.37.0/jj-v0.37.0-x86_64-apple-darwin.tar.gz",
+        "ec2707d3ae80a958c7704ac201f5e20949ea5354ba977de0784db2774d71ac81",
+    ),
+    (
+        "https://github.com/jj-vcs/jj/releases/download/v0.37.0/jj-v0.37.0-x86_64-unknown-linux-musl.tar.gz",
+        "84e75bec06d039a473f178e506e389b08022969023e1c9d7eb4e0e56c7d832b1",
+    ),
+    (
+        "https://github.com/joshmedeski/sesh/releases/download/v2.24.2/sesh_Darwin_arm64.tar.gz",
+        "2efb04b9de0b7bc99f87ab3e2a0f20fa9de946f162b45b362a1b626849a19cf7",
+    ),
+    (
+        "https://github.com/joshmedeski/sesh/releases/download/v2.24.2/sesh_Darwin_x86_64.tar.gz",
+        "667c17b157d6d354553efaceb1132be88a2e30e40f143377fb835423a7b3a19d",
+    ),
+    (
+        "https://github.com/joshmedeski/sesh/releases/download/v2.24.2/sesh_Linux_arm64.tar.gz",
+        "ed5a1254312bf4fe74b12a5b7fdc1b021869bf97046f819833e69af06c150513",
+    ),
+    (
+        "https://github.com/joshmedeski/sesh/releases/download/v2.24.2/sesh_Linux_x86_64.tar.gz",
+        "53db758ca110353a58ebc5bff32a4ab2a52090640a8ea11b6e1193c428c5204d",
+    ),
+    (
+        "https://github.com/jqlang/jq/releases/download/jq-1.8.1/jq-linux-amd64",
+        "8d4bc0c56b18656763379011d871603057e2cc5112207019296960c1e4f6193b",
+    ),
+    (
+        "https://github.com/jqlang/jq/releases/download/jq-1.8.1/jq-linux-arm64",
+        "74a4f69e8e9972b7855178c5af0c5b814d42c65f8d2aca29eb039302a8b02cf5",
+    ),
+    (
+        "https://github.com/jqlang/jq/releases/download/jq-1.8.1/jq-macos-amd64",
+        "fbfc15efb4aa96dd87f1088cd284ae37e776235da808e3b063a26a1067dadf19",
+    ),
+    (
+        "https://github.com/jqlang/jq/releases/download/jq-1.8.1/jq-macos-arm64",
+        "718c0fbb8f728c4b62cac2e65dba61cbdf2eed5e7eff38681097ec7b04dd815a",
+    ),
+    (
+        "https://github.com/json-c/json-c/archive/refs/tags/json-c-0.18-20240915.tar.gz",
+        "e11a320ef7d6cab0fdb8a1d6f760b7b9c328eaf851b8b233dd0ded77c9d59278",
+    ),
+    (
+        "https://github.com/junegunn/fzf/releases/download/v0.70.0/fzf-0.70.0-darwin_amd64.tar.gz",
+        "a5f0cd626b22c9a4ede8c4c96dd50adb5db05d24f5ae05a7db8949e70cdbcbf7",
+    ),
+    (
+        "https://github.com/junegunn/fzf/releases/download/v0.70.0/fzf-0.70.0-darwin_arm64.tar.gz",
+        "0c48ed17e428e6e092964d3dba0f11ac8138b5964c9f4305965e200b98ad4f8e",
+    ),
+    (
+        "https://github.com/junegunn/fzf/releases/download/v0.70.0/fzf-0.70.0-linux_amd64.tar.gz",
+        "cfec2fedd6c05d090ab461bf0b47c43b5ebb253f6a58d6ae8307c6c682533464",
+    ),
+    (
+        "https://github.com/junegunn/fzf/releases/download/v0.70.0/fzf-0.70.0-linux_arm64.tar.gz",
+        "7de86a9bb06954318a6ba1404cad218f1875eb8623406292adb4185cb81a8778",
+    ),
+    (
+        "https://github.com/knative/client/releases/download/knative-v1.20.0/kn-darwin-amd64",
+        "a167778b0efc465aadafaaa63632979a8eeb504f791ea6f2a8b431e3316375cb",
+    ),
+    (
+        "https://github.com/knative/client/releases/download/knative-v1.20.0/kn-darwin-arm64",
+        "c5cca3e1326e263a50278407fb8688f8cb677bcd65a49d3ad6b6721cbf9d1ae5",
+    ),
+    (
+        "https://github.com/knative/client/releases/download/knative-v1.20.0/kn-linux-amd64",
+        "f2b898cc8ab8ee92fbdd423f4dd32cc0f4c94757dba5decccda2941401b0e976",
+    ),
+    (
+        "https://github.com/knative/client/releases/download/knative-v1.20.0/kn-linux-arm64",
+        "29542b176a88fa67967a69052fde28c067f635f1ed5d4a437ea2dac9e5dc41b3",
+    ),
+    (
+        "https://github.com/kubernetes-sigs/kind/releases/download/v0.31.0/kind-darwin-amd64",
+        "5b04ed59f9a6311684196c5920da94d6332c1ff8a315e75826664e429704820a",
+    ),
+    (
+        "https://github.com/kubernetes-sigs/kind/releases/download/v0.31.0/kind-darwin-arm64",
+        "7ef32c70c300b401008dc76a529b30d7fcf6dae289648d51168e520d2b0f6ecc",
+    ),
+    (
+        "https://github.com/kubernetes-sigs/kind/releases/download/v0.31.0/kind-linux-amd64",
+        "baf90a4a3a0b6901319baded4b5348230c371f8462cfa785ed02a1b251b4bb36",
+    ),
+    (
+        "https://github.com/kubernetes-sigs/kind/releases/download/v0.31.0/kind-linux-arm64",
+        "3aabcecc795896e84f69ae6316e94e3efbd6df480d3ffca780dce7cb2d467913",
+    ),
+    (
+        "https://github.com/kubevirt/kubevirt/releases/download/v1.8.4/virtctl-v1.8.4-darwin-amd64",
+        "06f2d43a5245079733f17a66a833a5cfb151d181de3afffb20f53ec42b751587",
+    ),
+    (
+        "https://github.com/kubevirt/kubevirt/releases/download/v1.8.4/virtctl-v1.8.4-darwin-arm64",
+        "4d3132852c72bf721c6eead8c7f9c23864d0312447ab7316b5d1fa2ecb0a2fdf",
+    ),
+    (
+        "https://github.com/kubevirt/kubevirt/releases/download/v1.8.4/virtctl-v1.8.4-linux-amd64",
+        "42ecd170025f47f5c7ad336f4175a5ea76e2d3a4862a881a0002fafb533a5d8a",
+    ),
+    (
+        "https://github.com/kubevirt/kubevirt/releases/download/v1.8.4/virtctl-v1.8.4-linux-arm64",
+        "2a16cb13405952fa56bae35dc066ede8ed1ebc6050df8ee99cc0d12467b4dc37",
+    ),
+    (
+        "https://github.com/libevent/libevent/releases/download/release-2.1.12-stable/libevent-2.1.12-stable.tar.gz",
+        "22fdbe5a18db114046227ccde47cc8fbe11fa9ee2170b235f949275a161853ee",
+    ),
+    (
+        "https://github.com/libuv/libuv/archive/refs/tags/v1.52.0.tar.gz",
+        "32d2486edb49f99004a39ea8b472ac68b0b38ff3d364c7391edf38fd6b5db13f",
+    ),
+    (
+        "https://github.com/lima-vm/lima/releases/download/v2.0.3/lima-2.0.3-Darwin-arm64.tar.gz",
+        "2d9892c27ff2acb4a4363443cb2a1ad87968fd1a6c49fc112e38f4e0764a6c1a",
+    ),
+    (
+        "https://github.com/lima-vm/lima/releases/download/v2.0.3/lima-2.0.3-Darwin-x86_64.tar.gz",
+        "f235dd7555f08828c40a75b1a7cc162a0e42bdb5282a88440f4ea3235c8a3637",
+    ),
+    (
+        "https://github.com/lima-vm/lima/releases/download/v2.0.3/lima-2.0.3-Linux-aarch64.tar.gz",
+        "0ae4dfaa11e243fe281931d0911ec90fba80acf342347cd8c108f065bcb1dc1f",
+    ),
+    (
+        "https://github.com/lima-vm/lima/releases/download/v2.0.3/lima-2.0.3-Linux-x86_64.tar.gz",
+        "093af7b108a2561eee0255785d898af6268ddc40c8883a9ba99f04a85ec07dde",
+    ),
+    (
+        "https://github.com/mikefarah/yq/releases/download/v4.50.1/yq_darwin_amd64.tar.gz",
+        "d85e290875b0c952300b22dc7f33ed34b826bfe0823f07870b8ea2c78e201209",
+    ),
+    (
+        "https://github.com/mikefarah/yq/releases/download/v4.50.1/yq_darwin_arm64.tar.gz",
+        "912df91581dee3a047324b107a08977d1d51a55b099a6690dc832c6fc7eac100",
+    ),
+    (
+        "https://github.com/mikefarah/yq/releases/download/v4.50.1/yq_linux_amd64.tar.gz",
+        "72cb8d9181ec9c8e5819df6ac70751fd2cd06cb54d7625bc5f670bd32e0dd42f",
+    ),
+    (
+        "https://github.com/mikefarah/yq/releases/download/v4.50.1/yq_linux_arm64.tar.gz",
+        "104f4445863bcc41156ff9036b8e860de7dc9d1a96d0571becc85ada8e0c62e1",
+    ),
+    (
+        "https://github.com/modem-dev/hunk/releases/download/v0.16.0/hunkdiff-darwin-arm64.tar.gz",
+        "5284275b298d1106c10f84f5791cc66748b1f16501ff86ed0eb96ea210cb420c",
+    ),
+    (
+        "https://github.com/modem-dev/hunk/releases/download/v0.16.0/hunkdiff-darwin-x64.tar.gz",
+        "5fa94f8532593e5812d25aa684bb3f07fdb45b70bae6d7a99ff32474e3ac9dad",
+    ),
+    (
+        "https://github.com/modem-dev/hunk/releases/download/v0.16.0/hunkdiff-linux-arm64.tar.gz",
+        "f8084c1ec547efc15c26196e9d86e3704f93c4c2380110f45a946a8c094794aa",
+    ),
+    (
+        "https://github.com/modem-dev/hunk/releases/download/v0.16.0/hunkdiff-linux-x64.tar.gz",
+        "c8bc5af2f69a8ffedca83dd3c337544b42acb9c9a85add1fe08c01f897c6555e",
+    ),
+    (
+        "https://github.com/mozilla/sccache/releases/download/v0.14.0/sccache-v0.14.0-aarch64-apple-darwin.tar.gz",
+        "fb3fe57be9446f8d6565ad58d85204e0ed9f194a80bb2c38b67590e0949ac53a",
+    ),
+    (
+        "https://github.com/mozilla/sccache/releases/download/v0.14.0/sccache-v0.14.0-aarch64-unknown-linux-musl.tar.gz",
+        "ff96bdce12c010035baaae184427f2a604a9840ec81c29fec9202a77f2b1b285",
+    ),
+    (
+        "https://github.com/mozilla/sccache/releases/download/v0.14.0/sccache-v0.14.0-x86_64-apple-darwin.tar.gz",
+        "fc479bb5f65fe055425920ddfad81ad499f668c7d103d5097cc1ad485259f107",
+    ),
+    (
+        "https://github.com/mozilla/sccache/releases/download/v0.14.0/sccache-v0.14.0-x86_64-unknown-linux-musl.tar.gz",
+        "2756b61b73dea1f11b7b9a82ca7f157449de2371ff72d5d282cc72001d3422e4",
+    ),
+    (
+        "https://github.com/neovim/neovim/releases/download/v0.12.3/nvim-linux-arm64.tar.gz",
+        "5de780c6c1704840551110d5eb182f26633ec43a4b3a828999206746df134882",
+    ),
+    (
+        "https://github.com/neovim/neovim/releases/download/v0.12.3/nvim-linux-x86_64.tar.gz",
+        "f2ef9e2001393fbb8fe6fc3f2d2d2082a64c88d1552fae087060b38e2b5e84cc",
+    ),
+    (
+        "https://github.com/neovim/neovim/releases/download/v0.12.3/nvim-macos-arm64.tar.gz",
+        "54110529d739e5b8811f222df075c49d4d17ac442f8d95153f1ca4d434035949",
+    ),
+    (
+        "https://github.com/neovim/neovim/releases/download/v0.12.3/nvim-macos-x86_64.tar.gz",
+        "931a3ed41795f53e54f5c44bdf0bbd836d52f0ac4d82e4e70615de3943706cac",
+    ),
+    (
+        "https://github.com/nginx/nginx/archive/refs/tags/release-1.27.5.tar.gz",
+        "35764d85e27eac8e8c9eebfe6c5a593224ef63fdd1f2ea0916bf8b4d2335acc0",
+    ),
+    (
+        "https://github.com/ogulcancelik/herdr/releases/download/v0.7.4/herdr-linux-aarch64",
+        "ab0327d5c8c84618ad95b9abe0dc4d118de44ff26f1cf16ea7ec18c0f4f89f4d",
+    ),
+    (
+        "https://github.com/ogulcancelik/herdr/releases/download/v0.7.4/herdr-linux-x86_64",
+        "5071761c9e7b2c886c94d92ba9bef8b03bced690abce5b84f526d1a6c6b067b5",
+    ),
+    (
+        "https://github.com/ogulcancelik/herdr/releases/download/v0.7.4/herdr-macos-aarch64",
+        "78147d22f8d83e26fb704bafe45a081bda5acccf52a069930cdb4259089d8b07",
+    ),
+    (
+        "https://github.com/ogulcancelik/herdr/releases/download/v0.7.4/herdr-macos-x86_64",
+        "d042f410b0b15f01ace79913a84e3644e3eb117cd4d6f100470da0950514b76e",
+    ),
+    (
+        "https://github.com/opencontainers/umoci/archive/refs/tags/v0.6.0.tar.gz",
+        "4c99239fccaf6bee3a50a76fa89289210bee06d2b65747587f73b843dced1599",
+    ),
+    (
+        "https://github.com/sharkdp/bat/releases/download/v0.25.0/bat-v0.25.0-aarch64-apple-darwin.tar.gz",
+        "3abca7df1be4d52ef37ccd22f0b01da87f149f4ca1f74633d53deba87d98b078",
+    ),
+    (
+        "https://github.com/sharkdp/bat/releases/download/v0.25.0/bat-v0.25.0-aarch64-unknown-linux-gnu.tar.gz",
+        "1ba594e9b7f1e817e3359300173282fccae09f3360e37568161d86e8d0f087b0",
+    ),
+    (
+        "https://github.com/sharkdp/bat/releases/download/v0.25.0/bat-v0.25.0-x86_64-apple-darwin.tar.gz",
+        "2c5b079394e7e7379e2787a5ecd4cae072ee346cd87f10cd262495e658122310",
+    ),
+    (
+        "https://github.com/sharkdp/bat/releases/download/v0.25.0/bat-v0.25.0-x86_64-unknown-linux-musl.tar.gz",
+        "3ed3a7e93b208399749a2b4b55318f39a8376922173a8af75cfba26890adfc48",
+    ),
+    (
+        "https://github.com/sharkdp/fd/releases/download/v10.2.0/fd-v10.2.0-aarch64-apple-darwin.tar.gz",
+        "0908501901ff99232aa75a18bf9409e80dae10742dc3759a67249bca5a459490",
+    ),
+    (
+        "https://github.com/sharkdp/fd/releases/download/v10.2.0/fd-v10.2.0-aarch64-unknown-linux-gnu.tar.gz",
+        "fb1c351d076c7ed9e68ba63ed4647af730c4ee277f283c82fe3a84d784829ded",
+    ),
+    (
+        "https://github.com/sharkdp/fd/releases/download/v10.2.0/fd-v10.2.0-x86_64-apple-darwin.tar.gz",
+        "5de9ad944bd8937d8a2f823328d7d29ded794a9f845febffd86c55fdff7a5453",
+    ),
+    (
+        "https://github.com/sharkdp/fd/releases/download/v10.2.0/fd-v10.2.0-x86_64-unknown-linux-musl.tar.gz",
+        "fdaa40fadd825cb5a895baee0b353e569006fef98dae2c84190e53c1775ed88f",
+    ),
+    (
+        "https://github.com/siderolabs/talos/releases/download/v1.13.4/talosctl-darwin-amd64",
+        "da43eb5ec3a41818a11e9d5fa51a1c6b3f7ed8dda4cb66377624437a16ea3c8f",
+    ),
+    (
+        "https://github.com/siderolabs/talos/releases/download/v1.13.4/talosctl-darwin-arm64",
+        "71936f5acb86c62df4e6832a9474d872259b7312bed431a9cf6dbddb3b290aba",
+    ),
+    (
+        "https://github.com/siderolabs/talos/releases/download/v1.13.4/talosctl-linux-amd64",
+        "636bc917ce97e365c820dc46237575a01cfee27f569dbb527d84bde2235fd082",
+    ),
+    (
+        "https://github.com/siderolabs/talos/releases/download/v1.13.4/talosctl-linux-arm64",
+        "a185242de93d111b5517d49891f09fc5551ff226317c358da8f3816ad7db4406",
+    ),
+    (
+        "https://github.com/starship/starship/releases/download/v1.25.1/starship-aarch64-apple-darwin.tar.gz",
+        "43fcef328b7ddd3f8e2b2ceb7adefb9dca5719c853627f26a25ac7f2a1e2faee",
+    ),
+    (
+        "https://github.com/starship/starship/releases/download/v1.25.1/starship-aarch64-unknown-linux-musl.tar.gz",
+        "127e88a303a111736daedba9f8d942af4d94d28d47bf79e8acf3879e863ce6df",
+    ),
+    (
+        "https://github.com/starship/starship/releases/download/v1.25.1/starship-x86_64-apple-darwin.tar.gz",
+        "24a76288b840c0145abf391b312c75326b862ee225459351d8bfa2fd1bef0be8",
+    ),
+    (
+        "https://github.com/starship/starship/releases/download/v1.25.1/starship-x86_64-unknown-linux-musl.tar.gz",
+        "f445745e75d4daf601651b916c1471c2cf1b7ca232a415999451b320ed553715",
+    ),
+    (
+        "https://github.com/steveyegge/beads/releases/download/v0.47.1/beads_0.47.1_darwin_amd64.tar.gz",
+        "ce43d7b8ceb386520d7d0ecdf53969a846580093c0cf684f3618491d0a05eae4",
+    ),
+    (
+        "https://github.com/steveyegge/beads/releases/download/v0.47.1/beads_0.47.1_darwin_arm64.tar.gz",
+        "de65e35a1cc0dad8a438c57011e40153abf7a42a29b1edde5f1a33981c0dc919",
+    ),
+    (
+        "https://github.com/steveyegge/beads/releases/download/v0.47.1/beads_0.47.1_linux_amd64.tar.gz",
+        "faa1b87574f498f219b1cb380353420770b0f8eaeb86871ce4fc46b56267963b",
+    ),
+    (
+        "https://github.com/steveyegge/beads/releases/download/v0.47.1/beads_0.47.1_linux_arm64.tar.gz",
+        "0658af083dc5cc70c21faa77b091e8f815d3684fc2e9cad4ed9c8b41336bcf98",
+    ),
+    (
+        "https://github.com/tmux/tmux/releases/download/3.5a/tmux-3.5a.tar.gz",
+        "70691bfbbe623d25d99561192dfb52211ca7e1fa120271c57ae56fe24ca97521",
+    ),
+    (
+        "https://github.com/tree-sitter/tree-sitter/releases/download/v0.26.9/tree-sitter-cli-linux-arm64.zip",
+        "f05a5423216486dbde465c77e5b07cd6806c4d268c09330a0c90e7a1f3fb8cfb",
+    ),
+    (
+        "https://github.com/tree-sitter/tree-sitter/releases/download/v0.26.9/tree-sitter-cli-linux-x64.zip",
+        "d7806a8ea6c95a712bc9899e8f26532faa321bdcd77a19d612697d3e6bc0663d",
+    ),
+    (
+        "https://github.com/tree-sitter/tree-sitter/releases/download/v0.26.9/tree-sitter-cli-macos-arm64.zip",
+        "a2fcfe68911f1076f81258f9bed01cb6c53f7be3068ea71dc0d3f08857922a6f",
+    ),
+    (
+        "https://github.com/tree-sitter/tree-sitter/releases/download/v0.26.9/tree-sitter-cli-macos-x64.zip",
+        "7e2133a9abc4b9141a13593c4213ca49274c3144ca8693fb0805764f6519c2cd",
+    ),
+    (
+        "https://github.com/tsl0922/ttyd/archive/refs/tags/1.7.7.tar.gz",
+        "cc01828c5c863fa5c0e46bd66da7a27ba588c81c57e2580fe0f7f7ba31114bae",
+    ),
+    (
+        "https://github.com/tsl0922/ttyd/releases/download/1.7.7/ttyd.aarch64",
+        "446d361beead6d5e77d7bf1e0ea20c2cd24b3042f80d7712b39bc971fea0a5bd",
+    ),
+    (
+        "https://github.com/tsl0922/ttyd/releases/download/1.7.7/ttyd.x86_64",
+        "65575220c843536cad962fa55ba5bf07bced7ca5e50aa489c1505d77d517a651",
+    ),
+    (
+        "https://github.com/warmcat/libwebsockets/archive/refs/tags/v4.5.2.tar.gz",
+        "61c0a1af5787ca0bb73cbb51d15e4aabd083ab93b17bb3a2159aac31038a515a",
+    ),
+    (
+        "https://gnupg.org/ftp/gcrypt/gnupg/gnupg-2.5.16.tar.bz2",
+        "d98a7b45f3f70343ae9ba0bab82f3b6553291036c4953c26d7b149544a73f927",
+    ),
+    (
+        "https://gnupg.org/ftp/gcrypt/libassuan/libassuan-3.0.2.tar.bz2",
+        "b2263b91ed3e1d3d4db7fd09c2eb756b25d2af7e12928871846f14191425dd15",
+    ),
+    (
+        "https://gnupg.org/ftp/gcrypt/libgcrypt/libgcrypt-1.11.0.tar.bz2",
+        "5a3cb0f1e809283b38869c57dfb3b66acd319e98f9cf1c026ba28e9d442fdf8e",
+    ),
+    (
+        "https://gnupg.org/ftp/gcrypt/libgpg-error/libgpg-error-1.56.tar.bz2",
+        "05928e189eeca1e71bff14fd395abe8f36e9b8a02b3bdfc735770181b70aecd2",
+    ),
+    (
+        "https://gnupg.org/ftp/gcrypt/libksba/libksba-1.6.7.tar.bz2",
+        "d4392077dde893b704c72b6b37dbfc4abfad36032e0dfd2066edb22b27183b10",
+    ),
+    (
+        "https://gnupg.org/ftp/gcrypt/npth/npth-1.8.tar.bz2",
+        "a2b6cb020468c58784331757133a7112dbe838d227f8bec3a01382909efa1b63",
+    ),
+    (
+        "https://pkgconfig.freedesktop.org/releases/pkg-config-0.29.2.tar.gz",
+        "db043f5df7971d9bf97a81c75a46c4656e1a6235c05edad48d11a9314df5363f",
+    ),
+    (
+        "https://registry.npmjs.org/@mixmark-io/domino/-/domino-2.2.0.tgz",
+        "bc42d1fa14c5a95b84456dab4d37d3ed812d3a1ae1fe670829e4cc5396ad050e",
+    ),
+    (
+        "https://registry.npmjs.org/@nodelib/fs.scandir/-/fs.scandir-2.1.5.tgz",
+        "e800bcc56aed33d5e45525e77d81f5bd21e145623af190a7074dac652b7f8798",
+    ),
+    (
+        "https://registry.npmjs.org/@nodelib/fs.stat/-/fs.stat-2.0.5.tgz",
+        "91bafa32af897cc0d7765880cfc94966a3c46b139ffc466797114f7dcbc642a6",
+    ),
+    (
+        "https://registry.npmjs.org/@nodelib/fs.walk/-/fs.walk-1.2.8.tgz",
+        "0b98aac5b73a3b113f3b4e47d99ae78e2e4e1adf48b099ea0fbfcfef3dcd9a5c",
+    ),
+    (
+        "https://registry.npmjs.org/@one-ini/wasm/-/wasm-0.2.0.tgz",
+        "46b7a9963210f1a9ffd37d901dd70975ab73fe01c9a79913f09cfb3d7243c437",
+    ),
+    (
+        "https://registry.npmjs.org/@vscode/l10n/-/l10n-0.0.18.tgz",
+        "f51294bb2038cef307be242f7c2346a3d2af92aeb0d281720fb4c448e2dbb2dc",
+    ),
+    (
+        "https://registry.npmjs.org/ajv-draft-04/-/ajv-draft-04-1.0.0.tgz",
+        "a4a531fd47c2e05e1918e41165d6673a184dae16b040efca78be03fd0bb916d3",
+    ),
+    (
+        "https://registry.npmjs.org/ajv-i18n/-/ajv-i18n-4.2.0.tgz",
+        "76867268fcdaa6bbe49e59add7a75cf3ae1e9610d74aa26f7dc0455fdb033c71",
+    ),
+    (
+        "https://registry.npmjs.org/ajv/-/ajv-8.20.0.tgz",
+        "a0ef90036d7f210e358ad2e170b2ee921eb697023cd7182888c8e894329cbc7c",
+    ),
+    (
+        "https://registry.npmjs.org/balanced-match/-/balanced-match-1.0.2.tgz",
+        "5472a2f9517223c70ede2f6c2f970b5229769760b1b709c859de3706f59489c3",
+    ),
+    (
+        "https://registry.npmjs.org/bash-language-server/-/bash-language-server-5.6.0.tgz",
+        "9602ed7f00a9aa18d8aa05f910c147a8da5430ab8b4deb8fb9a597b7f6ad5cd7",
+    ),
+    (
+        "https://registry.npmjs.org/boolbase/-/boolbase-1.0.0.tgz",
+        "316b55881cd9e091151f060042446974e27e0ac6b8690a5d367e50bdf264fd4e",
+    ),
+    (
+        "https://registry.npmjs.org/brace-expansion/-/brace-expansion-2.0.1.tgz",
+        "d7ff9f72158fabf609a4c4eb309853d40d0910e974a2aa78f3e4961e93cc3046",
+    ),
+    (
+        "https://registry.npmjs.org/braces/-/braces-3.0.3.tgz",
+        "369fb69f30979f0cad137e30c2d726459c94684f64f6fa894ba00447883276a1",
+    ),
+    (
+        "https://registry.npmjs.org/commander/-/commander-15.0.0.tgz",
+        "28368d2fe0265f1759857d0ed1b5c4e97eb5c89859fc7dffc48940a4615c8184",
+    ),
+    (
+        "https://registry.npmjs.org/core-js/-/core-js-3.49.0.tgz",
+        "b1c4c717f4306fc68bd326723207811d212e56bcec86e1b50cae705deee737f4",
+    ),
+    (
+        "https://registry.npmjs.org/css-select/-/css-select-5.2.2.tgz",
+        "59abd1ae5564bcd84e5ddfbded18ef66ccc011efd43146dac0a5cd01424412b0",
+    ),
+    (
+        "https://registry.npmjs.org/css-what/-/css-what-6.2.2.tgz",
+        "74643fb6496739314cd039857929d3cabe52d4b7d792c5a2bb50930d59a1838b",
+    ),
+    (
+        "https://registry.npmjs.org/dockerfile-ast/-/dockerfile-ast-0.7.1.tgz",
+        "32dd3b51c553526ea738549a25ab4f1d30ca71fb3d2ebea904be6745959ef2f2",
+    ),
+    (
+        "https://registry.npmjs.org/dockerfile-language-server-nodejs/-/dockerfile-language-server-nodejs-0.15.0.tgz",
+        "249278e96eac95896998fb0d9ce51870bad18b41a759c873f5d091c85e2a1a37",
+    ),
+    (
+        "https://registry.npmjs.org/dockerfile-language-service/-/dockerfile-language-service-0.16.1.tgz",
+        "8ef018415d968347cac1715557aa9d324ce29193d198ae1e3e3ecb464a506db6",
+    ),
+    (
+        "https://registry.npmjs.org/dockerfile-utils/-/dockerfile-utils-0.16.3.tgz",
+        "643b16315cfa31288fb67828170729b60692ec85a896282d95471b7e2e3979fd",
+    ),
+    (
+        "https://registry.npmjs.org/dom-serializer/-/dom-serializer-2.0.0.tgz",
+        "0f3c699cfd3328aca11773f0fa10f14100f62068f44720cc984ac06248598f90",
+    ),
+    (
+        "https://registry.npmjs.org/domelementtype/-/domelementtype-2.3.0.tgz",
+        "35a93d285b27173232f13d059fde81ef34056d07221d524b58a8fb99a8e5bc3f",
+    ),
+    (
+        "https://registry.npmjs.org/domhandler/-/domhandler-5.0.3.tgz",
+        "5413509613b58809d8558fef05b0a61487fe2902c1d3b054b5dc50198fdf6758",
+    ),
+    (
+        "https://registry.npmjs.org/domutils/-/domutils-3.2.2.tgz",
+        "d62cb1005e441a875dc8b7fba9a17ea7582eef783e874731e7096cf01edcbd9a",
+    ),
+    (
+        "https://registry.npmjs.org/editorconfig/-/editorconfig-2.0.1.tgz",
+        "f97c38586f2c4aa805b60cc48fbfa35ea0c5f8e9bc14acd3c438d3e71be07a65",
+    ),
+    (
+        "https://registry.npmjs.org/entities/-/entities-4.5.0.tgz",
+        "6de5b4af5bcd75b57029f8ae66ab6ed3068b53ed3e599467651b93326439af9c",
+    ),
+    (
+        "https://registry.npmjs.org/fast-deep-equal/-/fast-deep-equal-3.1.3.tgz",
+        "6763f654eb92f5b1ec2431ae4a60923ad01617a68f8cb9033babb988d8318edf",
+    ),
+    (
+        "https://registry.npmjs.org/fast-glob/-/fast-glob-3.3.3.tgz",
+        "c62a9c29a78c2795539b8aa566360a13eed5857ec7649a61616c5f360970f4da",
+    ),
+    (
+        "https://registry.npmjs.org/fast-uri/-/fast-uri-3.1.2.tgz",
+        "2e440470838fe3f30eb483cd91bbead68ba18fb8559d98ac3384db2755401d36",
+    ),
+    (
+        "https://registry.npmjs.org/fastq/-/fastq-1.20.1.tgz",
+        "7da2d3cb64f29c2adb2b260d7f4fc7de33958205b86709c37d9a17f37b065ecf",
+    ),
+    (
+        "https://registry.npmjs.org/fill-range/-/fill-range-7.1.1.tgz",
+        "40dae5ba10940c7092c013b511e784eb4a48ec295a0f2b6b0be97fba80e6b194",
+    ),
+    (
+        "https://registry.npmjs.org/fuzzy-search/-/fuzzy-search-3.2.1.tgz",
+        "04781a4a02ffa9786f0fda7e16e86a6e5a7ad0c4c3dd268e74a0ac306c1f59de",
+    ),
+    (
+        "https://registry.npmjs.org/glob-parent/-/glob-parent-5.1.2.tgz",
+        "87338cc1a9ff0b3300683ab9981fbd16e8a0e0e6fd9f0b19f6ad42c0666fc7bf",
+    ),
+    (
+        "https://registry.npmjs.org/he/-/he-1.2.0.tgz",
+        "fd3442169db2c70f4dd4da5fee0de3a3703d2eeaaf27320cefa17c2635237297",
+    ),
+    (
+        "https://registry.npmjs.org/is-extglob/-/is-extglob-2.1.1.tgz",
+        "d6e18eab80db8c08cb7ff9b1f78b615092866d19109aec9bd93ca419e649d23a",
+    ),
+    (
+        "https://registry.npmjs.org/is-glob/-/is-glob-4.0.3.tgz",
+        "ff1cffd23b79ccd47343d4a3d5c901b03a17bb7994710c1a3215ca14b6c70d30",
+    ),
+    (
+        "https://registry.npmjs.org/is-number/-/is-number-7.0.0.tgz",
+        "d63f5863b4accfdb52510ccda01a4681ee9f14594c87dc31039e317cc8ce0e27",
+    ),
+    (
+        "https://registry.npmjs.org/json-schema-traverse/-/json-schema-traverse-1.0.0.tgz",
+        "e6ee77f952e27e346dd6e8cbbab182e157c535679ff326b0a792c15f48b2987d",
+    ),
+    (
+        "https://registry.npmjs.org/jsonc-parser/-/jsonc-parser-3.3.1.tgz",
+        "0c07ac7a46068e04667268026ae825fbc6c4a1204bad8e67d04e0bdd06aaab06",
+    ),
+    (
+        "https://registry.npmjs.org/merge2/-/merge2-1.4.1.tgz",
+        "343739a9118dcddb5913756c087d7842843c1559cdafdb5d9d40aa6ddd4f6c70",
+    ),
+    (
+        "https://registry.npmjs.org/micromatch/-/micromatch-4.0.8.tgz",
+        "6e06635f54df8f87ba52935ba401c6b4f756378fb453e4ff9c6b4acfff92c429",
+    ),
+    (
+        "https://registry.npmjs.org/minimatch/-/minimatch-10.0.1.tgz",
+        "8d587f80713945afadc92f127ed7e238bdef7dab0e2a2bf4b041274c65dac6cd",
+    ),
+    (
+        "https://registry.npmjs.org/node-fetch/-/node-fetch-2.7.0.tgz",
+        "e9a331173565996a542f3d6e8dc112b17f6e5d7c2bea7fd6b821e519a5758f86",
+    ),
+    (
+        "https://registry.npmjs.org/node-html-parser/-/node-html-parser-6.1.15-0.tgz",
+        "7c034f1c0a02ffc3bd6b168b4cb99f54a94f33abda4b35375de1b0b157e0b9e3",
+    ),
+    (
+        "https://registry.npmjs.org/nth-check/-/nth-check-2.1.1.tgz",
+        "884e88fb74a458186a6c3e58398af5f4de8b3d1db8ca2af7415e7dfe14999c02",
+    ),
+    (
+        "https://registry.npmjs.org/picomatch/-/picomatch-2.3.1.tgz",
+        "1d174edd33c12d1e0e342ad763f8d2c63dc99c2cc29171317c6ef5ac0ab782f2",
+    ),
+    (
+        "https://registry.npmjs.org/picomatch/-/picomatch-2.3.2.tgz",
+        "4530e29ec072a2fd31ef627ba733acf29f412b7ad309ac3af50bc2524bbc893c",
+    ),
+    (
+        "https://registry.npmjs.org/prettier/-/prettier-3.8.4.tgz",
+        "1fd159d6652dd85d3190adab9f42b20081bbbbb3b86f1a8a66b904402e2171e1",
+    ),
+    (
+        "https://registry.npmjs.org/queue-microtask/-/queue-microtask-1.2.3.tgz",
+        "05f6c7babbafab550a3c08f9a34321795d1f4514267edc2c3e9baefd2939803f",
+    ),
+    (
+        "https://registry.npmjs.org/regenerator-runtime/-/regenerator-runtime-0.13.11.tgz",
+        "6ba1a79f567c048f5cfab16e100ebaafd4b246d609b2d036de4be43bd4202ae0",
+    ),
+    (
+        "https://registry.npmjs.org/request-light/-/request-light-0.5.8.tgz",
+        "0b5c4acbb76ccd47bf17b63f9aafb0c76dbbc91e613b7ac1b8d94703598afbdf",
+    ),
+    (
+        "https://registry.npmjs.org/request-light/-/request-light-0.7.0.tgz",
+        "8307312d71878ff50ca4150464df2a3134b5ce8a4c876b28bf5e949c36a48606",
+    ),
+    (
+        "https://registry.npmjs.org/require-from-string/-/require-from-string-2.0.2.tgz",
+        "5ab713ae092a5eae232b5d035b7713de3e8a66693d646a409841869f3f115e9a",
+    ),
+    (
+        "https://registry.npmjs.org/reusify/-/reusify-1.1.0.tgz",
+        "1959fbbc38ef73d803d68ee4ab0cd927659504a24b8dd8bca323037400840863",
+    ),
+    (
+        "https://registry.npmjs.org/run-parallel/-/run-parallel-1.2.0.tgz",
+        "c9961f5bf925ee301be4212377e254a6f966a3309e163b6623a099973089282b",
+    ),
+    (
+        "https://registry.npmjs.org/semver/-/semver-7.8.5.tgz",
+        "a92457e9abcf8c44bf92b5c2dfe53a1ce4cc47f269f6dd4eb0fbaefc0e05d2f6",
+    ),
+    (
+        "https://registry.npmjs.org/to-regex-range/-/to-regex-range-5.0.1.tgz",
+        "1c1d1ab512395935b2ee5b7d1e2cf2778da71cf943ada371342118187a82208c",
+    ),
+    (
+        "https://registry.npmjs.org/tr46/-/tr46-0.0.3.tgz",
+        "be6e7c90a0d865073e2abe44dc582f06dceb3cbd3294aecc377330eda098dbaf",
+    ),
+    (
+        "https://registry.npmjs.org/turndown/-/turndown-7.2.0.tgz",
+        "16e64331cf87527da046a9b5a0187d4aa21854cb0bf58d9cc71c95891a91c72e",
+    ),
+    (
+        "https://registry.npmjs.org/typescript-language-server/-/typescript-language-server-5.3.0.tgz",
+        "a7a1f522fb471627564b358892dce3f611fdbe1224e05a2e993d0cd01ea776ad",
+    ),
+    (
+        "https://registry.npmjs.org/typescript/-/typescript-4.9.5.tgz",
+        "7e0e8a5f4a4114ea30ce26eacba1e09ce0ce7cb6b40a3cc7b873ca2fa346291e",
+    ),
+    (
+        "https://registry.npmjs.org/typescript/-/typescript-6.0.3.tgz",
+        "44444464088bfaca1b661ae019259cd92586fd91a80209aabaac9a3fe0dc912a",
+    ),
+    (
+        "https://registry.npmjs.org/vscode-css-languageservice/-/vscode-css-languageservice-6.3.10.tgz",
+        "503464b5f9a492d9a0cec630881a32f9da739f00e66ae8b07f49ced8ea5c274a",
+    ),
+    (
+        "https://registry.npmjs.org/vscode-html-languageservice/-/vscode-html-languageservice-5.6.2.tgz",
+        "2ac8c1e711a29cbe985f02adde19aa61bf8bac768bfb97b2933d0f55affad010",
+    ),
+    (
+        "https://registry.npmjs.org/vscode-json-languageservice/-/vscode-json-languageservice-4.1.8.tgz",
+        "f63fba2935070f01db92066a570e2f44b94577b70e629527dcea067dc3aac5d3",
+    ),
+    (
+        "https://registry.npmjs.org/vscode-json-languageservice/-/vscode-json-languageservice-5.7.2.tgz",
+        "eb35e54e30fd57a4cc2719952edec70d451e897eb014d782549eefdeebbd2533",
+    ),
+    (
+        "https://registry.npmjs.org/vscode-jsonrpc/-/vscode-jsonrpc-8.0.2.tgz",
+        "654278949d7d75cdf73fd9cdf4d61d036d6eaae26302bcd177d0d94f84adf1e0",
+    ),
+    (
+        "https://registry.npmjs.org/vscode-jsonrpc/-/vscode-jsonrpc-8.2.0.tgz",
+        "ee3367dab7d8048cfcf3b38ea26f64255afafb954e876945f766a62bc35edf12",
+    ),
+    (
+        "https://registry.npmjs.org/vscode-jsonrpc/-/vscode-jsonrpc-9.0.0-next.1.tgz",
+        "f9079530325bdb8df80f9fb1095f5af6ea424f7e71eeafd6009a691bc60f3150",
+    ),
+    (
+        "https://registry.npmjs.org/vscode-langservers-extracted/-/vscode-langservers-extracted-4.10.0.tgz",
+        "fc17196fc98070d02c9eabf5e523329e34ee4d0bcabcee521f22cdee5c2a0caa",
+    ),
+    (
+        "https://registry.npmjs.org/vscode-languageserver-protocol/-/vscode-languageserver-protocol-3.17.2.tgz",
+        "aaa1213ccd584485b0b3a4e8cafa00d43bdd927a4f2b174d6219b6a214d52c85",
+    ),
+    (
+        "https://registry.npmjs.org/vscode-languageserver-protocol/-/vscode-languageserver-protocol-3.17.5.tgz",
+        "829769485e9c762c336e3d730def7f9fd236578cc59df7ce3ebc726830fed03e",
+    ),
+    (
+        "https://registry.npmjs.org/vscode-languageserver-protocol/-/vscode-languageserver-protocol-3.18.1.tgz",
+        "215e597ff453e14896fd824d7c00d807c2abbd70c1027d9cc72b1160c56d8010",
+    ),
+    (
+        "https://registry.npmjs.org/vscode-languageserver-textdocument/-/vscode-languageserver-textdocument-1.0.12.tgz",
+        "bfac61d25c7266cc4f0a91795c0177435f8b421fd61c564f075d813a87a760c0",
+    ),
+    (
+        "https://registry.npmjs.org/vscode-languageserver-textdocument/-/vscode-languageserver-textdocument-1.0.13.tgz",
+        "84d69006e41b5d8ea37bead0a240dc572402128d5f1fded980aa98272ad95096",
+    ),
+    (
+        "https://registry.npmjs.org/vscode-languageserver-types/-/vscode-languageserver-types-3.17.2.tgz",
+        "c4a90cc44272ab27883841fdb41cd947b975d19dc772c9a98bbbcfb41e8e60ab",
+    ),
+    (
+        "https://registry.npmjs.org/vscode-languageserver-types/-/vscode-languageserver-types-3.17.3.tgz",
+        "7a61cdd0749248b3047e9a6b23030c8e03454eae44a29edd820e2194dfeb19d6",
+    ),
+    (
+        "https://registry.npmjs.org/vscode-languageserver-types/-/vscode-languageserver-types-3.17.5.tgz",
+        "6a3ebf4ea45489f50fffd514a91c011dd317036f5c1debe89a947cab1e714061",
+    ),
+    (
+        "https://registry.npmjs.org/vscode-languageserver-types/-/vscode-languageserver-types-3.18.0.tgz",
+        "a3f41d4867d0a16b9584fe2a65dea44ea2177a8e30e87db145f0733ebc61e33e",
+    ),
+    (
+        "https://registry.npmjs.org/vscode-languageserver/-/vscode-languageserver-10.0.1.tgz",
+        "d146145727e3648a969a453881789a74bf57e7895d463d35ed6ba9db08a5e941",
+    ),
+    (
+        "https://registry.npmjs.org/vscode-languageserver/-/vscode-languageserver-8.0.2.tgz",
+        "b861e4dcfa4fac9a3d5ea30e3aad490ded66073c27203a7669c5f0361bf43531",
+    ),
+    (
+        "https://registry.npmjs.org/vscode-languageserver/-/vscode-languageserver-9.0.1.tgz",
+        "f42a33bc9f833f747e3825dd2d8d57608c49ed3bcb2244a7499f43043631ce60",
+    ),
+    (
+        "https://registry.npmjs.org/vscode-markdown-languageservice/-/vscode-markdown-languageservice-0.5.0-alpha.1.tgz",
+        "447e958ac57a0766d2b8bdd53640fafbe83e6fe06f42fb4ff8d7819ff96ee087",
+    ),
+    (
+        "https://registry.npmjs.org/vscode-nls/-/vscode-nls-5.2.0.tgz",
+        "3ef164c5d32b72db8e1e0467df00ef9f98ca66a9d1b287e40baca745abffc0e3",
+    ),
+    (
+        "https://registry.npmjs.org/vscode-uri/-/vscode-uri-3.1.0.tgz",
+        "c96d56c27c33fa4c7ce81a34281772bc84684f8ef119c061cb896864831827d6",
+    ),
+    (
+        "https://registry.npmjs.org/web-tree-sitter/-/web-tree-sitter-0.24.5.tgz",
+        "9effbc07c4ec4618d51f943d17cff87e741081d2838da5c5dfe66b98b23b1c0a",
+    ),
+    (
+        "https://registry.npmjs.org/webidl-conversions/-/webidl-conversions-3.0.1.tgz",
+        "cf4528c2c81d01184341265747c6032074776f39d42eb0faa91a72f057cdbc24",
+    ),
+    (
+        "https://registry.npmjs.org/whatwg-url/-/whatwg-url-5.0.0.tgz",
+        "36c3a6f2d039aea001e4173b8b3eb8322cf7d45db4227b62b016096a81d76295",
+    ),
+    (
+        "https://registry.npmjs.org/yaml-language-server/-/yaml-language-server-1.23.0.tgz",
+        "a622f5be54da6b02083e300f5058fa143d8e8ff70529ee64103bd460fdc2142f",
+    ),
+    (
+        "https://registry.npmjs.org/yaml/-/yaml-2.8.3.tgz",
+        "a0412e454289b3a32833c5a3a0869c19601ebb008429580a04fdcbf94679501d",
+    ),
+    (
+        "https://registry.npmjs.org/zod/-/zod-3.24.2.tgz",
+        "5c41cc6b2009eea9bb9ea78063d5d74e9aa7af143ed3825103fc3982d535274e",
+    ),
+    (
+        "https://releases.hashicorp.com/terraform-ls/0.38.7/terraform-ls_0.38.7_darwin_amd64.zip",
+        "a6038b5a955b93caa9587f4ceeeeb1e0f934b8785e2791ca86c7cd874ad09278",
+    ),
+    (
+        "https://releases.hashicorp.com/terraform-ls/0.38.7/terraform-ls_0.38.7_darwin_arm64.zip",
+        "cf7670351a43307e6f1e86264ef6521432f98ddd3cfcb8e00b8a4a13e3590639",
+    ),
+    (
+        "https://releases.hashicorp.com/terraform-ls/0.38.7/terraform-ls_0.38.7_linux_amd64.zip",
+        "aa701137547c67ce3d091113195820812ce301b7eb4c7cf747f1fc6948c7f2da",
+    ),
+    (
+        "https://releases.hashicorp.com/terraform-ls/0.38.7/terraform-ls_0.38.7_linux_arm64.zip",
+        "edec0b81c8e24dc50ecacc317cf0e82257abf37450c0d073b4c7d7b729b88813",
+    ),
+    (
+        "https://releases.hashicorp.com/terraform/1.13.1/terraform_1.13.1_darwin_amd64.zip",
+        "b0bc33beb68452c90f3b978b1e42168c26d11dd0825b8e69b06832701c456f9d",
+    ),
+    (
+        "https://releases.hashicorp.com/terraform/1.13.1/terraform_1.13.1_darwin_arm64.zip",
+        "a67245b7b54156f38f6df535e4bdf0154195f1a47a0a0bf692d4f80f260d200b",
+    ),
+    (
+        "https://releases.hashicorp.com/terraform/1.13.1/terraform_1.13.1_linux_amd64.zip",
+        "b4a4bd942ba8007adc5261c7611dca53cc5b5e10f9bbb334a6ec8b1645f13bd0",
+    ),
+    (
+        "https://releases.hashicorp.com/terraform/1.13.1/terraform_1.13.1_linux_arm64.zip",
+        "94e5c8f5364c28c86aa4e7b862b371273999931137beaee2278542e2a39ce9f9",
+    ),
+    (
+        "https://repo1.maven.org/maven2/org/openapitools/openapi-generator-cli/7.18.0/openapi-generator-cli-7.18.0.jar",
+        "1b456d6486772837afb3419ea13ed20a91567b85b5ef1771bba4d8b3ab7c530c",
+    ),
+    (
+        "https://sdk.vorpal.build/source/ncurses-6.5-20250809.tar.gz",
+        "e67077219f4e56cc6cc56958104a9271871ef65ef7985081aced1fd5024f9f47",
+    ),
+    (
+        "https://www.sqlite.org/2026/sqlite-autoconf-3510200.tar.gz",
+        "77b34b4a8179c19fb2542d6f9de8df8f9a8d816a7baf0bc157b08284153c5d3d",
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::{catalog, testing::MockContext};
+    use anyhow::Result;
+    use vorpal_sdk::api::artifact::ArtifactSystem::{
+        Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux,
+    };
+
+    // Catalog artifacts whose sources are not pinned yet. Remove a name once `DIGESTS` has its
+    // archives; a new artifact belongs here only until its digests are added.
+    const UNPINNED: &[&str] = &[
+        "abseil-cpp",
+        "ansible",
+        "atlas",
+        "b3sum",
+        "bubblewrap",
+        "c-ares",
+        "capnproto",
+        "ccache",
+        "cfssl",
+        "chezmoi",
+        "dbmate",
+        "doggo",
+        "dumb-init",
+        "fastfetch",
+        "flatbuffers",
+        "flex",
+        "flyway",
+        "fontconfig",
+        "freetype",
+        "fx",
+        "gh-actions-tool-cache",
+        "ghz",
+        "giflib",
+        "git-absorb",
+        "gron",
+        "grpc-health-probe",
+        "haproxy",
+        "harfbuzz",
+        "hexyl",
+        "icu",
+        "jbang",
+        "jless",
+        "libcap",
+        "libffi",
+        "libjpeg-turbo",
+        "libpcap",
+        "libpng",
+        "libssh2",
+        "libwebp",
+        "libxml2",
+        "libxslt",
+        "libyaml",
+        "mc",
+        "minisign",
+        "mongosh",
+        "mtr",
+        "musl",
+        "ncdu",
+        "nghttp2",
+        "nmap",
+        "node-matrix",
+        "oniguruma",
+        "openssl",
+        "patchelf",
+        "pcre2",
+        "pre-commit",
+        "protobuf",
+        "protoc-gen-grpc-web",
+        "qsv",
+        "s5cmd",
+        "steampipe",
+        "stow",
+        "tcpdump",
+        "tini",
+        "universal-ctags",
+        "upx",
+        "wireguard-go",
+        "wireguard-tools",
+        "zig",
+        "zlib",
+    ];
+
+    #[tokio::test]
+    async fn catalog_sources_are_pinned() -> Result<()> {
+        let mut missing = vec![];
+
+        for system in [Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux] {
+            let context = &mut MockContext::new(system).build().await?;
+
+            for digest in catalog::build(context, |_, _| true).await? {
+                let Some(artifact) = context.get_artifact(&digest) else {
+                    continue;
+                };
+
+                if UNPINNED.contains(&artifact.name.as_str()) {
+                    continue;
+                }
+
+                for source in artifact.sources {
+                    if source.path.starts_with("https://") && source.digest.is_none() {
+                        missing.push(format!("{}: {}", artifact.name, source.path));
+                    }
+                }
+            }
+        }
+
+        missing.sort();
+        missing.dedup();
+
+        assert!(missing.is_empty(), "sources without a digest: {missing:#?}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+use std::fmt;
+use vorpal_sdk::api::artifact::ArtifactSystem;
+
+// Failures consumers may want to handle rather than report, e.g. skipping an artifact on a system
+// it does not support. They travel as `anyhow::Error` like every other failure in the crate; use
+// `downcast_ref::<ArtifactError>()` (or `is_unsupported_system`) to tell them apart.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ArtifactError {
+    // A config variable has a value the crate cannot use.
+    InvalidVariable {
+        variable: String,
+        value: String,
+    },
+
+    // A build needs an artifact that is passed in by digest and was not, e.g. the linux-vorpal
+    // rootfs for cross builds.
+    MissingDependency {
+        dependency: String,
+        variable: String,
+    },
+
+    // Sources with no local copy, as (url, path): missing from the offline directory, or private
+    // and not downloaded.
+    SourceUnavailable {
+        missing: Vec<(String, String)>,
+    },
+
+    // A name that is not in the catalog.
+    UnknownArtifact {
+        artifact: String,
+    },
+
+    // The artifact has no definition for the system; skip it there.
+    UnsupportedSystem {
+        artifact: String,
+        system: ArtifactSystem,
+    },
+
+    // The artifact has no build for a variant or channel, e.g. icu with musl.
+    UnsupportedVariant {
+        artifact: String,
+        variant: String,
+    },
+
+    // The version cannot be built, e.g. a pinned version the release assets do not cover.
+    UnsupportedVersion {
+        artifact: String,
+        version: String,
+    },
+}
+
+impl fmt::Display for ArtifactError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidVariable { variable, value } => {
+                write!(f, "invalid '{variable}' variable '{value}'")
+            }
+            Self::MissingDependency {
+                dependency,
+                variable,
+            } => write!(
+                f,
+                "missing {dependency}: pass its digest in the '{variable}' variable"
+            ),
+            Self::SourceUnavailable { missing } => {
+                write!(f, "{} source(s) have no local copy:", missing.len())?;
+
+                for (url, path) in missing.iter() {
+                    write!(f, "\n  {url} -> {path}")?;
+                }
+
+                Ok(())
+            }
+            Self::UnknownArtifact { artifact } => write!(f, "Unknown artifact: {artifact}"),
+            Self::UnsupportedSystem { artifact, system } => write!(
+                f,
+                "Unsupported system for {artifact} artifact: {}",
+                system.as_str_name()
+            ),
+            Self::UnsupportedVariant { artifact, variant } => {
+                write!(f, "{artifact} has no {variant} build")
+            }
+            Self::UnsupportedVersion { artifact, version } => {
+                write!(f, "unsupported {artifact} version '{version}'")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ArtifactError {}
+
+// Whether `error` only means the artifact is not available on the requested system, so callers
+// building a list of artifacts for several systems can skip it instead of failing.
+pub fn is_unsupported_system(error: &anyhow::Error) -> bool {
+    matches!(
+        error.downcast_ref::<ArtifactError>(),
+        Some(ArtifactError::UnsupportedSystem { .. })
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vorpal_sdk::api::artifact::ArtifactSystem::X8664Darwin;
+
+    #[test]
+    fn is_unsupported_system_through_context() {
+        let error = anyhow::Error::from(ArtifactError::UnsupportedSystem {
+            artifact: "ttyd".to_string(),
+            system: X8664Darwin,
+        })
+        .context("building dev");
+
+        assert!(is_unsupported_system(&error));
+        assert!(!is_unsupported_system(&anyhow::anyhow!("build failed")));
+        assert!(!is_unsupported_system(&anyhow::Error::from(
+            ArtifactError::UnknownArtifact {
+                artifact: "ttyd".to_string()
+            }
+        )));
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{
+    artifact::ArtifactPackage,
+    catalog::{self, Category},
+    context_cache, target,
+};
+use anyhow::{bail, Result};
+use serde_json::{json, Value};
+use std::collections::BTreeSet;
+use vorpal_sdk::context::ConfigContext;
+
+// Names of the artifacts the steps of `digest` use, e.g. toolchains and libraries.
+fn dependencies(context: &ConfigContext, digest: &str) -> Result<Vec<String>> {
+    let Some(artifact) = context.get_artifact(digest) else {
+        bail!("artifact not found in context: {digest}");
+    };
+
+    let mut names = BTreeSet::new();
+
+    for dependency in artifact.steps.iter().flat_map(|step| step.artifacts.iter()) {
+        let Some(dependency) = context.get_artifact(dependency) else {
+            bail!("artifact not found in context: {dependency}");
+        };
+
+        names.insert(dependency.name);
+    }
+
+    Ok(names.into_iter().collect())
+}
+
+async fn entry(
+    context: &mut ConfigContext,
+    category: Category,
+    package: &dyn ArtifactPackage,
+) -> Result<Value> {
+    let systems = package
+        .systems()
+        .into_iter()
+        .map(target::name)
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut value = json!({
+        "name": package.name(),
+        "version": package.version(),
+        "category": category.as_str(),
+        "systems": systems,
+    });
+
+    // Aliases, sources and dependencies come from the definition for the context's system, so
+    // artifacts that do not support it only list what holds for every system.
+    if !package.systems().contains(&context.get_system()) {
+        return Ok(value);
+    }
+
+    let digest = context_cache::build(context, package).await?;
+
+    let Some(artifact) = context.get_artifact(&digest) else {
+        bail!("artifact not found in context: {digest}");
+    };
+
+    let sources = artifact
+        .sources
+        .iter()
+        .map(|source| source.path.clone())
+        .collect::<Vec<_>>();
+
+    value["aliases"] = json!(artifact.aliases);
+    value["sources"] = json!(sources);
+    value["dependencies"] = json!(dependencies(context, &digest)?);
+
+    Ok(value)
+}
+
+// JSON document listing every catalog artifact with its version, aliases, systems, source URLs
+// and dependencies, for documentation sites and inventory tooling. Artifacts are only defined in
+// the context, never built, so the document is cheap to produce for any system.
+pub async fn manifest(context: &mut ConfigContext) -> Result<Value> {
+    let mut artifacts = vec![];
+
+    for group in catalog::all() {
+        for package in group.packages {
+            artifacts.push(entry(context, group.category, package.as_ref()).await?);
+        }
+    }
+
+    artifacts.sort_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    Ok(json!({
+        "system": target::name(context.get_system())?,
+        "artifacts": artifacts,
+    }))
+}
+
+#[cfg(all(test, feature = "terminal"))]
+mod tests {
+    use super::*;
+    use crate::{
+        artifact::{musl::Musl, tmux::Tmux},
+        testing::MockContext,
+        versions,
+    };
+    use vorpal_sdk::api::artifact::ArtifactSystem::Aarch64Darwin;
+
+    #[tokio::test]
+    async fn entry_lists_sources_and_dependencies() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Darwin).build().await?;
+
+        let tmux = entry(context, Category::Terminal, &Tmux::new()).await?;
+
+        assert_eq!(tmux["version"], versions::TMUX);
+        assert_eq!(tmux["category"], "terminal");
+        assert!(tmux["aliases"]
+            .as_array()
+            .is_some_and(|aliases| aliases.contains(&json!(format!("tmux:{}", versions::TMUX)))));
+        assert_eq!(tmux["dependencies"], json!(["libevent", "ncurses"]));
+        assert_eq!(tmux["sources"].as_array().map(Vec::len), Some(1));
+
+        let musl = entry(context, Category::BuildTools, &Musl::new()).await?;
+
+        assert_eq!(musl["systems"], json!(["aarch64-linux", "x86_64-linux"]));
+        assert!(musl.get("aliases").is_none());
+
+        Ok(())
+    }
+}
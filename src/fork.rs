@@ -0,0 +1,112 @@
+use crate::{artifact::ArtifactPackage, context_cache};
+use anyhow::{bail, Result};
+use vorpal_sdk::{api::artifact::ArtifactSystem, context::ConfigContext};
+
+// `ConfigContext::add_artifact` needs `&mut self`, so independent dependencies are resolved on
+// clones of the context and their artifacts are folded back in with `join`.
+pub struct Fork {
+    context: Option<ConfigContext>,
+    digest: String,
+}
+
+impl Fork {
+    pub fn new(context: ConfigContext, digest: String) -> Self {
+        Self {
+            context: Some(context),
+            digest,
+        }
+    }
+
+    pub fn resolved(digest: &str) -> Self {
+        Self {
+            context: None,
+            digest: digest.to_string(),
+        }
+    }
+}
+
+// Uses `value` when the caller already provided the dependency, otherwise builds the package's
+// default configuration on a clone of `context`.
+pub async fn build(
+    context: &ConfigContext,
+    value: Option<&str>,
+    package: impl ArtifactPackage,
+) -> Result<Fork> {
+    if let Some(digest) = value {
+        return Ok(Fork::resolved(digest));
+    }
+
+    let mut fork = context.clone();
+    let digest = context_cache::build(&mut fork, &package).await?;
+
+    Ok(Fork::new(fork, digest))
+}
+
+// Same as `build` for a dependency of a build for `system`. Default builds are for the host, so
+// for another system the dependency must be passed in, already built for it.
+pub async fn build_for(
+    context: &ConfigContext,
+    system: ArtifactSystem,
+    value: Option<&str>,
+    package: impl ArtifactPackage,
+) -> Result<Fork> {
+    if value.is_none() && system != context.get_system() {
+        bail!(
+            "{} is only built for the host by default: pass one built for {}",
+            package.name(),
+            system.as_str_name()
+        );
+    }
+
+    build(context, value, package).await
+}
+
+// Same as `build` for packages customised through `with_*`, which must not be memoized.
+pub async fn build_configured(
+    context: &ConfigContext,
+    value: Option<&str>,
+    package: impl ArtifactPackage,
+) -> Result<Fork> {
+    if let Some(digest) = value {
+        return Ok(Fork::resolved(digest));
+    }
+
+    let mut fork = context.clone();
+    let digest = package.build(&mut fork).await?;
+
+    Ok(Fork::new(fork, digest))
+}
+
+pub async fn join<const N: usize>(
+    context: &mut ConfigContext,
+    forks: [Fork; N],
+) -> Result<[String; N]> {
+    for fork in forks.iter() {
+        let Some(fork_context) = &fork.context else {
+            continue;
+        };
+
+        let mut artifacts = fork_context
+            .get_artifact_store()
+            .into_iter()
+            .filter(|(digest, _)| context.get_artifact(digest).is_none())
+            .collect::<Vec<_>>();
+
+        artifacts.sort_by(|a, b| a.0.cmp(&b.0));
+
+        // The agent has already prepared these, so adding them again only registers them with
+        // this context's store.
+        for (digest, artifact) in artifacts {
+            let added = context.add_artifact(&artifact).await?;
+
+            if added != digest {
+                bail!(
+                    "artifact '{}' changed digest when joined: {digest} != {added}",
+                    artifact.name
+                );
+            }
+        }
+    }
+
+    Ok(forks.map(|fork| fork.digest))
+}
@@ -0,0 +1,178 @@
+use anyhow::{bail, Result};
+use serde_json::json;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    str::FromStr,
+};
+use vorpal_sdk::context::ConfigContext;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Dot,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.to_lowercase().as_str() {
+            "dot" => Ok(Format::Dot),
+            "json" => Ok(Format::Json),
+            _ => bail!("unsupported graph format '{value}' (expected 'dot' or 'json')"),
+        }
+    }
+}
+
+pub struct Node {
+    pub dependencies: BTreeSet<String>,
+    pub name: String,
+    pub version: String,
+}
+
+// Every artifact reachable from `digests` through step dependencies, keyed by digest.
+pub fn nodes(context: &ConfigContext, digests: &[String]) -> Result<BTreeMap<String, Node>> {
+    let mut nodes = BTreeMap::new();
+    let mut pending = digests.to_vec();
+
+    while let Some(digest) = pending.pop() {
+        if nodes.contains_key(&digest) {
+            continue;
+        }
+
+        let Some(artifact) = context.get_artifact(&digest) else {
+            bail!("artifact not found in context: {digest}");
+        };
+
+        let version = artifact
+            .aliases
+            .iter()
+            .filter_map(|alias| alias.split_once(':'))
+            .find(|(name, _)| *name == artifact.name)
+            .map(|(_, version)| version.to_string())
+            .unwrap_or_default();
+
+        let dependencies = artifact
+            .steps
+            .iter()
+            .flat_map(|step| step.artifacts.iter().cloned())
+            .collect::<BTreeSet<_>>();
+
+        pending.extend(dependencies.iter().cloned());
+
+        nodes.insert(
+            digest,
+            Node {
+                dependencies,
+                name: artifact.name,
+                version,
+            },
+        );
+    }
+
+    Ok(nodes)
+}
+
+fn dot(nodes: &BTreeMap<String, Node>) -> String {
+    let mut lines = vec![
+        "digraph artifacts {".to_string(),
+        "    rankdir=LR;".to_string(),
+    ];
+
+    for (digest, node) in nodes.iter() {
+        let label = match node.version.is_empty() {
+            true => node.name.clone(),
+            false => format!("{}\\n{}", node.name, node.version),
+        };
+
+        lines.push(format!("    \"{digest}\" [label=\"{label}\"];"));
+    }
+
+    for (digest, node) in nodes.iter() {
+        for dependency in node.dependencies.iter() {
+            lines.push(format!("    \"{digest}\" -> \"{dependency}\";"));
+        }
+    }
+
+    lines.push("}".to_string());
+
+    lines.join("\n")
+}
+
+fn json(nodes: &BTreeMap<String, Node>) -> Result<String> {
+    let edges = nodes
+        .iter()
+        .flat_map(|(digest, node)| {
+            node.dependencies
+                .iter()
+                .map(move |dependency| json!({ "from": digest, "to": dependency }))
+        })
+        .collect::<Vec<_>>();
+
+    let nodes = nodes
+        .iter()
+        .map(|(digest, node)| {
+            json!({
+                "digest": digest,
+                "name": node.name,
+                "version": node.version,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    Ok(serde_json::to_string_pretty(
+        &json!({ "nodes": nodes, "edges": edges }),
+    )?)
+}
+
+// Edges point from an artifact to the artifacts its steps use.
+pub fn document(context: &ConfigContext, digests: &[String], format: Format) -> Result<String> {
+    let nodes = nodes(context, digests)?;
+
+    match format {
+        Format::Dot => Ok(dot(&nodes)),
+        Format::Json => json(&nodes),
+    }
+}
+
+#[cfg(all(test, feature = "terminal"))]
+mod tests {
+    use super::*;
+    use crate::{
+        artifact::{ttyd::Ttyd, ArtifactPackage},
+        testing::MockContext,
+    };
+    use vorpal_sdk::api::artifact::ArtifactSystem::Aarch64Darwin;
+
+    fn find<'a>(nodes: &'a BTreeMap<String, Node>, name: &str) -> (&'a String, &'a Node) {
+        nodes
+            .iter()
+            .find(|(_, node)| node.name == name)
+            .unwrap_or_else(|| panic!("{name} missing from graph"))
+    }
+
+    #[tokio::test]
+    async fn nodes_follow_dependency_chain() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Darwin).build().await?;
+
+        let ttyd = Ttyd::new().build(context).await?;
+
+        let nodes = nodes(context, &[ttyd.clone()])?;
+
+        let (cmake, _) = find(&nodes, "cmake");
+        let (libuv, libuv_node) = find(&nodes, "libuv");
+        let (libwebsockets, libwebsockets_node) = find(&nodes, "libwebsockets");
+        let (_, ttyd_node) = find(&nodes, "ttyd");
+
+        assert!(libuv_node.dependencies.contains(cmake));
+        assert!(libwebsockets_node.dependencies.contains(libuv));
+        assert!(ttyd_node.dependencies.contains(libwebsockets));
+        assert!(!ttyd_node.version.is_empty());
+
+        let dot = document(context, &[ttyd], Format::Dot)?;
+
+        assert!(dot.contains(&format!("-> \"{libwebsockets}\"")));
+
+        Ok(())
+    }
+}
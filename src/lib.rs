@@ -1,31 +1,86 @@
+#[cfg(feature = "development")]
+use crate::artifact::ArtifactPackage;
+#[cfg(feature = "development")]
 use anyhow::Result;
+use vorpal_sdk::api::artifact::{
+    ArtifactSystem,
+    ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+};
+#[cfg(feature = "development")]
 use vorpal_sdk::{
-    api::artifact::{
-        ArtifactSystem,
-        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
-    },
     artifact::{get_env_key, protoc::Protoc, rust_toolchain, rust_toolchain::RustToolchain},
     context::ConfigContext,
 };
 
+pub mod aliases;
 pub mod artifact;
+pub mod builder;
+pub mod catalog;
+pub mod channel;
+pub mod context_cache;
+pub mod devenv;
+pub mod digests;
+pub mod error;
+pub mod export;
+pub mod fork;
+pub mod graph;
+pub mod metadata;
+pub mod oci;
+pub mod overrides;
+pub mod registry;
+pub mod sbom;
+pub mod size_report;
+pub mod source;
+pub mod target;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod upstream;
+pub mod variant;
+pub mod versions;
 
 pub const DEFAULT_SYSTEMS: [ArtifactSystem; 4] =
     [Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux];
 
+#[cfg(feature = "development")]
 pub struct ProjectEnvironment {
+    artifacts: Vec<String>,
+    environments: Vec<String>,
+    hooks: Vec<String>,
     name: String,
     systems: Vec<ArtifactSystem>,
 }
 
+#[cfg(feature = "development")]
 impl ProjectEnvironment {
     pub fn new(name: &str, systems: Vec<ArtifactSystem>) -> Self {
         ProjectEnvironment {
+            artifacts: vec![],
+            environments: vec![],
+            hooks: vec![],
             name: name.to_string(),
             systems,
         }
     }
 
+    // Tools added next to the built-in ones, e.g. the ones a `devenv.toml` selects, see `devenv`.
+    pub fn with_artifacts(mut self, artifacts: Vec<String>) -> Self {
+        self.artifacts.extend(artifacts);
+        self
+    }
+
+    // `KEY=value` pairs exported on activation after the built-in ones, e.g. a `devenv.toml`'s
+    // `[env]`.
+    pub fn with_environments(mut self, environments: Vec<String>) -> Self {
+        self.environments.extend(environments);
+        self
+    }
+
+    // Commands run once the environment is active, e.g. a `devenv.toml`'s enter hooks.
+    pub fn with_hooks(mut self, hooks: Vec<String>) -> Self {
+        self.hooks.extend(hooks);
+        self
+    }
+
     pub async fn build(self, context: &mut ConfigContext) -> Result<String> {
         // Dependencies
 
@@ -46,13 +101,20 @@ impl ProjectEnvironment {
 
         // Artifact
 
-        vorpal_sdk::artifact::DevelopmentEnvironment::new(&self.name, self.systems)
-            .with_artifacts(vec![lima, protoc, rust_toolchain.clone()])
+        builder::environment::DevelopmentEnvironment::new(&self.name, self.systems)
+            .with_artifacts(
+                [lima, protoc, rust_toolchain.clone()]
+                    .into_iter()
+                    .chain(self.artifacts)
+                    .collect(),
+            )
             .with_environments(vec![
                 format!("PATH={}", rust_toolchain_bin),
                 format!("RUSTUP_HOME={}", get_env_key(&rust_toolchain)),
                 format!("RUSTUP_TOOLCHAIN={}", rust_toolchain_name),
             ])
+            .with_environments(self.environments)
+            .with_hooks(self.hooks)
             .build(context)
             .await
     }
@@ -0,0 +1,223 @@
+use anyhow::{bail, Result};
+use std::env;
+use vorpal_artifacts::{
+    catalog, error::ArtifactError, export, metadata, overrides, registry, source, target,
+    upstream::Upstream,
+};
+use vorpal_sdk::{
+    api::{
+        agent::agent_service_client::AgentServiceClient,
+        artifact::artifact_service_client::ArtifactServiceClient,
+    },
+    artifact::{get_default_address, system::get_system_default_str},
+    context::{build_channel, ConfigContext},
+};
+
+const USAGE: &str = "usage: artifacts list
+       artifacts show <name>
+       artifacts define <name>[,<name>...] [options]
+       artifacts manifest [options]
+
+options: [--agent <address>] [--registry <address>] [--system <system>]
+         [--variable <name>=<value>...] [--unlock]";
+
+struct Options {
+    agent: String,
+    names: Vec<String>,
+    registry: String,
+    system: String,
+    unlock: bool,
+    variables: Vec<String>,
+}
+
+fn upstream_url(upstream: Upstream) -> String {
+    match upstream {
+        Upstream::Directory { url, .. } => url.to_string(),
+        Upstream::Github(repository) => format!("https://github.com/{repository}"),
+        Upstream::Hashicorp(product) => format!("https://releases.hashicorp.com/{product}/"),
+        Upstream::Npm(package) => format!("https://www.npmjs.com/package/{package}"),
+    }
+}
+
+fn list() {
+    let mut rows = catalog::all()
+        .into_iter()
+        .flat_map(|group| {
+            group.packages.into_iter().map(move |package| {
+                (
+                    package.name().to_string(),
+                    package.version().to_string(),
+                    group.category.as_str(),
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+
+    rows.sort();
+
+    for (name, version, category) in rows {
+        println!("{name}\t{version}\t{category}");
+    }
+}
+
+fn show(name: &str) -> Result<()> {
+    let Some(package) = registry::get(name) else {
+        return Err(ArtifactError::UnknownArtifact {
+            artifact: name.to_string(),
+        }
+        .into());
+    };
+
+    let category = catalog::all()
+        .into_iter()
+        .find(|group| {
+            group
+                .packages
+                .iter()
+                .any(|candidate| candidate.name() == name)
+        })
+        .map(|group| group.category.as_str())
+        .unwrap_or_default();
+
+    let systems = package
+        .systems()
+        .into_iter()
+        .map(|system| target::name(system).map(str::to_string))
+        .collect::<Result<Vec<_>>>()?;
+
+    println!("name: {name}");
+    println!("version: {}", package.version());
+    println!("category: {category}");
+    println!("systems: {}", systems.join(", "));
+
+    if let Some(metadata) = metadata::get(name) {
+        println!("description: {}", metadata.description);
+        println!("homepage: {}", metadata.homepage);
+        println!("license: {}", metadata.license);
+    }
+
+    if let Some(upstream) = package.upstream() {
+        println!("upstream: {}", upstream_url(upstream));
+    }
+
+    Ok(())
+}
+
+fn parse_options(args: &[String]) -> Result<Options> {
+    let mut options = Options {
+        agent: get_default_address(),
+        names: vec![],
+        registry: get_default_address(),
+        system: get_system_default_str(),
+        unlock: false,
+        variables: vec![],
+    };
+
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        let mut value = |option: &str| match args.next() {
+            Some(value) => Ok(value.clone()),
+            None => bail!("missing value for {option}"),
+        };
+
+        match arg.as_str() {
+            "--agent" => options.agent = value(arg)?,
+            "--registry" => options.registry = value(arg)?,
+            "--system" => options.system = value(arg)?,
+            "--unlock" => options.unlock = true,
+            "--variable" => options.variables.push(value(arg)?),
+            _ if arg.starts_with('-') => bail!("unknown option: {arg}"),
+            _ => options.names.extend(
+                arg.split(',')
+                    .map(str::trim)
+                    .filter(|name| !name.is_empty())
+                    .map(str::to_string),
+            ),
+        }
+    }
+
+    Ok(options)
+}
+
+// Connects to the agent and registry the way `vorpal` starts a config, so artifacts can be
+// defined without a consumer config.
+async fn context(options: Options) -> Result<ConfigContext> {
+    overrides::load_env()?;
+
+    ConfigContext::new(
+        options.names.join(","),
+        env::current_dir()?,
+        "library".to_string(),
+        options.system,
+        options.unlock,
+        options.variables,
+        AgentServiceClient::new(build_channel(&options.agent).await?),
+        ArtifactServiceClient::new(build_channel(&options.registry).await?),
+        0,
+        options.registry,
+    )
+}
+
+// Registers each named artifact with the agent and prints its digest. Nothing is built here: the
+// digests are handed to `vorpal build`, which runs the steps, so this does not serve the context
+// with `ConfigContext::run`.
+async fn define(options: Options) -> Result<()> {
+    if options.names.is_empty() {
+        bail!("{USAGE}");
+    }
+
+    if let Some(name) = options
+        .names
+        .iter()
+        .find(|name| registry::get(name).is_none())
+    {
+        return Err(ArtifactError::UnknownArtifact {
+            artifact: name.to_string(),
+        }
+        .into());
+    }
+
+    let names = options.names.clone();
+
+    let context = &mut context(options).await?;
+
+    for name in names.iter() {
+        let digest = registry::build(context, name).await?;
+
+        println!("{name}\t{digest}");
+    }
+
+    source::check_offline()
+}
+
+// Prints `export::manifest` for the `--system` being defined.
+async fn manifest(options: Options) -> Result<()> {
+    if let Some(name) = options.names.first() {
+        bail!("unexpected argument: {name}");
+    }
+
+    let context = &mut context(options).await?;
+
+    let manifest = export::manifest(context).await?;
+
+    println!("{}", serde_json::to_string_pretty(&manifest)?);
+
+    source::check_offline()
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    match args.first().map(String::as_str) {
+        Some("list") if args.len() == 1 => list(),
+        Some("show") if args.len() == 2 => show(&args[1])?,
+        Some("define") => define(parse_options(&args[1..])?).await?,
+        Some("manifest") => manifest(parse_options(&args[1..])?).await?,
+        Some("-h" | "--help") => println!("{USAGE}"),
+        _ => bail!("{USAGE}"),
+    }
+
+    Ok(())
+}
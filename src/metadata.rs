@@ -0,0 +1,1266 @@
+// Upstream metadata for every artifact, keyed by artifact name. Licenses are SPDX expressions;
+// `NOASSERTION` marks the ones that are proprietary or not yet reviewed.
+
+pub struct Metadata {
+    pub description: &'static str,
+    pub homepage: &'static str,
+    pub license: &'static str,
+}
+
+const METADATA: &[(&str, Metadata)] = &[
+    (
+        "abseil-cpp",
+        Metadata {
+            description: "Abseil C++ common libraries",
+            homepage: "https://abseil.io",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "abtop",
+        Metadata {
+            description: "Top-like monitor for AI coding agent sessions",
+            homepage: "https://github.com/graykode/abtop",
+            license: "NOASSERTION",
+        },
+    ),
+    (
+        "ansible",
+        Metadata {
+            description: "Agentless configuration management and automation (ansible-core)",
+            homepage: "https://www.ansible.com",
+            license: "GPL-3.0-or-later",
+        },
+    ),
+    (
+        "argocd",
+        Metadata {
+            description: "Declarative GitOps continuous delivery CLI for Kubernetes",
+            homepage: "https://argo-cd.readthedocs.io",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "atlas",
+        Metadata {
+            description: "Declarative database schema management tool",
+            homepage: "https://atlasgo.io",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "awscli2",
+        Metadata {
+            description: "Unified command line interface to Amazon Web Services",
+            homepage: "https://aws.amazon.com/cli/",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "b3sum",
+        Metadata {
+            description: "Command line utility for BLAKE3 hashes",
+            homepage: "https://github.com/BLAKE3-team/BLAKE3",
+            license: "CC0-1.0 OR Apache-2.0",
+        },
+    ),
+    (
+        "bash-language-server",
+        Metadata {
+            description: "Language server for Bash",
+            homepage: "https://github.com/bash-lsp/bash-language-server",
+            license: "MIT",
+        },
+    ),
+    (
+        "bat",
+        Metadata {
+            description: "cat clone with syntax highlighting and Git integration",
+            homepage: "https://github.com/sharkdp/bat",
+            license: "MIT OR Apache-2.0",
+        },
+    ),
+    (
+        "beads",
+        Metadata {
+            description: "Issue tracker for coding agents",
+            homepage: "https://github.com/steveyegge/beads",
+            license: "NOASSERTION",
+        },
+    ),
+    (
+        "bottom",
+        Metadata {
+            description: "Cross-platform graphical process and system monitor",
+            homepage: "https://github.com/ClementTsang/bottom",
+            license: "MIT",
+        },
+    ),
+    (
+        "bubblewrap",
+        Metadata {
+            description: "Unprivileged sandboxing tool based on Linux namespaces",
+            homepage: "https://github.com/containers/bubblewrap",
+            license: "LGPL-2.0-or-later",
+        },
+    ),
+    (
+        "c-ares",
+        Metadata {
+            description: "Asynchronous DNS resolver library",
+            homepage: "https://c-ares.org",
+            license: "MIT",
+        },
+    ),
+    (
+        "capnproto",
+        Metadata {
+            description: "Cap'n Proto schema compiler and C++ serialization and RPC libraries",
+            homepage: "https://capnproto.org",
+            license: "MIT",
+        },
+    ),
+    (
+        "ccache",
+        Metadata {
+            description: "Compiler cache for C and C++",
+            homepage: "https://ccache.dev",
+            license: "GPL-3.0-or-later",
+        },
+    ),
+    (
+        "cfssl",
+        Metadata {
+            description: "Cloudflare's PKI and TLS toolkit",
+            homepage: "https://github.com/cloudflare/cfssl",
+            license: "BSD-2-Clause",
+        },
+    ),
+    (
+        "chezmoi",
+        Metadata {
+            description: "Dotfile manager",
+            homepage: "https://www.chezmoi.io",
+            license: "MIT",
+        },
+    ),
+    (
+        "cmake",
+        Metadata {
+            description: "Cross-platform build system generator",
+            homepage: "https://cmake.org",
+            license: "BSD-3-Clause",
+        },
+    ),
+    (
+        "crane",
+        Metadata {
+            description: "Tool for interacting with remote container images and registries",
+            homepage: "https://github.com/google/go-containerregistry",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "cue",
+        Metadata {
+            description: "Data validation and configuration language",
+            homepage: "https://cuelang.org",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "dbmate",
+        Metadata {
+            description: "Lightweight, framework-agnostic database migration tool",
+            homepage: "https://github.com/amacneil/dbmate",
+            license: "MIT",
+        },
+    ),
+    (
+        "delta",
+        Metadata {
+            description: "Syntax-highlighting pager for git, diff and grep output",
+            homepage: "https://github.com/dandavison/delta",
+            license: "MIT",
+        },
+    ),
+    (
+        "direnv",
+        Metadata {
+            description: "Per-directory shell environment loader",
+            homepage: "https://direnv.net",
+            license: "MIT",
+        },
+    ),
+    (
+        "dockerfile-language-server-nodejs",
+        Metadata {
+            description: "Language server for Dockerfiles",
+            homepage: "https://github.com/rcjsuen/dockerfile-language-server",
+            license: "MIT",
+        },
+    ),
+    (
+        "doggo",
+        Metadata {
+            description: "Command line DNS client",
+            homepage: "https://github.com/mr-karan/doggo",
+            license: "GPL-3.0-only",
+        },
+    ),
+    (
+        "doppler",
+        Metadata {
+            description: "CLI for the Doppler secrets manager",
+            homepage: "https://github.com/DopplerHQ/cli",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "dumb-init",
+        Metadata {
+            description: "Minimal init system that forwards signals to a container's process",
+            homepage: "https://github.com/Yelp/dumb-init",
+            license: "MIT",
+        },
+    ),
+    (
+        "eksctl",
+        Metadata {
+            description: "CLI for Amazon EKS clusters",
+            homepage: "https://eksctl.io",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "fastfetch",
+        Metadata {
+            description: "System information tool",
+            homepage: "https://github.com/fastfetch-cli/fastfetch",
+            license: "MIT",
+        },
+    ),
+    (
+        "fd",
+        Metadata {
+            description: "Fast and user-friendly alternative to find",
+            homepage: "https://github.com/sharkdp/fd",
+            license: "MIT OR Apache-2.0",
+        },
+    ),
+    (
+        "ffmpeg",
+        Metadata {
+            description: "Audio and video conversion and streaming toolkit",
+            homepage: "https://ffmpeg.org",
+            license: "GPL-2.0-or-later",
+        },
+    ),
+    (
+        "flatbuffers",
+        Metadata {
+            description: "FlatBuffers schema compiler (flatc) and C++ headers",
+            homepage: "https://flatbuffers.dev",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "flex",
+        Metadata {
+            description: "Fast lexical analyzer generator",
+            homepage: "https://github.com/westes/flex",
+            license: "BSD-2-Clause",
+        },
+    ),
+    (
+        "fluxcd",
+        Metadata {
+            description: "GitOps toolkit CLI for Kubernetes",
+            homepage: "https://fluxcd.io",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "flyway",
+        Metadata {
+            description: "Database migration tool",
+            homepage: "https://www.red-gate.com/products/flyway/",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "font-stack",
+        Metadata {
+            description: "Bundle of the fontconfig, freetype and harfbuzz font libraries",
+            homepage: "https://harfbuzz.github.io",
+            license: "(FTL OR GPL-2.0-or-later) AND HPND-sell-variant AND MIT-Modern-Variant",
+        },
+    ),
+    (
+        "fontconfig",
+        Metadata {
+            description: "Font discovery and configuration library",
+            homepage: "https://www.freedesktop.org/wiki/Software/fontconfig/",
+            license: "HPND-sell-variant",
+        },
+    ),
+    (
+        "freetype",
+        Metadata {
+            description: "Font rasterization library",
+            homepage: "https://freetype.org",
+            license: "FTL OR GPL-2.0-or-later",
+        },
+    ),
+    (
+        "fx",
+        Metadata {
+            description: "Terminal JSON viewer and processor",
+            homepage: "https://fx.wtf",
+            license: "MIT",
+        },
+    ),
+    (
+        "fzf",
+        Metadata {
+            description: "Command line fuzzy finder",
+            homepage: "https://github.com/junegunn/fzf",
+            license: "MIT",
+        },
+    ),
+    (
+        "gh-actions-tool-cache",
+        Metadata {
+            description: "GitHub Actions tool cache seeded with Go, Node.js and Python",
+            homepage: "https://github.com/actions/toolkit",
+            license: "NOASSERTION",
+        },
+    ),
+    (
+        "ghz",
+        Metadata {
+            description: "gRPC benchmarking and load testing tool",
+            homepage: "https://ghz.sh",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "giflib",
+        Metadata {
+            description: "Library and utilities for reading and writing GIF images",
+            homepage: "https://giflib.sourceforge.net",
+            license: "MIT",
+        },
+    ),
+    (
+        "git-absorb",
+        Metadata {
+            description: "Automatic git commit --fixup",
+            homepage: "https://github.com/tummychow/git-absorb",
+            license: "BSD-3-Clause",
+        },
+    ),
+    (
+        "glow",
+        Metadata {
+            description: "Terminal Markdown renderer",
+            homepage: "https://github.com/charmbracelet/glow",
+            license: "MIT",
+        },
+    ),
+    (
+        "golangci-lint",
+        Metadata {
+            description: "Go linters runner",
+            homepage: "https://golangci-lint.run",
+            license: "GPL-3.0-only",
+        },
+    ),
+    (
+        "gpg",
+        Metadata {
+            description: "GNU Privacy Guard",
+            homepage: "https://gnupg.org",
+            license: "GPL-3.0-or-later",
+        },
+    ),
+    (
+        "gron",
+        Metadata {
+            description: "Makes JSON greppable",
+            homepage: "https://github.com/tomnomnom/gron",
+            license: "MIT",
+        },
+    ),
+    (
+        "grpc-health-probe",
+        Metadata {
+            description: "Command-line tool to perform gRPC health checks",
+            homepage: "https://github.com/grpc-ecosystem/grpc-health-probe",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "gum",
+        Metadata {
+            description: "Tool for glamorous shell scripts",
+            homepage: "https://github.com/charmbracelet/gum",
+            license: "MIT",
+        },
+    ),
+    (
+        "haproxy",
+        Metadata {
+            description: "TCP and HTTP load balancer and reverse proxy",
+            homepage: "https://www.haproxy.org",
+            license: "GPL-2.0-or-later",
+        },
+    ),
+    (
+        "harfbuzz",
+        Metadata {
+            description: "OpenType text shaping engine",
+            homepage: "https://harfbuzz.github.io",
+            license: "MIT-Modern-Variant",
+        },
+    ),
+    (
+        "helm",
+        Metadata {
+            description: "Kubernetes package manager",
+            homepage: "https://helm.sh",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "herdr",
+        Metadata {
+            description: "Terminal manager for coding agents",
+            homepage: "https://github.com/ogulcancelik/herdr",
+            license: "NOASSERTION",
+        },
+    ),
+    (
+        "hexyl",
+        Metadata {
+            description: "Command line hex viewer",
+            homepage: "https://github.com/sharkdp/hexyl",
+            license: "MIT OR Apache-2.0",
+        },
+    ),
+    (
+        "hunk",
+        Metadata {
+            description: "Terminal diff viewer",
+            homepage: "https://github.com/modem-dev/hunk",
+            license: "NOASSERTION",
+        },
+    ),
+    (
+        "icu",
+        Metadata {
+            description: "International Components for Unicode C/C++ libraries",
+            homepage: "https://icu.unicode.org",
+            license: "Unicode-3.0",
+        },
+    ),
+    (
+        "image-libs",
+        Metadata {
+            description: "Bundle of the giflib, libjpeg-turbo, libpng and libwebp image codecs",
+            homepage: "https://developers.google.com/speed/webp",
+            license: "BSD-3-Clause AND IJG AND libpng-2.0 AND MIT AND Zlib",
+        },
+    ),
+    (
+        "jbang",
+        Metadata {
+            description: "Single-file Java and JVM script runner",
+            homepage: "https://www.jbang.dev",
+            license: "MIT",
+        },
+    ),
+    (
+        "jj",
+        Metadata {
+            description: "Git-compatible version control system",
+            homepage: "https://jj-vcs.github.io/jj/",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "jless",
+        Metadata {
+            description: "Command line JSON viewer",
+            homepage: "https://jless.io",
+            license: "MIT",
+        },
+    ),
+    (
+        "jq",
+        Metadata {
+            description: "Command line JSON processor",
+            homepage: "https://jqlang.org",
+            license: "MIT",
+        },
+    ),
+    (
+        "json-c",
+        Metadata {
+            description: "JSON implementation in C",
+            homepage: "https://github.com/json-c/json-c",
+            license: "MIT",
+        },
+    ),
+    (
+        "just",
+        Metadata {
+            description: "Command runner",
+            homepage: "https://just.systems",
+            license: "CC0-1.0",
+        },
+    ),
+    (
+        "k9s",
+        Metadata {
+            description: "Terminal UI for Kubernetes clusters",
+            homepage: "https://k9scli.io",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "kind",
+        Metadata {
+            description: "Kubernetes clusters in Docker containers",
+            homepage: "https://kind.sigs.k8s.io",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "kn",
+        Metadata {
+            description: "Knative command line client",
+            homepage: "https://knative.dev",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "kubectl",
+        Metadata {
+            description: "Kubernetes command line client",
+            homepage: "https://kubernetes.io",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "kubeseal",
+        Metadata {
+            description: "Client for Sealed Secrets",
+            homepage: "https://github.com/bitnami-labs/sealed-secrets",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "lazygit",
+        Metadata {
+            description: "Terminal UI for git",
+            homepage: "https://github.com/jesseduffield/lazygit",
+            license: "MIT",
+        },
+    ),
+    (
+        "libassuan",
+        Metadata {
+            description: "IPC library used by GnuPG",
+            homepage: "https://gnupg.org/software/libassuan/",
+            license: "LGPL-2.1-or-later",
+        },
+    ),
+    (
+        "libcap",
+        Metadata {
+            description: "Library for POSIX capabilities on Linux",
+            homepage: "https://sites.google.com/site/fullycapable/",
+            license: "BSD-3-Clause OR GPL-2.0-only",
+        },
+    ),
+    (
+        "libevent",
+        Metadata {
+            description: "Event notification library",
+            homepage: "https://libevent.org",
+            license: "BSD-3-Clause",
+        },
+    ),
+    (
+        "libffi",
+        Metadata {
+            description: "Portable foreign function interface library",
+            homepage: "https://sourceware.org/libffi/",
+            license: "MIT",
+        },
+    ),
+    (
+        "libgcrypt",
+        Metadata {
+            description: "Cryptographic library used by GnuPG",
+            homepage: "https://gnupg.org/software/libgcrypt/",
+            license: "LGPL-2.1-or-later",
+        },
+    ),
+    (
+        "libgpg-error",
+        Metadata {
+            description: "Common error codes for GnuPG components",
+            homepage: "https://gnupg.org/software/libgpg-error/",
+            license: "LGPL-2.1-or-later",
+        },
+    ),
+    (
+        "libjpeg-turbo",
+        Metadata {
+            description: "SIMD-accelerated JPEG codec library",
+            homepage: "https://libjpeg-turbo.org",
+            license: "IJG AND BSD-3-Clause AND Zlib",
+        },
+    ),
+    (
+        "libksba",
+        Metadata {
+            description: "X.509 and CMS library used by GnuPG",
+            homepage: "https://gnupg.org/software/libksba/",
+            license: "LGPL-3.0-or-later OR GPL-2.0-or-later",
+        },
+    ),
+    (
+        "libpcap",
+        Metadata {
+            description: "Portable packet capture library",
+            homepage: "https://www.tcpdump.org",
+            license: "BSD-3-Clause",
+        },
+    ),
+    (
+        "libpng",
+        Metadata {
+            description: "Official PNG reference library",
+            homepage: "http://www.libpng.org/pub/png/libpng.html",
+            license: "libpng-2.0",
+        },
+    ),
+    (
+        "libssh2",
+        Metadata {
+            description: "Client-side C library implementing the SSH2 protocol",
+            homepage: "https://libssh2.org",
+            license: "BSD-3-Clause",
+        },
+    ),
+    (
+        "libuv",
+        Metadata {
+            description: "Cross-platform asynchronous I/O library",
+            homepage: "https://libuv.org",
+            license: "MIT",
+        },
+    ),
+    (
+        "libwebp",
+        Metadata {
+            description: "WebP image codec library and tools",
+            homepage: "https://developers.google.com/speed/webp",
+            license: "BSD-3-Clause",
+        },
+    ),
+    (
+        "libwebsockets",
+        Metadata {
+            description: "Lightweight C networking library",
+            homepage: "https://libwebsockets.org",
+            license: "MIT",
+        },
+    ),
+    (
+        "libxml2",
+        Metadata {
+            description: "XML parsing library and xmllint validator",
+            homepage: "https://gitlab.gnome.org/GNOME/libxml2",
+            license: "MIT",
+        },
+    ),
+    (
+        "libxslt",
+        Metadata {
+            description: "XSLT processing library and xsltproc",
+            homepage: "https://gitlab.gnome.org/GNOME/libxslt",
+            license: "MIT",
+        },
+    ),
+    (
+        "libyaml",
+        Metadata {
+            description: "YAML 1.1 parser and emitter library",
+            homepage: "https://pyyaml.org/wiki/LibYAML",
+            license: "MIT",
+        },
+    ),
+    (
+        "lima",
+        Metadata {
+            description: "Linux virtual machines",
+            homepage: "https://lima-vm.io",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "lua-language-server",
+        Metadata {
+            description: "Language server for Lua",
+            homepage: "https://luals.github.io",
+            license: "MIT",
+        },
+    ),
+    (
+        "mbedtls",
+        Metadata {
+            description: "TLS and cryptography library",
+            homepage: "https://www.trustedfirmware.org/projects/mbed-tls/",
+            license: "Apache-2.0 OR GPL-2.0-or-later",
+        },
+    ),
+    (
+        "mc",
+        Metadata {
+            description: "MinIO client for object storage",
+            homepage: "https://min.io/docs/minio/linux/reference/minio-mc.html",
+            license: "AGPL-3.0-only",
+        },
+    ),
+    (
+        "minisign",
+        Metadata {
+            description: "Tool to sign files and verify signatures",
+            homepage: "https://jedisct1.github.io/minisign/",
+            license: "ISC",
+        },
+    ),
+    (
+        "mongosh",
+        Metadata {
+            description: "MongoDB shell",
+            homepage: "https://www.mongodb.com/docs/mongodb-shell/",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "mtr",
+        Metadata {
+            description: "Network diagnostic tool combining traceroute and ping",
+            homepage: "https://www.bitwizard.nl/mtr/",
+            license: "GPL-2.0-only",
+        },
+    ),
+    (
+        "musl",
+        Metadata {
+            description: "C standard library for static Linux binaries",
+            homepage: "https://musl.libc.org",
+            license: "MIT",
+        },
+    ),
+    (
+        "ncdu",
+        Metadata {
+            description: "Disk usage analyzer with an ncurses interface",
+            homepage: "https://dev.yorhel.nl/ncdu",
+            license: "MIT",
+        },
+    ),
+    (
+        "ncurses",
+        Metadata {
+            description: "Terminal control library",
+            homepage: "https://invisible-island.net/ncurses/",
+            license: "MIT",
+        },
+    ),
+    (
+        "neovim",
+        Metadata {
+            description: "Vim-fork focused on extensibility and usability",
+            homepage: "https://neovim.io",
+            license: "Apache-2.0 AND Vim",
+        },
+    ),
+    (
+        "nghttp2",
+        Metadata {
+            description: "HTTP/2 C library",
+            homepage: "https://nghttp2.org",
+            license: "MIT",
+        },
+    ),
+    (
+        "nginx",
+        Metadata {
+            description: "HTTP and reverse proxy server",
+            homepage: "https://nginx.org",
+            license: "BSD-2-Clause",
+        },
+    ),
+    (
+        "nmap",
+        Metadata {
+            description: "Network discovery and security auditing tool, with ncat and nping",
+            homepage: "https://nmap.org",
+            license: "NOASSERTION",
+        },
+    ),
+    (
+        "node-matrix",
+        Metadata {
+            description: "Side-by-side Node.js runtimes with a major version selector",
+            homepage: "https://nodejs.org",
+            license: "MIT",
+        },
+    ),
+    (
+        "nnn",
+        Metadata {
+            description: "Terminal file manager",
+            homepage: "https://github.com/jarun/nnn",
+            license: "BSD-2-Clause",
+        },
+    ),
+    (
+        "npth",
+        Metadata {
+            description: "Portable threads library used by GnuPG",
+            homepage: "https://gnupg.org/software/npth/",
+            license: "LGPL-2.1-or-later",
+        },
+    ),
+    (
+        "oniguruma",
+        Metadata {
+            description: "Regular expression library with multiple syntaxes and encodings",
+            homepage: "https://github.com/kkos/oniguruma",
+            license: "BSD-2-Clause",
+        },
+    ),
+    (
+        "op",
+        Metadata {
+            description: "1Password command line tool",
+            homepage: "https://developer.1password.com/docs/cli/",
+            license: "NOASSERTION",
+        },
+    ),
+    (
+        "openapi-generator-cli",
+        Metadata {
+            description: "Generates clients, servers and docs from OpenAPI specs",
+            homepage: "https://openapi-generator.tech",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "opencode",
+        Metadata {
+            description: "AI coding agent for the terminal",
+            homepage: "https://opencode.ai",
+            license: "MIT",
+        },
+    ),
+    (
+        "openjdk",
+        Metadata {
+            description: "Java Development Kit",
+            homepage: "https://openjdk.org",
+            license: "GPL-2.0-only WITH Classpath-exception-2.0",
+        },
+    ),
+    (
+        "openssl",
+        Metadata {
+            description: "TLS and cryptography toolkit",
+            homepage: "https://www.openssl.org",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "patchelf",
+        Metadata {
+            description: "Utility to modify ELF executables",
+            homepage: "https://github.com/NixOS/patchelf",
+            license: "GPL-3.0-or-later",
+        },
+    ),
+    (
+        "pcre2",
+        Metadata {
+            description: "Perl-compatible regular expression library with JIT",
+            homepage: "https://pcre2project.github.io/pcre2/",
+            license: "BSD-3-Clause",
+        },
+    ),
+    (
+        "pi",
+        Metadata {
+            description: "Coding agent CLI",
+            homepage: "https://github.com/earendil-works/pi",
+            license: "NOASSERTION",
+        },
+    ),
+    (
+        "pkg-config",
+        Metadata {
+            description: "Helper tool for compiling against installed libraries",
+            homepage: "https://www.freedesktop.org/wiki/Software/pkg-config/",
+            license: "GPL-2.0-or-later",
+        },
+    ),
+    (
+        "pre-commit",
+        Metadata {
+            description: "Framework for managing git pre-commit hooks",
+            homepage: "https://pre-commit.com",
+            license: "MIT",
+        },
+    ),
+    (
+        "protobuf",
+        Metadata {
+            description: "Protocol Buffers C++ runtime libraries",
+            homepage: "https://protobuf.dev",
+            license: "BSD-3-Clause",
+        },
+    ),
+    (
+        "protoc-gen-grpc-web",
+        Metadata {
+            description: "Protocol buffers plugin for gRPC-Web",
+            homepage: "https://github.com/grpc/grpc-web",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "protoc-plugins",
+        Metadata {
+            description: "Bundle of protoc and its Go and gRPC-Web plugins",
+            homepage: "https://protobuf.dev",
+            license: "NOASSERTION",
+        },
+    ),
+    (
+        "qsv",
+        Metadata {
+            description: "CSV data-wrangling toolkit",
+            homepage: "https://qsv.dathere.com",
+            license: "MIT OR Unlicense",
+        },
+    ),
+    (
+        "readline",
+        Metadata {
+            description: "Line editing library",
+            homepage: "https://tiswww.case.edu/php/chet/readline/rltop.html",
+            license: "GPL-3.0-or-later",
+        },
+    ),
+    (
+        "ripgrep",
+        Metadata {
+            description: "Recursive regex directory search",
+            homepage: "https://github.com/BurntSushi/ripgrep",
+            license: "MIT OR Unlicense",
+        },
+    ),
+    (
+        "s5cmd",
+        Metadata {
+            description: "Parallel S3 and local filesystem execution tool",
+            homepage: "https://github.com/peak/s5cmd",
+            license: "MIT",
+        },
+    ),
+    (
+        "sccache",
+        Metadata {
+            description: "Shared compilation cache",
+            homepage: "https://github.com/mozilla/sccache",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "sesh",
+        Metadata {
+            description: "Smart tmux session manager",
+            homepage: "https://github.com/joshmedeski/sesh",
+            license: "MIT",
+        },
+    ),
+    (
+        "skopeo",
+        Metadata {
+            description: "Tool for working with remote container images",
+            homepage: "https://github.com/containers/skopeo",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "sqlite3",
+        Metadata {
+            description: "SQL database engine and shell",
+            homepage: "https://sqlite.org",
+            license: "blessing",
+        },
+    ),
+    (
+        "starship",
+        Metadata {
+            description: "Cross-shell prompt",
+            homepage: "https://starship.rs",
+            license: "ISC",
+        },
+    ),
+    (
+        "steampipe",
+        Metadata {
+            description: "SQL interface for querying cloud APIs",
+            homepage: "https://steampipe.io",
+            license: "AGPL-3.0-only",
+        },
+    ),
+    (
+        "stow",
+        Metadata {
+            description: "Symlink farm manager",
+            homepage: "https://www.gnu.org/software/stow/",
+            license: "GPL-3.0-or-later",
+        },
+    ),
+    (
+        "talosctl",
+        Metadata {
+            description: "CLI for Talos Linux",
+            homepage: "https://www.talos.dev",
+            license: "MPL-2.0",
+        },
+    ),
+    (
+        "tcpdump",
+        Metadata {
+            description: "Command line packet analyzer",
+            homepage: "https://www.tcpdump.org",
+            license: "BSD-3-Clause",
+        },
+    ),
+    (
+        "terraform",
+        Metadata {
+            description: "Infrastructure as code tool",
+            homepage: "https://www.terraform.io",
+            license: "BUSL-1.1",
+        },
+    ),
+    (
+        "terraform-ls",
+        Metadata {
+            description: "Language server for Terraform",
+            homepage: "https://github.com/hashicorp/terraform-ls",
+            license: "MPL-2.0",
+        },
+    ),
+    (
+        "tini",
+        Metadata {
+            description: "Minimal init process for containers",
+            homepage: "https://github.com/krallin/tini",
+            license: "MIT",
+        },
+    ),
+    (
+        "tmux",
+        Metadata {
+            description: "Terminal multiplexer",
+            homepage: "https://github.com/tmux/tmux",
+            license: "ISC",
+        },
+    ),
+    (
+        "tree-sitter",
+        Metadata {
+            description: "Parser generator tool and incremental parsing library",
+            homepage: "https://tree-sitter.github.io",
+            license: "MIT",
+        },
+    ),
+    (
+        "ttyd",
+        Metadata {
+            description: "Share a terminal over the web",
+            homepage: "https://tsl0922.github.io/ttyd/",
+            license: "MIT",
+        },
+    ),
+    (
+        "typescript",
+        Metadata {
+            description: "Typed superset of JavaScript",
+            homepage: "https://www.typescriptlang.org",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "typescript-language-server",
+        Metadata {
+            description: "Language server for TypeScript",
+            homepage: "https://github.com/typescript-language-server/typescript-language-server",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "umoci",
+        Metadata {
+            description: "OCI image manipulation tool",
+            homepage: "https://umo.ci",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "universal-ctags",
+        Metadata {
+            description: "Source code indexer",
+            homepage: "https://ctags.io",
+            license: "GPL-2.0-or-later",
+        },
+    ),
+    (
+        "upx",
+        Metadata {
+            description: "Executable packer",
+            homepage: "https://upx.github.io",
+            license: "GPL-2.0-or-later",
+        },
+    ),
+    (
+        "uv",
+        Metadata {
+            description: "Python package and project manager",
+            homepage: "https://docs.astral.sh/uv/",
+            license: "MIT OR Apache-2.0",
+        },
+    ),
+    (
+        "vhs",
+        Metadata {
+            description: "Terminal GIF recorder",
+            homepage: "https://github.com/charmbracelet/vhs",
+            license: "MIT",
+        },
+    ),
+    (
+        "virtctl",
+        Metadata {
+            description: "CLI for KubeVirt virtual machines",
+            homepage: "https://kubevirt.io",
+            license: "Apache-2.0",
+        },
+    ),
+    (
+        "vscode-langservers-extracted",
+        Metadata {
+            description: "HTML, CSS, JSON and ESLint language servers",
+            homepage: "https://github.com/hrsh7th/vscode-langservers-extracted",
+            license: "MIT",
+        },
+    ),
+    (
+        "wireguard-go",
+        Metadata {
+            description: "Userspace WireGuard implementation",
+            homepage: "https://www.wireguard.com",
+            license: "MIT",
+        },
+    ),
+    (
+        "wireguard-tools",
+        Metadata {
+            description: "WireGuard configuration tools",
+            homepage: "https://www.wireguard.com",
+            license: "GPL-2.0-only",
+        },
+    ),
+    (
+        "x264",
+        Metadata {
+            description: "H.264 video encoder library",
+            homepage: "https://www.videolan.org/developers/x264.html",
+            license: "GPL-2.0-or-later",
+        },
+    ),
+    (
+        "yaml-language-server",
+        Metadata {
+            description: "Language server for YAML",
+            homepage: "https://github.com/redhat-developer/yaml-language-server",
+            license: "MIT",
+        },
+    ),
+    (
+        "yq",
+        Metadata {
+            description: "Command line YAML, JSON and XML processor",
+            homepage: "https://github.com/mikefarah/yq",
+            license: "MIT",
+        },
+    ),
+    (
+        "zig",
+        Metadata {
+            description: "Zig compiler and toolchain, with a C/C++ cross compiler for every target",
+            homepage: "https://ziglang.org",
+            license: "MIT",
+        },
+    ),
+    (
+        "zig-cc",
+        Metadata {
+            description: "cc, c++, ar and ranlib shims that cross compile with zig",
+            homepage: "https://ziglang.org",
+            license: "MIT",
+        },
+    ),
+    (
+        "zlib",
+        Metadata {
+            description: "Lossless data compression library",
+            homepage: "https://zlib.net",
+            license: "Zlib",
+        },
+    ),
+    (
+        "zoxide",
+        Metadata {
+            description: "Smarter cd command",
+            homepage: "https://github.com/ajeetdsouza/zoxide",
+            license: "MIT",
+        },
+    ),
+    (
+        "zsh",
+        Metadata {
+            description: "Z shell",
+            homepage: "https://www.zsh.org",
+            license: "NOASSERTION",
+        },
+    ),
+];
+
+pub fn get(name: &str) -> Option<&'static Metadata> {
+    METADATA
+        .iter()
+        .find(|(metadata_name, _)| *metadata_name == name)
+        .map(|(_, metadata)| metadata)
+}
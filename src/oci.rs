@@ -0,0 +1,384 @@
+use crate::{builder::toolset::Toolset, error::ArtifactError, graph, target};
+use anyhow::{bail, Result};
+use indoc::formatdoc;
+use vorpal_sdk::{
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Linux, X8664Linux},
+    },
+    artifact::get_env_key,
+    context::ConfigContext,
+};
+
+const DEFAULT_PATH: &str = "/usr/local/sbin:/usr/local/bin:/usr/sbin:/usr/bin:/sbin:/bin";
+
+// OCI images only hold Linux artifacts.
+fn architecture(name: &str, system: ArtifactSystem) -> Result<&'static str> {
+    match system {
+        Aarch64Linux => Ok("arm64"),
+        X8664Linux => Ok("amd64"),
+        system => Err(ArtifactError::UnsupportedSystem {
+            artifact: name.to_string(),
+            system,
+        }
+        .into()),
+    }
+}
+
+// Packages artifacts as an OCI image layout: `oci-layout`, `index.json` and `blobs/sha256/`, ready
+// for `skopeo copy oci:<output>:<tag> docker://...`. Each artifact becomes one uncompressed layer
+// holding its output at the same store path it has on the host, so rpaths and symlinks keep
+// resolving. Runtime dependencies such as shared libraries are layered too: every artifact the
+// given ones were built with is scanned for, and kept when a layered output refers to its path.
+pub struct OciImage<'a> {
+    artifacts: Vec<String>,
+    entrypoint: Vec<String>,
+    environments: Vec<String>,
+    init: Option<String>,
+    name: &'a str,
+    systems: Vec<ArtifactSystem>,
+    tag: &'a str,
+    target: Option<ArtifactSystem>,
+    toolsets: Vec<Toolset<'a>>,
+}
+
+impl<'a> OciImage<'a> {
+    pub fn new(name: &'a str, systems: Vec<ArtifactSystem>) -> Self {
+        Self {
+            artifacts: vec![],
+            entrypoint: vec![],
+            environments: vec![],
+            init: None,
+            name,
+            systems,
+            tag: "latest",
+            target: None,
+            toolsets: vec![],
+        }
+    }
+
+    pub fn with_artifact(mut self, artifact: &str) -> Self {
+        self.artifacts.push(artifact.to_string());
+        self
+    }
+
+    pub fn with_artifacts(mut self, artifacts: Vec<String>) -> Self {
+        self.artifacts.extend(artifacts);
+        self
+    }
+
+    // Arguments may reference env keys, e.g. `format!("{}/bin/ttyd", get_env_key(&ttyd))`.
+    pub fn with_entrypoint(mut self, entrypoint: Vec<String>) -> Self {
+        self.entrypoint = entrypoint;
+        self
+    }
+
+    // `KEY=VALUE`; values may reference env keys. Without a `PATH` entry, the image gets one with
+    // every artifact's `bin/` ahead of the usual system directories.
+    pub fn with_environment(mut self, environment: &str) -> Self {
+        self.environments.push(environment.to_string());
+        self
+    }
+
+    // Runs the entrypoint under `bin/<binary>` of an init artifact such as `Tini` or `DumbInit`, so
+    // PID 1 forwards signals and reaps zombies. The init artifact is added as a layer.
+    pub fn with_init(mut self, init: &str, binary: &str) -> Self {
+        self.artifacts.push(init.to_string());
+        self.init = Some(format!("{}/bin/{binary}", get_env_key(&init.to_string())));
+        self
+    }
+
+    pub fn with_tag(mut self, tag: &'a str) -> Self {
+        self.tag = tag;
+        self
+    }
+
+    // Artifacts given with `with_artifact` must already be built for `system`.
+    pub fn with_target(mut self, system: ArtifactSystem) -> Self {
+        self.target = Some(system);
+        self
+    }
+
+    // Builds the toolset for the image's system and adds it as a layer ahead of the artifacts it
+    // links to, which get layers of their own. A toolset added with `with_artifact` would leave
+    // its links dangling.
+    pub fn with_toolset(mut self, toolset: Toolset<'a>) -> Self {
+        self.toolsets.push(toolset);
+        self
+    }
+
+    pub async fn build(mut self, context: &mut ConfigContext) -> Result<String> {
+        if self.artifacts.is_empty() && self.toolsets.is_empty() {
+            bail!("OCI image '{}' has no artifacts", self.name);
+        }
+
+        let system = target::system(context, self.target);
+
+        let architecture = architecture(self.name, system)?;
+
+        for toolset in std::mem::take(&mut self.toolsets) {
+            let components = toolset.artifacts().to_vec();
+
+            self.artifacts
+                .push(toolset.with_target(system).build(context).await?);
+
+            for component in components {
+                if !self.artifacts.contains(&component) {
+                    self.artifacts.push(component);
+                }
+            }
+        }
+
+        let artifact_keys = self.artifacts.iter().map(get_env_key).collect::<Vec<_>>();
+
+        let closure = graph::nodes(context, &self.artifacts)?
+            .into_keys()
+            .filter(|digest| !self.artifacts.contains(digest))
+            .collect::<Vec<_>>();
+
+        let mut environments = self.environments.clone();
+
+        if !environments.iter().any(|env| env.starts_with("PATH=")) {
+            let path = artifact_keys
+                .iter()
+                .map(|key| format!("{key}/bin:"))
+                .collect::<String>();
+
+            environments.insert(0, format!("PATH={path}{DEFAULT_PATH}"));
+        }
+
+        let entrypoint: Vec<String> = match self.init {
+            Some(init) => [init, "--".to_string()]
+                .into_iter()
+                .chain(self.entrypoint)
+                .collect(),
+            None => self.entrypoint,
+        };
+
+        // Both are expanded by the unquoted heredocs below, so env keys resolve to store paths.
+        let environments = serde_json::to_string(&environments)?;
+        let entrypoint = serde_json::to_string(&entrypoint)?;
+
+        let artifacts = artifact_keys
+            .iter()
+            .map(|key| format!("\"{key}\""))
+            .collect::<Vec<_>>()
+            .join(" \\\n    ");
+
+        let candidates = closure
+            .iter()
+            .map(|digest| format!("\"{}\"", get_env_key(digest)))
+            .collect::<Vec<_>>()
+            .join(" \\\n    ");
+
+        let tag = self.tag;
+
+        let step_script = formatdoc! {"
+            mkdir -pv \"$VORPAL_OUTPUT/blobs/sha256\"
+
+            blob() {{
+                digest=\"$(sha256sum \"$1\" | cut -d ' ' -f 1)\"
+                size=\"$(wc -c < \"$1\" | tr -d ' ')\"
+                mv \"$1\" \"$VORPAL_OUTPUT/blobs/sha256/$digest\"
+            }}
+
+            layered=\"\"
+
+            for artifact in \\
+                {artifacts}; do
+                layered=\"$layered $artifact\"
+            done
+
+            candidates=\"\"
+
+            for candidate in \\
+                {candidates}; do
+                candidates=\"$candidates $candidate\"
+            done
+
+            # Store paths have no spaces, so the lists split on them. A candidate joins the layers
+            # once a layered output mentions its path in a file or a symlink target, until a pass
+            # adds nothing.
+            while [ -n \"$candidates\" ]; do
+                found=\"\"
+                remaining=\"\"
+
+                for candidate in $candidates; do
+                    referenced=\"\"
+
+                    for artifact in $layered; do
+                        if grep -rqsF \"$candidate\" \"$artifact\" || \\
+                            [ -n \"$(find \"$artifact\" -type l -lname \"$candidate*\" -print -quit)\" ]; then
+                            referenced=1
+                            break
+                        fi
+                    done
+
+                    if [ -n \"$referenced\" ]; then
+                        found=1
+                        layered=\"$layered $candidate\"
+                    else
+                        remaining=\"$remaining $candidate\"
+                    fi
+                done
+
+                [ -n \"$found\" ] || break
+
+                candidates=\"$remaining\"
+            done
+
+            diff_ids=\"\"
+            layers=\"\"
+
+            for artifact in $layered; do
+                tar --create --file layer.tar --directory / \\
+                    --sort=name --mtime=@0 --owner=0 --group=0 --numeric-owner \\
+                    \"${{artifact#/}}\"
+
+                blob layer.tar
+
+                diff_ids=\"$diff_ids${{diff_ids:+,}}\\\"sha256:$digest\\\"\"
+                layers=\"$layers${{layers:+,}}{{\\\"mediaType\\\":\\\"application/vnd.oci.image.layer.v1.tar\\\",\\\"digest\\\":\\\"sha256:$digest\\\",\\\"size\\\":$size}}\"
+            done
+
+            cat > config.json <<EOF
+            {{\"architecture\":\"{architecture}\",\"os\":\"linux\",\"created\":\"1970-01-01T00:00:00Z\",\"config\":{{\"Env\":{environments},\"Entrypoint\":{entrypoint}}},\"rootfs\":{{\"type\":\"layers\",\"diff_ids\":[$diff_ids]}}}}
+            EOF
+
+            blob config.json
+
+            cat > manifest.json <<EOF
+            {{\"schemaVersion\":2,\"mediaType\":\"application/vnd.oci.image.manifest.v1+json\",\"config\":{{\"mediaType\":\"application/vnd.oci.image.config.v1+json\",\"digest\":\"sha256:$digest\",\"size\":$size}},\"layers\":[$layers]}}
+            EOF
+
+            blob manifest.json
+
+            cat > \"$VORPAL_OUTPUT/index.json\" <<EOF
+            {{\"schemaVersion\":2,\"mediaType\":\"application/vnd.oci.image.index.v1+json\",\"manifests\":[{{\"mediaType\":\"application/vnd.oci.image.manifest.v1+json\",\"digest\":\"sha256:$digest\",\"size\":$size,\"annotations\":{{\"org.opencontainers.image.ref.name\":\"{tag}\"}}}}]}}
+            EOF
+
+            echo '{{\"imageLayoutVersion\":\"1.0.0\"}}' > \"$VORPAL_OUTPUT/oci-layout\"",
+        };
+
+        let step_artifacts = self
+            .artifacts
+            .iter()
+            .chain(closure.iter())
+            .cloned()
+            .collect();
+
+        let steps =
+            vec![target::shell(context, system, step_artifacts, vec![], step_script).await?];
+
+        target::build(
+            context,
+            system,
+            self.name,
+            vec![format!("{}:{tag}", self.name)],
+            vec![],
+            steps,
+            self.systems,
+        )
+        .await
+    }
+}
+
+#[cfg(all(test, feature = "library"))]
+mod tests {
+    use super::*;
+    use crate::{
+        artifact::{libpng::Libpng, pcre2::Pcre2, zlib::Zlib, ArtifactPackage},
+        error,
+        testing::{self, MockContext},
+    };
+    use vorpal_sdk::api::artifact::ArtifactSystem::Aarch64Darwin;
+
+    #[tokio::test]
+    async fn build_layers_every_artifact() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Linux).build().await?;
+
+        let zlib = Zlib::new().build(context).await?;
+
+        let image = OciImage::new("zlib-image", vec![Aarch64Linux])
+            .with_artifact(&zlib)
+            .with_tag("1.0")
+            .build(context)
+            .await?;
+
+        assert!(testing::dependencies(context, &image)?.contains(&"zlib".to_string()));
+        assert_eq!(testing::aliases(context, &image)?, ["zlib-image:1.0"]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_scans_the_build_closure() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Linux).build().await?;
+
+        let libpng = Libpng::new().build(context).await?;
+        let zlib = Zlib::new().build(context).await?;
+
+        let image = OciImage::new("libpng-image", vec![Aarch64Linux])
+            .with_artifact(&libpng)
+            .build(context)
+            .await?;
+
+        // zlib is only a candidate: it is mounted for the scan, and layered if libpng refers to it.
+        let step = testing::artifact(context, &image)?.steps[0].clone();
+        let script = step.script.unwrap_or_default();
+
+        assert!(step.artifacts.contains(&zlib));
+        assert!(script.contains(&format!("\"{}\"", get_env_key(&zlib))));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_layers_toolset_components() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Linux).build().await?;
+
+        let pcre2 = Pcre2::new().build(context).await?;
+        let zlib = Zlib::new().build(context).await?;
+
+        let image = OciImage::new("tools-image", vec![Aarch64Linux])
+            .with_toolset(
+                Toolset::new("tools", vec![Aarch64Linux])
+                    .with_artifacts(vec![pcre2.clone(), zlib.clone()]),
+            )
+            .build(context)
+            .await?;
+
+        let dependencies = testing::dependencies(context, &image)?;
+
+        for name in ["pcre2", "tools", "zlib"] {
+            assert!(dependencies.contains(&name.to_string()));
+        }
+
+        // Every layered artifact is quoted once in the layer loop.
+        let step = testing::artifact(context, &image)?.steps[0].clone();
+        let script = step.script.unwrap_or_default();
+
+        for component in [&pcre2, &zlib] {
+            assert!(step.artifacts.contains(component));
+            assert!(script.contains(&format!("\"{}\"", get_env_key(component))));
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_rejects_darwin() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Darwin).build().await?;
+
+        let zlib = Zlib::new().build(context).await?;
+
+        let result = OciImage::new("zlib-image", vec![Aarch64Darwin])
+            .with_artifact(&zlib)
+            .build(context)
+            .await;
+
+        assert!(result.is_err_and(|error| error::is_unsupported_system(&error)));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,197 @@
+use crate::target;
+use anyhow::{bail, Context, Result};
+use std::{collections::BTreeMap, env, fs, path::Path, sync::OnceLock};
+use vorpal_sdk::api::artifact::ArtifactSystem::{
+    Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux,
+};
+
+// Path to a `versions.toml` overriding the compiled-in versions, keyed by artifact name:
+//
+//   [jq]
+//   version = "1.8.1"
+//   url = "https://github.com/jqlang/jq/releases/download/jq-{version}/jq-{version}.tar.gz"
+//   digest = "..."
+//
+//   [kubectl]
+//   version = "1.34.1"
+//
+//   [kubectl.aarch64-linux]
+//   url = "https://dl.k8s.io/release/v{version}/bin/linux/arm64/kubectl"
+//   digest = "..."
+//
+// `url` replaces the primary source of artifacts that build one source everywhere. Artifacts
+// that download a different archive per system only take it from a table named after the system
+// (see `target::name`), see `source::for_system`. `{version}` in either expands to the `version`
+// given alongside. A `url` needs its `digest`, as the pinned table only covers the compiled-in
+// sources.
+const FILE_ENV: &str = "VORPAL_VERSIONS_FILE";
+
+static OVERRIDES: OnceLock<BTreeMap<String, Override>> = OnceLock::new();
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Override {
+    pub digest: Option<String>,
+    pub systems: BTreeMap<String, Override>,
+    pub url: Option<String>,
+    pub version: Option<String>,
+}
+
+fn is_system(key: &str) -> bool {
+    [Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux]
+        .into_iter()
+        .any(|system| target::name(system).is_ok_and(|name| name == key))
+}
+
+fn parse_fields(name: &str, fields: &toml::Table, nested: bool) -> Result<Override> {
+    let mut entry = Override::default();
+
+    for (key, value) in fields {
+        if let Some(table) = value.as_table() {
+            if nested || !is_system(key) {
+                bail!("unknown table '{name}.{key}' (expected a system such as 'aarch64-linux')");
+            }
+
+            let system = parse_fields(&format!("{name}.{key}"), table, true)?;
+
+            if system.url.is_none() {
+                bail!("'{name}.{key}' needs a 'url'");
+            }
+
+            entry.systems.insert(key.to_string(), system);
+
+            continue;
+        }
+
+        let Some(value) = value.as_str() else {
+            bail!("'{name}.{key}' must be a string");
+        };
+
+        let field = match key.as_str() {
+            "digest" => &mut entry.digest,
+            "url" => &mut entry.url,
+            "version" if !nested => &mut entry.version,
+            _ => bail!("unknown key '{name}.{key}' (expected 'digest', 'url' or 'version')"),
+        };
+
+        *field = Some(value.to_string());
+    }
+
+    if entry.url.is_some() && entry.digest.is_none() {
+        bail!("'{name}.url' needs a 'digest' alongside it");
+    }
+
+    Ok(entry)
+}
+
+fn parse(contents: &str) -> Result<BTreeMap<String, Override>> {
+    let table = contents.parse::<toml::Table>()?;
+
+    let mut overrides = BTreeMap::new();
+
+    for (name, value) in table {
+        let Some(fields) = value.as_table() else {
+            bail!("'{name}' must be a table");
+        };
+
+        let mut entry = parse_fields(&name, fields, false)?;
+
+        if let Some(version) = entry.version.clone() {
+            let urls = std::iter::once(&mut entry.url)
+                .chain(entry.systems.values_mut().map(|system| &mut system.url));
+
+            for url in urls.flatten() {
+                *url = url.replace("{version}", &version);
+            }
+        }
+
+        overrides.insert(name, entry);
+    }
+
+    Ok(overrides)
+}
+
+// Must run before any artifact is built; overrides are fixed for the rest of the process.
+pub fn load(path: impl AsRef<Path>) -> Result<()> {
+    let path = path.as_ref();
+
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("failed to read versions file {}", path.display()))?;
+
+    let overrides =
+        parse(&contents).with_context(|| format!("invalid versions file {}", path.display()))?;
+
+    if OVERRIDES.set(overrides).is_err() {
+        bail!("versions overrides are already loaded");
+    }
+
+    Ok(())
+}
+
+pub fn load_env() -> Result<()> {
+    match env::var(FILE_ENV) {
+        Ok(path) if !path.is_empty() => load(path),
+        _ => Ok(()),
+    }
+}
+
+pub fn get(name: &str) -> Option<&'static Override> {
+    OVERRIDES.get()?.get(name)
+}
+
+pub fn version<'a>(name: &str, default: &'a str) -> &'a str {
+    get(name)
+        .and_then(|entry| entry.version.as_deref())
+        .unwrap_or(default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_requires_digest_for_url() -> Result<()> {
+        let overrides = parse(
+            r#"
+            [jq]
+            version = "1.8.1"
+            url = "https://github.com/jqlang/jq/releases/download/jq-{version}/jq-{version}.tar.gz"
+            digest = "0123"
+            "#,
+        )?;
+
+        assert_eq!(
+            overrides["jq"].url.as_deref(),
+            Some("https://github.com/jqlang/jq/releases/download/jq-1.8.1/jq-1.8.1.tar.gz")
+        );
+
+        assert!(parse("[jq]\nurl = \"https://example.com/jq.tar.gz\"").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_reads_per_system_sources() -> Result<()> {
+        let overrides = parse(
+            r#"
+            [kubectl]
+            version = "1.34.1"
+
+            [kubectl.aarch64-linux]
+            url = "https://dl.k8s.io/release/v{version}/bin/linux/arm64/kubectl"
+            digest = "0123"
+            "#,
+        )?;
+
+        assert_eq!(
+            overrides["kubectl"].systems["aarch64-linux"].url.as_deref(),
+            Some("https://dl.k8s.io/release/v1.34.1/bin/linux/arm64/kubectl")
+        );
+
+        assert!(
+            parse("[kubectl.linux]\nurl = \"https://example.com\"\ndigest = \"0123\"").is_err()
+        );
+        assert!(parse("[kubectl.aarch64-linux]\ndigest = \"0123\"").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(feature = "cloud")]
+use crate::artifact::terraform::Terraform;
+use crate::{artifact::ArtifactPackage, catalog, context_cache, error::ArtifactError, versions};
+use anyhow::Result;
+use vorpal_sdk::context::ConfigContext;
+
+pub fn all() -> Vec<Box<dyn ArtifactPackage>> {
+    let mut packages = catalog::all()
+        .into_iter()
+        .flat_map(|group| group.packages)
+        .collect::<Vec<_>>();
+
+    packages.sort_by(|a, b| a.name().cmp(b.name()));
+
+    packages
+}
+
+pub fn names() -> Vec<String> {
+    all()
+        .iter()
+        .map(|package| package.name().to_string())
+        .collect()
+}
+
+pub fn get(name: &str) -> Option<Box<dyn ArtifactPackage>> {
+    all().into_iter().find(|package| package.name() == name)
+}
+
+// Versions of `name` listed in `versions::PINNED`, oldest first.
+pub fn pinned(name: &str) -> Vec<&'static str> {
+    versions::PINNED
+        .iter()
+        .filter(|(pinned, _)| *pinned == name)
+        .map(|(_, version)| *version)
+        .collect()
+}
+
+// The catalog package at one of its `versions::PINNED` versions instead of the default.
+pub fn get_pinned(name: &str, version: &str) -> Option<Box<dyn ArtifactPackage>> {
+    let pinned = versions::PINNED
+        .iter()
+        .copied()
+        .find(|pinned| *pinned == (name, version))?;
+
+    match pinned {
+        #[cfg(feature = "cloud")]
+        ("terraform", version) => Some(Box::new(Terraform::new().with_version(version))),
+        _ => None,
+    }
+}
+
+pub async fn build(context: &mut ConfigContext, name: &str) -> Result<String> {
+    match get(name) {
+        Some(package) => context_cache::build(context, package.as_ref()).await,
+        None => Err(ArtifactError::UnknownArtifact {
+            artifact: name.to_string(),
+        }
+        .into()),
+    }
+}
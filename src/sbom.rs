@@ -0,0 +1,285 @@
+use crate::{graph, metadata};
+use anyhow::{bail, Result};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use vorpal_sdk::context::ConfigContext;
+
+const NOASSERTION: &str = "NOASSERTION";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    CycloneDx,
+    Spdx,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.to_lowercase().as_str() {
+            "cyclonedx" => Ok(Format::CycloneDx),
+            "spdx" => Ok(Format::Spdx),
+            _ => bail!("unsupported sbom format '{value}' (expected 'cyclonedx' or 'spdx')"),
+        }
+    }
+}
+
+struct Component {
+    dependencies: BTreeSet<String>,
+    digest: String,
+    name: String,
+    sources: Vec<(String, Option<String>)>,
+    version: String,
+}
+
+impl Component {
+    fn metadata(&self) -> Option<&'static metadata::Metadata> {
+        metadata::get(&self.name)
+    }
+
+    fn license(&self) -> &'static str {
+        self.metadata()
+            .map(|metadata| metadata.license)
+            .unwrap_or(NOASSERTION)
+    }
+
+    fn download_location(&self) -> Option<&str> {
+        self.sources
+            .iter()
+            .map(|(path, _)| path.as_str())
+            .find(|path| path.starts_with("http://") || path.starts_with("https://"))
+    }
+}
+
+// Covers every artifact reachable from `digests`, so the document also includes toolchains and
+// libraries that were only needed to build the roots.
+fn components(context: &ConfigContext, digests: &[String]) -> Result<BTreeMap<String, Component>> {
+    let mut components = BTreeMap::new();
+
+    for (digest, node) in graph::nodes(context, digests)? {
+        let Some(artifact) = context.get_artifact(&digest) else {
+            bail!("artifact not found in context: {digest}");
+        };
+
+        let sources = artifact
+            .sources
+            .iter()
+            .map(|source| (source.path.clone(), source.digest.clone()))
+            .collect();
+
+        components.insert(
+            digest.clone(),
+            Component {
+                dependencies: node.dependencies,
+                digest,
+                name: node.name,
+                sources,
+                version: node.version,
+            },
+        );
+    }
+
+    Ok(components)
+}
+
+// Honours SOURCE_DATE_EPOCH so documents for the same artifacts are reproducible.
+fn timestamp() -> Result<String> {
+    let seconds = match std::env::var("SOURCE_DATE_EPOCH") {
+        Ok(value) => value.parse::<i64>()?,
+        Err(_) => SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64,
+    };
+
+    let days = seconds.div_euclid(86_400);
+    let time = seconds.rem_euclid(86_400);
+
+    // Civil date from days since the epoch (Howard Hinnant's algorithm).
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    Ok(format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    ))
+}
+
+fn cyclonedx(components: &BTreeMap<String, Component>) -> Result<Value> {
+    let bom_components = components
+        .values()
+        .map(|component| {
+            let mut value = json!({
+                "type": "application",
+                "bom-ref": component.digest,
+                "name": component.name,
+                "version": component.version,
+            });
+
+            let mut references = vec![];
+
+            if let Some(metadata) = component.metadata() {
+                value["description"] = json!(metadata.description);
+
+                if metadata.license != NOASSERTION {
+                    value["licenses"] = json!([{ "expression": metadata.license }]);
+                }
+
+                references.push(json!({ "type": "website", "url": metadata.homepage }));
+            }
+
+            if let Some(location) = component.download_location() {
+                references.push(json!({ "type": "distribution", "url": location }));
+            }
+
+            if !references.is_empty() {
+                value["externalReferences"] = json!(references);
+            }
+
+            let properties = std::iter::once(json!({
+                "name": "vorpal:digest",
+                "value": component.digest,
+            }))
+            .chain(component.sources.iter().filter_map(|(path, digest)| {
+                digest.as_ref().map(
+                    |digest| json!({ "name": format!("vorpal:source:{path}"), "value": digest }),
+                )
+            }))
+            .collect::<Vec<_>>();
+
+            value["properties"] = json!(properties);
+
+            value
+        })
+        .collect::<Vec<_>>();
+
+    let dependencies = components
+        .values()
+        .map(|component| {
+            json!({
+                "ref": component.digest,
+                "dependsOn": component.dependencies,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    Ok(json!({
+        "bomFormat": "CycloneDX",
+        "specVersion": "1.5",
+        "version": 1,
+        "metadata": {
+            "timestamp": timestamp()?,
+            "tools": {
+                "components": [{
+                    "type": "application",
+                    "name": env!("CARGO_PKG_NAME"),
+                    "version": env!("CARGO_PKG_VERSION"),
+                }],
+            },
+        },
+        "components": bom_components,
+        "dependencies": dependencies,
+    }))
+}
+
+fn spdx(
+    context: &ConfigContext,
+    components: &BTreeMap<String, Component>,
+    digests: &[String],
+) -> Result<Value> {
+    let spdx_id = |digest: &str| format!("SPDXRef-{digest}");
+
+    let packages = components
+        .values()
+        .map(|component| {
+            let mut value = json!({
+                "SPDXID": spdx_id(&component.digest),
+                "name": component.name,
+                "versionInfo": component.version,
+                "downloadLocation": component.download_location().unwrap_or(NOASSERTION),
+                "filesAnalyzed": false,
+                "licenseConcluded": NOASSERTION,
+                "licenseDeclared": component.license(),
+                "copyrightText": NOASSERTION,
+                "comment": format!("vorpal digest {}", component.digest),
+            });
+
+            if let Some(metadata) = component.metadata() {
+                value["description"] = json!(metadata.description);
+                value["homepage"] = json!(metadata.homepage);
+            }
+
+            value
+        })
+        .collect::<Vec<_>>();
+
+    let roots = digests.iter().collect::<BTreeSet<_>>();
+
+    let relationships = roots
+        .iter()
+        .map(|digest| {
+            json!({
+                "spdxElementId": "SPDXRef-DOCUMENT",
+                "relationshipType": "DESCRIBES",
+                "relatedSpdxElement": spdx_id(digest),
+            })
+        })
+        .chain(components.values().flat_map(|component| {
+            component.dependencies.iter().map(|dependency| {
+                json!({
+                    "spdxElementId": spdx_id(&component.digest),
+                    "relationshipType": "DEPENDS_ON",
+                    "relatedSpdxElement": spdx_id(dependency),
+                })
+            })
+        }))
+        .collect::<Vec<_>>();
+
+    let mut hasher = Sha256::new();
+
+    for digest in roots.iter() {
+        hasher.update(digest.as_bytes());
+    }
+
+    let namespace = format!(
+        "https://github.com/ALT-F4-LLC/artifacts.vorpal/sbom/{}/{:x}",
+        context.get_system().as_str_name(),
+        hasher.finalize()
+    );
+
+    Ok(json!({
+        "spdxVersion": "SPDX-2.3",
+        "dataLicense": "CC0-1.0",
+        "SPDXID": "SPDXRef-DOCUMENT",
+        "name": env!("CARGO_PKG_NAME"),
+        "documentNamespace": namespace,
+        "creationInfo": {
+            "created": timestamp()?,
+            "creators": [format!("Tool: {}-{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))],
+        },
+        "packages": packages,
+        "relationships": relationships,
+    }))
+}
+
+pub fn document(context: &ConfigContext, digests: &[String], format: Format) -> Result<String> {
+    let components = components(context, digests)?;
+
+    let document = match format {
+        Format::CycloneDx => cyclonedx(&components)?,
+        Format::Spdx => spdx(context, &components, digests)?,
+    };
+
+    Ok(serde_json::to_string_pretty(&document)?)
+}
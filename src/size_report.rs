@@ -0,0 +1,157 @@
+use crate::graph;
+use anyhow::Result;
+use indoc::formatdoc;
+use std::collections::BTreeSet;
+use vorpal_sdk::{
+    artifact::{get_env_key, step, Artifact},
+    context::ConfigContext,
+};
+
+// Context variable that adds the report to a config, e.g. `--artifact-variable size-report=true`.
+pub const VARIABLE: &str = "size-report";
+
+// Location of the report inside the report artifact's output.
+pub const PATH: &str = "share/vorpal/size-report.json";
+
+// Joins the artifact table, the measured sizes and the closure memberships into the report.
+// Sizes are printed with `%.0f` because some awks overflow `%d` past 2 GiB.
+const REPORT_AWK: &str = r#"BEGIN { FS = "\t" }
+FILENAME == ARGV[1] { name[$1] = $2; version[$1] = $3; order[++count] = $1; next }
+FILENAME == ARGV[2] { bytes[$1] = $2; files[$1] = $3; next }
+{
+    if (!($1 in closure_count)) { roots[++root_count] = $1 }
+    closure_bytes[$1] += bytes[$2]
+    closure_count[$1]++
+}
+END {
+    printf "{\n  \"artifacts\": ["
+    for (i = 1; i <= count; i++) {
+        d = order[i]
+        printf "%s\n    {\"digest\": \"%s\", \"name\": \"%s\", \"version\": \"%s\", \"bytes\": %.0f, \"files\": %.0f}", (i > 1 ? "," : ""), d, name[d], version[d], bytes[d], files[d]
+    }
+    printf "\n  ],\n  \"closures\": ["
+    for (i = 1; i <= root_count; i++) {
+        d = roots[i]
+        printf "%s\n    {\"digest\": \"%s\", \"name\": \"%s\", \"version\": \"%s\", \"bytes\": %.0f, \"artifacts\": %.0f}", (i > 1 ? "," : ""), d, name[d], version[d], closure_bytes[d], closure_count[d]
+    }
+    printf "\n  ]\n}\n"
+}"#;
+
+// Artifact `<name>-size-report` whose output holds a JSON report at `PATH`: the installed size
+// and file count of every artifact reachable from `roots` (see `graph::nodes`), and for each root
+// the total size of its closure, itself included. The step mounts every artifact it measures, so
+// building the report builds the whole closure; CI diffs the report across version bumps.
+pub async fn build(context: &mut ConfigContext, name: &str, roots: &[String]) -> Result<String> {
+    let nodes = graph::nodes(context, roots)?;
+
+    let artifacts_table = nodes
+        .iter()
+        .map(|(digest, node)| format!("{digest}\t{}\t{}", node.name, node.version))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let measure_script = nodes
+        .keys()
+        .map(|digest| format!("measure \"{digest}\" \"{}\"", get_env_key(digest)))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut closures = vec![];
+
+    for root in roots.iter() {
+        let mut closure = BTreeSet::new();
+        let mut pending = vec![root.clone()];
+
+        while let Some(digest) = pending.pop() {
+            if let Some(node) = nodes.get(&digest) {
+                if closure.insert(digest) {
+                    pending.extend(node.dependencies.iter().cloned());
+                }
+            }
+        }
+
+        closures.extend(
+            closure
+                .into_iter()
+                .map(|member| format!("{root}\t{member}")),
+        );
+    }
+
+    let closures_table = closures.join("\n");
+
+    let step_script = formatdoc! {"
+        report=\"$VORPAL_WORKSPACE/size-report\"
+
+        mkdir -pv \"$report\" \"$(dirname \"$VORPAL_OUTPUT/{PATH}\")\"
+
+        cat > \"$report/artifacts.tsv\" <<'EOF'
+        {artifacts_table}
+        EOF
+
+        cat > \"$report/closures.tsv\" <<'EOF'
+        {closures_table}
+        EOF
+
+        cat > \"$report/report.awk\" <<'EOF'
+        {REPORT_AWK}
+        EOF
+
+        : > \"$report/sizes.tsv\"
+
+        measure() {{
+            bytes=\"$(find \"$2\" -type f -print0 | xargs -0 wc -c 2>/dev/null | awk '$2 != \"total\" {{ sum += $1 }} END {{ printf \"%.0f\", sum }}')\"
+            files=\"$(find \"$2\" -type f | wc -l | tr -d ' ')\"
+
+            printf '%s\\t%s\\t%s\\n' \"$1\" \"$bytes\" \"$files\" >> \"$report/sizes.tsv\"
+        }}
+
+        {measure_script}
+
+        awk -f \"$report/report.awk\" \"$report/artifacts.tsv\" \"$report/sizes.tsv\" \"$report/closures.tsv\" > \"$VORPAL_OUTPUT/{PATH}\"
+
+        cat \"$VORPAL_OUTPUT/{PATH}\"",
+    };
+
+    let name = format!("{name}-size-report");
+
+    let steps = vec![
+        step::shell(
+            context,
+            nodes.keys().cloned().collect(),
+            vec![],
+            step_script,
+            vec![],
+        )
+        .await?,
+    ];
+
+    Artifact::new(&name, steps, vec![context.get_system()])
+        .build(context)
+        .await
+}
+
+#[cfg(all(test, feature = "terminal"))]
+mod tests {
+    use super::*;
+    use crate::{
+        artifact::{tmux::Tmux, ArtifactPackage},
+        testing::{self, MockContext},
+    };
+    use vorpal_sdk::api::artifact::ArtifactSystem::Aarch64Darwin;
+
+    #[tokio::test]
+    async fn build_mounts_the_closure() -> Result<()> {
+        let context = &mut MockContext::new(Aarch64Darwin).build().await?;
+
+        let tmux = Tmux::new().build(context).await?;
+        let report = build(context, "dev", &[tmux]).await?;
+
+        assert_eq!(testing::artifact(context, &report)?.name, "dev-size-report");
+        assert_eq!(
+            testing::dependencies(context, &report)?,
+            ["libevent", "ncurses", "tmux"]
+        );
+
+        Ok(())
+    }
+}
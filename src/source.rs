@@ -0,0 +1,431 @@
+use crate::{digests, error::ArtifactError, overrides, target};
+use anyhow::{bail, Result};
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    io::Write,
+    path::Path,
+    process::{Command, Stdio},
+    sync::Mutex,
+};
+use vorpal_sdk::{
+    api::{self, artifact::ArtifactSystem},
+    artifact::ArtifactSource,
+};
+
+// Comma separated `prefix=replacement` rules applied to every source URL, e.g.
+// `https://ftpmirror.gnu.org/=https://mirrors.kernel.org/gnu/`.
+const MIRRORS_ENV: &str = "VORPAL_SOURCE_MIRRORS";
+
+// Registries that build steps download dependencies from themselves instead of through declared
+// sources. `mirrored` applies the same rules to them.
+pub const CRATES_IO_INDEX: &str = "https://index.crates.io/";
+pub const GO_PROXY: &str = "https://proxy.golang.org/";
+pub const PYPI_INDEX: &str = "https://pypi.org/simple/";
+
+// Comma separated hosts that are currently unreachable; sources fall back to their next URL.
+const SKIP_HOSTS_ENV: &str = "VORPAL_SOURCE_SKIP_HOSTS";
+
+// Directory laid out like `wget --force-directories`, i.e. `<dir>/<host>/<path>`. When set, every
+// remote source is read from it instead of the network.
+const OFFLINE_ENV: &str = "VORPAL_SOURCE_OFFLINE";
+
+// Comma separated `prefix=VARIABLE` rules for private sources, e.g.
+// `https://artifactory.example.com/=ARTIFACTORY_TOKEN`. URLs under a prefix are downloaded while
+// the config is evaluated, sending `Authorization: Bearer $VARIABLE` (or `Header:$VARIABLE` for a
+// `prefix=Header:VARIABLE` rule), and the source points at the local copy. The agent fetches
+// sources without headers, so this keeps the token out of the artifact and its digest.
+const AUTH_ENV: &str = "VORPAL_SOURCE_AUTH";
+
+// Directory private sources are downloaded to, laid out like the offline directory. The path is
+// part of the source, so set it to the same directory everywhere digests are compared.
+const CACHE_ENV: &str = "VORPAL_SOURCE_CACHE";
+
+// Remote URL to the local path it was expected at, for sources missing from the offline directory
+// and private sources that could not be downloaded.
+static OFFLINE_MISSING: Mutex<BTreeMap<String, String>> = Mutex::new(BTreeMap::new());
+
+pub struct SourceUrls<'a> {
+    mirrors: Vec<&'a str>,
+    name: &'a str,
+    path: &'a str,
+    system: Option<&'a str>,
+}
+
+impl<'a> SourceUrls<'a> {
+    pub fn new(name: &'a str, path: &'a str) -> Self {
+        Self {
+            mirrors: vec![],
+            name,
+            path,
+            system: None,
+        }
+    }
+
+    pub fn with_mirror(mut self, mirror: &'a str) -> Self {
+        self.mirrors.push(mirror);
+        self
+    }
+
+    // Takes `versions.toml` overrides from the `[<name>.<system>]` table, see `for_system`.
+    pub fn with_system(mut self, system: &'a str) -> Self {
+        self.system = Some(system);
+        self
+    }
+
+    fn urls(&self) -> Vec<String> {
+        let rules = rewrite_rules();
+
+        std::iter::once(self.path)
+            .chain(self.mirrors.iter().copied())
+            .map(|url| rewrite(&rules, url))
+            .collect()
+    }
+
+    fn offline_path(&self, directory: &str) -> String {
+        let candidates = std::iter::once(self.path)
+            .chain(self.mirrors.iter().copied())
+            .filter_map(|url| offline_path(directory, url))
+            .collect::<Vec<_>>();
+
+        if let Some(path) = candidates.iter().find(|path| Path::new(path).exists()) {
+            return path.clone();
+        }
+
+        let Some(path) = candidates.into_iter().next() else {
+            return self.path.to_string();
+        };
+
+        if let Ok(mut missing) = OFFLINE_MISSING.lock() {
+            missing.insert(self.path.to_string(), path.clone());
+        }
+
+        path
+    }
+
+    pub fn build(mut self) -> api::artifact::ArtifactSource {
+        let mut pinned = None;
+
+        // A `versions.toml` url replaces the declared one along with its mirrors, which still
+        // point at the compiled-in version. `overrides` only accepts a url together with its
+        // digest, so a replaced source is always pinned.
+        let entry = match self.system {
+            Some(system) => overrides::get(self.name).and_then(|entry| entry.systems.get(system)),
+            None => overrides::get(self.name),
+        };
+
+        if let Some(entry) = entry {
+            if let Some(url) = entry.url.as_deref() {
+                self.path = url;
+                self.mirrors.clear();
+            }
+
+            pinned = entry.digest.as_deref();
+        }
+
+        let urls = self.urls();
+        let skip_hosts = skip_hosts();
+
+        let path = match env::var(OFFLINE_ENV) {
+            Ok(directory) if !directory.is_empty() => self.offline_path(&directory),
+            _ => {
+                let url = urls
+                    .iter()
+                    .find(|url| !skip_hosts.iter().any(|skip| host(url) == skip))
+                    .unwrap_or(&urls[0]);
+
+                authenticated(url).unwrap_or_else(|| url.clone())
+            }
+        };
+
+        // Mirrors serve the same archive, so the digest pinned for any of the declared URLs
+        // applies to whichever one is fetched.
+        let digest = pinned.or_else(|| {
+            std::iter::once(path.as_str())
+                .chain(std::iter::once(self.path))
+                .chain(self.mirrors.iter().copied())
+                .find_map(digests::get)
+        });
+
+        let source = ArtifactSource::new(self.name, &path);
+
+        match digest {
+            Some(digest) => source.with_digest(digest).build(),
+            None => source.build(),
+        }
+    }
+}
+
+pub fn new(name: &str, path: &str) -> api::artifact::ArtifactSource {
+    SourceUrls::new(name, path).build()
+}
+
+// Source whose archive differs per system, such as a prebuilt release. One `versions.toml` url or
+// digest cannot fit every system, so they are only taken from the `[<name>.<system>]` table.
+pub fn for_system(
+    name: &str,
+    path: &str,
+    system: ArtifactSystem,
+) -> Result<api::artifact::ArtifactSource> {
+    let system = target::name(system)?;
+
+    if let Some(entry) = overrides::get(name) {
+        if entry.url.is_some() || entry.digest.is_some() {
+            bail!(
+                "'{name}' downloads a different source per system; set 'url' and 'digest' under \
+                 [{name}.{system}] in the versions file instead"
+            );
+        }
+    }
+
+    Ok(SourceUrls::new(name, path).with_system(system).build())
+}
+
+// Source for a GitHub repository at a branch, tag or commit that has no release archive yet, for
+// tracking unreleased fixes. Returns the archive and its top-level directory, matched with a glob
+// because GitHub names it after the full commit hash even when `reference` is abbreviated. Branch
+// archives move with the branch, so they have no pinned digest.
+pub fn github_ref(
+    name: &str,
+    repository: &str,
+    reference: &str,
+) -> Result<(api::artifact::ArtifactSource, String)> {
+    let Some((_, repo)) = repository.split_once('/') else {
+        bail!("invalid GitHub repository '{repository}' for {name}");
+    };
+
+    if reference.is_empty() {
+        bail!("empty source ref for {name}");
+    }
+
+    let path = format!("https://codeload.github.com/{repository}/tar.gz/{reference}");
+
+    Ok((new(name, &path), format!("{repo}-*")))
+}
+
+// `url` rewritten by the `VORPAL_SOURCE_MIRRORS` rules, for registries such as `GO_PROXY` that
+// build steps reach directly. `None` when no rule covers it, so the step stays unchanged.
+pub fn mirrored(url: &str) -> Option<String> {
+    let rewritten = rewrite(&rewrite_rules(), url);
+
+    (rewritten != url).then_some(rewritten)
+}
+
+pub fn is_offline() -> bool {
+    env::var(OFFLINE_ENV).is_ok_and(|directory| !directory.is_empty())
+}
+
+// Fails with every source that was requested while offline but is missing from the directory, or
+// that is private and could not be downloaded, so an air-gapped mirror can be completed in one
+// pass.
+pub fn check_offline() -> Result<()> {
+    let missing = match OFFLINE_MISSING.lock() {
+        Ok(missing) => missing.clone(),
+        Err(_) => bail!("offline source list is poisoned"),
+    };
+
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    Err(ArtifactError::SourceUnavailable {
+        missing: missing.into_iter().collect(),
+    }
+    .into())
+}
+
+// Header rules from `AUTH_ENV` as (prefix, header, variable); `None` means a bearer token.
+fn auth_rules(value: &str) -> Vec<(String, Option<String>, String)> {
+    value
+        .split(',')
+        .filter_map(|rule| rule.trim().split_once('='))
+        .map(|(prefix, credential)| match credential.split_once(':') {
+            Some((header, variable)) => (
+                prefix.to_string(),
+                Some(header.to_string()),
+                variable.to_string(),
+            ),
+            None => (prefix.to_string(), None, credential.to_string()),
+        })
+        .collect()
+}
+
+// Local copy of `url` when an `AUTH_ENV` rule covers it, downloaded unless it is already cached.
+// Versioned URLs never change, so a cached copy is reused as is.
+fn authenticated(url: &str) -> Option<String> {
+    let rules = auth_rules(&env::var(AUTH_ENV).unwrap_or_default());
+
+    let (_, header, variable) = rules
+        .iter()
+        .find(|(prefix, _, _)| url.starts_with(prefix.as_str()))?;
+
+    let directory = match env::var(CACHE_ENV) {
+        Ok(directory) if !directory.is_empty() => directory,
+        _ => env::temp_dir().join("vorpal-sources").display().to_string(),
+    };
+
+    let path = offline_path(&directory, url)?;
+
+    if Path::new(&path).exists() {
+        return Some(path);
+    }
+
+    let downloaded = match env::var(variable) {
+        Ok(token) if !token.is_empty() => {
+            let header = match header {
+                Some(header) => format!("{header}: {token}"),
+                None => format!("Authorization: Bearer {token}"),
+            };
+
+            download(url, &header, &path)
+        }
+        _ => false,
+    };
+
+    if !downloaded {
+        if let Ok(mut missing) = OFFLINE_MISSING.lock() {
+            missing.insert(url.to_string(), path.clone());
+        }
+    }
+
+    Some(path)
+}
+
+// Downloads with `curl`, passing the header on stdin so the token is not in the process list,
+// into a partial file that is only moved into place once complete.
+fn download(url: &str, header: &str, path: &str) -> bool {
+    let partial = format!("{path}.partial");
+
+    if let Some(parent) = Path::new(path).parent() {
+        if fs::create_dir_all(parent).is_err() {
+            return false;
+        }
+    }
+
+    let Ok(mut child) = Command::new("curl")
+        .args(["--fail", "--location", "--silent", "--show-error"])
+        .args(["--header", "@-", "--output", &partial, url])
+        .stdin(Stdio::piped())
+        .spawn()
+    else {
+        return false;
+    };
+
+    if let Some(mut stdin) = child.stdin.take() {
+        if writeln!(stdin, "{header}").is_err() {
+            return false;
+        }
+    }
+
+    match child.wait() {
+        Ok(status) if status.success() => fs::rename(&partial, path).is_ok(),
+        _ => false,
+    }
+}
+
+fn offline_path(directory: &str, url: &str) -> Option<String> {
+    let (_, rest) = url.split_once("://")?;
+    let rest = rest.split(['?', '#']).next().unwrap_or(rest);
+
+    Some(format!("{}/{rest}", directory.trim_end_matches('/')))
+}
+
+fn host(url: &str) -> &str {
+    let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+
+    without_scheme.split('/').next().unwrap_or_default()
+}
+
+fn rewrite(rules: &[(String, String)], url: &str) -> String {
+    rules
+        .iter()
+        .find_map(|(prefix, replacement)| {
+            url.strip_prefix(prefix.as_str())
+                .map(|rest| format!("{replacement}{rest}"))
+        })
+        .unwrap_or_else(|| url.to_string())
+}
+
+fn rewrite_rules() -> Vec<(String, String)> {
+    env::var(MIRRORS_ENV)
+        .unwrap_or_default()
+        .split(',')
+        .filter_map(|rule| rule.trim().split_once('='))
+        .map(|(prefix, replacement)| (prefix.to_string(), replacement.to_string()))
+        .collect()
+}
+
+fn skip_hosts() -> Vec<String> {
+    env::var(SKIP_HOSTS_ENV)
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|host| !host.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn github_ref_maps_to_codeload() -> Result<()> {
+        let (source, directory) = github_ref("skopeo", "containers/skopeo", "main")?;
+
+        assert_eq!(
+            source.path,
+            "https://codeload.github.com/containers/skopeo/tar.gz/main"
+        );
+        assert_eq!(directory, "skopeo-*");
+        assert!(github_ref("skopeo", "skopeo", "main").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn rewrite_uses_first_matching_prefix() {
+        let rules = [
+            (
+                "https://github.com/".to_string(),
+                "https://mirror.example.com/github/".to_string(),
+            ),
+            (
+                "https://github.com/gnupg/".to_string(),
+                "https://gnupg.example.com/".to_string(),
+            ),
+        ];
+
+        assert_eq!(
+            rewrite(&rules, "https://github.com/gnupg/gnupg/archive/v2.tar.gz"),
+            "https://mirror.example.com/github/gnupg/gnupg/archive/v2.tar.gz"
+        );
+        assert_eq!(
+            rewrite(&rules, "https://www.gnupg.org/ftp/gcrypt/gnupg.tar.bz2"),
+            "https://www.gnupg.org/ftp/gcrypt/gnupg.tar.bz2"
+        );
+    }
+
+    #[test]
+    fn auth_rules_default_to_bearer() {
+        let rules = auth_rules(
+            "https://artifactory.example.com/=ARTIFACTORY_TOKEN, https://mirror.example.com/=X-Api-Key:MIRROR_KEY",
+        );
+
+        assert_eq!(
+            rules,
+            [
+                (
+                    "https://artifactory.example.com/".to_string(),
+                    None,
+                    "ARTIFACTORY_TOKEN".to_string()
+                ),
+                (
+                    "https://mirror.example.com/".to_string(),
+                    Some("X-Api-Key".to_string()),
+                    "MIRROR_KEY".to_string()
+                ),
+            ]
+        );
+    }
+}
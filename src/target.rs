@@ -0,0 +1,138 @@
+use crate::error::ArtifactError;
+use anyhow::{bail, Result};
+use vorpal_sdk::{
+    api,
+    api::artifact::{
+        ArtifactSystem,
+        ArtifactSystem::{Aarch64Darwin, Aarch64Linux, X8664Darwin, X8664Linux},
+    },
+    artifact::{linux_vorpal::LinuxVorpal, step, Artifact},
+    context::ConfigContext,
+};
+
+// The SDK takes both an artifact's `target` and its step sandbox from `context.get_system()`.
+// Builders asked for another system with `with_target` go through these helpers instead; native
+// builds still go through the SDK so their digests are unchanged.
+
+pub fn system(context: &ConfigContext, target: Option<ArtifactSystem>) -> ArtifactSystem {
+    target.unwrap_or(context.get_system())
+}
+
+pub fn name(system: ArtifactSystem) -> Result<&'static str> {
+    match system {
+        Aarch64Darwin => Ok("aarch64-darwin"),
+        Aarch64Linux => Ok("aarch64-linux"),
+        X8664Darwin => Ok("x86_64-darwin"),
+        X8664Linux => Ok("x86_64-linux"),
+        _ => bail!("unsupported system: {}", system.as_str_name()),
+    }
+}
+
+// Linux steps run inside the linux-vorpal rootfs, which can only be built on Linux, so cross
+// builds take its digest from the `linux-vorpal-<system>` variable and fetch it from the registry.
+async fn rootfs(context: &mut ConfigContext, system: ArtifactSystem) -> Result<String> {
+    let variable = format!("linux-vorpal-{}", name(system)?);
+
+    let Some(digest) = context.get_variable(&variable) else {
+        return Err(ArtifactError::MissingDependency {
+            dependency: "linux-vorpal".to_string(),
+            variable,
+        }
+        .into());
+    };
+
+    context.fetch_artifact(&digest).await
+}
+
+pub async fn shell(
+    context: &mut ConfigContext,
+    system: ArtifactSystem,
+    artifacts: Vec<String>,
+    environments: Vec<String>,
+    script: String,
+) -> Result<api::artifact::ArtifactStep> {
+    shell_with_binds(
+        context,
+        system,
+        artifacts,
+        environments,
+        script,
+        &[],
+        vec![],
+    )
+    .await
+}
+
+// `shell` with host directories bound read-write at the same path inside the Linux sandbox, for
+// state that must outlive the step such as a compiler cache. Each must already exist on the
+// worker; darwin steps are not sandboxed and reach them directly. A bind may name a secret, e.g.
+// `$VORPAL_COMPILER_CACHE_DIR`, which the worker expands like the step's env keys; secrets reach
+// the step as environment variables without being part of its digest.
+pub async fn shell_with_binds(
+    context: &mut ConfigContext,
+    system: ArtifactSystem,
+    artifacts: Vec<String>,
+    environments: Vec<String>,
+    script: String,
+    binds: &[String],
+    secrets: Vec<api::artifact::ArtifactStepSecret>,
+) -> Result<api::artifact::ArtifactStep> {
+    if system == context.get_system() && binds.is_empty() {
+        return step::shell(context, artifacts, environments, script, secrets).await;
+    }
+
+    match system {
+        Aarch64Darwin | X8664Darwin => Ok(step::bash(artifacts, environments, secrets, script)),
+        Aarch64Linux | X8664Linux => {
+            let rootfs = match system == context.get_system() {
+                true => LinuxVorpal::new().build(context).await?,
+                false => rootfs(context, system).await?,
+            };
+
+            let arguments = binds
+                .iter()
+                .flat_map(|directory| ["--bind", directory.as_str(), directory.as_str()])
+                .collect();
+
+            step::bwrap(
+                arguments,
+                artifacts,
+                environments,
+                Some(rootfs),
+                secrets,
+                script,
+            )
+            .await
+        }
+        _ => bail!("unsupported system: {}", system.as_str_name()),
+    }
+}
+
+pub async fn build(
+    context: &mut ConfigContext,
+    system: ArtifactSystem,
+    name: &str,
+    aliases: Vec<String>,
+    sources: Vec<api::artifact::ArtifactSource>,
+    steps: Vec<api::artifact::ArtifactStep>,
+    systems: Vec<ArtifactSystem>,
+) -> Result<String> {
+    if system == context.get_system() {
+        return Artifact::new(name, steps, systems)
+            .with_aliases(aliases)
+            .with_sources(sources)
+            .build(context)
+            .await;
+    }
+
+    let artifact = api::artifact::Artifact {
+        aliases,
+        name: name.to_string(),
+        sources,
+        steps,
+        systems: systems.into_iter().map(|system| system.into()).collect(),
+        target: system.into(),
+    };
+
+    context.add_artifact(&artifact).await
+}
@@ -0,0 +1,230 @@
+use crate::target;
+use anyhow::{bail, Result};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::{BTreeSet, HashMap},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+use tokio::net::TcpListener;
+use tokio_stream::wrappers::TcpListenerStream;
+use tonic::{
+    transport::{Endpoint, Server},
+    Request, Response, Status,
+};
+use vorpal_sdk::{
+    api::{
+        agent::{
+            agent_service_client::AgentServiceClient,
+            agent_service_server::{AgentService, AgentServiceServer},
+            PrepareArtifactRequest, PrepareArtifactResponse,
+        },
+        artifact::{
+            artifact_service_client::ArtifactServiceClient,
+            artifact_service_server::{ArtifactService, ArtifactServiceServer},
+            Artifact, ArtifactRequest, ArtifactResponse, ArtifactSystem, ArtifactsRequest,
+            ArtifactsResponse, GetArtifactAliasRequest, GetArtifactAliasResponse,
+            StoreArtifactRequest,
+        },
+    },
+    context::ConfigContext,
+};
+
+// Every mock gets its own context path, which keeps `context_cache` entries from leaking
+// between tests running in the same process.
+static CONTEXTS: AtomicUsize = AtomicUsize::new(0);
+
+type Store = Arc<Mutex<HashMap<String, Artifact>>>;
+
+fn digest(artifact: &Artifact) -> Result<String, Status> {
+    let json = serde_json::to_vec(artifact).map_err(|error| Status::internal(error.to_string()))?;
+
+    Ok(format!("{:x}", Sha256::digest(json)))
+}
+
+// Stands in for the agent: artifacts are "prepared" by hashing them, without fetching sources
+// or running steps.
+struct MockAgent {
+    store: Store,
+}
+
+#[tonic::async_trait]
+impl AgentService for MockAgent {
+    type PrepareArtifactStream =
+        tokio_stream::Once<std::result::Result<PrepareArtifactResponse, Status>>;
+
+    async fn prepare_artifact(
+        &self,
+        request: Request<PrepareArtifactRequest>,
+    ) -> std::result::Result<Response<Self::PrepareArtifactStream>, Status> {
+        let Some(artifact) = request.into_inner().artifact else {
+            return Err(Status::invalid_argument("missing artifact"));
+        };
+
+        let artifact_digest = digest(&artifact)?;
+
+        self.store
+            .lock()
+            .map_err(|_| Status::internal("store is poisoned"))?
+            .insert(artifact_digest.clone(), artifact.clone());
+
+        Ok(Response::new(tokio_stream::once(Ok(
+            PrepareArtifactResponse {
+                artifact: Some(artifact),
+                artifact_digest: Some(artifact_digest),
+                artifact_output: None,
+            },
+        ))))
+    }
+}
+
+// Stands in for the registry: only artifacts prepared by the mock agent can be fetched, and
+// aliases never resolve.
+struct MockRegistry {
+    store: Store,
+}
+
+#[tonic::async_trait]
+impl ArtifactService for MockRegistry {
+    async fn get_artifact(
+        &self,
+        request: Request<ArtifactRequest>,
+    ) -> std::result::Result<Response<Artifact>, Status> {
+        let digest = request.into_inner().digest;
+
+        let store = self
+            .store
+            .lock()
+            .map_err(|_| Status::internal("store is poisoned"))?;
+
+        match store.get(&digest) {
+            Some(artifact) => Ok(Response::new(artifact.clone())),
+            None => Err(Status::not_found(format!("artifact not found: {digest}"))),
+        }
+    }
+
+    async fn get_artifact_alias(
+        &self,
+        request: Request<GetArtifactAliasRequest>,
+    ) -> std::result::Result<Response<GetArtifactAliasResponse>, Status> {
+        let name = request.into_inner().name;
+
+        Err(Status::not_found(format!("alias not found: {name}")))
+    }
+
+    async fn get_artifacts(
+        &self,
+        _: Request<ArtifactsRequest>,
+    ) -> std::result::Result<Response<ArtifactsResponse>, Status> {
+        let store = self
+            .store
+            .lock()
+            .map_err(|_| Status::internal("store is poisoned"))?;
+
+        let mut digests = store.keys().cloned().collect::<Vec<_>>();
+
+        digests.sort();
+
+        Ok(Response::new(ArtifactsResponse { digests }))
+    }
+
+    async fn store_artifact(
+        &self,
+        _: Request<StoreArtifactRequest>,
+    ) -> std::result::Result<Response<ArtifactResponse>, Status> {
+        Err(Status::unimplemented("the mock registry is read-only"))
+    }
+}
+
+// An offline `ConfigContext` backed by in-process agent and registry services, so artifact
+// graphs can be built and inspected without a Vorpal agent.
+pub struct MockContext {
+    system: ArtifactSystem,
+    variables: Vec<String>,
+}
+
+impl MockContext {
+    pub fn new(system: ArtifactSystem) -> Self {
+        Self {
+            system,
+            variables: vec![],
+        }
+    }
+
+    pub fn with_variable(mut self, name: &str, value: &str) -> Self {
+        self.variables.push(format!("{name}={value}"));
+        self
+    }
+
+    pub async fn build(self) -> Result<ConfigContext> {
+        let store = Store::default();
+
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+
+        let agent = AgentServiceServer::new(MockAgent {
+            store: store.clone(),
+        });
+
+        let registry = ArtifactServiceServer::new(MockRegistry { store });
+
+        tokio::spawn(
+            Server::builder()
+                .add_service(agent)
+                .add_service(registry)
+                .serve_with_incoming(TcpListenerStream::new(listener)),
+        );
+
+        let channel = Endpoint::from_shared(format!("http://{address}"))?.connect_lazy();
+
+        let id = CONTEXTS.fetch_add(1, Ordering::Relaxed);
+
+        ConfigContext::new(
+            "mock".to_string(),
+            PathBuf::from(format!("/vorpal-mock/{id}")),
+            "library".to_string(),
+            target::name(self.system)?.to_string(),
+            false,
+            self.variables,
+            AgentServiceClient::new(channel.clone()),
+            ArtifactServiceClient::new(channel),
+            0,
+            format!("http://{address}"),
+        )
+    }
+}
+
+pub fn artifact(context: &ConfigContext, digest: &str) -> Result<Artifact> {
+    match context.get_artifact(digest) {
+        Some(artifact) => Ok(artifact),
+        None => bail!("artifact not found in context: {digest}"),
+    }
+}
+
+pub fn aliases(context: &ConfigContext, digest: &str) -> Result<Vec<String>> {
+    Ok(artifact(context, digest)?.aliases)
+}
+
+pub fn source_paths(context: &ConfigContext, digest: &str) -> Result<Vec<String>> {
+    Ok(artifact(context, digest)?
+        .sources
+        .into_iter()
+        .map(|source| source.path)
+        .collect())
+}
+
+// Names of the artifacts used directly by any step, sorted and deduplicated.
+pub fn dependencies(context: &ConfigContext, digest: &str) -> Result<Vec<String>> {
+    let mut names = BTreeSet::new();
+
+    for step in artifact(context, digest)?.steps {
+        for dependency in step.artifacts {
+            names.insert(artifact(context, &dependency)?.name);
+        }
+    }
+
+    Ok(names.into_iter().collect())
+}
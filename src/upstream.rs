@@ -0,0 +1,309 @@
+// Where `check-updates` looks for newer releases, keyed by artifact name. Artifacts built with
+// `binary_artifact!` use their GitHub repository and are not listed here.
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Upstream {
+    // Index page listing `<prefix><version>.tar.*` archives, e.g. a GNU FTP directory.
+    Directory {
+        url: &'static str,
+        prefix: &'static str,
+    },
+    // Latest release of an `owner/repository`.
+    Github(&'static str),
+    // Product name in the HashiCorp releases API.
+    Hashicorp(&'static str),
+    // Package name in the npm registry.
+    Npm(&'static str),
+}
+
+const UPSTREAMS: &[(&str, Upstream)] = &[
+    ("abseil-cpp", Upstream::Github("abseil/abseil-cpp")),
+    ("ansible", Upstream::Github("ansible/ansible")),
+    ("atlas", Upstream::Github("ariga/atlas")),
+    ("b3sum", Upstream::Github("BLAKE3-team/BLAKE3")),
+    (
+        "bash-language-server",
+        Upstream::Npm("bash-language-server"),
+    ),
+    // Releases after 0.10.0 only build with meson, so newer ones are reported but not adopted.
+    ("bubblewrap", Upstream::Github("containers/bubblewrap")),
+    ("c-ares", Upstream::Github("c-ares/c-ares")),
+    ("capnproto", Upstream::Github("capnproto/capnproto")),
+    ("ccache", Upstream::Github("ccache/ccache")),
+    ("cfssl", Upstream::Github("cloudflare/cfssl")),
+    ("cmake", Upstream::Github("Kitware/CMake")),
+    ("crane", Upstream::Github("google/go-containerregistry")),
+    (
+        "dockerfile-language-server-nodejs",
+        Upstream::Npm("dockerfile-language-server-nodejs"),
+    ),
+    (
+        "ffmpeg",
+        Upstream::Directory {
+            url: "https://ffmpeg.org/releases/",
+            prefix: "ffmpeg-",
+        },
+    ),
+    ("flatbuffers", Upstream::Github("google/flatbuffers")),
+    ("flex", Upstream::Github("westes/flex")),
+    ("flyway", Upstream::Github("flyway/flyway")),
+    (
+        "fontconfig",
+        Upstream::Directory {
+            url: "https://www.freedesktop.org/software/fontconfig/release/",
+            prefix: "fontconfig-",
+        },
+    ),
+    (
+        "freetype",
+        Upstream::Directory {
+            url: "https://download.savannah.gnu.org/releases/freetype/",
+            prefix: "freetype-",
+        },
+    ),
+    (
+        "giflib",
+        Upstream::Directory {
+            url: "https://sourceforge.net/projects/giflib/files/",
+            prefix: "giflib-",
+        },
+    ),
+    (
+        "gpg",
+        Upstream::Directory {
+            url: "https://gnupg.org/ftp/gcrypt/gnupg/",
+            prefix: "gnupg-",
+        },
+    ),
+    ("harfbuzz", Upstream::Github("harfbuzz/harfbuzz")),
+    ("helm", Upstream::Github("helm/helm")),
+    // Tags look like `release-77-1`, so only new major releases are reported.
+    ("icu", Upstream::Github("unicode-org/icu")),
+    ("jbang", Upstream::Github("jbangdev/jbang")),
+    ("kubectl", Upstream::Github("kubernetes/kubernetes")),
+    (
+        "libassuan",
+        Upstream::Directory {
+            url: "https://gnupg.org/ftp/gcrypt/libassuan/",
+            prefix: "libassuan-",
+        },
+    ),
+    (
+        "libcap",
+        Upstream::Directory {
+            url: "https://mirrors.edge.kernel.org/pub/linux/libs/security/linux-privs/libcap2/",
+            prefix: "libcap-",
+        },
+    ),
+    ("libevent", Upstream::Github("libevent/libevent")),
+    ("libffi", Upstream::Github("libffi/libffi")),
+    (
+        "libgcrypt",
+        Upstream::Directory {
+            url: "https://gnupg.org/ftp/gcrypt/libgcrypt/",
+            prefix: "libgcrypt-",
+        },
+    ),
+    (
+        "libgpg-error",
+        Upstream::Directory {
+            url: "https://gnupg.org/ftp/gcrypt/libgpg-error/",
+            prefix: "libgpg-error-",
+        },
+    ),
+    (
+        "libjpeg-turbo",
+        Upstream::Github("libjpeg-turbo/libjpeg-turbo"),
+    ),
+    (
+        "libksba",
+        Upstream::Directory {
+            url: "https://gnupg.org/ftp/gcrypt/libksba/",
+            prefix: "libksba-",
+        },
+    ),
+    (
+        "libpcap",
+        Upstream::Directory {
+            url: "https://www.tcpdump.org/release/",
+            prefix: "libpcap-",
+        },
+    ),
+    (
+        "libssh2",
+        Upstream::Directory {
+            url: "https://libssh2.org/download/",
+            prefix: "libssh2-",
+        },
+    ),
+    ("libuv", Upstream::Github("libuv/libuv")),
+    (
+        "libwebp",
+        Upstream::Directory {
+            url:
+                "https://storage.googleapis.com/downloads.webmproject.org/releases/webp/index.html",
+            prefix: "libwebp-",
+        },
+    ),
+    ("libwebsockets", Upstream::Github("warmcat/libwebsockets")),
+    // libxml2 2.14 dropped LZMA support, so updates are tracked within the 2.13 series.
+    (
+        "libxml2",
+        Upstream::Directory {
+            url: "https://download.gnome.org/sources/libxml2/2.13/",
+            prefix: "libxml2-",
+        },
+    ),
+    (
+        "libxslt",
+        Upstream::Directory {
+            url: "https://download.gnome.org/sources/libxslt/1.1/",
+            prefix: "libxslt-",
+        },
+    ),
+    ("libyaml", Upstream::Github("yaml/libyaml")),
+    ("lima", Upstream::Github("lima-vm/lima")),
+    (
+        "lua-language-server",
+        Upstream::Github("LuaLS/lua-language-server"),
+    ),
+    ("mbedtls", Upstream::Github("Mbed-TLS/mbedtls")),
+    ("minisign", Upstream::Github("jedisct1/minisign")),
+    ("mongosh", Upstream::Github("mongodb-js/mongosh")),
+    (
+        "mtr",
+        Upstream::Directory {
+            url: "https://www.bitwizard.nl/mtr/files/",
+            prefix: "mtr-",
+        },
+    ),
+    (
+        "musl",
+        Upstream::Directory {
+            url: "https://musl.libc.org/releases/",
+            prefix: "musl-",
+        },
+    ),
+    (
+        "ncdu",
+        Upstream::Directory {
+            url: "https://dev.yorhel.nl/download/",
+            prefix: "ncdu-",
+        },
+    ),
+    (
+        "ncurses",
+        Upstream::Directory {
+            url: "https://ftp.gnu.org/gnu/ncurses/",
+            prefix: "ncurses-",
+        },
+    ),
+    ("neovim", Upstream::Github("neovim/neovim")),
+    ("nghttp2", Upstream::Github("nghttp2/nghttp2")),
+    (
+        "nmap",
+        Upstream::Directory {
+            url: "https://nmap.org/dist/",
+            prefix: "nmap-",
+        },
+    ),
+    ("nnn", Upstream::Github("jarun/nnn")),
+    (
+        "npth",
+        Upstream::Directory {
+            url: "https://gnupg.org/ftp/gcrypt/npth/",
+            prefix: "npth-",
+        },
+    ),
+    ("oniguruma", Upstream::Github("kkos/oniguruma")),
+    ("openssl", Upstream::Github("openssl/openssl")),
+    ("pcre2", Upstream::Github("PCRE2Project/pcre2")),
+    ("pi", Upstream::Github("earendil-works/pi")),
+    (
+        "pkg-config",
+        Upstream::Directory {
+            url: "https://pkgconfig.freedesktop.org/releases/",
+            prefix: "pkg-config-",
+        },
+    ),
+    ("protobuf", Upstream::Github("protocolbuffers/protobuf")),
+    (
+        "readline",
+        Upstream::Directory {
+            url: "https://ftp.gnu.org/gnu/readline/",
+            prefix: "readline-",
+        },
+    ),
+    ("skopeo", Upstream::Github("containers/skopeo")),
+    ("steampipe", Upstream::Github("turbot/steampipe")),
+    (
+        "stow",
+        Upstream::Directory {
+            url: "https://ftp.gnu.org/gnu/stow/",
+            prefix: "stow-",
+        },
+    ),
+    (
+        "tcpdump",
+        Upstream::Directory {
+            url: "https://www.tcpdump.org/release/",
+            prefix: "tcpdump-",
+        },
+    ),
+    ("terraform", Upstream::Hashicorp("terraform")),
+    ("terraform-ls", Upstream::Hashicorp("terraform-ls")),
+    ("tmux", Upstream::Github("tmux/tmux")),
+    ("ttyd", Upstream::Github("tsl0922/ttyd")),
+    ("typescript", Upstream::Npm("typescript")),
+    (
+        "typescript-language-server",
+        Upstream::Npm("typescript-language-server"),
+    ),
+    ("umoci", Upstream::Github("opencontainers/umoci")),
+    ("universal-ctags", Upstream::Github("universal-ctags/ctags")),
+    ("upx", Upstream::Github("upx/upx")),
+    (
+        "vscode-langservers-extracted",
+        Upstream::Npm("vscode-langservers-extracted"),
+    ),
+    (
+        "yaml-language-server",
+        Upstream::Npm("yaml-language-server"),
+    ),
+    ("zig", Upstream::Github("ziglang/zig")),
+    ("zlib", Upstream::Github("madler/zlib")),
+];
+
+pub fn get(name: &str) -> Option<Upstream> {
+    UPSTREAMS
+        .iter()
+        .find(|(upstream_name, _)| *upstream_name == name)
+        .map(|(_, upstream)| *upstream)
+}
+
+// Leading tag prefixes (`v`, `release-`, `jq-`) and trailing suffixes (`-stable`) are dropped.
+pub fn normalize(version: &str) -> &str {
+    let Some(start) = version.find(|c: char| c.is_ascii_digit()) else {
+        return version;
+    };
+
+    let version = &version[start..];
+
+    let end = version
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(version.len());
+
+    version[..end].trim_end_matches('.')
+}
+
+// Compares dotted numeric versions component by component; `1.10` is newer than `1.9`.
+pub fn is_newer(candidate: &str, current: &str) -> bool {
+    let parse = |version: &str| {
+        normalize(version)
+            .split('.')
+            .map(|part| part.parse::<u64>().unwrap_or(0))
+            .collect::<Vec<_>>()
+    };
+
+    parse(candidate) > parse(current)
+}
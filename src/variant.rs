@@ -0,0 +1,171 @@
+use crate::{aliases, builder::musl, error::ArtifactError};
+use anyhow::Result;
+use vorpal_sdk::context::ConfigContext;
+
+// Config variable choosing static or shared libraries for artifacts not given a linkage with
+// `with_linkage`, e.g. `--variable linkage=static`.
+pub const VARIABLE: &str = "linkage";
+
+// Feature set of a source-built artifact. `Full` is the regular workstation build; `Minimal`
+// drops optional features, programs and dependencies for small CI images. What each one turns off
+// is up to the artifact.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Variant {
+    Minimal,
+    #[default]
+    Full,
+}
+
+// Whether an artifact installs shared or static libraries. `Musl` builds are Linux only and link
+// statically against musl instead of glibc, see `builder::musl`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Linkage {
+    #[default]
+    Shared,
+    Static,
+    Musl,
+}
+
+// The linkage given with `with_linkage`, else the `linkage` variable, else shared. The `libc`
+// variable then switches Linux builds to musl, see `musl::linkage`.
+pub fn linkage(context: &ConfigContext, linkage: Option<Linkage>) -> Result<Linkage> {
+    let linkage = match linkage {
+        Some(linkage) => linkage,
+        None => match context.get_variable(VARIABLE).as_deref() {
+            None | Some("shared") => Linkage::Shared,
+            Some("static") => Linkage::Static,
+            Some(value) => {
+                return Err(ArtifactError::InvalidVariable {
+                    variable: VARIABLE.to_string(),
+                    value: value.to_string(),
+                }
+                .into())
+            }
+        },
+    };
+
+    musl::linkage(context, linkage)
+}
+
+// Autotools flags installing only the libraries `linkage` asks for.
+pub fn configure_flags(linkage: Linkage) -> [&'static str; 2] {
+    match linkage {
+        Linkage::Shared => ["--enable-shared", "--disable-static"],
+        Linkage::Static | Linkage::Musl => ["--disable-shared", "--enable-static"],
+    }
+}
+
+// Tag suffix naming the non-default choices, e.g. `minimal`, `static`, `musl` or
+// `minimal-static`.
+pub fn suffix(variant: Variant, linkage: Linkage) -> Option<String> {
+    let parts = [
+        (variant == Variant::Minimal).then_some("minimal"),
+        match linkage {
+            Linkage::Shared => None,
+            Linkage::Static => Some("static"),
+            Linkage::Musl => Some("musl"),
+        },
+    ];
+
+    let parts = parts.into_iter().flatten().collect::<Vec<_>>();
+
+    (!parts.is_empty()).then(|| parts.join("-"))
+}
+
+// `name:version`, with the suffix appended for non-default builds.
+pub fn alias(name: &str, version: &str, variant: Variant, linkage: Linkage) -> String {
+    match suffix(variant, linkage) {
+        Some(suffix) => format!("{name}:{version}-{suffix}"),
+        None => format!("{name}:{version}"),
+    }
+}
+
+// `aliases::versioned` with the suffix appended to every tag and `latest` replaced by the suffix
+// itself (`ncurses:6.5-minimal`, ..., `ncurses:minimal`), so variants never take over the default
+// build's aliases.
+pub fn aliases(name: &str, version: &str, variant: Variant, linkage: Linkage) -> Vec<String> {
+    let versioned = aliases::versioned(name, version);
+
+    let Some(suffix) = suffix(variant, linkage) else {
+        return versioned;
+    };
+
+    versioned
+        .into_iter()
+        .map(|alias| match alias.strip_suffix(":latest") {
+            Some(name) => format!("{name}:{suffix}"),
+            None => format!("{alias}-{suffix}"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockContext;
+    use vorpal_sdk::api::artifact::ArtifactSystem::{Aarch64Darwin, Aarch64Linux};
+
+    #[tokio::test]
+    async fn linkage_prefers_explicit_then_variable() -> Result<()> {
+        let context = MockContext::new(Aarch64Darwin).build().await?;
+
+        assert_eq!(linkage(&context, None)?, Linkage::Shared);
+
+        let context = MockContext::new(Aarch64Darwin)
+            .with_variable(VARIABLE, "static")
+            .build()
+            .await?;
+
+        assert_eq!(linkage(&context, None)?, Linkage::Static);
+        assert_eq!(linkage(&context, Some(Linkage::Shared))?, Linkage::Shared);
+
+        let context = MockContext::new(Aarch64Linux)
+            .with_variable(musl::VARIABLE, "musl")
+            .build()
+            .await?;
+
+        assert_eq!(linkage(&context, None)?, Linkage::Musl);
+
+        let context = MockContext::new(Aarch64Darwin)
+            .with_variable(VARIABLE, "dynamic")
+            .build()
+            .await?;
+
+        assert!(linkage(&context, None).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn aliases_keep_default_build_unsuffixed() {
+        assert_eq!(
+            aliases("zlib", "1.3.1", Variant::Full, Linkage::Shared),
+            aliases::versioned("zlib", "1.3.1")
+        );
+    }
+
+    #[test]
+    fn aliases_suffix_variants() {
+        assert_eq!(
+            aliases("zlib", "1.3.1", Variant::Full, Linkage::Static),
+            [
+                "zlib:1.3.1-static",
+                "zlib:1.3-static",
+                "zlib:1-static",
+                "zlib:static"
+            ]
+        );
+        assert_eq!(
+            aliases("x264", "20191217-2245", Variant::Minimal, Linkage::Static),
+            ["x264:20191217-2245-minimal-static", "x264:minimal-static"]
+        );
+        assert_eq!(
+            alias("gpg", "2.4.8", Variant::Minimal, Linkage::Shared),
+            "gpg:2.4.8-minimal"
+        );
+        assert_eq!(
+            alias("tmux", "3.5a", Variant::Full, Linkage::Musl),
+            "tmux:3.5a-musl"
+        );
+    }
+}
@@ -0,0 +1,163 @@
+pub const ABSEIL_CPP: &str = "20250814.1";
+pub const ABTOP: &str = "0.5.1";
+pub const ANSIBLE: &str = "2.19.3";
+pub const ARGOCD: &str = "3.2.3";
+pub const ATLAS: &str = "0.37.0";
+pub const AWSCLI2: &str = "2.33.1";
+pub const B3SUM: &str = "1.8.2";
+pub const BASH_LANGUAGE_SERVER: &str = "5.6.0";
+pub const BAT: &str = "0.25.0";
+pub const BEADS: &str = "0.47.1";
+pub const BOTTOM: &str = "0.11.1";
+pub const BUBBLEWRAP: &str = "0.10.0";
+pub const C_ARES: &str = "1.34.5";
+pub const CAPNPROTO: &str = "1.2.0";
+pub const CCACHE: &str = "4.12.1";
+pub const CFSSL: &str = "1.6.5";
+pub const CHEZMOI: &str = "2.65.0";
+pub const CMAKE: &str = "4.2.3";
+pub const CRANE: &str = "0.20.7";
+pub const CUE: &str = "0.15.1";
+pub const DBMATE: &str = "2.28.0";
+pub const DELTA: &str = "0.18.2";
+pub const DIRENV: &str = "v2.37.1";
+pub const DOCKERFILE_LANGUAGE_SERVER: &str = "0.15.0";
+pub const DOGGO: &str = "1.0.5";
+pub const DOPPLER: &str = "3.75.1";
+pub const DUMB_INIT: &str = "1.2.5";
+pub const EKSCTL: &str = "0.227.0";
+pub const FASTFETCH: &str = "2.49.0";
+pub const FD: &str = "10.2.0";
+pub const FFMPEG: &str = "8.0.1";
+pub const FLATBUFFERS: &str = "25.2.10";
+pub const FLEX: &str = "2.6.4";
+pub const FLUXCD: &str = "2.7.5";
+pub const FLYWAY: &str = "11.14.1";
+pub const FONT_STACK: &str = "11.2.1";
+pub const FONTCONFIG: &str = "2.16.0";
+pub const FREETYPE: &str = "2.13.3";
+pub const FX: &str = "36.0.0";
+pub const FZF: &str = "0.70.0";
+pub const GH_ACTIONS_TOOL_CACHE_GO: &str = "1.26.0";
+pub const GH_ACTIONS_TOOL_CACHE_NODE: &str = "22.22.0";
+pub const GHZ: &str = "0.120.0";
+pub const GIFLIB: &str = "5.2.2";
+pub const GIT_ABSORB: &str = "0.8.0";
+pub const GLOW: &str = "2.1.1";
+pub const GOLANGCI_LINT: &str = "2.11.4";
+pub const GPG: &str = "2.5.16";
+pub const GRON: &str = "0.7.1";
+pub const GRPC_HEALTH_PROBE: &str = "0.4.40";
+pub const GUM: &str = "0.17.0";
+pub const HAPROXY: &str = "3.2.4";
+pub const HARFBUZZ: &str = "11.2.1";
+pub const HELM: &str = "4.0.4";
+pub const HERDR: &str = "0.7.4";
+pub const HEXYL: &str = "0.16.0";
+pub const HUNK: &str = "0.16.0";
+pub const ICU: &str = "77.1";
+pub const IMAGE_LIBS: &str = "1.6.0";
+pub const JBANG: &str = "0.131.0";
+pub const JJ: &str = "0.37.0";
+pub const JLESS: &str = "0.9.0";
+pub const JQ: &str = "1.8.1";
+pub const JSON_C: &str = "0.18-20240915";
+pub const JUST: &str = "1.45.0";
+pub const K9S: &str = "0.50.18";
+pub const KIND: &str = "0.31.0";
+pub const KN: &str = "1.20.0";
+pub const KUBECTL: &str = "1.35.0";
+pub const KUBESEAL: &str = "0.34.0";
+pub const LAZYGIT: &str = "0.44.1";
+pub const LIBASSUAN: &str = "3.0.2";
+pub const LIBCAP: &str = "2.76";
+pub const LIBEVENT: &str = "2.1.12";
+pub const LIBFFI: &str = "3.5.2";
+pub const LIBGCRYPT: &str = "1.11.0";
+pub const LIBGPG_ERROR: &str = "1.56";
+pub const LIBJPEG_TURBO: &str = "3.1.1";
+pub const LIBKSBA: &str = "1.6.7";
+pub const LIBPCAP: &str = "1.10.5";
+pub const LIBPNG: &str = "1.6.50";
+pub const LIBSSH2: &str = "1.11.1";
+pub const LIBUV: &str = "1.52.0";
+pub const LIBWEBP: &str = "1.6.0";
+pub const LIBWEBSOCKETS: &str = "4.5.2";
+pub const LIBXML2: &str = "2.13.8";
+pub const LIBXSLT: &str = "1.1.43";
+pub const LIBYAML: &str = "0.2.5";
+pub const LIMA: &str = "2.0.3";
+pub const LUA_LANGUAGE_SERVER: &str = "3.18.2";
+pub const MBEDTLS: &str = "3.6.5";
+pub const MC: &str = "2025-08-13T08-35-41Z";
+pub const MINISIGN: &str = "0.12";
+pub const MONGOSH: &str = "2.5.8";
+pub const MTR: &str = "0.95";
+pub const MUSL: &str = "1.2.5";
+pub const NCDU: &str = "1.22";
+pub const NCURSES: &str = "6.5-20250809";
+pub const NEOVIM: &str = "0.12.3";
+pub const NGHTTP2: &str = "1.66.0";
+pub const NGINX: &str = "1.27.5";
+pub const NMAP: &str = "7.98";
+pub const NNN: &str = "5.1";
+pub const NODE_MATRIX_18: &str = "18.20.8";
+pub const NODE_MATRIX_20: &str = "20.19.5";
+pub const NODE_MATRIX_22: &str = "22.21.0";
+pub const NPTH: &str = "1.8";
+pub const ONIGURUMA: &str = "6.9.10";
+pub const OP: &str = "2.34.1";
+pub const OPENAPI_GENERATOR_CLI: &str = "7.18.0";
+pub const OPENCODE: &str = "1.17.11";
+pub const OPENJDK: &str = "25.0.1";
+pub const OPENJDK_RELEASE: &str = "2fbf10d8c78e40bd87641c434705079d/8";
+pub const OPENSSL: &str = "3.5.4";
+pub const PATCHELF: &str = "0.18.0";
+pub const PCRE2: &str = "10.45";
+pub const PI: &str = "0.80.2";
+pub const PKG_CONFIG: &str = "0.29.2";
+pub const PRE_COMMIT: &str = "4.3.0";
+pub const PROTOBUF: &str = "34.0";
+pub const PROTOC_GEN_GRPC_WEB: &str = "1.5.0";
+pub const PROTOC_PLUGINS: &str = "34.0";
+pub const QSV: &str = "7.1.0";
+pub const READLINE: &str = "8.2";
+pub const RIPGREP: &str = "14.1.1";
+pub const S5CMD: &str = "2.3.0";
+pub const SCCACHE: &str = "0.14.0";
+pub const SESH: &str = "2.24.2";
+pub const SKOPEO: &str = "1.21.0";
+pub const SQLITE3: &str = "3.51.2";
+pub const STARSHIP: &str = "1.25.1";
+pub const STEAMPIPE: &str = "2.1.0";
+pub const STOW: &str = "2.4.1";
+pub const TALOSCTL: &str = "1.13.4";
+pub const TCPDUMP: &str = "4.99.5";
+pub const TERRAFORM: &str = "1.13.1";
+pub const TERRAFORM_LS: &str = "0.38.7";
+pub const TINI: &str = "0.19.0";
+pub const TMUX: &str = "3.5a";
+pub const TREE_SITTER: &str = "0.26.9";
+pub const TTYD: &str = "1.7.7";
+pub const TYPESCRIPT: &str = "6.0.3";
+pub const TYPESCRIPT_LANGUAGE_SERVER: &str = "5.3.0";
+pub const UMOCI: &str = "0.6.0";
+pub const UNIVERSAL_CTAGS: &str = "6.1.0";
+pub const UPX: &str = "4.2.4";
+pub const UV: &str = "0.10.11";
+pub const VHS: &str = "0.10.0";
+pub const VIRTCTL: &str = "1.8.4";
+pub const VSCODE_LANGSERVERS_EXTRACTED: &str = "4.10.0";
+pub const WIREGUARD_GO: &str = "0.0.20250522";
+pub const WIREGUARD_TOOLS: &str = "1.0.20250521";
+pub const X264: &str = "20191217-2245-stable";
+pub const YAML_LANGUAGE_SERVER: &str = "1.23.0";
+pub const YQ: &str = "4.50.1";
+pub const ZIG: &str = "0.15.1";
+pub const ZLIB: &str = "1.3.1";
+pub const ZOXIDE: &str = "0.9.9";
+pub const ZSH: &str = "5.9";
+
+// Versions kept buildable next to the default one, oldest first, for projects that cannot move
+// yet; see `registry::get_pinned`.
+pub const PINNED: &[(&str, &str)] = &[("terraform", "1.5.7")];